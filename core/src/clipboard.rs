@@ -21,6 +21,21 @@ pub enum Kind {
     Primary,
 }
 
+/// Returns whether the current platform has a [`Kind::Primary`] clipboard.
+///
+/// Widgets use this to decide whether selecting text should copy it to the
+/// primary clipboard and whether a middle click should paste from it.
+pub fn has_primary() -> bool {
+    cfg!(all(
+        unix,
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "android"
+        ))
+    ))
+}
+
 /// A null implementation of the [`Clipboard`] trait.
 #[derive(Debug, Clone, Copy)]
 pub struct Null;
@@ -1,4 +1,6 @@
+use palette::color_difference::Wcag21RelativeContrast;
 use palette::rgb::{Srgb, Srgba};
+use palette::{FromColor, Hsl, Hsv, Mix, Oklch};
 
 /// A color in the `sRGB` color space.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -158,6 +160,119 @@ impl Color {
             ..self
         }
     }
+
+    /// Creates an opaque [`Color`] from its HSL components.
+    ///
+    /// The hue is in degrees, while the saturation and lightness must be
+    /// in the `[0.0, 1.0]` range.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        Srgb::from_color(Hsl::new(hue, saturation, lightness)).into()
+    }
+
+    /// Converts the [`Color`] into its HSL components, ignoring its alpha.
+    ///
+    /// The hue is returned in degrees, in the `[0.0, 360.0)` range.
+    pub fn into_hsl(self) -> [f32; 3] {
+        let hsl = Hsl::from_color(Srgb::from(self));
+
+        [
+            hsl.hue.into_positive_degrees(),
+            hsl.saturation,
+            hsl.lightness,
+        ]
+    }
+
+    /// Creates an opaque [`Color`] from its HSV components.
+    ///
+    /// The hue is in degrees, while the saturation and value must be
+    /// in the `[0.0, 1.0]` range.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        Srgb::from_color(Hsv::new(hue, saturation, value)).into()
+    }
+
+    /// Converts the [`Color`] into its HSV components, ignoring its alpha.
+    ///
+    /// The hue is returned in degrees, in the `[0.0, 360.0)` range.
+    pub fn into_hsv(self) -> [f32; 3] {
+        let hsv = Hsv::from_color(Srgb::from(self));
+
+        [hsv.hue.into_positive_degrees(), hsv.saturation, hsv.value]
+    }
+
+    /// Creates an opaque [`Color`] from its OKLCH components.
+    ///
+    /// The lightness must be in the `[0.0, 1.0]` range, the chroma is
+    /// usually in the `[0.0, 0.4]` range, and the hue is in degrees.
+    ///
+    /// Colors outside of the `sRGB` gamut are clamped.
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32) -> Color {
+        let rgb = Srgb::from_color(Oklch::new(lightness, chroma, hue));
+
+        Color::from_rgb(
+            rgb.red.clamp(0.0, 1.0),
+            rgb.green.clamp(0.0, 1.0),
+            rgb.blue.clamp(0.0, 1.0),
+        )
+    }
+
+    /// Converts the [`Color`] into its OKLCH components, ignoring its alpha.
+    ///
+    /// The hue is returned in degrees, in the `[0.0, 360.0)` range.
+    pub fn into_oklch(self) -> [f32; 3] {
+        let oklch = Oklch::from_color(Srgb::from(self));
+
+        [oklch.l, oklch.chroma, oklch.hue.into_positive_degrees()]
+    }
+
+    /// Mixes the [`Color`] with another one in linear space.
+    ///
+    /// A `factor` of `0.0` produces this [`Color`], while a `factor` of
+    /// `1.0` produces the other one.
+    pub fn mix(self, other: Color, factor: f32) -> Color {
+        let mixed = Srgba::from(self)
+            .into_linear()
+            .mix(Srgba::from(other).into_linear(), factor.clamp(0.0, 1.0));
+
+        Srgba::from_linear(mixed).into()
+    }
+
+    /// Increases the HSL lightness of the [`Color`] by the given amount.
+    pub fn lighten(self, amount: f32) -> Color {
+        let [hue, saturation, lightness] = self.into_hsl();
+
+        Color {
+            a: self.a,
+            ..Color::from_hsl(
+                hue,
+                saturation,
+                (lightness + amount).clamp(0.0, 1.0),
+            )
+        }
+    }
+
+    /// Decreases the HSL lightness of the [`Color`] by the given amount.
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Returns the relative luminance of the [`Color`], as defined by
+    /// [WCAG 2.1].
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(self) -> f32 {
+        Srgb::from(self).relative_luminance().luma
+    }
+
+    /// Returns the contrast ratio between the [`Color`] and another one,
+    /// as defined by [WCAG 2.1].
+    ///
+    /// The ratio ranges from `1.0` (no contrast) to `21.0` (black on white).
+    /// WCAG requires at least `4.5` for regular text and `3.0` for large text.
+    ///
+    /// [WCAG 2.1]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        Srgb::from(self).relative_contrast(Srgb::from(other))
+    }
 }
 
 impl From<[f32; 3]> for Color {
@@ -273,4 +388,29 @@ mod tests {
         assert_relative_eq!(result.b, 0.3);
         assert_relative_eq!(result.a, 1.0);
     }
+
+    #[test]
+    fn color_spaces() {
+        use approx::assert_relative_eq;
+
+        let color = Color::from_rgb(0.8, 0.3, 0.1);
+
+        let [h, s, l] = color.into_hsl();
+        let hsl = Color::from_hsl(h, s, l);
+        assert_relative_eq!(hsl.r, color.r, epsilon = 1e-5);
+        assert_relative_eq!(hsl.g, color.g, epsilon = 1e-5);
+        assert_relative_eq!(hsl.b, color.b, epsilon = 1e-5);
+
+        let [l, c, h] = color.into_oklch();
+        let oklch = Color::from_oklch(l, c, h);
+        assert_relative_eq!(oklch.r, color.r, epsilon = 1e-4);
+        assert_relative_eq!(oklch.g, color.g, epsilon = 1e-4);
+        assert_relative_eq!(oklch.b, color.b, epsilon = 1e-4);
+
+        assert_relative_eq!(
+            Color::BLACK.contrast_ratio(Color::WHITE),
+            21.0,
+            epsilon = 1e-4
+        );
+    }
 }
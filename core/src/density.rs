@@ -0,0 +1,138 @@
+//! Adapt the size of the built-in widgets to the input method of the user.
+//!
+//! Widgets read the [`Density`] from their [`Renderer`] when they are laid out
+//! and drawn.
+//!
+//! [`Renderer`]: crate::Renderer
+use crate::layout;
+use crate::{Padding, Size, Vector};
+
+/// The density of the built-in widgets.
+///
+/// It scales the default paddings and control sizes of widgets and, for
+/// [`Density::Touch`], enforces a minimum size for their hit targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// Smaller paddings and controls, for dense desktop interfaces.
+    Compact,
+    /// The regular size of paddings and controls.
+    #[default]
+    Comfortable,
+    /// Larger paddings and controls, with hit targets of at least
+    /// [`Density::MIN_TOUCH_TARGET`] logical pixels.
+    Touch,
+}
+
+impl Density {
+    /// The minimum size of a hit target in [`Density::Touch`] mode.
+    pub const MIN_TOUCH_TARGET: f32 = 44.0;
+
+    /// Returns the factor used to scale default paddings and control sizes.
+    pub fn scale(self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Touch => 1.5,
+        }
+    }
+
+    /// Returns the minimum width and height of a hit target.
+    pub fn min_target(self) -> f32 {
+        match self {
+            Density::Compact | Density::Comfortable => 0.0,
+            Density::Touch => Self::MIN_TOUCH_TARGET,
+        }
+    }
+
+    /// Scales the given default [`Padding`].
+    pub fn padding(self, padding: Padding) -> Padding {
+        let scale = self.scale();
+
+        Padding {
+            top: padding.top * scale,
+            right: padding.right * scale,
+            bottom: padding.bottom * scale,
+            left: padding.left * scale,
+        }
+    }
+
+    /// Expands the given [`layout::Node`] until both of its sides reach the
+    /// [`min_target`], keeping its children centered.
+    ///
+    /// The node never grows past the maximum size of the given
+    /// [`layout::Limits`].
+    ///
+    /// [`min_target`]: Self::min_target
+    pub fn expand(
+        self,
+        limits: &layout::Limits,
+        node: layout::Node,
+    ) -> layout::Node {
+        let bounds = node.bounds();
+        let max = limits.max();
+
+        let size = Size::new(
+            bounds.width.max(self.min_target().min(max.width)),
+            bounds.height.max(self.min_target().min(max.height)),
+        );
+
+        if size == bounds.size() {
+            return node;
+        }
+
+        let offset = Vector::new(
+            (size.width - bounds.width) / 2.0,
+            (size.height - bounds.height) / 2.0,
+        );
+
+        layout::Node::with_children(
+            size,
+            node.children()
+                .iter()
+                .cloned()
+                .map(|child| child.translate(offset))
+                .collect(),
+        )
+        .move_to(bounds.position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn touch_expands_both_axes() {
+        let node = layout::Node::with_children(
+            Size::new(20.0, 10.0),
+            vec![layout::Node::new(Size::new(20.0, 10.0))],
+        );
+
+        let node = Density::Touch.expand(&layout::Limits::NONE, node);
+
+        assert_eq!(node.size(), Size::new(44.0, 44.0));
+        assert_eq!(
+            node.children()[0].bounds().position(),
+            crate::Point::new(12.0, 17.0)
+        );
+    }
+
+    #[test]
+    fn expansion_is_limited() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(30.0, 100.0));
+        let node = layout::Node::new(Size::new(20.0, 10.0));
+
+        let node = Density::Touch.expand(&limits, node);
+
+        assert_eq!(node.size(), Size::new(30.0, 44.0));
+    }
+
+    #[test]
+    fn comfortable_keeps_size() {
+        let node = layout::Node::new(Size::new(20.0, 10.0));
+
+        let node = Density::Comfortable.expand(&layout::Limits::NONE, node);
+
+        assert_eq!(node.size(), Size::new(20.0, 10.0));
+    }
+}
@@ -0,0 +1,44 @@
+//! Mirror the directional visuals of the built-in widgets for right-to-left
+//! languages.
+use std::sync::atomic::{self, AtomicBool};
+
+/// The horizontal direction of the built-in widgets.
+///
+/// In [`Direction::RightToLeft`], widgets mirror their directional visuals;
+/// like the handle of a pick list, the vertical scrollbar of a scrollable,
+/// or the filled part of a slider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Widgets start on the left side and grow to the right.
+    #[default]
+    LeftToRight,
+    /// Widgets start on the right side and grow to the left.
+    RightToLeft,
+}
+
+static RIGHT_TO_LEFT: AtomicBool = AtomicBool::new(false);
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Direction::RightToLeft
+    }
+}
+
+/// Returns the current [`Direction`] of the built-in widgets.
+pub fn current() -> Direction {
+    if RIGHT_TO_LEFT.load(atomic::Ordering::Relaxed) {
+        Direction::RightToLeft
+    } else {
+        Direction::LeftToRight
+    }
+}
+
+/// Sets the current [`Direction`] of the built-in widgets.
+///
+/// Widgets read the current [`Direction`] when they are drawn, unless a
+/// direction has been set for them explicitly; so this is normally set
+/// once, before running an application.
+pub fn set(direction: Direction) {
+    RIGHT_TO_LEFT.store(direction.is_rtl(), atomic::Ordering::Relaxed);
+}
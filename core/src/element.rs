@@ -321,7 +321,8 @@ where
         viewport: &Rectangle,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages)
+            .with_received_at(shell.received_at());
 
         let status = self.widget.on_event(
             tree,
@@ -1,6 +1,7 @@
 //! Handle events of a user interface.
 use crate::keyboard;
 use crate::mouse;
+use crate::pen;
 use crate::touch;
 use crate::window;
 
@@ -23,6 +24,9 @@ pub enum Event {
 
     /// A touch event
     Touch(touch::Event),
+
+    /// A pen event
+    Pen(pen::Event),
 }
 
 /// The status of an [`Event`] after being processed.
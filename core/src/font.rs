@@ -12,6 +12,9 @@ pub struct Font {
     pub stretch: Stretch,
     /// The [`Style`] of the [`Font`].
     pub style: Style,
+    /// The families used, in order, for the characters missing in the main
+    /// [`Family`] of the [`Font`].
+    pub fallback: &'static [Family],
 }
 
 impl Font {
@@ -21,6 +24,7 @@ impl Font {
         weight: Weight::Normal,
         stretch: Stretch::Normal,
         style: Style::Normal,
+        fallback: &[],
     };
 
     /// A monospaced font with normal [`Weight`].
@@ -37,6 +41,18 @@ impl Font {
             ..Self::DEFAULT
         }
     }
+
+    /// Sets the fallback families of the [`Font`].
+    ///
+    /// Any character missing in the main [`Family`] is displayed with the
+    /// first family of the list that contains it; like a Latin font falling
+    /// back to a CJK font, and then to an emoji font.
+    pub const fn fallback(self, families: &'static [Family]) -> Self {
+        Font {
+            fallback: families,
+            ..self
+        }
+    }
 }
 
 /// A font family.
@@ -0,0 +1,72 @@
+//! Configure how built-in widgets recognize pointer gestures.
+use crate::time::Duration;
+
+use std::sync::RwLock;
+
+/// The thresholds used by the built-in widgets to recognize gestures, like
+/// double clicks, drags, and long presses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// The maximum time between two clicks of a double click.
+    pub double_click: Duration,
+
+    /// The distance, in logical pixels, that the pointer needs to travel
+    /// while pressed before a drag starts.
+    ///
+    /// It is also the maximum distance between two clicks of a double click.
+    pub drag: f32,
+
+    /// The time a press needs to be held to become a long press.
+    pub long_press: Duration,
+}
+
+impl Thresholds {
+    /// Returns the conventional [`Thresholds`] of the current platform.
+    pub const fn platform() -> Self {
+        if cfg!(target_os = "windows") {
+            Self {
+                double_click: Duration::from_millis(500),
+                drag: 4.0,
+                long_press: Duration::from_millis(500),
+            }
+        } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+            Self {
+                double_click: Duration::from_millis(500),
+                drag: 3.0,
+                long_press: Duration::from_millis(500),
+            }
+        } else if cfg!(target_os = "android") {
+            Self {
+                double_click: Duration::from_millis(300),
+                drag: 8.0,
+                long_press: Duration::from_millis(400),
+            }
+        } else {
+            Self {
+                double_click: Duration::from_millis(400),
+                drag: 8.0,
+                long_press: Duration::from_millis(500),
+            }
+        }
+    }
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self::platform()
+    }
+}
+
+static CURRENT: RwLock<Thresholds> = RwLock::new(Thresholds::platform());
+
+/// Returns the current [`Thresholds`] of the built-in widgets.
+pub fn current() -> Thresholds {
+    *CURRENT.read().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Sets the current [`Thresholds`] of the built-in widgets.
+///
+/// This is normally set once, before running an application.
+pub fn set(thresholds: Thresholds) {
+    *CURRENT.write().unwrap_or_else(|error| error.into_inner()) = thresholds;
+}
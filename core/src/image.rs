@@ -1,7 +1,7 @@
 //! Load and draw raster graphics.
 pub use bytes::Bytes;
 
-use crate::{Radians, Rectangle, Size};
+use crate::{Color, Radians, Rectangle, Size};
 
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
@@ -29,6 +29,11 @@ pub struct Image<H = Handle> {
     /// This can avoid graphical glitches, specially when using
     /// [`FilterMethod::Nearest`].
     pub snap: bool,
+
+    /// The color to tint the image with, if any.
+    ///
+    /// The color of every pixel of the image is multiplied by it.
+    pub tint: Option<Color>,
 }
 
 impl Image<Handle> {
@@ -40,6 +45,7 @@ impl Image<Handle> {
             rotation: Radians(0.0),
             opacity: 1.0,
             snap: false,
+            tint: None,
         }
     }
 
@@ -66,6 +72,18 @@ impl Image<Handle> {
         self.snap = snap;
         self
     }
+
+    /// Sets the tint color of the [`Image`].
+    pub fn tint(mut self, color: impl Into<Color>) -> Self {
+        self.tint = Some(color.into());
+        self
+    }
+}
+
+impl From<Handle> for Image {
+    fn from(handle: Handle) -> Self {
+        Image::new(handle)
+    }
 }
 
 impl From<&Handle> for Image {
@@ -3,6 +3,7 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod invariant;
 
 pub use limits::Limits;
 pub use node::Node;
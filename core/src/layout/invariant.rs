@@ -0,0 +1,191 @@
+//! Validate the invariants of a layout.
+//!
+//! A layout is valid when:
+//!
+//! - Every [`Node`] has finite, non-negative bounds.
+//! - The root [`Node`] respects the [`Limits`] it was laid out with.
+//! - Every child lies within the bounds of its parent, unless the parent
+//!   is explicitly [`overflowing`].
+//!
+//! [`overflowing`]: Node::overflowing
+use crate::layout::{Limits, Node};
+use crate::{Rectangle, Size};
+
+use std::fmt;
+
+/// The tolerance used to absorb floating point errors when comparing bounds.
+pub const TOLERANCE: f32 = 0.01;
+
+/// A broken invariant of a layout.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// A [`Node`] has bounds that are not finite or have a negative size.
+    InvalidBounds {
+        /// The indices of the children leading to the [`Node`] from the root.
+        path: Vec<usize>,
+        /// The bounds of the [`Node`].
+        bounds: Rectangle,
+    },
+    /// The root [`Node`] does not respect its [`Limits`].
+    OutOfLimits {
+        /// The [`Size`] of the root [`Node`].
+        size: Size,
+        /// The [`Limits`] the root [`Node`] was laid out with.
+        limits: Limits,
+    },
+    /// A child [`Node`] lies outside the bounds of its parent.
+    Overflow {
+        /// The indices of the children leading to the child from the root.
+        path: Vec<usize>,
+        /// The bounds of the child, relative to its parent.
+        bounds: Rectangle,
+        /// The [`Size`] of the parent.
+        parent: Size,
+    },
+}
+
+impl Violation {
+    /// Returns true if the [`Violation`] can never be produced by a correct
+    /// layout, regardless of how constrained it is.
+    ///
+    /// Overflow and out of limits violations may happen when the contents
+    /// of a widget do not fit in the available space; but invalid bounds are
+    /// always a bug.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Self::InvalidBounds { .. })
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBounds { path, bounds } => {
+                write!(f, "node {path:?} has invalid bounds: {bounds:?}")
+            }
+            Self::OutOfLimits { size, limits } => {
+                write!(
+                    f,
+                    "root node of size {size:?} does not respect {limits:?}"
+                )
+            }
+            Self::Overflow {
+                path,
+                bounds,
+                parent,
+            } => write!(
+                f,
+                "node {path:?} with bounds {bounds:?} overflows \
+                its parent of size {parent:?}"
+            ),
+        }
+    }
+}
+
+/// Checks the invariants of the given root [`Node`], laid out with the
+/// given [`Limits`], and returns all the broken ones.
+pub fn check(root: &Node, limits: &Limits) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let size = root.size();
+
+    if size.width < limits.min().width - TOLERANCE
+        || size.height < limits.min().height - TOLERANCE
+        || size.width > limits.max().width + TOLERANCE
+        || size.height > limits.max().height + TOLERANCE
+    {
+        violations.push(Violation::OutOfLimits {
+            size,
+            limits: *limits,
+        });
+    }
+
+    check_node(root, &mut Vec::new(), &mut violations);
+
+    violations
+}
+
+fn check_node(
+    node: &Node,
+    path: &mut Vec<usize>,
+    violations: &mut Vec<Violation>,
+) {
+    let bounds = node.bounds();
+
+    if !is_valid(bounds) {
+        violations.push(Violation::InvalidBounds {
+            path: path.clone(),
+            bounds,
+        });
+
+        return;
+    }
+
+    for (i, child) in node.children().iter().enumerate() {
+        path.push(i);
+
+        let child_bounds = child.bounds();
+
+        if !node.is_overflowing()
+            && is_valid(child_bounds)
+            && (child_bounds.x < -TOLERANCE
+                || child_bounds.y < -TOLERANCE
+                || child_bounds.x + child_bounds.width
+                    > bounds.width + TOLERANCE
+                || child_bounds.y + child_bounds.height
+                    > bounds.height + TOLERANCE)
+        {
+            violations.push(Violation::Overflow {
+                path: path.clone(),
+                bounds: child_bounds,
+                parent: bounds.size(),
+            });
+        }
+
+        check_node(child, path, violations);
+
+        let _ = path.pop();
+    }
+}
+
+fn is_valid(bounds: Rectangle) -> bool {
+    bounds.x.is_finite()
+        && bounds.y.is_finite()
+        && bounds.width.is_finite()
+        && bounds.height.is_finite()
+        && bounds.width >= 0.0
+        && bounds.height >= 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point, Size};
+
+    #[test]
+    fn overflowing_children_are_reported() {
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        let node = Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![
+                Node::new(Size::new(50.0, 50.0)).move_to(Point::new(60.0, 0.0))
+            ],
+        );
+
+        assert!(matches!(
+            check(&node, &limits).as_slice(),
+            [Violation::Overflow { path, .. }] if path == &[0]
+        ));
+
+        assert!(check(&node.overflowing(), &limits).is_empty());
+    }
+
+    #[test]
+    fn invalid_bounds_are_fatal() {
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let node = Node::new(Size::new(f32::NAN, 10.0));
+
+        let violations = check(&node, &limits);
+
+        assert!(violations.iter().any(Violation::is_fatal));
+    }
+}
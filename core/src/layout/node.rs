@@ -5,6 +5,7 @@ use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    is_overflowing: bool,
 }
 
 impl Node {
@@ -23,6 +24,7 @@ impl Node {
                 height: size.height,
             },
             children,
+            is_overflowing: false,
         }
     }
 
@@ -49,6 +51,20 @@ impl Node {
         &self.children
     }
 
+    /// Marks the [`Node`] as explicitly overflowing; that is, its children
+    /// are allowed to lie outside of its bounds.
+    ///
+    /// This is useful for widgets that clip or scroll their contents.
+    pub fn overflowing(mut self) -> Self {
+        self.is_overflowing = true;
+        self
+    }
+
+    /// Returns true if the [`Node`] is explicitly overflowing.
+    pub fn is_overflowing(&self) -> bool {
+        self.is_overflowing
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         mut self,
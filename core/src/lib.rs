@@ -12,8 +12,11 @@
 pub mod alignment;
 pub mod border;
 pub mod clipboard;
+pub mod density;
+pub mod direction;
 pub mod event;
 pub mod font;
+pub mod gesture;
 pub mod gradient;
 pub mod image;
 pub mod keyboard;
@@ -21,7 +24,9 @@ pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod padding;
+pub mod pen;
 pub mod renderer;
+pub mod style;
 pub mod svg;
 pub mod text;
 pub mod theme;
@@ -53,6 +58,8 @@ pub use border::Border;
 pub use clipboard::Clipboard;
 pub use color::Color;
 pub use content_fit::ContentFit;
+pub use density::Density;
+pub use direction::Direction;
 pub use element::Element;
 pub use event::Event;
 pub use font::Font;
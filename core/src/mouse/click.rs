@@ -1,4 +1,5 @@
 //! Track mouse clicks.
+use crate::gesture;
 use crate::time::Instant;
 use crate::Point;
 
@@ -73,9 +74,11 @@ impl Click {
             None
         };
 
-        self.position == new_position
+        let thresholds = gesture::current();
+
+        self.position.distance(new_position) <= thresholds.drag
             && duration
-                .map(|duration| duration.as_millis() <= 300)
+                .map(|duration| duration <= thresholds.double_click)
                 .unwrap_or(false)
     }
 }
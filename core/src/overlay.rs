@@ -1,9 +1,12 @@
 //! Display interactive elements on top of other widgets.
+pub mod placement;
+
 mod element;
 mod group;
 
 pub use element::Element;
 pub use group::Group;
+pub use placement::Placement;
 
 use crate::event::{self, Event};
 use crate::layout;
@@ -159,7 +159,8 @@ where
         shell: &mut Shell<'_, B>,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages)
+            .with_received_at(shell.received_at());
 
         let event_status = self.content.on_event(
             event,
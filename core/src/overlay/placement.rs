@@ -0,0 +1,280 @@
+//! Place overlays next to an anchor, avoiding the edges of the window.
+use crate::{Alignment, Pixels, Point, Rectangle, Size};
+
+/// The side of an anchor where an overlay is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    Bottom,
+    /// On the left of the anchor.
+    Left,
+    /// On the right of the anchor.
+    Right,
+}
+
+impl Side {
+    /// Returns the opposite [`Side`].
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// The strategy used to place an overlay next to an anchor.
+///
+/// An overlay is placed on its preferred [`Side`] of the anchor, aligned
+/// along it. When it collides with the edges of the viewport, a
+/// [`Placement`] can:
+///
+/// - flip the overlay to the opposite [`Side`], if there is more room there;
+/// - shift the overlay along the anchor until it is fully visible;
+/// - and limit its size to the available space, with [`Placement::limits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    /// The preferred [`Side`] of the anchor.
+    pub side: Side,
+    /// The alignment of the overlay along the [`Side`] of the anchor.
+    pub align: Alignment,
+    /// The distance between the anchor and the overlay.
+    pub gap: f32,
+    /// Whether the overlay may flip to the opposite [`Side`].
+    pub flip: bool,
+    /// Whether the overlay may shift along the anchor to stay inside the
+    /// viewport.
+    pub shift: bool,
+}
+
+impl Placement {
+    /// Creates a new [`Placement`] on the given [`Side`], centered and with
+    /// every collision fallback enabled.
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            align: Alignment::Center,
+            gap: 0.0,
+            flip: true,
+            shift: true,
+        }
+    }
+
+    /// Sets the alignment of the [`Placement`].
+    pub fn align(self, align: impl Into<Alignment>) -> Self {
+        Self {
+            align: align.into(),
+            ..self
+        }
+    }
+
+    /// Sets the gap of the [`Placement`].
+    pub fn gap(self, gap: impl Into<Pixels>) -> Self {
+        Self {
+            gap: gap.into().0,
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Placement`] may flip to the opposite [`Side`].
+    pub fn flip(self, flip: bool) -> Self {
+        Self { flip, ..self }
+    }
+
+    /// Sets whether the [`Placement`] may shift along the anchor.
+    pub fn shift(self, shift: bool) -> Self {
+        Self { shift, ..self }
+    }
+
+    /// Returns the maximum [`Size`] an overlay can have next to the anchor
+    /// without overflowing the viewport.
+    ///
+    /// Overlays that can shrink—like a scrollable list—should be laid out
+    /// within these limits.
+    pub fn limits(&self, anchor: Rectangle, viewport: Rectangle) -> Size {
+        let main = if self.flip {
+            self.space(self.side, anchor, viewport).max(self.space(
+                self.side.opposite(),
+                anchor,
+                viewport,
+            ))
+        } else {
+            self.space(self.side, anchor, viewport)
+        };
+
+        let cross = if self.side.is_vertical() {
+            viewport.width
+        } else {
+            viewport.height
+        };
+
+        if self.side.is_vertical() {
+            Size::new(cross, main)
+        } else {
+            Size::new(main, cross)
+        }
+    }
+
+    /// Resolves the bounds of an overlay of the given [`Size`] next to the
+    /// anchor, applying the collision fallbacks of the [`Placement`].
+    pub fn resolve(
+        &self,
+        anchor: Rectangle,
+        size: Size,
+        viewport: Rectangle,
+    ) -> Rectangle {
+        let side = self.side_for(anchor, size, viewport);
+        let mut bounds =
+            Rectangle::new(self.position(side, anchor, size), size);
+
+        if self.shift {
+            bounds.x = bounds
+                .x
+                .min(viewport.x + viewport.width - bounds.width)
+                .max(viewport.x);
+
+            bounds.y = bounds
+                .y
+                .min(viewport.y + viewport.height - bounds.height)
+                .max(viewport.y);
+        }
+
+        bounds
+    }
+
+    /// Returns the [`Side`] an overlay of the given [`Size`] ends up on.
+    pub fn side_for(
+        &self,
+        anchor: Rectangle,
+        size: Size,
+        viewport: Rectangle,
+    ) -> Side {
+        if !self.flip {
+            return self.side;
+        }
+
+        let extent = |side: Side| {
+            if side.is_vertical() {
+                size.height
+            } else {
+                size.width
+            }
+        };
+
+        let space = self.space(self.side, anchor, viewport);
+
+        if space >= extent(self.side) {
+            return self.side;
+        }
+
+        let opposite = self.side.opposite();
+
+        if self.space(opposite, anchor, viewport) > space {
+            opposite
+        } else {
+            self.side
+        }
+    }
+
+    fn space(&self, side: Side, anchor: Rectangle, viewport: Rectangle) -> f32 {
+        let space = match side {
+            Side::Top => anchor.y - viewport.y,
+            Side::Bottom => {
+                viewport.y + viewport.height - (anchor.y + anchor.height)
+            }
+            Side::Left => anchor.x - viewport.x,
+            Side::Right => {
+                viewport.x + viewport.width - (anchor.x + anchor.width)
+            }
+        };
+
+        (space - self.gap).max(0.0)
+    }
+
+    fn position(&self, side: Side, anchor: Rectangle, size: Size) -> Point {
+        let align = |start: f32, length: f32, extent: f32| match self.align {
+            Alignment::Start => start,
+            Alignment::Center => start + (length - extent) / 2.0,
+            Alignment::End => start + length - extent,
+        };
+
+        match side {
+            Side::Top => Point::new(
+                align(anchor.x, anchor.width, size.width),
+                anchor.y - self.gap - size.height,
+            ),
+            Side::Bottom => Point::new(
+                align(anchor.x, anchor.width, size.width),
+                anchor.y + anchor.height + self.gap,
+            ),
+            Side::Left => Point::new(
+                anchor.x - self.gap - size.width,
+                align(anchor.y, anchor.height, size.height),
+            ),
+            Side::Right => Point::new(
+                anchor.x + anchor.width + self.gap,
+                align(anchor.y, anchor.height, size.height),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn preferred_side() {
+        let placement = Placement::new(Side::Bottom).gap(5.0);
+        let anchor =
+            Rectangle::new(Point::new(40.0, 10.0), Size::new(20.0, 10.0));
+
+        assert_eq!(
+            placement.resolve(anchor, Size::new(10.0, 10.0), VIEWPORT),
+            Rectangle::new(Point::new(45.0, 25.0), Size::new(10.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn flips_when_out_of_room() {
+        let placement = Placement::new(Side::Bottom).gap(5.0);
+        let anchor =
+            Rectangle::new(Point::new(40.0, 80.0), Size::new(20.0, 10.0));
+        let size = Size::new(10.0, 20.0);
+
+        assert_eq!(placement.side_for(anchor, size, VIEWPORT), Side::Top);
+        assert_eq!(
+            placement.resolve(anchor, size, VIEWPORT),
+            Rectangle::new(Point::new(45.0, 55.0), size)
+        );
+        assert_eq!(placement.limits(anchor, VIEWPORT), Size::new(100.0, 75.0));
+    }
+
+    #[test]
+    fn shifts_inside_viewport() {
+        let placement = Placement::new(Side::Top)
+            .align(Alignment::Center)
+            .flip(false);
+        let anchor =
+            Rectangle::new(Point::new(90.0, 50.0), Size::new(10.0, 10.0));
+
+        assert_eq!(
+            placement.resolve(anchor, Size::new(30.0, 10.0), VIEWPORT),
+            Rectangle::new(Point::new(70.0, 40.0), Size::new(30.0, 10.0))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Handle pen and stylus events.
+use crate::{Point, Radians};
+
+/// A pen interaction.
+///
+/// Pens also produce regular [`touch::Event`]s, so widgets that are not
+/// interested in their extra state keep working. Each [`Event`] is delivered
+/// right before its equivalent [`touch::Event`].
+///
+/// [`touch::Event`]: crate::touch::Event
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum Event {
+    /// A pen touched the surface.
+    Pressed { id: Pen, sample: Sample },
+
+    /// A pen moved while touching the surface.
+    Moved { id: Pen, sample: Sample },
+
+    /// A pen was lifted from the surface.
+    Lifted { id: Pen, sample: Sample },
+
+    /// A pen interaction was canceled.
+    Lost { id: Pen, sample: Sample },
+}
+
+impl Event {
+    /// Returns the [`Sample`] of the [`Event`].
+    pub fn sample(&self) -> &Sample {
+        match self {
+            Event::Pressed { sample, .. }
+            | Event::Moved { sample, .. }
+            | Event::Lifted { sample, .. }
+            | Event::Lost { sample, .. } => sample,
+        }
+    }
+}
+
+/// A unique identifier representing a pen on a pen interaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pen(pub u64);
+
+/// The state of a pen at some point of an interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The position of the tip of the pen.
+    pub position: Point,
+
+    /// The pressure of the tip of the pen, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// The angle between the pen and the surface, if known.
+    ///
+    /// It is `0` when the pen lies flat and `π/2` when it is perpendicular to
+    /// the surface.
+    pub altitude: Option<Radians>,
+
+    /// The direction the pen is pointing to on the surface plane, if known.
+    ///
+    /// It is `0` when the pen points to the right and grows clockwise.
+    pub azimuth: Option<Radians>,
+
+    /// Whether the eraser end of the pen is being used.
+    pub is_eraser: bool,
+}
@@ -3,7 +3,8 @@
 mod null;
 
 use crate::{
-    Background, Border, Color, Rectangle, Shadow, Size, Transformation, Vector,
+    Background, Border, Color, Density, Font, Pixels, Rectangle, Shadow, Size,
+    Transformation, Vector,
 };
 
 /// A component that can be used by widgets to draw themselves on a screen.
@@ -61,6 +62,28 @@ pub trait Renderer {
 
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
+
+    /// Returns the [`Density`] of the built-in widgets drawn by the
+    /// [`Renderer`].
+    fn density(&self) -> Density {
+        Density::default()
+    }
+}
+
+/// A renderer that can draw without a window; for instance, to render
+/// offscreen images.
+pub trait Headless: Renderer + Sized {
+    /// Creates a new [`Headless`] renderer, if supported by the platform.
+    fn new(default_font: Font, default_text_size: Pixels) -> Option<Self>;
+
+    /// Draws the primitives recorded in the [`Renderer`] into a buffer of
+    /// RGBA pixels in the sRGB color space, with the given physical size.
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8>;
 }
 
 /// A polygon with four sides.
@@ -7,6 +7,8 @@ use crate::{
     Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
 
+use std::ops::Range;
+
 impl Renderer for () {
     fn start_layer(&mut self, _bounds: Rectangle) {}
 
@@ -26,6 +28,21 @@ impl Renderer for () {
     }
 }
 
+impl renderer::Headless for () {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Option<Self> {
+        None
+    }
+
+    fn screenshot(
+        &mut self,
+        _size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        Vec::new()
+    }
+}
+
 impl text::Renderer for () {
     type Font = Font;
     type Paragraph = ();
@@ -107,6 +124,10 @@ impl text::Paragraph for () {
         None
     }
 
+    fn hit_index(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
     fn hit_span(&self, _point: Point) -> Option<usize> {
         None
     }
@@ -114,6 +135,10 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn range_bounds(&self, _range: Range<usize>) -> Vec<Rectangle> {
+        vec![]
+    }
 }
 
 impl text::Editor for () {
@@ -133,6 +158,22 @@ impl text::Editor for () {
         (0, 0)
     }
 
+    fn range_bounds(
+        &self,
+        _start: (usize, usize),
+        _end: (usize, usize),
+    ) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn hit_test(&self, _point: Point) -> Option<(usize, usize)> {
+        None
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        vec![]
+    }
+
     fn selection(&self) -> Option<String> {
         None
     }
@@ -161,6 +202,7 @@ impl text::Editor for () {
         _new_font: Self::Font,
         _new_size: Pixels,
         _new_line_height: text::LineHeight,
+        _new_wrapping: text::Wrapping,
         _new_highlighter: &mut impl text::Highlighter,
     ) {
     }
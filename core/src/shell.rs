@@ -1,3 +1,4 @@
+use crate::time::Instant;
 use crate::window;
 
 /// A connection to the state of a shell.
@@ -12,6 +13,7 @@ pub struct Shell<'a, Message> {
     redraw_request: Option<window::RedrawRequest>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    received_at: Option<Instant>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -22,9 +24,30 @@ impl<'a, Message> Shell<'a, Message> {
             redraw_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            received_at: None,
         }
     }
 
+    /// Sets the [`Instant`] when the event processed with the [`Shell`]
+    /// entered the runtime.
+    ///
+    /// Widgets creating a local [`Shell`] should carry over the [`Instant`]
+    /// of their own [`Shell`].
+    pub fn with_received_at(mut self, received_at: Option<Instant>) -> Self {
+        self.received_at = received_at;
+        self
+    }
+
+    /// Returns the [`Instant`] when the event processed with the [`Shell`]
+    /// entered the runtime, if known.
+    ///
+    /// The events handled in the same update share the [`Instant`] of the
+    /// earliest input among them. Useful to measure the latency of reacting
+    /// to input.
+    pub fn received_at(&self) -> Option<Instant> {
+        self.received_at
+    }
+
     /// Returns true if the [`Shell`] contains no published messages
     pub fn is_empty(&self) -> bool {
         self.messages.is_empty()
@@ -36,6 +59,15 @@ impl<'a, Message> Shell<'a, Message> {
     }
 
     /// Requests a new frame to be drawn.
+    ///
+    /// Widgets can use this to drive their own animations: either for the
+    /// [`NextFrame`] or at a specific time with [`At`]. Once the time comes,
+    /// the widgets will receive a [`window::Event::RedrawRequested`].
+    ///
+    /// If multiple widgets request a redraw, the earliest request wins.
+    ///
+    /// [`NextFrame`]: window::RedrawRequest::NextFrame
+    /// [`At`]: window::RedrawRequest::At
     pub fn request_redraw(&mut self, request: window::RedrawRequest) {
         match self.redraw_request {
             None => {
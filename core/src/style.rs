@@ -0,0 +1,169 @@
+//! Compose and share the appearance of widgets.
+//!
+//! A [`Layer`] is a set of overrides for the properties shared by the
+//! appearances of most widgets—like their background, text color, border,
+//! or shadow. Since it is not tied to any particular widget, the same
+//! [`Layer`] can be applied to any appearance that is [`Layered`].
+//!
+//! Style functions can be stacked with [`Compose::then`] and
+//! [`Compose::with`], producing new style functions that can be passed to
+//! the `style` method of any widget with some `Status`.
+use crate::border;
+use crate::{Background, Border, Color, Pixels, Shadow};
+
+/// A set of overrides for the common properties of an appearance.
+///
+/// Properties that are `None` are left untouched when the [`Layer`] is
+/// applied.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Layer {
+    /// The [`Background`] override.
+    pub background: Option<Background>,
+    /// The text [`Color`] override.
+    pub text_color: Option<Color>,
+    /// The border [`Color`] override.
+    pub border_color: Option<Color>,
+    /// The border width override.
+    pub border_width: Option<f32>,
+    /// The border [`border::Radius`] override.
+    pub border_radius: Option<border::Radius>,
+    /// The [`Shadow`] override.
+    pub shadow: Option<Shadow>,
+}
+
+impl Layer {
+    /// Creates an empty [`Layer`], which overrides nothing.
+    pub const fn new() -> Self {
+        Self {
+            background: None,
+            text_color: None,
+            border_color: None,
+            border_width: None,
+            border_radius: None,
+            shadow: None,
+        }
+    }
+
+    /// Sets the [`Background`] override of the [`Layer`].
+    pub fn background(self, background: impl Into<Background>) -> Self {
+        Self {
+            background: Some(background.into()),
+            ..self
+        }
+    }
+
+    /// Sets the text [`Color`] override of the [`Layer`].
+    pub fn text_color(self, color: impl Into<Color>) -> Self {
+        Self {
+            text_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Sets the border [`Color`] override of the [`Layer`].
+    pub fn border_color(self, color: impl Into<Color>) -> Self {
+        Self {
+            border_color: Some(color.into()),
+            ..self
+        }
+    }
+
+    /// Sets the border width override of the [`Layer`].
+    pub fn width(self, width: impl Into<Pixels>) -> Self {
+        Self {
+            border_width: Some(width.into().0),
+            ..self
+        }
+    }
+
+    /// Sets the border [`border::Radius`] override of the [`Layer`].
+    pub fn rounded(self, radius: impl Into<border::Radius>) -> Self {
+        Self {
+            border_radius: Some(radius.into()),
+            ..self
+        }
+    }
+
+    /// Sets the [`Shadow`] override of the [`Layer`].
+    pub fn shadow(self, shadow: Shadow) -> Self {
+        Self {
+            shadow: Some(shadow),
+            ..self
+        }
+    }
+
+    /// Stacks the given [`Layer`] on top of this one.
+    ///
+    /// The overrides of `other` take precedence.
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            background: other.background.or(self.background),
+            text_color: other.text_color.or(self.text_color),
+            border_color: other.border_color.or(self.border_color),
+            border_width: other.border_width.or(self.border_width),
+            border_radius: other.border_radius.or(self.border_radius),
+            shadow: other.shadow.or(self.shadow),
+        }
+    }
+
+    /// Applies the border overrides of the [`Layer`] to the given [`Border`].
+    pub fn border(&self, border: Border) -> Border {
+        Border {
+            color: self.border_color.unwrap_or(border.color),
+            width: self.border_width.unwrap_or(border.width),
+            radius: self.border_radius.unwrap_or(border.radius),
+        }
+    }
+}
+
+/// An appearance that a [`Layer`] can be applied to.
+///
+/// Overrides for properties the appearance does not have are ignored.
+pub trait Layered: Sized {
+    /// Applies the given [`Layer`] to the appearance.
+    fn layer(self, layer: &Layer) -> Self;
+}
+
+/// The composition of style functions.
+///
+/// It is implemented for any style function that takes a `Theme` and
+/// some `Status`.
+pub trait Compose<Theme, Status, Style>:
+    Fn(&Theme, Status) -> Style + Sized
+{
+    /// Stacks the given style function on top of this one.
+    ///
+    /// The resulting style function produces the style of this one and
+    /// then passes it through `next`.
+    fn then(
+        self,
+        next: impl Fn(&Theme, Status, Style) -> Style,
+    ) -> impl Fn(&Theme, Status) -> Style
+    where
+        Status: Copy,
+    {
+        move |theme: &Theme, status: Status| {
+            next(theme, status, self(theme, status))
+        }
+    }
+
+    /// Stacks the [`Layer`] produced by the given function on top of this
+    /// style function.
+    fn with(
+        self,
+        layer: impl Fn(&Theme, Status) -> Layer,
+    ) -> impl Fn(&Theme, Status) -> Style
+    where
+        Status: Copy,
+        Style: Layered,
+    {
+        move |theme: &Theme, status: Status| {
+            self(theme, status).layer(&layer(theme, status))
+        }
+    }
+}
+
+impl<T, Theme, Status, Style> Compose<Theme, Status, Style> for T where
+    T: Fn(&Theme, Status) -> Style
+{
+}
@@ -61,6 +61,12 @@ impl Svg<Handle> {
     }
 }
 
+impl From<Handle> for Svg {
+    fn from(handle: Handle) -> Self {
+        Svg::new(handle)
+    }
+}
+
 impl From<&Handle> for Svg {
     fn from(handle: &Handle) -> Self {
         Svg::new(handle.clone())
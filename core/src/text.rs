@@ -2,6 +2,7 @@
 pub mod editor;
 pub mod highlighter;
 pub mod paragraph;
+pub mod spell;
 
 pub use editor::Editor;
 pub use highlighter::Highlighter;
@@ -9,7 +10,7 @@ pub use paragraph::Paragraph;
 
 use crate::alignment;
 use crate::{
-    Background, Border, Color, Padding, Pixels, Point, Rectangle, Size,
+    Background, Border, Color, Padding, Pixels, Point, Rectangle, Size, Vector,
 };
 
 use std::borrow::Cow;
@@ -41,11 +42,25 @@ pub struct Text<Content = String, Font = crate::Font> {
 
     /// The [`Shaping`] strategy of the [`Text`].
     pub shaping: Shaping,
+
+    /// The [`Justification`] of the [`Text`].
+    pub justification: Justification,
 }
 
 /// The shaping strategy of some text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Shaping {
+    /// [`Basic`] shaping for ASCII text, and [`Advanced`] shaping otherwise.
+    ///
+    /// This strategy keeps plain text cheap, while still falling back to your
+    /// system fonts for any emoji or complex scripts.
+    ///
+    /// This is the default.
+    ///
+    /// [`Basic`]: Self::Basic
+    /// [`Advanced`]: Self::Advanced
+    #[default]
+    Auto,
     /// No shaping and no font fallback.
     ///
     /// This shaping strategy is very cheap, but it will not display complex
@@ -53,9 +68,6 @@ pub enum Shaping {
     ///
     /// You should use this strategy when you have complete control of the text
     /// and the font you are displaying in your application.
-    ///
-    /// This is the default.
-    #[default]
     Basic,
     /// Advanced text shaping and font fallback.
     ///
@@ -67,6 +79,73 @@ pub enum Shaping {
     Advanced,
 }
 
+/// The justification of the lines of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Justification {
+    /// Lines keep their natural width.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// Lines are stretched to fill the width of the bounds, except for the
+    /// last line of every paragraph.
+    ///
+    /// Words are never hyphenated; lines only break between words.
+    Full,
+}
+
+/// The antialiasing strategy used to rasterize the glyphs of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Antialiasing {
+    /// Glyphs are smoothed with the coverage of whole pixels.
+    ///
+    /// This is the default.
+    #[default]
+    Grayscale,
+    /// Glyphs are smoothed with the coverage of the red, green, and blue
+    /// subpixels of horizontal RGB displays; sharpening small text on
+    /// standard-DPI monitors.
+    ///
+    /// Text is still drawn in grayscale over transparent backgrounds and
+    /// when it is rotated.
+    Subpixel,
+}
+
+/// The outline drawn around the glyphs of some text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outline {
+    /// The [`Color`] of the [`Outline`].
+    pub color: Color,
+    /// The width of the [`Outline`], outside of the glyphs.
+    pub width: f32,
+}
+
+/// The drop shadow of some text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The [`Color`] of the [`Shadow`].
+    pub color: Color,
+    /// The offset of the [`Shadow`] from the text.
+    pub offset: Vector,
+}
+
+/// The wrapping strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrapping {
+    /// No wrapping; lines longer than the available width are clipped.
+    None,
+    /// Wraps at the word level.
+    ///
+    /// This is the default.
+    #[default]
+    Word,
+    /// Wraps at the glyph level.
+    Glyph,
+    /// Wraps at the word level, or falls back to the glyph level if a word
+    /// does not fit on a line by itself.
+    WordOrGlyph,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -1,6 +1,6 @@
 //! Edit text.
 use crate::text::highlighter::{self, Highlighter};
-use crate::text::LineHeight;
+use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::sync::Arc;
@@ -24,6 +24,26 @@ pub trait Editor: Sized + Default {
     /// Line and column, respectively.
     fn cursor_position(&self) -> (usize, usize);
 
+    /// Returns all the bounds of the text between the given cursor positions
+    /// of the [`Editor`], relative to its current scroll offset.
+    ///
+    /// Positions are given as line and column, respectively.
+    fn range_bounds(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) -> Vec<Rectangle>;
+
+    /// Returns the cursor position of the [`Editor`] closest to the given
+    /// [`Point`], relative to its current scroll offset, if any.
+    ///
+    /// Line and column, respectively.
+    fn hit_test(&self, point: Point) -> Option<(usize, usize)>;
+
+    /// Returns the index and the vertical offset of every line of the
+    /// [`Editor`] currently in view, relative to its current scroll offset.
+    fn visible_lines(&self) -> Vec<(usize, f32)>;
+
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
@@ -50,6 +70,7 @@ pub trait Editor: Sized + Default {
         new_font: Self::Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     );
 
@@ -75,6 +96,16 @@ pub enum Action {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Select the text between the given cursor positions, scrolling
+    /// the end of the selection into view.
+    ///
+    /// Positions are given as line and column, respectively.
+    SelectRange {
+        /// The start of the selection.
+        start: (usize, usize),
+        /// The end of the selection.
+        end: (usize, usize),
+    },
     /// Perform an [`Edit`].
     Edit(Edit),
     /// Click the [`Editor`] at the given [`Point`].
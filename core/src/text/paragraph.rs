@@ -3,6 +3,8 @@ use crate::alignment;
 use crate::text::{Difference, Hit, Span, Text};
 use crate::{Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -37,6 +39,23 @@ pub trait Paragraph: Sized + Default {
     /// [`Paragraph`], returning information about the nearest character.
     fn hit_test(&self, point: Point) -> Option<Hit>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the byte index of the contents nearest to it.
+    ///
+    /// Unlike [`hit_test`](Self::hit_test), the index is relative to the
+    /// start of the whole contents; not to the start of the line that was
+    /// hit.
+    fn hit_index(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of the glyph at the given byte index of the
+    /// contents of the [`Paragraph`], if it is laid out.
+    ///
+    /// Characters joined into a single glyph, like ligatures, share the same
+    /// bounds.
+    fn character_bounds(&self, index: usize) -> Option<Rectangle> {
+        self.range_bounds(index..index + 1).into_iter().next()
+    }
+
     /// Tests whether the provided point is within the boundaries of a
     /// [`Span`] in the [`Paragraph`], returning the index of the [`Span`]
     /// that was hit.
@@ -46,6 +65,10 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Returns all bounds for the provided byte range of the contents of the
+    /// [`Paragraph`]. A range can have multiple bounds for each line it's on.
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
@@ -95,6 +118,7 @@ impl<P: Paragraph> Plain<P> {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            justification: text.justification,
         }) {
             Difference::None => {}
             Difference::Bounds => {
@@ -128,6 +152,18 @@ impl<P: Paragraph> Plain<P> {
         self.raw.min_width()
     }
 
+    /// Returns the byte index of the contents of the [`Paragraph`] nearest
+    /// to the given point, if it is within its boundaries.
+    pub fn hit_index(&self, point: Point) -> Option<usize> {
+        self.raw.hit_index(point)
+    }
+
+    /// Returns the bounds of the glyph at the given byte index of the
+    /// contents of the [`Paragraph`], if it is laid out.
+    pub fn character_bounds(&self, index: usize) -> Option<Rectangle> {
+        self.raw.character_bounds(index)
+    }
+
     /// Returns the cached [`Paragraph`].
     pub fn raw(&self) -> &P {
         &self.raw
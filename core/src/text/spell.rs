@@ -0,0 +1,96 @@
+//! Check the spelling of text.
+use std::ops::Range;
+
+/// A spell checker that can be plugged into text editing widgets.
+///
+/// Implementors usually wrap a dictionary owned by the application.
+pub trait Checker {
+    /// Returns true if the given word is spelled correctly.
+    fn check(&self, word: &str) -> bool;
+
+    /// Returns a list of replacement suggestions for the given word,
+    /// ordered from most to least likely.
+    fn suggest(&self, word: &str) -> Vec<String>;
+}
+
+/// Returns the byte ranges of the words in the given text.
+///
+/// A word is a run of alphanumeric characters, possibly joined by
+/// apostrophes (e.g. `don't`).
+pub fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut chars = text.char_indices().peekable();
+
+    std::iter::from_fn(move || {
+        let (start, _) = chars.find(|(_, c)| c.is_alphanumeric())?;
+        let mut end = text.len();
+
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_alphanumeric() {
+                let _ = chars.next();
+            } else if is_apostrophe(c)
+                && text[i + c.len_utf8()..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphanumeric)
+            {
+                let _ = chars.next();
+            } else {
+                end = i;
+                break;
+            }
+        }
+
+        Some(start..end)
+    })
+}
+
+/// Returns the byte ranges of the words in the given text that the
+/// [`Checker`] considers misspelled.
+pub fn misspellings<'a>(
+    checker: &'a dyn Checker,
+    text: &'a str,
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    words(text).filter(move |range| !checker.check(&text[range.clone()]))
+}
+
+/// Returns the byte range of the word in the given text that contains
+/// the given byte index, if any.
+pub fn word_at(text: &str, index: usize) -> Option<Range<usize>> {
+    words(text).find(|range| range.start <= index && index <= range.end)
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_split() {
+        let text = "Hello, world! don't stop";
+
+        let words: Vec<_> = words(text).map(|range| &text[range]).collect();
+
+        assert_eq!(words, ["Hello", "world", "don't", "stop"]);
+    }
+
+    #[test]
+    fn trailing_apostrophes_are_not_words() {
+        let text = "dogs' 'quoted'";
+
+        let words: Vec<_> = words(text).map(|range| &text[range]).collect();
+
+        assert_eq!(words, ["dogs", "quoted"]);
+    }
+
+    #[test]
+    fn word_at_cursor() {
+        let text = "foo bar";
+
+        assert_eq!(word_at(text, 0), Some(0..3));
+        assert_eq!(word_at(text, 3), Some(0..3));
+        assert_eq!(word_at(text, 5), Some(4..7));
+    }
+}
@@ -1,7 +1,11 @@
 //! Use the built-in theme and styles.
+pub mod editor;
 pub mod palette;
+pub mod role;
 
+pub use editor::Editor;
 pub use palette::Palette;
+pub use role::Role;
 
 use std::fmt;
 use std::sync::Arc;
@@ -0,0 +1,101 @@
+//! Tweak the colors of a [`Theme`] while an application is running.
+use crate::theme::palette::{self, Palette};
+use crate::theme::role::Role;
+use crate::theme::Theme;
+use crate::Color;
+
+use std::collections::BTreeMap;
+
+/// An editor of the colors of a [`Theme`].
+///
+/// It starts from an existing [`Theme`] and keeps track of the colors of
+/// every [`Role`] that has been overridden, so they can be listed, reset,
+/// and exported as a new custom [`Theme`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Editor {
+    name: String,
+    palette: Palette,
+    extended: palette::Extended,
+    overrides: BTreeMap<Role, Color>,
+}
+
+impl Editor {
+    /// Creates a new [`Editor`] with the given name, starting from
+    /// the colors of the given [`Theme`].
+    pub fn new(name: impl Into<String>, theme: &Theme) -> Self {
+        Self {
+            name: name.into(),
+            palette: theme.palette(),
+            extended: *theme.extended_palette(),
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the name of the [`Theme`] being edited.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the base [`Palette`] of the [`Theme`] being edited.
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    /// Replaces the base [`Palette`] of the [`Theme`] being edited.
+    ///
+    /// The colors of every [`Role`] are generated again from the new
+    /// [`Palette`], but overridden colors are kept.
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+        self.extended = palette::Extended::generate(palette);
+    }
+
+    /// Returns the current [`Color`] of the given [`Role`].
+    pub fn get(&self, role: Role) -> Color {
+        self.overrides
+            .get(&role)
+            .copied()
+            .unwrap_or_else(|| role.get(&self.extended))
+    }
+
+    /// Overrides the [`Color`] of the given [`Role`].
+    pub fn set(&mut self, role: Role, color: Color) {
+        let _ = self.overrides.insert(role, color);
+    }
+
+    /// Resets the [`Color`] of the given [`Role`] to the one generated
+    /// from the base [`Palette`].
+    pub fn reset(&mut self, role: Role) {
+        let _ = self.overrides.remove(&role);
+    }
+
+    /// Returns true if the [`Color`] of the given [`Role`] is overridden.
+    pub fn is_overridden(&self, role: Role) -> bool {
+        self.overrides.contains_key(&role)
+    }
+
+    /// Returns an iterator over all the overridden roles and their colors.
+    pub fn overrides(&self) -> impl Iterator<Item = (Role, Color)> + '_ {
+        self.overrides.iter().map(|(role, color)| (*role, *color))
+    }
+
+    /// Returns the [`palette::Extended`] with all the overrides applied.
+    pub fn extended_palette(&self) -> palette::Extended {
+        let mut extended = self.extended;
+
+        for (role, color) in self.overrides() {
+            role.set(&mut extended, color);
+        }
+
+        extended
+    }
+
+    /// Exports the edited colors as a new custom [`Theme`].
+    pub fn theme(&self) -> Theme {
+        let extended = self.extended_palette();
+
+        Theme::custom_with_fn(self.name.clone(), self.palette, move |_| {
+            extended
+        })
+    }
+}
@@ -4,7 +4,6 @@ use crate::{color, Color};
 use once_cell::sync::Lazy;
 use palette::color_difference::Wcag21RelativeContrast;
 use palette::rgb::Rgb;
-use palette::{FromColor, Hsl, Mix};
 
 /// A color palette.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -287,6 +286,106 @@ impl Palette {
         success: color!(0xb1b695),
         danger: color!(0xe06b75),
     };
+
+    /// Generates a readable [`Palette`] from a single accent [`Color`].
+    ///
+    /// The background and text colors are neutrals slightly tinted with the
+    /// hue of the accent, while the success and danger colors are green and
+    /// red with a lightness and chroma similar to the accent.
+    pub fn from_accent(accent: Color, is_dark: bool) -> Self {
+        let [lightness, chroma, hue] = accent.into_oklch();
+
+        let (background, text) = if is_dark {
+            (
+                Color::from_oklch(0.22, 0.02, hue),
+                Color::from_oklch(0.94, 0.01, hue),
+            )
+        } else {
+            (
+                Color::from_oklch(0.98, 0.01, hue),
+                Color::from_oklch(0.20, 0.02, hue),
+            )
+        };
+
+        let lightness = lightness.clamp(0.5, 0.75);
+        let chroma = chroma.clamp(0.1, 0.2);
+
+        Self {
+            background,
+            text,
+            primary: accent,
+            success: Color::from_oklch(lightness, chroma, 145.0),
+            danger: Color::from_oklch(lightness, chroma, 25.0),
+        }
+    }
+
+    /// Generates a [`Palette`] from the dominant colors of an image, given
+    /// its pixels in RGBA8 format.
+    ///
+    /// The most common saturated color becomes the accent of the
+    /// [`Palette`], which will be dark if the image is mostly dark.
+    ///
+    /// Returns `None` if the image has no opaque pixels.
+    pub fn from_pixels(pixels: &[u8]) -> Option<Self> {
+        const BUCKETS: usize = 16;
+        const MAX_SAMPLES: usize = 100_000;
+
+        #[derive(Clone, Copy, Default)]
+        struct Bucket {
+            weight: f32,
+            sum: [f32; 3],
+        }
+
+        let mut buckets = vec![Bucket::default(); BUCKETS * BUCKETS * BUCKETS];
+        let mut luminance = 0.0;
+        let mut samples = 0;
+
+        let step = (pixels.len() / 4 / MAX_SAMPLES).max(1);
+
+        for pixel in pixels.chunks_exact(4).step_by(step) {
+            if pixel[3] < 128 {
+                continue;
+            }
+
+            let color = Color::from_rgb8(pixel[0], pixel[1], pixel[2]);
+            let [_lightness, chroma, _hue] = color.into_oklch();
+
+            luminance += color.relative_luminance();
+            samples += 1;
+
+            let index = |channel: u8| usize::from(channel) * BUCKETS / 256;
+
+            let bucket = &mut buckets[index(pixel[0]) * BUCKETS * BUCKETS
+                + index(pixel[1]) * BUCKETS
+                + index(pixel[2])];
+
+            // Saturated colors make better accents than grays
+            let weight = 0.05 + chroma;
+
+            bucket.weight += weight;
+            bucket.sum[0] += color.r * weight;
+            bucket.sum[1] += color.g * weight;
+            bucket.sum[2] += color.b * weight;
+        }
+
+        if samples == 0 {
+            return None;
+        }
+
+        let dominant = buckets
+            .iter()
+            .max_by(|a, b| a.weight.total_cmp(&b.weight))?;
+
+        let accent = Color::from_rgb(
+            dominant.sum[0] / dominant.weight,
+            dominant.sum[1] / dominant.weight,
+            dominant.sum[2] / dominant.weight,
+        );
+
+        let is_dark = luminance / (samples as f32) < 0.18;
+
+        Some(Self::from_accent(accent, is_dark))
+    }
 }
 
 /// An extended set of colors generated from a [`Palette`].
@@ -458,8 +557,8 @@ pub struct Background {
 impl Background {
     /// Generates a set of [`Background`] colors from the base and text colors.
     pub fn new(base: Color, text: Color) -> Self {
-        let weak = mix(base, text, 0.15);
-        let strong = mix(base, text, 0.40);
+        let weak = base.mix(text, 0.15);
+        let strong = base.mix(text, 0.40);
 
         Self {
             base: Pair::new(base, text),
@@ -483,7 +582,7 @@ pub struct Primary {
 impl Primary {
     /// Generates a set of [`Primary`] colors from the base, background, and text colors.
     pub fn generate(base: Color, background: Color, text: Color) -> Self {
-        let weak = mix(base, background, 0.4);
+        let weak = base.mix(background, 0.4);
         let strong = deviate(base, 0.1);
 
         Self {
@@ -508,9 +607,9 @@ pub struct Secondary {
 impl Secondary {
     /// Generates a set of [`Secondary`] colors from the base and text colors.
     pub fn generate(base: Color, text: Color) -> Self {
-        let base = mix(base, text, 0.2);
-        let weak = mix(base, text, 0.1);
-        let strong = mix(base, text, 0.3);
+        let base = base.mix(text, 0.2);
+        let weak = base.mix(text, 0.1);
+        let strong = base.mix(text, 0.3);
 
         Self {
             base: Pair::new(base, text),
@@ -534,7 +633,7 @@ pub struct Success {
 impl Success {
     /// Generates a set of [`Success`] colors from the base, background, and text colors.
     pub fn generate(base: Color, background: Color, text: Color) -> Self {
-        let weak = mix(base, background, 0.4);
+        let weak = base.mix(background, 0.4);
         let strong = deviate(base, 0.1);
 
         Self {
@@ -559,7 +658,7 @@ pub struct Danger {
 impl Danger {
     /// Generates a set of [`Danger`] colors from the base, background, and text colors.
     pub fn generate(base: Color, background: Color, text: Color) -> Self {
-        let weak = mix(base, background, 0.4);
+        let weak = base.mix(background, 0.4);
         let strong = deviate(base, 0.1);
 
         Self {
@@ -570,52 +669,20 @@ impl Danger {
     }
 }
 
-fn darken(color: Color, amount: f32) -> Color {
-    let mut hsl = to_hsl(color);
-
-    hsl.lightness = if hsl.lightness - amount < 0.0 {
-        0.0
-    } else {
-        hsl.lightness - amount
-    };
-
-    from_hsl(hsl)
-}
-
-fn lighten(color: Color, amount: f32) -> Color {
-    let mut hsl = to_hsl(color);
-
-    hsl.lightness = if hsl.lightness + amount > 1.0 {
-        1.0
-    } else {
-        hsl.lightness + amount
-    };
-
-    from_hsl(hsl)
-}
-
 fn deviate(color: Color, amount: f32) -> Color {
     if is_dark(color) {
-        lighten(color, amount)
+        color.lighten(amount)
     } else {
-        darken(color, amount)
+        color.darken(amount)
     }
 }
 
-fn mix(a: Color, b: Color, factor: f32) -> Color {
-    let a_lin = Rgb::from(a).into_linear();
-    let b_lin = Rgb::from(b).into_linear();
-
-    let mixed = a_lin.mix(b_lin, factor);
-    Rgb::from_linear(mixed).into()
-}
-
 fn readable(background: Color, text: Color) -> Color {
     if is_readable(background, text) {
         text
     } else {
-        let white_contrast = relative_contrast(background, Color::WHITE);
-        let black_contrast = relative_contrast(background, Color::BLACK);
+        let white_contrast = background.contrast_ratio(Color::WHITE);
+        let black_contrast = background.contrast_ratio(Color::BLACK);
 
         if white_contrast >= black_contrast {
             Color::WHITE
@@ -626,7 +693,9 @@ fn readable(background: Color, text: Color) -> Color {
 }
 
 fn is_dark(color: Color) -> bool {
-    to_hsl(color).lightness < 0.6
+    let [_hue, _saturation, lightness] = color.into_hsl();
+
+    lightness < 0.6
 }
 
 fn is_readable(a: Color, b: Color) -> bool {
@@ -635,18 +704,3 @@ fn is_readable(a: Color, b: Color) -> bool {
 
     a_srgb.has_enhanced_contrast_text(b_srgb)
 }
-
-fn relative_contrast(a: Color, b: Color) -> f32 {
-    let a_srgb = Rgb::from(a);
-    let b_srgb = Rgb::from(b);
-
-    a_srgb.relative_contrast(b_srgb)
-}
-
-fn to_hsl(color: Color) -> Hsl {
-    Hsl::from_color(Rgb::from(color))
-}
-
-fn from_hsl(hsl: Hsl) -> Color {
-    Rgb::from_color(hsl).into()
-}
@@ -0,0 +1,183 @@
+//! Enumerate the colors of a [`palette::Extended`].
+//!
+//! Every built-in widget style is derived from the colors of an extended
+//! palette. Therefore, a [`Role`] uniquely identifies a color that can be
+//! tweaked to change the appearance of the built-in widgets.
+use crate::theme::palette;
+use crate::Color;
+
+use std::fmt;
+
+/// A color of a [`palette::Extended`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Role {
+    /// The [`Group`] of the [`Role`].
+    pub group: Group,
+    /// The [`Shade`] of the [`Role`].
+    pub shade: Shade,
+    /// Whether the [`Role`] is the text color of its [`palette::Pair`].
+    pub is_text: bool,
+}
+
+/// A set of colors of a [`palette::Extended`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Group {
+    /// The [`palette::Background`] colors.
+    Background,
+    /// The [`palette::Primary`] colors.
+    Primary,
+    /// The [`palette::Secondary`] colors.
+    Secondary,
+    /// The [`palette::Success`] colors.
+    Success,
+    /// The [`palette::Danger`] colors.
+    Danger,
+}
+
+impl Group {
+    /// All of the groups of a [`palette::Extended`].
+    pub const ALL: &'static [Self] = &[
+        Self::Background,
+        Self::Primary,
+        Self::Secondary,
+        Self::Success,
+        Self::Danger,
+    ];
+}
+
+/// A [`palette::Pair`] of a [`Group`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shade {
+    /// The base pair.
+    Base,
+    /// The weak pair.
+    Weak,
+    /// The strong pair.
+    Strong,
+}
+
+impl Shade {
+    /// All of the shades of a [`Group`].
+    pub const ALL: &'static [Self] = &[Self::Base, Self::Weak, Self::Strong];
+}
+
+impl Role {
+    /// Returns an iterator over all the roles of a [`palette::Extended`].
+    pub fn all() -> impl Iterator<Item = Self> {
+        Group::ALL.iter().flat_map(|&group| {
+            Shade::ALL.iter().flat_map(move |&shade| {
+                [false, true].into_iter().map(move |is_text| Self {
+                    group,
+                    shade,
+                    is_text,
+                })
+            })
+        })
+    }
+
+    /// Returns the [`Color`] of the [`Role`] in the given palette.
+    pub fn get(self, palette: &palette::Extended) -> Color {
+        let pair = self.pair(palette);
+
+        if self.is_text {
+            pair.text
+        } else {
+            pair.color
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Role`] in the given palette.
+    pub fn set(self, palette: &mut palette::Extended, color: Color) {
+        let pair = self.pair_mut(palette);
+
+        if self.is_text {
+            pair.text = color;
+        } else {
+            pair.color = color;
+        }
+    }
+
+    fn pair(self, palette: &palette::Extended) -> &palette::Pair {
+        let (base, weak, strong) = match self.group {
+            Group::Background => {
+                let colors = &palette.background;
+                (&colors.base, &colors.weak, &colors.strong)
+            }
+            Group::Primary => {
+                let colors = &palette.primary;
+                (&colors.base, &colors.weak, &colors.strong)
+            }
+            Group::Secondary => {
+                let colors = &palette.secondary;
+                (&colors.base, &colors.weak, &colors.strong)
+            }
+            Group::Success => {
+                let colors = &palette.success;
+                (&colors.base, &colors.weak, &colors.strong)
+            }
+            Group::Danger => {
+                let colors = &palette.danger;
+                (&colors.base, &colors.weak, &colors.strong)
+            }
+        };
+
+        match self.shade {
+            Shade::Base => base,
+            Shade::Weak => weak,
+            Shade::Strong => strong,
+        }
+    }
+
+    fn pair_mut(self, palette: &mut palette::Extended) -> &mut palette::Pair {
+        let (base, weak, strong) = match self.group {
+            Group::Background => {
+                let colors = &mut palette.background;
+                (&mut colors.base, &mut colors.weak, &mut colors.strong)
+            }
+            Group::Primary => {
+                let colors = &mut palette.primary;
+                (&mut colors.base, &mut colors.weak, &mut colors.strong)
+            }
+            Group::Secondary => {
+                let colors = &mut palette.secondary;
+                (&mut colors.base, &mut colors.weak, &mut colors.strong)
+            }
+            Group::Success => {
+                let colors = &mut palette.success;
+                (&mut colors.base, &mut colors.weak, &mut colors.strong)
+            }
+            Group::Danger => {
+                let colors = &mut palette.danger;
+                (&mut colors.base, &mut colors.weak, &mut colors.strong)
+            }
+        };
+
+        match self.shade {
+            Shade::Base => base,
+            Shade::Weak => weak,
+            Shade::Strong => strong,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = match self.group {
+            Group::Background => "background",
+            Group::Primary => "primary",
+            Group::Secondary => "secondary",
+            Group::Success => "success",
+            Group::Danger => "danger",
+        };
+
+        let shade = match self.shade {
+            Shade::Base => "base",
+            Shade::Weak => "weak",
+            Shade::Strong => "strong",
+        };
+
+        let part = if self.is_text { "text" } else { "color" };
+
+        write!(f, "{group}.{shade}.{part}")
+    }
+}
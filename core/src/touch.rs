@@ -1,4 +1,7 @@
 //! Build touch events.
+use crate::gesture;
+use crate::mouse;
+use crate::time::Instant;
 use crate::Point;
 
 /// A touch interaction.
@@ -21,3 +24,105 @@ pub enum Event {
 /// A unique identifier representing a finger on a touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Finger(pub u64);
+
+/// A recognizer of long presses.
+///
+/// A single finger held down without moving for the
+/// [`gesture::Thresholds::long_press`] duration is turned into a secondary
+/// click: the touch interaction is lost, and the right mouse button is
+/// pressed and released at its position. This way, context menus work on
+/// touch screens out of the box.
+#[derive(Debug, Clone, Default)]
+pub struct LongPress {
+    pending: Option<(Finger, Point, Instant)>,
+    recognized: Option<Finger>,
+}
+
+impl LongPress {
+    /// Processes a touch [`Event`] and returns whether it should still be
+    /// delivered.
+    ///
+    /// The rest of a touch interaction recognized as a long press is
+    /// discarded.
+    pub fn update(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::FingerPressed { id, position } => {
+                // Pressing more than one finger cancels the gesture
+                self.pending =
+                    if self.pending.is_none() && self.recognized.is_none() {
+                        Some((id, position, Instant::now()))
+                    } else {
+                        None
+                    };
+
+                true
+            }
+            Event::FingerMoved { id, position } => {
+                if self.recognized == Some(id) {
+                    return false;
+                }
+
+                if let Some((finger, origin, _)) = self.pending {
+                    if finger == id
+                        && position.distance(origin) > gesture::current().drag
+                    {
+                        self.pending = None;
+                    }
+                }
+
+                true
+            }
+            Event::FingerLifted { id, .. } | Event::FingerLost { id, .. } => {
+                if self.recognized == Some(id) {
+                    self.recognized = None;
+
+                    return false;
+                }
+
+                if self.pending.is_some_and(|(finger, _, _)| finger == id) {
+                    self.pending = None;
+                }
+
+                true
+            }
+        }
+    }
+
+    /// Returns the time when the current touch interaction becomes a long
+    /// press, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.pending.map(|(_, _, pressed_at)| {
+            pressed_at + gesture::current().long_press
+        })
+    }
+
+    /// Recognizes the current touch interaction as a long press, if its
+    /// [`deadline`] has passed.
+    ///
+    /// It returns the events that replace the touch interaction.
+    ///
+    /// [`deadline`]: Self::deadline
+    pub fn recognize(&mut self, now: Instant) -> Option<[crate::Event; 3]> {
+        let deadline = self.deadline()?;
+
+        if now < deadline {
+            return None;
+        }
+
+        let (finger, position, _) = self.pending.take()?;
+        self.recognized = Some(finger);
+
+        Some([
+            crate::Event::Touch(Event::FingerLost {
+                id: finger,
+                position,
+            }),
+            crate::Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Right,
+            )),
+            crate::Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Right,
+            )),
+        ])
+    }
+}
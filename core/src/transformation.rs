@@ -31,6 +31,27 @@ impl Transformation {
         Transformation(Mat4::from_scale(Vec3::new(scaling, scaling, 1.0)))
     }
 
+    /// Creates a 2D affine transformation out of the columns of its matrix.
+    ///
+    /// A point `(x, y)` is transformed into
+    /// `(a * x + c * y + e, b * x + d * y + f)`.
+    pub fn affine(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Transformation(Mat4::from_cols(
+            Vec4::new(a, b, 0.0, 0.0),
+            Vec4::new(c, d, 0.0, 0.0),
+            Vec4::Z,
+            Vec4::new(e, f, 0.0, 1.0),
+        ))
+    }
+
+    /// Returns the inverse of the [`Transformation`], if it is invertible.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.0.determinant();
+
+        (determinant.is_finite() && determinant != 0.0)
+            .then(|| Transformation(self.0.inverse()))
+    }
+
     /// Returns the scale factor of the [`Transformation`].
     pub fn scale_factor(&self) -> f32 {
         self.0.x_axis.x
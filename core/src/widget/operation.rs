@@ -1,11 +1,15 @@
 //! Query or update internal widget state.
+pub mod adjustable;
 pub mod focusable;
 pub mod scrollable;
 pub mod text_input;
+pub mod toggleable;
 
+pub use adjustable::Adjustable;
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
+pub use toggleable::Toggleable;
 
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
@@ -45,6 +49,12 @@ pub trait Operation<T = ()>: Send {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that can be toggled.
+    fn toggleable(&mut self, _state: &mut dyn Toggleable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that can be adjusted to a value.
+    fn adjustable(&mut self, _state: &mut dyn Adjustable, _id: Option<&Id>) {}
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -85,6 +95,14 @@ where
         self.as_mut().text_input(state, id);
     }
 
+    fn toggleable(&mut self, state: &mut dyn Toggleable, id: Option<&Id>) {
+        self.as_mut().toggleable(state, id);
+    }
+
+    fn adjustable(&mut self, state: &mut dyn Adjustable, id: Option<&Id>) {
+        self.as_mut().adjustable(state, id);
+    }
+
     fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
         self.as_mut().custom(state, id);
     }
@@ -160,6 +178,14 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn toggleable(&mut self, state: &mut dyn Toggleable, id: Option<&Id>) {
+            self.operation.toggleable(state, id);
+        }
+
+        fn adjustable(&mut self, state: &mut dyn Adjustable, id: Option<&Id>) {
+            self.operation.adjustable(state, id);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -243,6 +269,22 @@ where
                     self.operation.text_input(state, id);
                 }
 
+                fn toggleable(
+                    &mut self,
+                    state: &mut dyn Toggleable,
+                    id: Option<&Id>,
+                ) {
+                    self.operation.toggleable(state, id);
+                }
+
+                fn adjustable(
+                    &mut self,
+                    state: &mut dyn Adjustable,
+                    id: Option<&Id>,
+                ) {
+                    self.operation.adjustable(state, id);
+                }
+
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
@@ -271,6 +313,14 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn toggleable(&mut self, state: &mut dyn Toggleable, id: Option<&Id>) {
+            self.operation.toggleable(state, id);
+        }
+
+        fn adjustable(&mut self, state: &mut dyn Adjustable, id: Option<&Id>) {
+            self.operation.adjustable(state, id);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -350,6 +400,14 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn toggleable(&mut self, state: &mut dyn Toggleable, id: Option<&Id>) {
+            self.operation.toggleable(state, id);
+        }
+
+        fn adjustable(&mut self, state: &mut dyn Adjustable, id: Option<&Id>) {
+            self.operation.adjustable(state, id);
+        }
+
         fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -0,0 +1,48 @@
+//! Operate on widgets that can be adjusted to a value.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::Rectangle;
+
+/// The internal state of a widget that can be adjusted to a value in some
+/// range; like a slider.
+pub trait Adjustable {
+    /// Returns the current value of the widget.
+    fn value(&self) -> f64;
+
+    /// Adjusts the widget to the given value, like a user would.
+    ///
+    /// The value is clamped and stepped by the widget itself, and it produces
+    /// its message the next time it processes an event.
+    fn set_value(&mut self, value: f64);
+}
+
+/// Produces an [`Operation`] that adjusts the widget with the given [`Id`]
+/// to the given value.
+pub fn set_value<T>(target: Id, value: f64) -> impl Operation<T> {
+    struct SetValue {
+        target: Id,
+        value: f64,
+    }
+
+    impl<T> Operation<T> for SetValue {
+        fn adjustable(&mut self, state: &mut dyn Adjustable, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.set_value(self.value);
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    SetValue { target, value }
+}
@@ -91,6 +91,36 @@ pub fn count() -> impl Operation<Count> {
     }
 }
 
+/// Produces an [`Operation`] that finds out whether any widget is focused.
+pub fn is_any_focused() -> impl Operation<bool> {
+    struct IsAnyFocused {
+        is_focused: bool,
+    }
+
+    impl Operation<bool> for IsAnyFocused {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            self.is_focused |= state.is_focused();
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            if !self.is_focused {
+                operate_on_children(self);
+            }
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            Outcome::Some(self.is_focused)
+        }
+    }
+
+    IsAnyFocused { is_focused: false }
+}
+
 /// Produces an [`Operation`] that searches for the current focused widget, and
 /// - if found, focuses the previous focusable widget.
 /// - if not found, focuses the last focusable widget.
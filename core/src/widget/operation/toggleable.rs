@@ -0,0 +1,59 @@
+//! Operate on widgets that can be toggled.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::Rectangle;
+
+/// The internal state of a widget that can be toggled; like a checkbox.
+pub trait Toggleable {
+    /// Returns whether the widget is toggled on.
+    fn is_toggled(&self) -> bool;
+
+    /// Toggles the widget to the given value, like a user would.
+    ///
+    /// The widget produces its message the next time it processes an event.
+    fn set_toggled(&mut self, is_toggled: bool);
+}
+
+/// Produces an [`Operation`] that flips the widget with the given [`Id`].
+pub fn toggle<T>(target: Id) -> impl Operation<T> {
+    Toggle {
+        target,
+        is_toggled: None,
+    }
+}
+
+/// Produces an [`Operation`] that toggles the widget with the given [`Id`]
+/// to the given value.
+pub fn set_toggled<T>(target: Id, is_toggled: bool) -> impl Operation<T> {
+    Toggle {
+        target,
+        is_toggled: Some(is_toggled),
+    }
+}
+
+struct Toggle {
+    target: Id,
+    is_toggled: Option<bool>,
+}
+
+impl<T> Operation<T> for Toggle {
+    fn toggleable(&mut self, state: &mut dyn Toggleable, id: Option<&Id>) {
+        match id {
+            Some(id) if id == &self.target => {
+                let is_toggled = self.is_toggled.unwrap_or(!state.is_toggled());
+
+                state.set_toggled(is_toggled);
+            }
+            _ => {}
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
@@ -7,11 +7,13 @@ use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
-    Widget,
+    Background, Color, Element, Layout, Length, Pixels, Point, Rectangle, Size,
+    Theme, Vector, Widget,
 };
 
-pub use text::{LineHeight, Shaping};
+use std::ops::Range;
+
+pub use text::{Justification, LineHeight, Outline, Shadow, Shaping};
 
 /// A paragraph of text.
 #[allow(missing_debug_implementations)]
@@ -29,6 +31,8 @@ where
     vertical_alignment: alignment::Vertical,
     font: Option<Renderer::Font>,
     shaping: Shaping,
+    justification: Justification,
+    highlights: Vec<(Range<usize>, Background)>,
     class: Theme::Class<'a>,
 }
 
@@ -48,7 +52,9 @@ where
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
-            shaping: Shaping::Basic,
+            shaping: Shaping::default(),
+            justification: Justification::default(),
+            highlights: Vec::new(),
             class: Theme::default(),
         }
     }
@@ -115,6 +121,28 @@ where
         self
     }
 
+    /// Sets the [`Justification`] of the [`Text`].
+    ///
+    /// Justified text fills its bounds; so it works best with a fixed or
+    /// [`Length::Fill`] width.
+    pub fn justification(mut self, justification: Justification) -> Self {
+        self.justification = justification;
+        self
+    }
+
+    /// Highlights the given byte range of the contents of the [`Text`]
+    /// with a [`Background`]; for instance, to show search matches.
+    ///
+    /// This method can be called multiple times to highlight many ranges.
+    pub fn highlight(
+        mut self,
+        range: Range<usize>,
+        background: impl Into<Background>,
+    ) -> Self {
+        self.highlights.push((range, background.into()));
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -140,7 +168,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -198,6 +229,7 @@ where
             self.horizontal_alignment,
             self.vertical_alignment,
             self.shaping,
+            self.justification,
         )
     }
 
@@ -214,6 +246,7 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        draw_highlights(renderer, layout, state.0.raw(), &self.highlights);
         draw(renderer, defaults, layout, state.0.raw(), style, viewport);
     }
 }
@@ -232,6 +265,7 @@ pub fn layout<Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
+    justification: Justification,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -253,6 +287,7 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping,
+            justification,
         });
 
         paragraph.min_bounds()
@@ -293,14 +328,133 @@ pub fn draw<Renderer>(
         alignment::Vertical::Bottom => bounds.y + bounds.height,
     };
 
+    let position = Point::new(x, y);
+
+    if let Some(shadow) = appearance.shadow {
+        let position = position + shadow.offset;
+
+        if let Some(outline) = appearance.outline {
+            draw_outline(
+                renderer,
+                paragraph,
+                position,
+                outline.width,
+                shadow.color,
+                viewport,
+            );
+        }
+
+        renderer.fill_paragraph(paragraph, position, shadow.color, *viewport);
+    }
+
+    if let Some(outline) = appearance.outline {
+        draw_outline(
+            renderer,
+            paragraph,
+            position,
+            outline.width,
+            outline.color,
+            viewport,
+        );
+    }
+
     renderer.fill_paragraph(
         paragraph,
-        Point::new(x, y),
+        position,
         appearance.color.unwrap_or(style.text_color),
         *viewport,
     );
 }
 
+/// Draws an outline of the given width around a [`Paragraph`] by filling
+/// it repeatedly around its position.
+fn draw_outline<Renderer>(
+    renderer: &mut Renderer,
+    paragraph: &Renderer::Paragraph,
+    position: Point,
+    width: f32,
+    color: Color,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    if width <= 0.0 {
+        return;
+    }
+
+    // One ring of samples per pixel of width; so thin glyphs leave no gaps
+    let rings = width.ceil() as usize;
+
+    for ring in 1..=rings {
+        let radius = width * ring as f32 / rings as f32;
+        let samples = ring * 8;
+
+        for i in 0..samples {
+            let angle = i as f32 / samples as f32 * std::f32::consts::TAU;
+            let offset = Vector::new(angle.cos(), angle.sin()) * radius;
+
+            renderer.fill_paragraph(
+                paragraph,
+                position + offset,
+                color,
+                *viewport,
+            );
+        }
+    }
+}
+
+/// Draws the given byte range highlights of a [`Paragraph`] positioned
+/// like [`draw`] does.
+pub fn draw_highlights<Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    paragraph: &Renderer::Paragraph,
+    highlights: &[(Range<usize>, Background)],
+) where
+    Renderer: text::Renderer,
+{
+    if highlights.is_empty() {
+        return;
+    }
+
+    let bounds = layout.bounds();
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    let translation = Vector::new(x, y);
+
+    for (range, background) in highlights {
+        for bounds in paragraph.range_bounds(range.clone()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + translation,
+                    ..renderer::Quad::default()
+                },
+                *background,
+            );
+        }
+    }
+}
+
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -342,6 +496,12 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+
+    /// The [`Outline`] drawn around the glyphs of the text, if any.
+    pub outline: Option<Outline>,
+
+    /// The [`Shadow`] drawn behind the text, if any.
+    pub shadow: Option<Shadow>,
 }
 
 /// The theme catalog of a [`Text`].
@@ -375,13 +535,14 @@ impl Catalog for Theme {
 
 /// The default text styling; color is inherited.
 pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+    Style::default()
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().text),
+        ..Style::default()
     }
 }
 
@@ -389,6 +550,7 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().primary),
+        ..Style::default()
     }
 }
 
@@ -396,6 +558,7 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.extended_palette().secondary.strong.color),
+        ..Style::default()
     }
 }
 
@@ -403,6 +566,7 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().success),
+        ..Style::default()
     }
 }
 
@@ -410,5 +574,6 @@ pub fn success(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().danger),
+        ..Style::default()
     }
 }
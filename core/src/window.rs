@@ -7,6 +7,7 @@ mod id;
 mod level;
 mod mode;
 mod position;
+mod present_mode;
 mod redraw_request;
 mod user_attention;
 
@@ -16,6 +17,7 @@ pub use id::Id;
 pub use level::Level;
 pub use mode::Mode;
 pub use position::Position;
+pub use present_mode::PresentMode;
 pub use redraw_request::RedrawRequest;
 pub use settings::Settings;
 pub use user_attention::UserAttention;
@@ -59,4 +59,11 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The graphics resources of the window were lost and have been created
+    /// again; like after a driver reset.
+    ///
+    /// The renderer uploads its resources again on its own; but any graphics
+    /// resources created by the application outside of it are invalid.
+    GraphicsRecovered,
 }
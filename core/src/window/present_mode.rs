@@ -0,0 +1,27 @@
+/// The strategy used to present the frames of a window.
+///
+/// Not every mode is supported by every platform and graphics backend; an
+/// unsupported mode falls back to [`PresentMode::Vsync`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PresentMode {
+    /// Frames are presented in sync with the refresh rate of the display.
+    ///
+    /// There is no tearing; but a new frame may wait up to a full refresh
+    /// to be shown.
+    #[default]
+    Vsync,
+
+    /// Frames are presented in sync with the refresh rate of the display;
+    /// but a new frame replaces any frame still waiting to be shown.
+    ///
+    /// There is no tearing, and the latency is lower than [`Vsync`]; at the
+    /// cost of rendering frames that may never be shown.
+    ///
+    /// [`Vsync`]: Self::Vsync
+    Mailbox,
+
+    /// Frames are presented as soon as they are rendered.
+    ///
+    /// The latency is the lowest possible; but there may be tearing.
+    Immediate,
+}
@@ -24,10 +24,15 @@ mod platform;
 #[path = "settings/other.rs"]
 mod platform;
 
-use crate::window::{Icon, Level, Position};
+use crate::text;
+use crate::window::{Icon, Level, Position, PresentMode};
 use crate::Size;
 
 pub use platform::PlatformSpecific;
+
+#[cfg(target_os = "windows")]
+pub use platform::{Backdrop, CornerPreference};
+
 /// The window settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings {
@@ -53,8 +58,30 @@ pub struct Settings {
     pub decorations: bool,
 
     /// Whether the window should be transparent.
+    ///
+    /// The background of the window will be composited with the windows
+    /// behind it using the alpha channel of the rendered pixels.
     pub transparent: bool,
 
+    /// Whether the content behind a transparent window should be blurred.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Requires the `org_kde_kwin_blur_manager` protocol.
+    /// - **Windows:** Use the `backdrop` platform specific setting for
+    ///   acrylic and mica materials instead.
+    /// - **X11 / Web:** Unsupported.
+    pub blur: bool,
+
+    /// Whether the window should have a drop shadow.
+    ///
+    /// If `None`, the default of the platform is kept; that is, no shadow
+    /// for undecorated windows on Windows and a shadow on macOS.
+    ///
+    /// ## Platform-specific
+    /// - **Windows:** Only has an effect on undecorated windows.
+    /// - **Linux / Web:** Unsupported.
+    pub shadow: Option<bool>,
+
     /// The window [`Level`].
     pub level: Level,
 
@@ -64,6 +91,14 @@ pub struct Settings {
     /// Platform specific settings.
     pub platform_specific: PlatformSpecific,
 
+    /// The [`text::Antialiasing`] strategy of the text in the window.
+    ///
+    /// Subpixel antialiasing is disabled for transparent windows, since the
+    /// colors behind them are unknown.
+    ///
+    /// By default, it is [`text::Antialiasing::Grayscale`].
+    pub text_antialiasing: text::Antialiasing,
+
     /// Whether the window will close when the user requests it, e.g. when a user presses the
     /// close button.
     ///
@@ -73,6 +108,12 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// The [`PresentMode`] of the window.
+    ///
+    /// By default, it is `None`; and the present mode configured by the
+    /// graphics backend is used.
+    pub present_mode: Option<PresentMode>,
 }
 
 impl Default for Settings {
@@ -86,10 +127,14 @@ impl Default for Settings {
             resizable: true,
             decorations: true,
             transparent: false,
+            blur: false,
+            shadow: None,
             level: Level::default(),
             icon: None,
             exit_on_close_request: true,
             platform_specific: PlatformSpecific::default(),
+            text_antialiasing: text::Antialiasing::default(),
+            present_mode: None,
         }
     }
 }
@@ -8,6 +8,19 @@ pub struct PlatformSpecific {
 
     /// Whether show or hide the window icon in the taskbar.
     pub skip_taskbar: bool,
+
+    /// The system-drawn [`Backdrop`] material of the window.
+    ///
+    /// The window must be [`transparent`] for the backdrop to be visible.
+    /// Requires Windows 11 build 22523 or later.
+    ///
+    /// [`transparent`]: crate::window::Settings::transparent
+    pub backdrop: Backdrop,
+
+    /// The [`CornerPreference`] of the window.
+    ///
+    /// Requires Windows 11 build 22000 or later.
+    pub corner_preference: CornerPreference,
 }
 
 impl Default for PlatformSpecific {
@@ -15,6 +28,38 @@ impl Default for PlatformSpecific {
         Self {
             drag_and_drop: true,
             skip_taskbar: false,
+            backdrop: Backdrop::default(),
+            corner_preference: CornerPreference::default(),
         }
     }
 }
+
+/// The system-drawn material behind the contents of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backdrop {
+    /// The system decides the backdrop.
+    #[default]
+    Auto,
+    /// No backdrop.
+    None,
+    /// The Mica material, for long-lived windows.
+    Mica,
+    /// The Acrylic material, for transient windows like popups and menus.
+    Acrylic,
+    /// The Mica Alt material, for windows with tabs.
+    Tabbed,
+}
+
+/// The preferred shape of the corners of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CornerPreference {
+    /// The system decides whether to round the corners.
+    #[default]
+    Default,
+    /// Never round the corners.
+    DoNotRound,
+    /// Round the corners, if appropriate.
+    Round,
+    /// Round the corners with a small radius, if appropriate.
+    RoundSmall,
+}
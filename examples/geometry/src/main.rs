@@ -1,7 +1,7 @@
 //! This example showcases a simple native custom widget that renders using
 //! arbitrary low-level geometry.
 mod rainbow {
-    use iced::advanced::graphics::color;
+    use iced::advanced::graphics::{color, BlendMode};
     use iced::advanced::layout::{self, Layout};
     use iced::advanced::renderer;
     use iced::advanced::widget::{self, Widget};
@@ -134,6 +134,7 @@ mod rainbow {
                 },
                 transformation: Transformation::IDENTITY,
                 clip_bounds: Rectangle::INFINITE,
+                blend_mode: BlendMode::SourceOver,
             };
 
             renderer.with_translation(
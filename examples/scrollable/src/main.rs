@@ -345,5 +345,6 @@ fn progress_bar_custom_style(theme: &Theme) -> progress_bar::Style {
         background: theme.extended_palette().background.strong.color.into(),
         bar: Color::from_rgb8(250, 85, 134).into(),
         border: Border::default(),
+        ..progress_bar::primary(theme)
     }
 }
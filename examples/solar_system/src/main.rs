@@ -171,7 +171,7 @@ impl<Message> canvas::Program<Message> for State {
                     style: stroke::Style::Solid(Color::WHITE.scale_alpha(0.1)),
                     width: 1.0,
                     line_dash: canvas::LineDash {
-                        offset: 0,
+                        offset: 0.0,
                         segments: &[3.0, 6.0],
                     },
                     ..Stroke::default()
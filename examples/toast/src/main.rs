@@ -539,7 +539,8 @@ mod toast {
                 .zip(self.instants.iter_mut())
                 .map(|(((child, state), layout), instant)| {
                     let mut local_messages = vec![];
-                    let mut local_shell = Shell::new(&mut local_messages);
+                    let mut local_shell = Shell::new(&mut local_messages)
+                        .with_received_at(shell.received_at());
 
                     let status = child.as_widget_mut().on_event(
                         state,
@@ -2,6 +2,9 @@
 use futures::Future;
 
 /// An `async-std` executor.
+///
+/// It spawns futures in the global runtime of `async-std`; so they share it
+/// with the rest of the application.
 #[derive(Debug)]
 pub struct Executor;
 
@@ -30,6 +33,11 @@ pub mod time {
         subscription::from_recipe(Every(duration))
     }
 
+    /// Waits for the given `duration` to pass.
+    pub async fn sleep(duration: std::time::Duration) {
+        async_std::task::sleep(duration).await;
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
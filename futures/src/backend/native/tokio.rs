@@ -2,20 +2,60 @@
 use futures::Future;
 
 /// A `tokio` executor.
-pub type Executor = tokio::runtime::Runtime;
+///
+/// If it is created inside the context of an existing multi-threaded `tokio`
+/// runtime, it will share it instead of creating its own. This way, tasks
+/// and subscriptions can use any resources bound to that runtime; like
+/// database pools or RPC clients.
+///
+/// A current-thread runtime is never shared, since its thread is blocked by
+/// the event loop of the application and could never run any tasks.
+///
+/// An application can run inside the context of its own runtime by entering
+/// it first:
+///
+/// ```no_run
+/// # fn run_application() {}
+/// let runtime = tokio::runtime::Runtime::new().expect("Create runtime");
+/// let _guard = runtime.enter();
+///
+/// // Tasks and subscriptions will be spawned in `runtime`
+/// run_application();
+/// ```
+#[derive(Debug)]
+pub struct Executor {
+    handle: tokio::runtime::Handle,
+    _runtime: Option<tokio::runtime::Runtime>,
+}
 
 impl crate::Executor for Executor {
     fn new() -> Result<Self, futures::io::Error> {
-        tokio::runtime::Runtime::new()
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            if handle.runtime_flavor()
+                == tokio::runtime::RuntimeFlavor::MultiThread
+            {
+                return Ok(Self {
+                    handle,
+                    _runtime: None,
+                });
+            }
+        }
+
+        let runtime = tokio::runtime::Runtime::new()?;
+
+        Ok(Self {
+            handle: runtime.handle().clone(),
+            _runtime: Some(runtime),
+        })
     }
 
     #[allow(clippy::let_underscore_future)]
     fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
-        let _ = tokio::runtime::Runtime::spawn(self, future);
+        let _ = self.handle.spawn(future);
     }
 
     fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
-        let _guard = tokio::runtime::Runtime::enter(self);
+        let _guard = self.handle.enter();
         f()
     }
 }
@@ -34,6 +74,11 @@ pub mod time {
         subscription::from_recipe(Every(duration))
     }
 
+    /// Waits for the given `duration` to pass.
+    pub async fn sleep(duration: std::time::Duration) {
+        tokio::time::sleep(duration).await;
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
@@ -29,6 +29,11 @@ pub mod time {
         subscription::from_recipe(Every(duration))
     }
 
+    /// Waits for the given `duration` to pass.
+    pub async fn sleep(duration: std::time::Duration) {
+        let _ = wasm_timer::Delay::new(duration).await;
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
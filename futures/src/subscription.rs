@@ -1,6 +1,14 @@
 //! Listen to external events in your application.
 mod tracker;
 
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+mod timing;
+
 pub use tracker::Tracker;
 
 use crate::core::event;
@@ -0,0 +1,232 @@
+use crate::backend::default::time::sleep;
+use crate::core::time::Duration;
+use crate::futures::future::{self, Either};
+use crate::futures::stream::{self, StreamExt};
+use crate::subscription::{EventStream, Hasher, Recipe, Subscription};
+use crate::{BoxFuture, BoxStream, MaybeSend};
+
+use std::hash::Hash;
+
+impl<T> Subscription<T> {
+    /// Delays the output of the [`Subscription`] until the given `duration`
+    /// has passed without any new output; producing only the latest one.
+    ///
+    /// This is useful to react to bursts of events once they settle; like
+    /// searching as the user types.
+    ///
+    /// Each [`Subscription`] in a [`batch`] is debounced on its own.
+    ///
+    /// [`batch`]: Self::batch
+    pub fn debounce(self, duration: Duration) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        self.timed("debounce", duration, debounce)
+    }
+
+    /// Limits the output of the [`Subscription`] to one every `duration`.
+    ///
+    /// The first output is produced right away. Any output produced during
+    /// the following `duration` is dropped, except for the latest one; which
+    /// is produced once the `duration` has passed. This is useful to keep up
+    /// with a high-frequency source without being flooded by it; like the
+    /// resize events of a window.
+    ///
+    /// Each [`Subscription`] in a [`batch`] is throttled on its own.
+    ///
+    /// [`batch`]: Self::batch
+    pub fn throttle(self, duration: Duration) -> Self
+    where
+        T: MaybeSend + 'static,
+    {
+        self.timed("throttle", duration, throttle)
+    }
+
+    /// Groups the output of the [`Subscription`] produced within the given
+    /// `duration` of each other.
+    ///
+    /// A group starts with the first output produced after the previous
+    /// group, and it is produced once the `duration` has passed. This is
+    /// useful to process the readings of a sensor in bulk, for instance.
+    ///
+    /// Each [`Subscription`] in a [`batch`] is grouped on its own.
+    ///
+    /// [`batch`]: Self::batch
+    pub fn batch_within(self, duration: Duration) -> Subscription<Vec<T>>
+    where
+        T: MaybeSend + 'static,
+    {
+        self.timed("batch_within", duration, batch_within)
+    }
+
+    fn timed<A>(
+        mut self,
+        name: &'static str,
+        duration: Duration,
+        operator: fn(BoxStream<T>, Duration) -> BoxStream<A>,
+    ) -> Subscription<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Timed {
+                        recipe,
+                        name,
+                        duration,
+                        operator,
+                    }) as Box<dyn Recipe<Output = A>>
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Transforms the stream of a [`Recipe`] with a time-based operator.
+struct Timed<A, B> {
+    recipe: Box<dyn Recipe<Output = A>>,
+    name: &'static str,
+    duration: Duration,
+    operator: fn(BoxStream<A>, Duration) -> BoxStream<B>,
+}
+
+impl<A, B> Recipe for Timed<A, B>
+where
+    A: 'static,
+    B: 'static,
+{
+    type Output = B;
+
+    fn hash(&self, state: &mut Hasher) {
+        self.name.hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        (self.operator)(self.recipe.stream(input), self.duration)
+    }
+}
+
+fn timer(duration: Duration) -> BoxFuture<()> {
+    Box::pin(sleep(duration))
+}
+
+/// Produces the latest item of the stream once `duration` has passed
+/// without any new items.
+fn debounce<T>(stream: BoxStream<T>, duration: Duration) -> BoxStream<T>
+where
+    T: MaybeSend + 'static,
+{
+    let stream = stream::unfold(
+        (stream.fuse(), None),
+        move |(mut stream, mut pending)| async move {
+            loop {
+                let Some(item) = pending.take() else {
+                    pending = Some(stream.next().await?);
+                    continue;
+                };
+
+                let next = match future::select(stream.next(), timer(duration))
+                    .await
+                {
+                    Either::Left((next, _)) => next,
+                    Either::Right(_) => None,
+                };
+
+                match next {
+                    Some(newer) => {
+                        pending = Some(newer);
+                    }
+                    None => {
+                        return Some((item, (stream, None)));
+                    }
+                }
+            }
+        },
+    );
+
+    crate::boxed_stream(stream)
+}
+
+/// Produces the first item of the stream right away, and then at most one
+/// item every `duration`; the latest one received in the meantime.
+fn throttle<T>(stream: BoxStream<T>, duration: Duration) -> BoxStream<T>
+where
+    T: MaybeSend + 'static,
+{
+    let stream = stream::unfold(
+        (stream.fuse(), None, None),
+        move |(mut stream, mut window, mut pending)| async move {
+            loop {
+                let Some(mut timeout) = window.take() else {
+                    let item = stream.next().await?;
+
+                    return Some((item, (stream, Some(timer(duration)), None)));
+                };
+
+                let next =
+                    match future::select(stream.next(), &mut timeout).await {
+                        Either::Left((next, _)) => Some(next),
+                        Either::Right(_) => None,
+                    };
+
+                match next {
+                    Some(Some(item)) => {
+                        pending = Some(item);
+                        window = Some(timeout);
+                    }
+                    Some(None) => {
+                        return pending
+                            .take()
+                            .map(|item| (item, (stream, None, None)));
+                    }
+                    None => {
+                        if let Some(item) = pending.take() {
+                            return Some((
+                                item,
+                                (stream, Some(timer(duration)), None),
+                            ));
+                        }
+                    }
+                }
+            }
+        },
+    );
+
+    crate::boxed_stream(stream)
+}
+
+/// Groups the items of the stream produced within `duration` of the first
+/// one of each group.
+fn batch_within<T>(
+    stream: BoxStream<T>,
+    duration: Duration,
+) -> BoxStream<Vec<T>>
+where
+    T: MaybeSend + 'static,
+{
+    let stream = stream::unfold(stream.fuse(), move |mut stream| async move {
+        let mut batch = vec![stream.next().await?];
+        let mut timeout = timer(duration);
+
+        loop {
+            match future::select(stream.next(), &mut timeout).await {
+                Either::Left((Some(item), _)) => {
+                    batch.push(item);
+                }
+                Either::Left((None, _)) | Either::Right(_) => {
+                    break;
+                }
+            }
+        }
+
+        Some((batch, stream))
+    });
+
+    crate::boxed_stream(stream)
+}
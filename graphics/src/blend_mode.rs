@@ -0,0 +1,49 @@
+/// The way the colors of some drawing are combined with the colors already
+/// drawn behind it.
+///
+/// The Porter-Duff modes are exact. The separable modes assume an opaque
+/// background in some renderers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// Nothing is drawn and the background is cleared.
+    Clear,
+    /// Only the drawing is kept.
+    Source,
+    /// Only the background is kept.
+    Destination,
+    /// The drawing is placed over the background.
+    ///
+    /// This is the default.
+    #[default]
+    SourceOver,
+    /// The background is placed over the drawing.
+    DestinationOver,
+    /// The drawing is kept where the background is.
+    SourceIn,
+    /// The background is kept where the drawing is.
+    DestinationIn,
+    /// The drawing is kept where the background is not.
+    SourceOut,
+    /// The background is kept where the drawing is not.
+    DestinationOut,
+    /// The drawing is placed over the background, but only where the
+    /// background is.
+    SourceAtop,
+    /// The background is placed over the drawing, but only where the
+    /// drawing is.
+    DestinationAtop,
+    /// The drawing and the background are kept where they do not overlap.
+    Xor,
+    /// The colors of the drawing and the background are added.
+    Plus,
+    /// The colors of the drawing and the background are multiplied;
+    /// darkening the background.
+    Multiply,
+    /// The inverted colors of the drawing and the background are
+    /// multiplied; lightening the background.
+    Screen,
+    /// The darkest color of the drawing and the background is kept.
+    Darken,
+    /// The lightest color of the drawing and the background is kept.
+    Lighten,
+}
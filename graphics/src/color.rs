@@ -13,6 +13,13 @@ impl Packed {
     pub fn components(self) -> [f32; 4] {
         self.0
     }
+
+    /// Returns the [`Packed`] color with the given alpha channel.
+    pub fn with_alpha(self, alpha: f32) -> Self {
+        let [r, g, b, _] = self.0;
+
+        Self([r, g, b, alpha])
+    }
 }
 
 /// A flag that indicates whether the renderer should perform gamma correction.
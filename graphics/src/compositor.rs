@@ -1,5 +1,7 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::core::text;
+use crate::core::window::PresentMode;
 use crate::core::Color;
 use crate::futures::{MaybeSend, MaybeSync};
 use crate::{Error, Settings, Viewport};
@@ -19,11 +21,56 @@ pub trait Compositor: Sized {
     type Surface;
 
     /// Creates a new [`Compositor`].
+    ///
+    /// The backends in the `ICED_BACKEND` environment variable or, if it is
+    /// not set, in the [`Settings::backends`] are tried in order; until one
+    /// of them succeeds.
     fn new<W: Window + Clone>(
         settings: Settings,
         compatible_window: W,
     ) -> impl Future<Output = Result<Self, Error>> {
-        Self::with_backend(settings, compatible_window, None)
+        async move {
+            let preferred = std::env::var("ICED_BACKEND").ok();
+
+            let candidates: Vec<&str> = match &preferred {
+                Some(backends) => backends
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|candidate| !candidate.is_empty())
+                    .collect(),
+                None => settings.backends.to_vec(),
+            };
+
+            if candidates.is_empty() {
+                return Self::with_backend(settings, compatible_window, None)
+                    .await;
+            }
+
+            let mut errors = Vec::new();
+
+            for backend in candidates {
+                match Self::with_backend(
+                    settings,
+                    compatible_window.clone(),
+                    Some(backend),
+                )
+                .await
+                {
+                    Ok(compositor) => {
+                        log::info!("Selected backend: {backend}");
+
+                        return Ok(compositor);
+                    }
+                    Err(error) => {
+                        log::warn!("Backend {backend} failed: {error}");
+
+                        errors.push(error);
+                    }
+                }
+            }
+
+            Err(Error::List(errors))
+        }
     }
 
     /// Creates a new [`Compositor`] with a backend preference.
@@ -39,6 +86,20 @@ pub trait Compositor: Sized {
     /// Creates a [`Self::Renderer`] for the [`Compositor`].
     fn create_renderer(&self) -> Self::Renderer;
 
+    /// Creates a [`Self::Renderer`] for the [`Compositor`] that rasterizes
+    /// text with the given [`text::Antialiasing`] strategy.
+    ///
+    /// By default, the strategy is ignored; for renderers only capable of
+    /// grayscale antialiasing.
+    fn create_renderer_with(
+        &self,
+        text_antialiasing: text::Antialiasing,
+    ) -> Self::Renderer {
+        let _ = text_antialiasing;
+
+        self.create_renderer()
+    }
+
     /// Crates a new [`Surface`] for the given window.
     ///
     /// [`Surface`]: Self::Surface
@@ -59,9 +120,41 @@ pub trait Compositor: Sized {
         height: u32,
     );
 
+    /// Configures a new [`Surface`] with the given dimensions and
+    /// [`PresentMode`].
+    ///
+    /// An unsupported [`PresentMode`] falls back to the default present mode
+    /// of the [`Compositor`].
+    ///
+    /// [`Surface`]: Self::Surface
+    fn configure_surface_with(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        _present_mode: PresentMode,
+    ) {
+        self.configure_surface(surface, width, height);
+    }
+
+    /// Returns the [`PresentMode`]s supported by the given [`Surface`].
+    ///
+    /// [`Surface`]: Self::Surface
+    fn present_modes(&self, _surface: &Self::Surface) -> Vec<PresentMode> {
+        vec![PresentMode::Vsync]
+    }
+
     /// Returns [`Information`] used by this [`Compositor`].
     fn fetch_information(&self) -> Information;
 
+    /// Returns the [`Statistics`] of the last frame presented with the given
+    /// [`Renderer`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn statistics(&self, _renderer: &Self::Renderer) -> Statistics {
+        Statistics::default()
+    }
+
     /// Loads a font from its bytes.
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         crate::text::font_system()
@@ -136,6 +229,22 @@ pub enum SurfaceError {
     /// There is no more memory left to allocate a new frame.
     #[error("There is no more memory left to allocate a new frame")]
     OutOfMemory,
+    /// The graphics device was lost and has been recreated; so the surfaces
+    /// of the [`Compositor`] must be configured and its renderers created
+    /// again.
+    #[error("The graphics device was lost and has been recreated")]
+    DeviceLost,
+}
+
+/// The statistics of a frame presented by a [`Compositor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    /// The number of primitives in the frame.
+    pub primitives: usize,
+    /// The number of draw calls issued to the GPU for the frame.
+    ///
+    /// It is zero for software renderers.
+    pub draw_calls: usize,
 }
 
 /// Contains information about the graphics (e.g. graphics adapter, graphics backend).
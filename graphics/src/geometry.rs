@@ -1,23 +1,31 @@
 //! Build and draw geometry.
+pub mod export;
 pub mod fill;
 pub mod frame;
+pub mod freehand;
 pub mod path;
 pub mod stroke;
+pub mod vertex;
 
 mod cache;
+mod insets;
 mod style;
 mod text;
 
-pub use cache::Cache;
+pub use cache::{Cache, Layers, Tiles};
 pub use fill::Fill;
 pub use frame::Frame;
+pub use freehand::Freehand;
+pub use insets::Insets;
 pub use path::Path;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
-pub use style::Style;
-pub use text::Text;
+pub use style::{Style, Tiling};
+pub use text::{Along, Text};
+pub use vertex::{TexturedVertex, Vertex};
 
 pub use crate::core::{Image, Svg};
 pub use crate::gradient::{self, Gradient};
+pub use crate::BlendMode;
 
 use crate::cache::Cached;
 use crate::core::{self, Size};
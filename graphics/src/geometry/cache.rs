@@ -1,7 +1,9 @@
 use crate::cache::{self, Cached};
-use crate::core::Size;
+use crate::core::{Point, Rectangle, Size, Vector};
 use crate::geometry::{self, Frame};
 
+use std::cell::RefCell;
+
 pub use cache::Group;
 
 /// A simple cache that stores generated geometry to avoid recomputation.
@@ -96,6 +98,242 @@ where
     }
 }
 
+/// A stack of [`Cache`] layers that can be cleared independently.
+///
+/// Useful to split a drawing by how often it changes; like a static
+/// background, some semi-static data, and a dynamic cursor on top.
+pub struct Layers<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    layers: Vec<Cache<Renderer>>,
+}
+
+impl<Renderer> Layers<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Creates a new set of empty [`Layers`] with the given amount of layers.
+    pub fn new(count: usize) -> Self {
+        Self {
+            layers: (0..count).map(|_| Cache::new()).collect(),
+        }
+    }
+
+    /// Returns the amount of layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns true if there are no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns the [`Cache`] of the given layer.
+    ///
+    /// # Panics
+    /// Panics if the layer does not exist.
+    pub fn layer(&self, index: usize) -> &Cache<Renderer> {
+        &self.layers[index]
+    }
+
+    /// Clears the given layer, forcing a redraw of only that layer the next
+    /// time the [`Layers`] are drawn.
+    ///
+    /// # Panics
+    /// Panics if the layer does not exist.
+    pub fn clear(&self, index: usize) {
+        self.layers[index].clear();
+    }
+
+    /// Clears all of the layers.
+    pub fn clear_all(&self) {
+        for layer in &self.layers {
+            layer.clear();
+        }
+    }
+
+    /// Draws the geometry of every layer, from bottom to top, using the
+    /// provided closure.
+    ///
+    /// The closure receives the index of the layer to draw, and it will only
+    /// be called for the layers that need to be redrawn. See
+    /// [`Cache::draw`] for the details.
+    pub fn draw(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        draw_fn: impl Fn(usize, &mut Frame<Renderer>),
+    ) -> Vec<Renderer::Geometry> {
+        self.layers
+            .iter()
+            .enumerate()
+            .map(|(index, layer)| {
+                layer.draw(renderer, bounds, |frame| draw_fn(index, frame))
+            })
+            .collect()
+    }
+}
+
+/// A grid of [`Cache`] tiles that can be damaged by region.
+///
+/// Useful to redraw only the changed region of a large drawing; like a live
+/// waveform that appends a few samples at a time. Only the tiles that
+/// intersect a damaged region are tessellated and uploaded again.
+pub struct Tiles<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    tile_size: Size,
+    grid: RefCell<Grid<Renderer>>,
+}
+
+struct Grid<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    bounds: Size,
+    tiles: Vec<(Rectangle, Cache<Renderer>)>,
+}
+
+impl<Renderer> Tiles<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Creates a new set of empty [`Tiles`] with the given tile size.
+    pub fn new(tile_size: impl Into<Size>) -> Self {
+        let tile_size = tile_size.into();
+
+        Self {
+            tile_size: Size::new(
+                tile_size.width.max(1.0),
+                tile_size.height.max(1.0),
+            ),
+            grid: RefCell::new(Grid {
+                bounds: Size::ZERO,
+                tiles: Vec::new(),
+            }),
+        }
+    }
+
+    /// Damages the given region, forcing a redraw of only the tiles that
+    /// intersect it the next time the [`Tiles`] are drawn.
+    pub fn damage(&self, region: Rectangle) {
+        for (bounds, tile) in &self.grid.borrow().tiles {
+            if bounds.intersects(&region) {
+                tile.clear();
+            }
+        }
+    }
+
+    /// Clears all of the tiles.
+    pub fn clear(&self) {
+        for (_, tile) in &self.grid.borrow().tiles {
+            tile.clear();
+        }
+    }
+
+    /// Draws the geometry of every tile using the provided closure.
+    ///
+    /// The closure receives the region of the tile to draw, and it will only
+    /// be called for the tiles that need to be redrawn. It draws in the
+    /// coordinates of the whole drawing; anything outside of the region of
+    /// the tile is clipped, so drawing only what intersects it is enough.
+    ///
+    /// Every tile is redrawn when the bounds change.
+    pub fn draw(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        draw_fn: impl Fn(Rectangle, &mut Frame<Renderer>),
+    ) -> Vec<Renderer::Geometry> {
+        let mut grid = self.grid.borrow_mut();
+
+        if grid.bounds != bounds {
+            *grid = Grid {
+                bounds,
+                tiles: self
+                    .regions(bounds)
+                    .map(|region| (region, Cache::new()))
+                    .collect(),
+            };
+        }
+
+        grid.tiles
+            .iter()
+            .map(|(region, tile)| {
+                let region = *region;
+
+                tile.draw(renderer, bounds, |frame| {
+                    frame.with_clip(region, |frame| {
+                        frame.translate(Vector::new(-region.x, -region.y));
+
+                        draw_fn(region, frame);
+                    });
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the regions of the tiles that cover the given bounds.
+    fn regions(&self, bounds: Size) -> impl Iterator<Item = Rectangle> {
+        let tile = self.tile_size;
+
+        let columns = (bounds.width / tile.width).ceil() as usize;
+        let rows = (bounds.height / tile.height).ceil() as usize;
+
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| {
+                let position = Point::new(
+                    column as f32 * tile.width,
+                    row as f32 * tile.height,
+                );
+
+                Rectangle::new(
+                    position,
+                    Size::new(
+                        tile.width.min(bounds.width - position.x),
+                        tile.height.min(bounds.height - position.y),
+                    ),
+                )
+            })
+        })
+    }
+}
+
+impl<Renderer> std::fmt::Debug for Tiles<Renderer>
+where
+    Renderer: geometry::Renderer,
+    <Renderer::Geometry as Cached>::Cache: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tiles")
+            .field("tile_size", &self.tile_size)
+            .field(
+                "tiles",
+                &self
+                    .grid
+                    .borrow()
+                    .tiles
+                    .iter()
+                    .map(|(region, _)| region)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<Renderer> std::fmt::Debug for Layers<Renderer>
+where
+    Renderer: geometry::Renderer,
+    <Renderer::Geometry as Cached>::Cache: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.layers).finish()
+    }
+}
+
 impl<Renderer> std::fmt::Debug for Cache<Renderer>
 where
     Renderer: geometry::Renderer,
@@ -0,0 +1,698 @@
+//! Export geometry as an SVG document.
+use crate::cache::{self, Cached};
+use crate::core::alignment;
+use crate::core::font::{self, Font};
+use crate::core::image;
+use crate::core::renderer;
+use crate::core::svg;
+use crate::core::{
+    Background, Color, Image, Point, Radians, Rectangle, Size, Svg,
+    Transformation, Vector,
+};
+use crate::geometry::fill::{self, Fill};
+use crate::geometry::frame;
+use crate::geometry::stroke::{LineCap, LineJoin, Stroke};
+use crate::geometry::{
+    self, vertex, BlendMode, Path, Style, Text, TexturedVertex, Vertex,
+};
+use crate::gradient::Gradient;
+
+use lyon_path::math::{self, Transform};
+use std::fmt::Write;
+use std::sync::atomic::{self, AtomicU64};
+
+/// A renderer that records geometry to export it as an SVG document.
+///
+/// Any drawing logic that is generic over a [`geometry::Renderer`] can draw
+/// with it.
+///
+/// Patterns, textured meshes, and Porter-Duff blend modes are not exported,
+/// and only images and SVGs loaded from a path or from SVG bytes are
+/// embedded. The triangles of a mesh are filled with the average color of
+/// their vertices.
+#[derive(Debug, Default)]
+pub struct Renderer {
+    elements: Vec<String>,
+}
+
+impl Renderer {
+    /// Creates a new empty export [`Renderer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an SVG document of the given [`Size`] with all the geometry
+    /// drawn so far.
+    pub fn to_svg(&self, size: Size) -> String {
+        let mut document = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">",
+            width = size.width,
+            height = size.height,
+        );
+
+        for element in &self.elements {
+            document.push_str(element);
+        }
+
+        document.push_str("</svg>");
+        document
+    }
+}
+
+impl renderer::Renderer for Renderer {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, _transformation: Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn fill_quad(
+        &mut self,
+        _quad: renderer::Quad,
+        _background: impl Into<Background>,
+    ) {
+    }
+
+    fn clear(&mut self) {
+        self.elements.clear();
+    }
+}
+
+impl geometry::Renderer for Renderer {
+    type Geometry = Geometry;
+    type Frame = Frame;
+
+    fn new_frame(&self, size: Size) -> Self::Frame {
+        Frame::new(size)
+    }
+
+    fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.elements.extend(geometry.elements);
+    }
+}
+
+/// The geometry recorded by an export [`Renderer`].
+#[derive(Debug, Clone, Default)]
+pub struct Geometry {
+    elements: Vec<String>,
+}
+
+impl Cached for Geometry {
+    type Cache = Self;
+
+    fn load(cache: &Self::Cache) -> Self {
+        cache.clone()
+    }
+
+    fn cache(
+        self,
+        _group: cache::Group,
+        _previous: Option<Self::Cache>,
+    ) -> Self::Cache {
+        self
+    }
+}
+
+/// The frame of an export [`Renderer`].
+#[derive(Debug)]
+pub struct Frame {
+    size: Size,
+    transform: Transform,
+    stack: Vec<Transform>,
+    elements: Vec<String>,
+    clip: Option<String>,
+    blend_mode: Option<&'static str>,
+    blur: Option<f32>,
+}
+
+impl Frame {
+    /// Creates a new empty [`Frame`] with the given [`Size`].
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            transform: Transform::identity(),
+            stack: Vec::new(),
+            elements: Vec::new(),
+            clip: None,
+            blend_mode: None,
+            blur: None,
+        }
+    }
+
+    fn image(
+        &mut self,
+        bounds: Rectangle,
+        href: &str,
+        rotation: Radians,
+        opacity: f32,
+    ) {
+        let rotation = (rotation.0 != 0.0).then(|| (rotation, bounds.center()));
+        let transform = transform_attribute(&self.transform, rotation);
+
+        self.elements.push(format!(
+            "<image href=\"{href}\" x=\"{}\" y=\"{}\" width=\"{}\" \
+             height=\"{}\" preserveAspectRatio=\"none\" \
+             opacity=\"{opacity}\"{transform}/>",
+            bounds.x, bounds.y, bounds.width, bounds.height,
+        ));
+    }
+}
+
+impl frame::Backend for Frame {
+    type Geometry = Geometry;
+
+    fn width(&self) -> f32 {
+        self.size.width
+    }
+
+    fn height(&self) -> f32 {
+        self.size.height
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.size.width / 2.0, self.size.height / 2.0)
+    }
+
+    fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    fn transform(&self) -> Transformation {
+        let t = &self.transform;
+
+        Transformation::affine(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = self
+            .transform
+            .pre_translate(math::Vector::new(translation.x, translation.y));
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform = self
+            .transform
+            .pre_rotate(math::Angle::radians(angle.into().0));
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+
+        self.scale_nonuniform(Vector::new(scale, scale));
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        self.transform = self.transform.pre_scale(scale.x, scale.y);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        Self {
+            size: clip_bounds.size(),
+            transform: Transform::translation(clip_bounds.x, clip_bounds.y),
+            stack: Vec::new(),
+            elements: Vec::new(),
+            clip: Some(format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                clip_bounds.x,
+                clip_bounds.y,
+                clip_bounds.width,
+                clip_bounds.height,
+            )),
+            blend_mode: None,
+            blur: None,
+        }
+    }
+
+    fn draft_clip_path(&mut self, path: &Path) -> Self {
+        Self {
+            size: self.size,
+            transform: self.transform,
+            stack: Vec::new(),
+            elements: Vec::new(),
+            clip: Some(format!(
+                "<path d=\"{}\"{}/>",
+                path_data(path),
+                transform_attribute(&self.transform, None),
+            )),
+            blend_mode: None,
+            blur: None,
+        }
+    }
+
+    fn draft_blend(&mut self, blend_mode: BlendMode) -> Self {
+        let blend_mode = match blend_mode {
+            BlendMode::Plus => Some("plus-lighter"),
+            BlendMode::Multiply => Some("multiply"),
+            BlendMode::Screen => Some("screen"),
+            BlendMode::Darken => Some("darken"),
+            BlendMode::Lighten => Some("lighten"),
+            BlendMode::Clear
+            | BlendMode::Source
+            | BlendMode::Destination
+            | BlendMode::SourceOver
+            | BlendMode::DestinationOver
+            | BlendMode::SourceIn
+            | BlendMode::DestinationIn
+            | BlendMode::SourceOut
+            | BlendMode::DestinationOut
+            | BlendMode::SourceAtop
+            | BlendMode::DestinationAtop
+            | BlendMode::Xor => None,
+        };
+
+        Self {
+            size: self.size,
+            transform: self.transform,
+            stack: Vec::new(),
+            elements: Vec::new(),
+            clip: None,
+            blend_mode,
+            blur: None,
+        }
+    }
+
+    fn draft_blur(&mut self, radius: f32) -> Self {
+        // The radius follows the scale of the current transform
+        let scale = self.transform.determinant().abs().sqrt();
+
+        Self {
+            size: self.size,
+            transform: self.transform,
+            stack: Vec::new(),
+            elements: Vec::new(),
+            clip: None,
+            blend_mode: None,
+            blur: Some(radius * scale),
+        }
+    }
+
+    fn paste(&mut self, frame: Self) {
+        let mut elements = frame.elements.concat();
+
+        if let Some(radius) = frame.blur.filter(|radius| *radius > 0.0) {
+            let id = next_id();
+
+            elements = format!(
+                "<filter id=\"{id}\" x=\"-50%\" y=\"-50%\" width=\"200%\" \
+                 height=\"200%\"><feGaussianBlur stdDeviation=\"{}\"/>\
+                 </filter><g filter=\"url(#{id})\">{elements}</g>",
+                radius / 2.0,
+            );
+        }
+
+        if let Some(blend_mode) = frame.blend_mode {
+            elements = format!(
+                "<g style=\"mix-blend-mode:{blend_mode}\">{elements}</g>"
+            );
+        }
+
+        let Some(clip) = frame.clip else {
+            self.elements.push(elements);
+            return;
+        };
+
+        let id = next_id();
+
+        self.elements.push(format!(
+            "<clipPath id=\"{id}\">{clip}</clipPath>\
+             <g clip-path=\"url(#{id})\">{elements}</g>",
+        ));
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+
+        let Some((definitions, paint)) = paint(&stroke.style, "stroke") else {
+            return;
+        };
+
+        let line_cap = match stroke.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        };
+
+        let line_join = match stroke.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+
+        let mut dash = String::new();
+
+        if !stroke.line_dash.segments.is_empty() {
+            let segments: Vec<_> = stroke
+                .line_dash
+                .segments
+                .iter()
+                .map(f32::to_string)
+                .collect();
+
+            let _ = write!(
+                dash,
+                " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+                segments.join(" "),
+                stroke.line_dash.offset,
+            );
+        }
+
+        self.elements.push(format!(
+            "{definitions}<path d=\"{}\" fill=\"none\"{paint} \
+             stroke-width=\"{}\" stroke-linecap=\"{line_cap}\" \
+             stroke-linejoin=\"{line_join}\"{dash}{}/>",
+            path_data(path),
+            stroke.width,
+            transform_attribute(&self.transform, None),
+        ));
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+
+        let Some((definitions, paint)) = paint(&fill.style, "fill") else {
+            return;
+        };
+
+        let rule = match fill.rule {
+            fill::Rule::NonZero => "nonzero",
+            fill::Rule::EvenOdd => "evenodd",
+        };
+
+        self.elements.push(format!(
+            "{definitions}<path d=\"{}\"{paint} fill-rule=\"{rule}\"{}/>",
+            path_data(path),
+            transform_attribute(&self.transform, None),
+        ));
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+
+        let anchor = match text.horizontal_alignment {
+            alignment::Horizontal::Left => "start",
+            alignment::Horizontal::Center => "middle",
+            alignment::Horizontal::Right => "end",
+        };
+
+        let baseline = match text.vertical_alignment {
+            alignment::Vertical::Top => "hanging",
+            alignment::Vertical::Center => "middle",
+            alignment::Vertical::Bottom => "text-after-edge",
+        };
+
+        self.elements.push(format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"{}\"{}{} \
+             text-anchor=\"{anchor}\" dominant-baseline=\"{baseline}\" \
+             xml:space=\"preserve\"{}>{}</text>",
+            text.position.x,
+            text.position.y,
+            text.size.0,
+            font_attributes(text.font),
+            color_attributes(text.color, "fill", "fill-opacity"),
+            transform_attribute(&self.transform, None),
+            escape(&text.content),
+        ));
+    }
+
+    fn fill_rectangle(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        fill: impl Into<Fill>,
+    ) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]) {
+        for [a, b, c] in vertex::triangles(vertices, indices) {
+            let path = Path::new(|builder| {
+                builder.move_to(a.position);
+                builder.line_to(b.position);
+                builder.line_to(c.position);
+                builder.close();
+            });
+
+            let color = Color {
+                r: (a.color.r + b.color.r + c.color.r) / 3.0,
+                g: (a.color.g + b.color.g + c.color.g) / 3.0,
+                b: (a.color.b + b.color.b + c.color.b) / 3.0,
+                a: (a.color.a + b.color.a + c.color.a) / 3.0,
+            };
+
+            self.fill(&path, color);
+        }
+    }
+
+    fn draw_textured_mesh(
+        &mut self,
+        _vertices: &[TexturedVertex],
+        _indices: &[u32],
+        _handle: image::Handle,
+    ) {
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
+        let image = image.into();
+
+        let href = match &image.handle {
+            image::Handle::Path(_, path) => escape(&path.to_string_lossy()),
+            image::Handle::Bytes(..) | image::Handle::Rgba { .. } => {
+                return;
+            }
+        };
+
+        self.image(bounds, &href, image.rotation, image.opacity);
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        let svg = svg.into();
+
+        let href = match svg.handle.data() {
+            svg::Data::Path(path) => escape(&path.to_string_lossy()),
+            svg::Data::Bytes(bytes) => {
+                // Compressed SVGs cannot be embedded as text
+                let Ok(source) = std::str::from_utf8(bytes) else {
+                    return;
+                };
+
+                format!(
+                    "data:image/svg+xml;utf8,{}",
+                    escape(source).replace('%', "%25").replace('#', "%23")
+                )
+            }
+        };
+
+        self.image(bounds, &href, svg.rotation, svg.opacity);
+    }
+
+    fn into_geometry(self) -> Self::Geometry {
+        Geometry {
+            elements: self.elements,
+        }
+    }
+}
+
+fn next_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+
+    format!("iced-{}", NEXT.fetch_add(1, atomic::Ordering::Relaxed))
+}
+
+fn path_data(path: &Path) -> String {
+    let mut data = String::new();
+
+    for event in path.raw().iter() {
+        let _ = match event {
+            lyon_path::Event::Begin { at } => {
+                write!(data, "M{} {} ", at.x, at.y)
+            }
+            lyon_path::Event::Line { to, .. } => {
+                write!(data, "L{} {} ", to.x, to.y)
+            }
+            lyon_path::Event::Quadratic { ctrl, to, .. } => {
+                write!(data, "Q{} {} {} {} ", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            lyon_path::Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                data,
+                "C{} {} {} {} {} {} ",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            lyon_path::Event::End { close: true, .. } => write!(data, "Z "),
+            lyon_path::Event::End { close: false, .. } => Ok(()),
+        };
+    }
+
+    data.trim_end().to_owned()
+}
+
+fn transform_attribute(
+    transform: &Transform,
+    rotation: Option<(Radians, Point)>,
+) -> String {
+    let mut attribute = String::new();
+
+    if *transform != Transform::identity() {
+        let _ = write!(
+            attribute,
+            "matrix({} {} {} {} {} {})",
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32,
+        );
+    }
+
+    if let Some((rotation, center)) = rotation {
+        let _ = write!(
+            attribute,
+            " rotate({} {} {})",
+            rotation.0.to_degrees(),
+            center.x,
+            center.y,
+        );
+    }
+
+    if attribute.is_empty() {
+        attribute
+    } else {
+        format!(" transform=\"{}\"", attribute.trim_start())
+    }
+}
+
+/// Returns the definitions and the attributes of the given [`Style`] for the
+/// given SVG property; or `None` if it cannot be exported.
+fn paint(style: &Style, property: &str) -> Option<(String, String)> {
+    match style {
+        Style::Solid(color) => {
+            let opacity = format!("{property}-opacity");
+
+            Some((String::new(), color_attributes(*color, property, &opacity)))
+        }
+        Style::Gradient(gradient) => {
+            let id = next_id();
+
+            let (kind, geometry, stops) = match gradient {
+                Gradient::Linear(linear) => (
+                    "linearGradient",
+                    format!(
+                        "x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+                        linear.start.x,
+                        linear.start.y,
+                        linear.end.x,
+                        linear.end.y,
+                    ),
+                    &linear.stops,
+                ),
+                Gradient::Radial(radial) => (
+                    "radialGradient",
+                    format!(
+                        "cx=\"{}\" cy=\"{}\" r=\"{}\"",
+                        radial.center.x, radial.center.y, radial.radius,
+                    ),
+                    &radial.stops,
+                ),
+            };
+
+            let stops: String = stops
+                .iter()
+                .flatten()
+                .map(|stop| {
+                    format!(
+                        "<stop offset=\"{}\"{}/>",
+                        stop.offset,
+                        color_attributes(
+                            stop.color,
+                            "stop-color",
+                            "stop-opacity"
+                        ),
+                    )
+                })
+                .collect();
+
+            Some((
+                format!(
+                    "<defs><{kind} id=\"{id}\" \
+                     gradientUnits=\"userSpaceOnUse\" {geometry}>\
+                     {stops}</{kind}></defs>"
+                ),
+                format!(" {property}=\"url(#{id})\""),
+            ))
+        }
+        Style::Pattern(..) => None,
+    }
+}
+
+fn color_attributes(color: Color, property: &str, opacity: &str) -> String {
+    let [r, g, b, _a] = color.into_rgba8();
+
+    format!(" {property}=\"rgb({r},{g},{b})\" {opacity}=\"{}\"", color.a)
+}
+
+fn font_attributes(font: Font) -> String {
+    let family = match font.family {
+        font::Family::Name(name) => escape(name),
+        font::Family::Serif => String::from("serif"),
+        font::Family::SansSerif => String::from("sans-serif"),
+        font::Family::Cursive => String::from("cursive"),
+        font::Family::Fantasy => String::from("fantasy"),
+        font::Family::Monospace => String::from("monospace"),
+    };
+
+    let weight = match font.weight {
+        font::Weight::Thin => 100,
+        font::Weight::ExtraLight => 200,
+        font::Weight::Light => 300,
+        font::Weight::Normal => 400,
+        font::Weight::Medium => 500,
+        font::Weight::Semibold => 600,
+        font::Weight::Bold => 700,
+        font::Weight::ExtraBold => 800,
+        font::Weight::Black => 900,
+    };
+
+    let style = match font.style {
+        font::Style::Normal => "normal",
+        font::Style::Italic => "italic",
+        font::Style::Oblique => "oblique",
+    };
+
+    format!(
+        " font-family=\"{family}\" font-weight=\"{weight}\" \
+         font-style=\"{style}\""
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -1,15 +1,16 @@
 //! Fill [`Geometry`] with a certain style.
 //!
 //! [`Geometry`]: super::Renderer::Geometry
-pub use crate::geometry::Style;
+pub use crate::geometry::{Style, Tiling};
 
+use crate::core::image;
 use crate::core::Color;
 use crate::gradient::{self, Gradient};
 
 /// The style used to fill geometry.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Fill {
-    /// The color or gradient of the fill.
+    /// The color, gradient, or pattern of the fill.
     ///
     /// By default, it is set to [`Style::Solid`] with [`Color::BLACK`].
     pub style: Style,
@@ -61,6 +62,26 @@ impl From<gradient::Linear> for Fill {
     }
 }
 
+impl Fill {
+    /// Creates a new [`Fill`] that repeats the image of the given handle,
+    /// following the given [`Tiling`].
+    pub fn pattern(handle: impl Into<image::Handle>, tiling: Tiling) -> Self {
+        Fill {
+            style: Style::Pattern(handle.into(), tiling),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<gradient::Radial> for Fill {
+    fn from(gradient: gradient::Radial) -> Self {
+        Fill {
+            style: Style::Gradient(Gradient::Radial(gradient)),
+            ..Default::default()
+        }
+    }
+}
+
 /// The fill rule defines how to determine what is inside and what is outside of
 /// a shape.
 ///
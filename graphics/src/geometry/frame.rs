@@ -1,6 +1,13 @@
 //! Draw and generate geometry.
-use crate::core::{Point, Radians, Rectangle, Size, Vector};
-use crate::geometry::{self, Fill, Image, Path, Stroke, Svg, Text};
+use crate::core::alignment;
+use crate::core::image;
+use crate::core::{
+    Color, Point, Radians, Rectangle, Shadow, Size, Transformation, Vector,
+};
+use crate::geometry::{
+    self, Along, BlendMode, Fill, Image, Insets, LineJoin, Path, Stroke, Svg,
+    Text, TexturedVertex, Vertex,
+};
 
 /// The region of a surface that can be used to draw geometry.
 #[allow(missing_debug_implementations)]
@@ -48,6 +55,30 @@ where
         self.raw.fill(path, fill);
     }
 
+    /// Draws the given [`Path`] on the [`Frame`] by filling it with the
+    /// provided style, on top of the given [`Shadow`].
+    ///
+    /// The [`Shadow`] is a blurred fill of the [`Path`] with its color; see
+    /// [`with_blur`](Self::with_blur).
+    pub fn fill_with_shadow(
+        &mut self,
+        path: &Path,
+        fill: impl Into<Fill>,
+        shadow: Shadow,
+    ) {
+        if shadow.color.a > 0.0 {
+            self.with_save(|frame| {
+                frame.translate(shadow.offset);
+
+                frame.with_blur(shadow.blur_radius, |frame| {
+                    frame.fill(path, shadow.color);
+                });
+            });
+        }
+
+        self.fill(path, fill);
+    }
+
     /// Draws an axis-aligned rectangle given its top-left corner coordinate and
     /// its `Size` on the [`Frame`] by filling it with the provided style.
     pub fn fill_rectangle(
@@ -68,20 +99,218 @@ where
     /// Draws the characters of the given [`Text`] on the [`Frame`], filling
     /// them with the given color.
     ///
+    /// The [`Shadow`] of the [`Text`] is drawn first, followed by its
+    /// [`Outline`] and, finally, its characters.
+    ///
     /// __Warning:__ All text will be rendered on top of all the layers of
     /// a `Canvas`. Therefore, it is currently only meant to be used for
     /// overlays, which is the most common use case.
+    ///
+    /// [`Shadow`]: crate::core::text::Shadow
+    /// [`Outline`]: crate::core::text::Outline
     pub fn fill_text(&mut self, text: impl Into<Text>) {
+        let mut text = text.into();
+        let outline = text.outline.take();
+
+        if let Some(shadow) = text.shadow.take() {
+            let shadow = Text {
+                position: text.position + shadow.offset,
+                color: shadow.color,
+                ..text.clone()
+            };
+
+            if let Some(outline) = outline {
+                self.stroke_text(&shadow, outline.width, shadow.color);
+            }
+
+            self.raw.fill_text(shadow);
+        }
+
+        if let Some(outline) = outline {
+            self.stroke_text(&text, outline.width, outline.color);
+        }
+
         self.raw.fill_text(text);
     }
 
+    fn stroke_text(&mut self, text: &Text, width: f32, color: Color) {
+        // Strokes are centered; the inner half is covered by the characters
+        text.draw_with(|path, _color| {
+            self.raw.stroke(
+                &path,
+                Stroke {
+                    width: width * 2.0,
+                    line_join: LineJoin::Round,
+                    ..Stroke::default().with_color(color)
+                },
+            );
+        });
+    }
+
+    /// Measures the [`Size`] of the given [`Text`] before drawing it on the
+    /// [`Frame`].
+    ///
+    /// The current transform of the [`Frame`] is not applied.
+    pub fn measure_text(&self, text: &Text) -> Size {
+        text.measure()
+    }
+
+    /// Draws the characters of the given [`Text`] on the [`Frame`], rotated
+    /// clockwise by the given angle around its position.
+    ///
+    /// Rotated glyphs are drawn as paths, instead of rasterized text.
+    pub fn fill_text_rotated(
+        &mut self,
+        text: impl Into<Text>,
+        angle: impl Into<Radians>,
+    ) {
+        let text = text.into();
+
+        self.with_save(|frame| {
+            frame.translate(Vector::new(text.position.x, text.position.y));
+            frame.rotate(angle);
+
+            frame.fill_text(Text {
+                position: Point::ORIGIN,
+                ..text
+            });
+        });
+    }
+
+    /// Draws the characters of the given [`Text`] along the given [`Path`],
+    /// following its curves.
+    ///
+    /// Each character is placed by its distance along the [`Path`], as
+    /// described by the [`Along`] placement; and rotated to match the
+    /// direction of the [`Path`] at that point. The position of the [`Text`]
+    /// is ignored; and the characters that fall outside of the [`Path`] are
+    /// not drawn.
+    pub fn fill_text_along(
+        &mut self,
+        path: &Path,
+        text: impl Into<Text>,
+        along: Along,
+    ) {
+        let text = text.into();
+        let glyphs = text.glyphs();
+        let arclength = Arclength::new(path);
+
+        let width = glyphs.last().map_or(0.0, |glyph| glyph.x + glyph.width)
+            + along.spacing * glyphs.len().saturating_sub(1) as f32;
+
+        let start = along.offset
+            - match text.horizontal_alignment {
+                alignment::Horizontal::Left => 0.0,
+                alignment::Horizontal::Center => width / 2.0,
+                alignment::Horizontal::Right => width,
+            };
+
+        for (i, glyph) in glyphs.into_iter().enumerate() {
+            let distance =
+                start + glyph.x + glyph.width / 2.0 + along.spacing * i as f32;
+
+            let Some((point, angle)) = arclength.sample(distance) else {
+                continue;
+            };
+
+            self.with_save(|frame| {
+                frame.translate(Vector::new(point.x, point.y));
+                frame.rotate(angle);
+
+                frame.fill_text(Text {
+                    content: text.content[glyph.range].to_owned(),
+                    position: Point::ORIGIN,
+                    color: text.color,
+                    size: text.size,
+                    line_height: text.line_height,
+                    font: text.font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: text.vertical_alignment,
+                    shaping: text.shaping,
+                    outline: text.outline,
+                    shadow: text.shadow,
+                });
+            });
+        }
+    }
+
+    /// Draws the triangles described by the given vertices and indices on
+    /// the [`Frame`], interpolating the colors of their vertices.
+    ///
+    /// Every three indices are a triangle. The triangles are drawn as they
+    /// are, without any tessellation; which is much cheaper than filling a
+    /// [`Path`] for large meshes.
+    pub fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]) {
+        self.raw.draw_mesh(vertices, indices);
+    }
+
+    /// Draws the triangles described by the given vertices and indices on
+    /// the [`Frame`], mapping the image of the given handle onto them.
+    ///
+    /// Like [`draw_mesh`](Self::draw_mesh), the triangles are drawn without
+    /// any tessellation.
+    pub fn draw_textured_mesh(
+        &mut self,
+        vertices: &[TexturedVertex],
+        indices: &[u32],
+        handle: impl Into<image::Handle>,
+    ) {
+        self.raw
+            .draw_textured_mesh(vertices, indices, handle.into());
+    }
+
+    /// Draws the image of the given handle on the [`Frame`] as a nine-slice
+    /// filling the destination; stretching its center and edges, while
+    /// keeping its corners fixed.
+    ///
+    /// This is useful to draw scalable panels and buttons out of a single
+    /// image.
+    pub fn draw_image_nine_slice(
+        &mut self,
+        handle: impl Into<image::Handle>,
+        insets: Insets,
+        destination: Rectangle,
+    ) {
+        let (vertices, indices) = insets.mesh(destination);
+
+        self.raw
+            .draw_textured_mesh(&vertices, &indices, handle.into());
+    }
+
     /// Draws the given [`Image`] on the [`Frame`] inside the given bounds.
     #[cfg(feature = "image")]
     pub fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
         self.raw.draw_image(bounds, image);
     }
 
+    /// Draws the given [`Image`] on the [`Frame`] inside the given bounds,
+    /// applying its rotation around the given `pivot` instead of its center.
+    #[cfg(feature = "image")]
+    pub fn draw_image_around(
+        &mut self,
+        bounds: Rectangle,
+        pivot: Point,
+        image: impl Into<Image>,
+    ) {
+        let image = image.into();
+
+        // A rotation around any pivot is the same rotation around the center
+        // with the center itself rotated around the pivot
+        let (sin, cos) = image.rotation.0.sin_cos();
+        let offset = bounds.center() - pivot;
+
+        let rotated = Vector::new(
+            offset.x * cos - offset.y * sin,
+            offset.x * sin + offset.y * cos,
+        );
+
+        self.raw.draw_image(bounds + (rotated - offset), image);
+    }
+
     /// Draws the given [`Svg`] on the [`Frame`] inside the given bounds.
+    ///
+    /// The bounds are mapped by the current transform before the [`Svg`] is
+    /// rasterized; so it stays crisp at any scale.
     #[cfg(feature = "svg")]
     pub fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
         self.raw.draw_svg(bounds, svg);
@@ -104,6 +333,12 @@ where
     }
 
     /// Pushes the current transform in the transform stack.
+    ///
+    /// Every call must be matched by a call to [`pop_transform`]; prefer
+    /// [`with_save`] when the drawing operations fit in a closure.
+    ///
+    /// [`pop_transform`]: Self::pop_transform
+    /// [`with_save`]: Self::with_save
     pub fn push_transform(&mut self) {
         self.raw.push_transform();
     }
@@ -113,6 +348,31 @@ where
         self.raw.pop_transform();
     }
 
+    /// Returns the current transform of the [`Frame`].
+    ///
+    /// The transform maps points in the current local coordinates to the
+    /// coordinates of the [`Frame`]; where the origin is its top-left
+    /// corner.
+    pub fn transform(&self) -> Transformation {
+        self.raw.transform()
+    }
+
+    /// Maps the given [`Point`] from the current local coordinates to the
+    /// coordinates of the [`Frame`].
+    pub fn transform_point(&self, point: Point) -> Point {
+        point * self.transform()
+    }
+
+    /// Maps the given [`Point`] from the coordinates of the [`Frame`] to the
+    /// current local coordinates; like the position of the cursor when hit
+    /// testing.
+    ///
+    /// Returns `None` if the current transform is not invertible; like when
+    /// it scales by zero.
+    pub fn untransform_point(&self, point: Point) -> Option<Point> {
+        Some(point * self.transform().inverse()?)
+    }
+
     /// Executes the given drawing operations within a [`Rectangle`] region,
     /// clipping any geometry that overflows its bounds. Any transformations
     /// performed are local to the provided closure.
@@ -133,6 +393,77 @@ where
         result
     }
 
+    /// Executes the given drawing operations within the region of the given
+    /// [`Path`], clipping any geometry outside of it.
+    ///
+    /// Unlike [`with_clip`], the drawing operations keep the current
+    /// transform; which also applies to the [`Path`]. Any transformations
+    /// performed are local to the provided closure.
+    ///
+    /// Fills and strokes are clipped to the exact shape of the [`Path`],
+    /// while images and text are clipped to its bounds.
+    ///
+    /// [`with_clip`]: Self::with_clip
+    #[inline]
+    pub fn with_clip_path<R>(
+        &mut self,
+        path: &Path,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let mut frame = Self {
+            raw: self.raw.draft_clip_path(path),
+        };
+
+        let result = f(&mut frame);
+        self.paste(frame);
+
+        result
+    }
+
+    /// Executes the given drawing operations, blending their fills and
+    /// strokes with what is drawn behind them using the given [`BlendMode`].
+    ///
+    /// Text and images are drawn normally. Any transformations performed are
+    /// local to the provided closure.
+    #[inline]
+    pub fn with_blend<R>(
+        &mut self,
+        blend_mode: BlendMode,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let mut frame = Self {
+            raw: self.raw.draft_blend(blend_mode),
+        };
+
+        let result = f(&mut frame);
+        self.paste(frame);
+
+        result
+    }
+
+    /// Executes the given drawing operations, blurring their fills and
+    /// strokes together with the given radius.
+    ///
+    /// Like the blur radius of a [`Shadow`], the radius is twice the standard
+    /// deviation of the blur; and it is scaled by the current transform.
+    /// Text and images are drawn normally. Any transformations performed are
+    /// local to the provided closure.
+    #[inline]
+    pub fn with_blur<R>(
+        &mut self,
+        radius: impl Into<f32>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let mut frame = Self {
+            raw: self.raw.draft_blur(radius.into()),
+        };
+
+        let result = f(&mut frame);
+        self.paste(frame);
+
+        result
+    }
+
     /// Creates a new [`Frame`] with the given [`Size`].
     ///
     /// Draw its contents back to this [`Frame`] with [`paste`].
@@ -175,6 +506,77 @@ where
     }
 }
 
+/// A [`Path`] flattened into line segments, measured by their length.
+struct Arclength {
+    segments: Vec<Segment>,
+}
+
+struct Segment {
+    from: Point,
+    to: Point,
+    /// The distance along the path at the end of the segment.
+    end: f32,
+}
+
+impl Arclength {
+    const TOLERANCE: f32 = 0.1;
+
+    fn new(path: &Path) -> Self {
+        use lyon_path::iterator::PathIterator;
+
+        let mut segments = Vec::new();
+        let mut end = 0.0;
+
+        for event in path.raw().iter().flattened(Self::TOLERANCE) {
+            let (from, to) = match event {
+                lyon_path::Event::Line { from, to } => (from, to),
+                lyon_path::Event::End {
+                    last,
+                    first,
+                    close: true,
+                } => (last, first),
+                _ => continue,
+            };
+
+            let from = Point::new(from.x, from.y);
+            let to = Point::new(to.x, to.y);
+            let length = from.distance(to);
+
+            if length <= f32::EPSILON {
+                continue;
+            }
+
+            end += length;
+            segments.push(Segment { from, to, end });
+        }
+
+        Self { segments }
+    }
+
+    /// Returns the point at the given distance along the path, together
+    /// with the direction of the path at that point.
+    fn sample(&self, distance: f32) -> Option<(Point, Radians)> {
+        if distance < 0.0 {
+            return None;
+        }
+
+        let index = self
+            .segments
+            .partition_point(|segment| segment.end < distance);
+
+        let segment = self.segments.get(index)?;
+
+        let direction = segment.to - segment.from;
+        let length = segment.from.distance(segment.to);
+        let t = 1.0 - (segment.end - distance) / length;
+
+        Some((
+            segment.from + direction * t,
+            Radians(direction.y.atan2(direction.x)),
+        ))
+    }
+}
+
 /// The internal implementation of a [`Frame`].
 ///
 /// Analogous to [`Frame`]. See [`Frame`] for the documentation
@@ -190,6 +592,7 @@ pub trait Backend: Sized {
 
     fn push_transform(&mut self);
     fn pop_transform(&mut self);
+    fn transform(&self) -> Transformation;
 
     fn translate(&mut self, translation: Vector);
     fn rotate(&mut self, angle: impl Into<Radians>);
@@ -197,6 +600,9 @@ pub trait Backend: Sized {
     fn scale_nonuniform(&mut self, scale: impl Into<Vector>);
 
     fn draft(&mut self, clip_bounds: Rectangle) -> Self;
+    fn draft_clip_path(&mut self, path: &Path) -> Self;
+    fn draft_blend(&mut self, blend_mode: BlendMode) -> Self;
+    fn draft_blur(&mut self, radius: f32) -> Self;
     fn paste(&mut self, frame: Self);
 
     fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>);
@@ -210,6 +616,14 @@ pub trait Backend: Sized {
         fill: impl Into<Fill>,
     );
 
+    fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]);
+    fn draw_textured_mesh(
+        &mut self,
+        vertices: &[TexturedVertex],
+        indices: &[u32],
+        handle: image::Handle,
+    );
+
     fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>);
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>);
 
@@ -239,12 +653,19 @@ impl Backend for () {
     fn push_transform(&mut self) {}
     fn pop_transform(&mut self) {}
 
+    fn transform(&self) -> Transformation {
+        Transformation::IDENTITY
+    }
+
     fn translate(&mut self, _translation: Vector) {}
     fn rotate(&mut self, _angle: impl Into<Radians>) {}
     fn scale(&mut self, _scale: impl Into<f32>) {}
     fn scale_nonuniform(&mut self, _scale: impl Into<Vector>) {}
 
     fn draft(&mut self, _clip_bounds: Rectangle) -> Self {}
+    fn draft_clip_path(&mut self, _path: &Path) -> Self {}
+    fn draft_blend(&mut self, _blend_mode: BlendMode) -> Self {}
+    fn draft_blur(&mut self, _radius: f32) -> Self {}
     fn paste(&mut self, _frame: Self) {}
 
     fn stroke<'a>(&mut self, _path: &Path, _stroke: impl Into<Stroke<'a>>) {}
@@ -259,6 +680,15 @@ impl Backend for () {
     ) {
     }
 
+    fn draw_mesh(&mut self, _vertices: &[Vertex], _indices: &[u32]) {}
+    fn draw_textured_mesh(
+        &mut self,
+        _vertices: &[TexturedVertex],
+        _indices: &[u32],
+        _handle: image::Handle,
+    ) {
+    }
+
     fn draw_image(&mut self, _bounds: Rectangle, _image: impl Into<Image>) {}
     fn draw_svg(&mut self, _bounds: Rectangle, _svg: impl Into<Svg>) {}
 
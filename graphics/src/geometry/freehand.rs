@@ -0,0 +1,294 @@
+//! Draw smooth freehand strokes from raw input points.
+use crate::core::{Point, Vector};
+use crate::geometry::Path;
+
+use std::f32::consts::PI;
+
+/// A point of a [`Freehand`] stroke.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The position of the [`Sample`].
+    pub position: Point,
+
+    /// The pressure of the [`Sample`], from `0.0` to `1.0`.
+    pub pressure: f32,
+}
+
+/// A freehand stroke, built from the raw points of some pointer input.
+///
+/// The raw points are smoothed and simplified, and the resulting outline has
+/// a variable width that follows the pressure of each point. This makes it
+/// suitable for handwriting, annotations, and whiteboards.
+///
+/// The [`Path`] of a [`Freehand`] stroke is an outline; it must be filled,
+/// not stroked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Freehand {
+    samples: Vec<Sample>,
+    width: f32,
+    thinning: f32,
+    smoothing: f32,
+    tolerance: f32,
+}
+
+impl Freehand {
+    /// The pressure used for points without one, like those of a mouse.
+    pub const DEFAULT_PRESSURE: f32 = 0.5;
+
+    /// Creates a new empty [`Freehand`] stroke with the given maximum width.
+    pub fn new(width: f32) -> Self {
+        Self {
+            samples: Vec::new(),
+            width,
+            thinning: 0.5,
+            smoothing: 0.5,
+            tolerance: 0.5,
+        }
+    }
+
+    /// Sets how much the pressure thins the [`Freehand`] stroke, from `0.0`
+    /// (constant width) to `1.0` (zero width without pressure).
+    pub fn thinning(mut self, thinning: f32) -> Self {
+        self.thinning = thinning.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets how much the raw points are smoothed, from `0.0` (not at all) to
+    /// `1.0` (heavily).
+    pub fn smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Sets the maximum distance, in pixels, that the simplified stroke may
+    /// deviate from the smoothed points.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance.max(0.0);
+        self
+    }
+
+    /// Adds a raw point to the [`Freehand`] stroke, with an optional
+    /// pressure.
+    pub fn push(&mut self, position: Point, pressure: Option<f32>) {
+        self.samples.push(Sample {
+            position,
+            pressure: pressure
+                .unwrap_or(Self::DEFAULT_PRESSURE)
+                .clamp(0.0, 1.0),
+        });
+    }
+
+    /// Returns the raw points of the [`Freehand`] stroke.
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// Returns true if the [`Freehand`] stroke has no points.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Removes all the points of the [`Freehand`] stroke.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the smoothed and simplified center line of the [`Freehand`]
+    /// stroke.
+    pub fn centerline(&self) -> Vec<Sample> {
+        let Some((first, rest)) = self.samples.split_first() else {
+            return Vec::new();
+        };
+
+        let mut smoothed = Vec::with_capacity(self.samples.len());
+        let mut current = *first;
+
+        smoothed.push(current);
+
+        for sample in rest {
+            let factor = 1.0 - self.smoothing;
+
+            current = Sample {
+                position: current.position
+                    + (sample.position - current.position) * factor,
+                pressure: current.pressure
+                    + (sample.pressure - current.pressure) * factor,
+            };
+
+            smoothed.push(current);
+        }
+
+        // The stroke should always end under the pointer
+        if let (Some(last), Some(raw)) = (smoothed.last_mut(), rest.last()) {
+            last.position = raw.position;
+        }
+
+        simplify(&smoothed, self.tolerance)
+    }
+
+    /// Builds the outline [`Path`] of the [`Freehand`] stroke.
+    pub fn path(&self) -> Path {
+        let centerline = self.centerline();
+
+        let radius = |sample: &Sample| {
+            self.width / 2.0
+                * (1.0 - self.thinning + self.thinning * sample.pressure)
+        };
+
+        match centerline.as_slice() {
+            [] => Path::new(|_| {}),
+            [sample] => Path::circle(sample.position, radius(sample)),
+            samples => {
+                let mut left = Vec::with_capacity(samples.len());
+                let mut right = Vec::with_capacity(samples.len());
+
+                for (i, sample) in samples.iter().enumerate() {
+                    let previous = samples[i.saturating_sub(1)].position;
+                    let next = samples[(i + 1).min(samples.len() - 1)].position;
+
+                    let offset = normal(next - previous) * radius(sample);
+
+                    left.push(sample.position + offset);
+                    right.push(sample.position - offset);
+                }
+
+                let first = &samples[0];
+                let last = &samples[samples.len() - 1];
+
+                Path::new(|builder| {
+                    builder.move_to(left[0]);
+                    curve_through(builder, &left);
+
+                    for point in cap(
+                        last.position,
+                        normal(
+                            last.position - samples[samples.len() - 2].position,
+                        ),
+                        radius(last),
+                    ) {
+                        builder.line_to(point);
+                    }
+
+                    right.reverse();
+                    builder.line_to(right[0]);
+                    curve_through(builder, &right);
+
+                    for point in cap(
+                        first.position,
+                        normal(first.position - samples[1].position),
+                        radius(first),
+                    ) {
+                        builder.line_to(point);
+                    }
+
+                    builder.close();
+                })
+            }
+        }
+    }
+}
+
+/// Returns the unit normal of the given direction, pointing to its left.
+fn normal(direction: Vector) -> Vector {
+    let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
+
+    if length == 0.0 {
+        Vector::new(0.0, 0.0)
+    } else {
+        Vector::new(-direction.y / length, direction.x / length)
+    }
+}
+
+/// Returns the points of a round cap around the given center, going from the
+/// side of the normal to the opposite one, clockwise.
+fn cap(
+    center: Point,
+    normal: Vector,
+    radius: f32,
+) -> impl Iterator<Item = Point> {
+    const SEGMENTS: usize = 8;
+
+    let angle = normal.y.atan2(normal.x);
+
+    (1..=SEGMENTS).map(move |i| {
+        let angle = angle - PI * i as f32 / SEGMENTS as f32;
+
+        center + Vector::new(angle.cos(), angle.sin()) * radius
+    })
+}
+
+/// Draws a smooth curve through the given points, using their midpoints as
+/// the ends of quadratic segments.
+fn curve_through(
+    builder: &mut crate::geometry::path::Builder,
+    points: &[Point],
+) {
+    for window in points.windows(2).skip(1) {
+        let middle = Point::new(
+            (window[0].x + window[1].x) / 2.0,
+            (window[0].y + window[1].y) / 2.0,
+        );
+
+        builder.quadratic_curve_to(window[0], middle);
+    }
+
+    if let Some(last) = points.last() {
+        builder.line_to(*last);
+    }
+}
+
+/// Simplifies a polyline with the Ramer-Douglas-Peucker algorithm.
+fn simplify(samples: &[Sample], tolerance: f32) -> Vec<Sample> {
+    if samples.len() < 3 || tolerance == 0.0 {
+        return samples.to_vec();
+    }
+
+    let mut keep = vec![false; samples.len()];
+    let mut stack = vec![(0, samples.len() - 1)];
+
+    keep[0] = true;
+    keep[samples.len() - 1] = true;
+
+    while let Some((start, end)) = stack.pop() {
+        let a = samples[start].position;
+        let b = samples[end].position;
+
+        let (index, distance) = (start + 1..end)
+            .map(|i| (i, distance_to_segment(samples[i].position, a, b)))
+            .fold((start, 0.0), |farthest, candidate| {
+                if candidate.1 > farthest.1 {
+                    candidate
+                } else {
+                    farthest
+                }
+            });
+
+        if distance > tolerance {
+            keep[index] = true;
+
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    samples
+        .iter()
+        .zip(keep)
+        .filter_map(|(sample, keep)| keep.then_some(*sample))
+        .collect()
+}
+
+fn distance_to_segment(point: Point, a: Point, b: Point) -> f32 {
+    let segment = b - a;
+    let length_squared = segment.x * segment.x + segment.y * segment.y;
+
+    if length_squared == 0.0 {
+        return point.distance(a);
+    }
+
+    let t = (((point.x - a.x) * segment.x + (point.y - a.y) * segment.y)
+        / length_squared)
+        .clamp(0.0, 1.0);
+
+    point.distance(a + segment * t)
+}
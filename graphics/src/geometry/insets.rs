@@ -0,0 +1,103 @@
+use crate::core::{Padding, Point, Rectangle, Size};
+use crate::geometry::TexturedVertex;
+
+/// The insets of an image drawn as a nine-slice; which keep its corners
+/// fixed, while its edges and center stretch.
+#[derive(Debug, Clone, Copy)]
+pub struct Insets {
+    /// The size of the image, in pixels.
+    pub image_size: Size<u32>,
+
+    /// The distance from each edge of the image to its stretched center,
+    /// in pixels of the image.
+    pub padding: Padding,
+
+    /// The scale of the fixed corners once drawn.
+    pub scale: f32,
+}
+
+impl Insets {
+    /// Creates new [`Insets`] for an image of the given size.
+    pub fn new(image_size: Size<u32>, padding: impl Into<Padding>) -> Self {
+        Self {
+            image_size,
+            padding: padding.into(),
+            scale: 1.0,
+        }
+    }
+
+    /// Sets the scale of the fixed corners of the [`Insets`] once drawn.
+    pub fn scale(self, scale: f32) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Returns the vertices and indices of a mesh that draws the image in
+    /// the given destination as a nine-slice.
+    pub(crate) fn mesh(
+        &self,
+        destination: Rectangle,
+    ) -> ([TexturedVertex; 16], [u32; 54]) {
+        let xs = slices(
+            destination.x,
+            destination.width,
+            self.padding.left * self.scale,
+            self.padding.right * self.scale,
+        );
+
+        let ys = slices(
+            destination.y,
+            destination.height,
+            self.padding.top * self.scale,
+            self.padding.bottom * self.scale,
+        );
+
+        let us = slices(
+            0.0,
+            1.0,
+            self.padding.left / self.image_size.width.max(1) as f32,
+            self.padding.right / self.image_size.width.max(1) as f32,
+        );
+
+        let vs = slices(
+            0.0,
+            1.0,
+            self.padding.top / self.image_size.height.max(1) as f32,
+            self.padding.bottom / self.image_size.height.max(1) as f32,
+        );
+
+        let vertices = std::array::from_fn(|i| TexturedVertex {
+            position: Point::new(xs[i % 4], ys[i / 4]),
+            uv: Point::new(us[i % 4], vs[i / 4]),
+        });
+
+        let mut indices = [0; 54];
+
+        for (slice, triangles) in indices.chunks_exact_mut(6).enumerate() {
+            let a = (slice / 3 * 4 + slice % 3) as u32;
+
+            triangles.copy_from_slice(&[a, a + 1, a + 5, a, a + 5, a + 4]);
+        }
+
+        (vertices, indices)
+    }
+}
+
+/// Returns the four boundaries of the slices of the given span.
+///
+/// The fixed slices shrink proportionally when they do not fit.
+fn slices(start: f32, length: f32, before: f32, after: f32) -> [f32; 4] {
+    let fixed = before + after;
+
+    let scale = if fixed > length && fixed > 0.0 {
+        length / fixed
+    } else {
+        1.0
+    };
+
+    [
+        start,
+        start + before * scale,
+        start + length - after * scale,
+        start + length,
+    ]
+}
@@ -1,6 +1,7 @@
 //! Build different kinds of 2D shapes.
 pub mod arc;
 
+mod boolean;
 mod builder;
 
 #[doc(no_inline)]
@@ -9,7 +10,9 @@ pub use builder::Builder;
 
 pub use lyon_path;
 
-use iced_core::{Point, Size};
+use iced_core::{Point, Size, Vector};
+
+use lyon_path::iterator::PathIterator;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -53,6 +56,114 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Returns a new [`Path`] covering the area of either this [`Path`] or
+    /// the other one.
+    ///
+    /// Boolean operations fill both paths with the non-zero rule, close
+    /// any open subpaths, and flatten curves into line segments.
+    pub fn union(&self, other: &Path) -> Path {
+        boolean::apply(self, other, boolean::Operation::Union)
+    }
+
+    /// Returns a new [`Path`] covering the area of both this [`Path`] and
+    /// the other one.
+    ///
+    /// See [`union`](Self::union) for the details of boolean operations.
+    pub fn intersection(&self, other: &Path) -> Path {
+        boolean::apply(self, other, boolean::Operation::Intersection)
+    }
+
+    /// Returns a new [`Path`] covering the area of this [`Path`] that is
+    /// not covered by the other one.
+    ///
+    /// See [`union`](Self::union) for the details of boolean operations.
+    pub fn difference(&self, other: &Path) -> Path {
+        boolean::apply(self, other, boolean::Operation::Difference)
+    }
+
+    /// Returns a new [`Path`] covering the area of exactly one of this
+    /// [`Path`] and the other one.
+    ///
+    /// See [`union`](Self::union) for the details of boolean operations.
+    pub fn xor(&self, other: &Path) -> Path {
+        boolean::apply(self, other, boolean::Operation::Xor)
+    }
+
+    /// Returns true if the given point is inside the area of the [`Path`],
+    /// filled with the non-zero rule.
+    ///
+    /// The point must be in the coordinates of the [`Path`]. If the [`Path`]
+    /// is drawn with a transform, apply it with [`transform`](Self::transform)
+    /// first.
+    pub fn contains(&self, point: Point) -> bool {
+        let mut winding = 0;
+
+        for (from, to) in self.segments(true) {
+            let side = cross(to - from, point - from);
+
+            if from.y <= point.y {
+                if to.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+
+        winding != 0
+    }
+
+    /// Returns true if the given point is on the outline of the [`Path`]
+    /// when stroked with the given width.
+    ///
+    /// The point must be in the coordinates of the [`Path`]. If the [`Path`]
+    /// is drawn with a transform, apply it with [`transform`](Self::transform)
+    /// first.
+    pub fn hit_stroke(&self, point: Point, stroke_width: f32) -> bool {
+        let radius = stroke_width / 2.0;
+
+        self.segments(false).any(|(from, to)| {
+            let segment = to - from;
+            let length = segment.x * segment.x + segment.y * segment.y;
+
+            let t = if length > 0.0 {
+                let offset = point - from;
+
+                ((offset.x * segment.x + offset.y * segment.y) / length)
+                    .clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            point.distance(from + segment * t) <= radius
+        })
+    }
+
+    /// Returns the line segments of the flattened [`Path`]; closing every
+    /// subpath if `close_all` is true.
+    fn segments(
+        &self,
+        close_all: bool,
+    ) -> impl Iterator<Item = (Point, Point)> + '_ {
+        const TOLERANCE: f32 = 0.01;
+
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .filter_map(move |event| match event {
+                lyon_path::Event::Line { from, to } => Some((from, to)),
+                lyon_path::Event::End { last, first, close }
+                    if close || close_all =>
+                {
+                    Some((last, first))
+                }
+                _ => None,
+            })
+            .map(|(from, to)| {
+                (Point::new(from.x, from.y), Point::new(to.x, to.y))
+            })
+    }
+
     /// Returns the internal [`lyon_path::Path`].
     #[inline]
     pub fn raw(&self) -> &lyon_path::Path {
@@ -67,3 +178,7 @@ impl Path {
         }
     }
 }
+
+fn cross(a: Vector, b: Vector) -> f32 {
+    a.x * b.y - a.y * b.x
+}
@@ -0,0 +1,507 @@
+//! Combine the areas of different paths.
+use crate::geometry::Path;
+
+use iced_core::{Point, Vector};
+
+use lyon_path::iterator::PathIterator;
+use std::collections::{HashMap, HashSet};
+
+/// The maximum distance between a curve and the line segments that
+/// approximate it.
+const TOLERANCE: f32 = 0.01;
+
+/// The maximum sine of the angle between collinear edges.
+const COLLINEAR_TOLERANCE: f32 = 1e-5;
+
+/// A boolean operation between the areas of two paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// The area covered by either path.
+    Union,
+    /// The area covered by both paths.
+    Intersection,
+    /// The area covered by the first path, but not by the second.
+    Difference,
+    /// The area covered by exactly one of the paths.
+    Xor,
+}
+
+/// Applies the given [`Operation`] to the areas of the given paths.
+///
+/// Both paths are filled with the non-zero rule; open subpaths are closed,
+/// and curves are flattened into line segments.
+pub fn apply(a: &Path, b: &Path, operation: Operation) -> Path {
+    let contours = combine(&contours(a), &contours(b), operation);
+
+    Path::new(|builder| {
+        for contour in contours {
+            builder.move_to(contour[0]);
+
+            for point in &contour[1..] {
+                builder.line_to(*point);
+            }
+
+            builder.close();
+        }
+    })
+}
+
+type Contour = Vec<Point>;
+
+fn combine(a: &[Contour], b: &[Contour], operation: Operation) -> Vec<Contour> {
+    let (edges_a, edges_b) = split(&edges(a), &edges(b));
+
+    let keys_a: HashSet<_> = edges_a.iter().map(Edge::key).collect();
+    let keys_b: HashSet<_> = edges_b.iter().map(Edge::key).collect();
+
+    let mut result = Vec::new();
+
+    for (edges, other, other_keys, is_first) in
+        [(edges_a, b, &keys_b, true), (edges_b, a, &keys_a, false)]
+    {
+        for edge in edges {
+            // Edges shared by both paths lie on the boundary of the other
+            // path; so they are classified by the sides their areas are at
+            let coincidence = if other_keys.contains(&edge.key()) {
+                Some(true)
+            } else if other_keys.contains(&edge.reversed().key()) {
+                Some(false)
+            } else {
+                None
+            };
+
+            if let Some(is_same_direction) = coincidence {
+                // Only one copy of a shared edge is kept, if any
+                let is_kept = is_first
+                    && match operation {
+                        Operation::Union | Operation::Intersection => {
+                            is_same_direction
+                        }
+                        Operation::Difference => !is_same_direction,
+                        Operation::Xor => false,
+                    };
+
+                if is_kept {
+                    result.push(edge);
+                }
+
+                continue;
+            }
+
+            let is_inside = winding(other, edge.midpoint()) != 0;
+
+            // Edges inside the other path are reversed when they bound
+            // a hole of the result
+            let edge = match (operation, is_first, is_inside) {
+                (Operation::Union | Operation::Xor, _, false)
+                | (Operation::Intersection, _, true)
+                | (Operation::Difference, true, false) => edge,
+                (Operation::Difference, false, true)
+                | (Operation::Xor, _, true) => edge.reversed(),
+                _ => continue,
+            };
+
+            result.push(edge);
+        }
+    }
+
+    chain(result)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    from: Point,
+    to: Point,
+}
+
+impl Edge {
+    fn midpoint(&self) -> Point {
+        self.from + (self.to - self.from) * 0.5
+    }
+
+    fn reversed(self) -> Self {
+        Self {
+            from: self.to,
+            to: self.from,
+        }
+    }
+
+    fn key(&self) -> (u32, u32, u32, u32) {
+        (
+            self.from.x.to_bits(),
+            self.from.y.to_bits(),
+            self.to.x.to_bits(),
+            self.to.y.to_bits(),
+        )
+    }
+
+    /// Returns the position of the given point along the [`Edge`], if it
+    /// lies strictly between its endpoints.
+    ///
+    /// The point is assumed to be on the line of the [`Edge`].
+    fn locate(&self, point: Point) -> Option<f32> {
+        let direction = self.to - self.from;
+        let t = dot(point - self.from, direction) / dot(direction, direction);
+
+        (t > f32::EPSILON && t < 1.0 - f32::EPSILON).then_some(t)
+    }
+}
+
+/// Flattens the given [`Path`] into closed contours, oriented so that their
+/// total signed area is positive.
+fn contours(path: &Path) -> Vec<Contour> {
+    let mut contours = Vec::new();
+    let mut current = Vec::new();
+
+    for event in path.raw().iter().flattened(TOLERANCE) {
+        match event {
+            lyon_path::Event::Begin { at } => {
+                current = vec![Point::new(at.x, at.y)];
+            }
+            lyon_path::Event::Line { to, .. } => {
+                let to = Point::new(to.x, to.y);
+
+                if current.last() != Some(&to) {
+                    current.push(to);
+                }
+            }
+            lyon_path::Event::End { .. } => {
+                if current.len() > 1 && current.first() == current.last() {
+                    let _ = current.pop();
+                }
+
+                if current.len() >= 3 {
+                    contours.push(std::mem::take(&mut current));
+                }
+            }
+            lyon_path::Event::Quadratic { .. }
+            | lyon_path::Event::Cubic { .. } => {}
+        }
+    }
+
+    let area: f32 = contours.iter().map(Vec::as_slice).map(signed_area).sum();
+
+    if area < 0.0 {
+        for contour in &mut contours {
+            contour.reverse();
+        }
+    }
+
+    contours
+}
+
+fn signed_area(contour: &[Point]) -> f32 {
+    contour
+        .iter()
+        .zip(contour.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum::<f32>()
+        / 2.0
+}
+
+fn edges(contours: &[Contour]) -> Vec<Edge> {
+    contours
+        .iter()
+        .flat_map(|contour| {
+            contour
+                .iter()
+                .zip(contour.iter().cycle().skip(1))
+                .map(|(&from, &to)| Edge { from, to })
+        })
+        .collect()
+}
+
+/// Splits the edges of both sets at their intersections with each other.
+///
+/// Both edges of an intersection are split at the exact same point; so the
+/// resulting edges can be chained by their endpoints. Overlapping collinear
+/// edges are split at the endpoints of each other; so their shared segment
+/// becomes the same edge in both sets.
+fn split(a: &[Edge], b: &[Edge]) -> (Vec<Edge>, Vec<Edge>) {
+    let mut splits_a = vec![Vec::new(); a.len()];
+    let mut splits_b = vec![Vec::new(); b.len()];
+
+    for (i, edge_a) in a.iter().enumerate() {
+        for (j, edge_b) in b.iter().enumerate() {
+            if is_collinear(edge_a, edge_b) {
+                for point in [edge_b.from, edge_b.to] {
+                    if let Some(t) = edge_a.locate(point) {
+                        splits_a[i].push((t, point));
+                    }
+                }
+
+                for point in [edge_a.from, edge_a.to] {
+                    if let Some(u) = edge_b.locate(point) {
+                        splits_b[j].push((u, point));
+                    }
+                }
+
+                continue;
+            }
+
+            let Some((t, u)) = intersection(edge_a, edge_b) else {
+                continue;
+            };
+
+            // Snap intersections to existing endpoints; so they are shared
+            let point = if t <= f32::EPSILON {
+                edge_a.from
+            } else if t >= 1.0 - f32::EPSILON {
+                edge_a.to
+            } else if u <= f32::EPSILON {
+                edge_b.from
+            } else if u >= 1.0 - f32::EPSILON {
+                edge_b.to
+            } else {
+                edge_a.from + (edge_a.to - edge_a.from) * t
+            };
+
+            splits_a[i].push((t, point));
+            splits_b[j].push((u, point));
+        }
+    }
+
+    (apply_splits(a, splits_a), apply_splits(b, splits_b))
+}
+
+fn apply_splits(edges: &[Edge], splits: Vec<Vec<(f32, Point)>>) -> Vec<Edge> {
+    let mut result = Vec::with_capacity(edges.len());
+
+    for (edge, mut splits) in edges.iter().zip(splits) {
+        splits.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let mut from = edge.from;
+
+        for to in splits
+            .into_iter()
+            .map(|(_, point)| point)
+            .chain(Some(edge.to))
+        {
+            if from != to {
+                result.push(Edge { from, to });
+                from = to;
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns true if both edges lie on the same line.
+fn is_collinear(a: &Edge, b: &Edge) -> bool {
+    let r = a.to - a.from;
+    let length = dot(r, r).sqrt();
+
+    [b.from, b.to].into_iter().all(|point| {
+        let offset = point - a.from;
+
+        cross(r, offset).abs()
+            <= COLLINEAR_TOLERANCE * length * dot(offset, offset).sqrt()
+    })
+}
+
+/// Returns the position of the intersection of two edges along each of them.
+fn intersection(a: &Edge, b: &Edge) -> Option<(f32, f32)> {
+    let r = a.to - a.from;
+    let s = b.to - b.from;
+    let denominator = cross(r, s);
+
+    // Parallel edges do not intersect in a single point
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+
+    let offset = b.from - a.from;
+    let t = cross(offset, s) / denominator;
+    let u = cross(offset, r) / denominator;
+
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then_some((t, u))
+}
+
+/// Computes the winding number of the given contours around a point.
+fn winding(contours: &[Contour], point: Point) -> i32 {
+    let mut winding = 0;
+
+    for contour in contours {
+        for (&a, &b) in contour.iter().zip(contour.iter().cycle().skip(1)) {
+            let side = cross(b - a, point - a);
+
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding
+}
+
+/// Chains the given edges into closed contours by their endpoints.
+fn chain(edges: Vec<Edge>) -> Vec<Contour> {
+    let key = |point: Point| (point.x.to_bits(), point.y.to_bits());
+
+    let mut starts: HashMap<_, Vec<usize>> = HashMap::new();
+
+    for (i, edge) in edges.iter().enumerate() {
+        starts.entry(key(edge.from)).or_default().push(i);
+    }
+
+    let mut is_used = vec![false; edges.len()];
+    let mut contours = Vec::new();
+
+    for first in 0..edges.len() {
+        if is_used[first] {
+            continue;
+        }
+
+        is_used[first] = true;
+
+        let start = edges[first].from;
+        let mut contour = vec![start];
+        let mut current = first;
+
+        loop {
+            let end = edges[current].to;
+
+            if end == start {
+                break;
+            }
+
+            contour.push(end);
+
+            let next = starts.get(&key(end)).and_then(|candidates| {
+                candidates.iter().copied().find(|&i| !is_used[i])
+            });
+
+            let Some(next) = next else {
+                break;
+            };
+
+            is_used[next] = true;
+            current = next;
+        }
+
+        if contour.len() >= 3 {
+            contours.push(contour);
+        }
+    }
+
+    contours
+}
+
+fn cross(a: Vector, b: Vector) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Vector, b: Vector) -> f32 {
+    a.x * b.x + a.y * b.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> Contour {
+        vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+        ]
+    }
+
+    fn area(contours: &[Contour]) -> f32 {
+        contours.iter().map(Vec::as_slice).map(signed_area).sum()
+    }
+
+    #[test]
+    fn overlapping() {
+        let a = [square(0.0, 0.0, 10.0)];
+        let b = [square(5.0, 5.0, 10.0)];
+
+        for (operation, expected) in [
+            (Operation::Union, 175.0),
+            (Operation::Intersection, 25.0),
+            (Operation::Difference, 75.0),
+            (Operation::Xor, 150.0),
+        ] {
+            let result = combine(&a, &b, operation);
+
+            assert_eq!(area(&result), expected, "{operation:?}");
+        }
+    }
+
+    #[test]
+    fn identical() {
+        let a = [square(0.0, 0.0, 10.0)];
+
+        let union = combine(&a, &a, Operation::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union), 100.0);
+
+        let intersection = combine(&a, &a, Operation::Intersection);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(area(&intersection), 100.0);
+
+        assert!(combine(&a, &a, Operation::Difference).is_empty());
+        assert!(combine(&a, &a, Operation::Xor).is_empty());
+    }
+
+    #[test]
+    fn touching() {
+        let a = [square(0.0, 0.0, 10.0)];
+        let b = [square(10.0, 0.0, 10.0)];
+
+        let union = combine(&a, &b, Operation::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union), 200.0);
+
+        assert!(combine(&a, &b, Operation::Intersection).is_empty());
+
+        let difference = combine(&a, &b, Operation::Difference);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(area(&difference), 100.0);
+
+        let xor = combine(&a, &b, Operation::Xor);
+        assert_eq!(xor.len(), 1);
+        assert_eq!(area(&xor), 200.0);
+    }
+
+    #[test]
+    fn partially_touching() {
+        let a = [square(0.0, 0.0, 10.0)];
+        let b = [square(10.0, 5.0, 10.0)];
+
+        let union = combine(&a, &b, Operation::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union), 200.0);
+
+        assert!(combine(&a, &b, Operation::Intersection).is_empty());
+    }
+
+    #[test]
+    fn sharing_a_side() {
+        let a = [square(0.0, 0.0, 10.0)];
+        let b = [vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 5.0),
+            Point::new(0.0, 5.0),
+        ]];
+
+        let union = combine(&a, &b, Operation::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union), 100.0);
+
+        let intersection = combine(&a, &b, Operation::Intersection);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(area(&intersection), 50.0);
+
+        let difference = combine(&a, &b, Operation::Difference);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(area(&difference), 50.0);
+    }
+}
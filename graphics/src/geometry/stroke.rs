@@ -3,12 +3,14 @@
 //! [`Path`]: super::Path
 pub use crate::geometry::Style;
 
+use crate::geometry::Gradient;
+
 use iced_core::Color;
 
 /// The style of a stroke.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Stroke<'a> {
-    /// The color or gradient of the stroke.
+    /// The color, gradient, or pattern of the stroke.
     ///
     /// By default, it is set to a [`Style::Solid`] with [`Color::BLACK`].
     pub style: Style,
@@ -32,6 +34,14 @@ impl<'a> Stroke<'a> {
         }
     }
 
+    /// Sets the gradient of the [`Stroke`].
+    pub fn with_gradient(self, gradient: impl Into<Gradient>) -> Self {
+        Stroke {
+            style: Style::Gradient(gradient.into()),
+            ..self
+        }
+    }
+
     /// Sets the width of the [`Stroke`].
     pub fn with_width(self, width: f32) -> Self {
         Stroke { width, ..self }
@@ -46,6 +56,11 @@ impl<'a> Stroke<'a> {
     pub fn with_line_join(self, line_join: LineJoin) -> Self {
         Stroke { line_join, ..self }
     }
+
+    /// Sets the [`LineDash`] of the [`Stroke`].
+    pub fn with_line_dash(self, line_dash: LineDash<'a>) -> Self {
+        Stroke { line_dash, ..self }
+    }
 }
 
 impl<'a> Default for Stroke<'a> {
@@ -88,11 +103,25 @@ pub enum LineJoin {
 }
 
 /// The dash pattern used when stroking the line.
+///
+/// Dotted lines can be drawn with short dashes and a [`LineCap::Round`].
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LineDash<'a> {
     /// The alternating lengths of lines and gaps which describe the pattern.
+    ///
+    /// If the number of lengths is odd, they are repeated to yield an even
+    /// number. An empty pattern draws a solid line.
     pub segments: &'a [f32],
 
-    /// The offset of [`LineDash::segments`] to start the pattern.
-    pub offset: usize,
+    /// The distance into the [`LineDash::segments`] to start the pattern at.
+    ///
+    /// Changing it over time makes the dashes march along the line.
+    pub offset: f32,
+}
+
+impl<'a> LineDash<'a> {
+    /// Creates a new [`LineDash`] with the given segments and offset.
+    pub fn new(segments: &'a [f32], offset: f32) -> Self {
+        Self { segments, offset }
+    }
 }
@@ -1,14 +1,18 @@
-use crate::core::Color;
+use crate::core::image;
+use crate::core::{Color, Point, Size};
 use crate::geometry::Gradient;
 
 /// The coloring style of some drawing.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Style {
     /// A solid [`Color`].
     Solid(Color),
 
     /// A [`Gradient`] color.
     Gradient(Gradient),
+
+    /// An image repeated over the drawing, following some [`Tiling`].
+    Pattern(image::Handle, Tiling),
 }
 
 impl From<Color> for Style {
@@ -22,3 +26,37 @@ impl From<Gradient> for Style {
         Self::Gradient(gradient)
     }
 }
+
+/// The layout of the tiles of a [`Style::Pattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tiling {
+    /// The position of the top-left corner of one of the tiles.
+    ///
+    /// By default, it is set to [`Point::ORIGIN`].
+    pub origin: Point,
+
+    /// The size of a single tile.
+    ///
+    /// By default, it is set to `None`; which uses the dimensions of the
+    /// image in pixels.
+    pub size: Option<Size>,
+}
+
+impl Tiling {
+    /// Creates a new [`Tiling`] with tiles of the given [`Size`].
+    pub fn new(size: impl Into<Size>) -> Self {
+        Self {
+            size: Some(size.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the position of the top-left corner of one of the tiles of the
+    /// [`Tiling`].
+    pub fn origin(self, origin: impl Into<Point>) -> Self {
+        Self {
+            origin: origin.into(),
+            ..self
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use crate::core::alignment;
-use crate::core::text::{LineHeight, Shaping};
-use crate::core::{Color, Font, Pixels, Point, Size, Vector};
+use crate::core::text::{LineHeight, Outline, Shadow, Shaping};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Size, Vector};
 use crate::geometry::Path;
 use crate::text;
 
+use std::ops::Range;
+
 /// A bunch of text that can be drawn to a canvas
 #[derive(Debug, Clone)]
 pub struct Text {
@@ -32,21 +34,63 @@ pub struct Text {
     pub vertical_alignment: alignment::Vertical,
     /// The shaping strategy of the text.
     pub shaping: Shaping,
+    /// The [`Outline`] stroked around the glyphs of the text, if any.
+    pub outline: Option<Outline>,
+    /// The [`Shadow`] drawn behind the text, if any.
+    pub shadow: Option<Shadow>,
 }
 
 impl Text {
+    /// Measures the [`Size`] of the [`Text`] once laid out in a single line.
+    pub fn measure(&self) -> Size {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = self.buffer_line(font_system.raw());
+
+        let layout = buffer.layout(
+            font_system.raw(),
+            self.size.0,
+            None,
+            cosmic_text::Wrap::None,
+            None,
+            4,
+        );
+
+        let width = layout.iter().map(|line| line.w).fold(0.0, f32::max);
+
+        Size::new(width, self.line_height.to_absolute(self.size).0)
+    }
+
+    /// Returns the bounds of the [`Text`] once placed at its position,
+    /// following its alignment.
+    ///
+    /// This is useful to lay out text that must not overlap.
+    pub fn bounds(&self) -> Rectangle {
+        let size = self.measure();
+
+        let x = match self.horizontal_alignment {
+            alignment::Horizontal::Left => self.position.x,
+            alignment::Horizontal::Center => self.position.x - size.width / 2.0,
+            alignment::Horizontal::Right => self.position.x - size.width,
+        };
+
+        let y = match self.vertical_alignment {
+            alignment::Vertical::Top => self.position.y,
+            alignment::Vertical::Center => self.position.y - size.height / 2.0,
+            alignment::Vertical::Bottom => self.position.y - size.height,
+        };
+
+        Rectangle::new(Point::new(x, y), size)
+    }
+
     /// Computes the [`Path`]s of the [`Text`] and draws them using
     /// the given closure.
     pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
         let mut font_system =
             text::font_system().write().expect("Write font system");
 
-        let mut buffer = cosmic_text::BufferLine::new(
-            &self.content,
-            cosmic_text::LineEnding::default(),
-            cosmic_text::AttrsList::new(text::to_attributes(self.font)),
-            text::to_shaping(self.shaping),
-        );
+        let mut buffer = self.buffer_line(font_system.raw());
 
         let layout = buffer.layout(
             font_system.raw(),
@@ -166,6 +210,111 @@ impl Text {
             }
         }
     }
+
+    /// Computes the glyphs of the [`Text`], laid out in a single line
+    /// starting at the origin.
+    ///
+    /// Glyphs of the same cluster are merged together.
+    pub(crate) fn glyphs(&self) -> Vec<Glyph> {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = self.buffer_line(font_system.raw());
+
+        let layout = buffer.layout(
+            font_system.raw(),
+            self.size.0,
+            None,
+            cosmic_text::Wrap::None,
+            None,
+            4,
+        );
+
+        let mut glyphs: Vec<Glyph> = Vec::new();
+
+        for glyph in layout.iter().flat_map(|line| line.glyphs.iter()) {
+            match glyphs.last_mut() {
+                Some(last) if last.range == (glyph.start..glyph.end) => {
+                    last.width = (glyph.x + glyph.w - last.x).max(last.width);
+                }
+                _ => {
+                    glyphs.push(Glyph {
+                        range: glyph.start..glyph.end,
+                        x: glyph.x,
+                        width: glyph.w,
+                    });
+                }
+            }
+        }
+
+        glyphs
+    }
+
+    fn buffer_line(
+        &self,
+        font_system: &mut cosmic_text::FontSystem,
+    ) -> cosmic_text::BufferLine {
+        let attrs = text::to_attributes(self.font);
+        let mut attrs_list = cosmic_text::AttrsList::new(attrs);
+
+        for (range, attrs) in
+            text::to_spans(font_system, &self.content, self.font, attrs)
+        {
+            attrs_list.add_span(range, attrs);
+        }
+
+        cosmic_text::BufferLine::new(
+            &self.content,
+            cosmic_text::LineEnding::default(),
+            attrs_list,
+            text::to_shaping(self.shaping, &self.content),
+        )
+    }
+}
+
+/// A cluster of glyphs of some [`Text`].
+#[derive(Debug, Clone)]
+pub(crate) struct Glyph {
+    /// The range of the cluster in the content of the [`Text`].
+    pub range: Range<usize>,
+    /// The horizontal position of the cluster.
+    pub x: f32,
+    /// The width of the cluster.
+    pub width: f32,
+}
+
+/// The placement of some [`Text`] drawn along a [`Path`].
+///
+/// The horizontal alignment of the [`Text`] is relative to the
+/// [`offset`](Self::offset); while its vertical alignment is relative to the
+/// [`Path`] itself.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Along {
+    /// The distance along the [`Path`] where the [`Text`] is placed.
+    ///
+    /// By default, it is set to `0.0`; which is the start of the [`Path`].
+    pub offset: f32,
+
+    /// The extra space between each of the characters of the [`Text`].
+    ///
+    /// By default, it is set to `0.0`.
+    pub spacing: f32,
+}
+
+impl Along {
+    /// Creates a new [`Along`] placement at the given distance of a [`Path`].
+    pub fn new(offset: f32) -> Self {
+        Self {
+            offset,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the extra space between each of the characters of the [`Along`]
+    /// placement.
+    pub fn spacing(self, spacing: f32) -> Self {
+        Self { spacing, ..self }
+    }
 }
 
 impl Default for Text {
@@ -179,7 +328,9 @@ impl Default for Text {
             font: Font::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
-            shaping: Shaping::Basic,
+            shaping: Shaping::default(),
+            outline: None,
+            shadow: None,
         }
     }
 }
@@ -0,0 +1,42 @@
+//! Draw triangles that are already tessellated.
+use crate::core::{Color, Point};
+
+/// A vertex of a mesh with its own [`Color`].
+///
+/// The colors of the vertices are interpolated across their triangles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    /// The position of the [`Vertex`].
+    pub position: Point,
+
+    /// The [`Color`] of the [`Vertex`].
+    pub color: Color,
+}
+
+/// A vertex of a mesh mapped to a point of an image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TexturedVertex {
+    /// The position of the [`TexturedVertex`].
+    pub position: Point,
+
+    /// The normalized coordinates of the image at the [`TexturedVertex`].
+    ///
+    /// The image spans from `(0, 0)` to `(1, 1)`, and it is repeated
+    /// outside of that range.
+    pub uv: Point,
+}
+
+/// Returns the triangles described by the given vertices and indices.
+///
+/// Every three indices are a triangle; triangles with any index out of
+/// bounds are skipped, as well as any trailing indices.
+pub fn triangles<'a, T>(
+    vertices: &'a [T],
+    indices: &'a [u32],
+) -> impl Iterator<Item = [&'a T; 3]> + 'a {
+    indices.chunks_exact(3).filter_map(|triangle| {
+        let vertex = |i: usize| vertices.get(triangle[i] as usize);
+
+        Some([vertex(0)?, vertex(1)?, vertex(2)?])
+    })
+}
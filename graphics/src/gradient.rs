@@ -10,13 +10,16 @@ use half::f16;
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction or around a center.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors along the distance to its `center`, up to its
+    /// `radius`.
+    Radial(Radial),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +28,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
         }
     }
 }
@@ -63,19 +73,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -96,40 +94,129 @@ impl Linear {
 
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        let (colors, offsets) = pack_stops(&self.stops);
 
-        for (index, stop) in self.stops.iter().enumerate() {
-            let [r, g, b, a] =
-                color::pack(stop.map_or(Color::default(), |s| s.color))
-                    .components();
+        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
 
-            colors[index] = [
-                pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-            ];
+        Packed {
+            colors,
+            offsets,
+            direction,
+            kind: LINEAR,
+        }
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The radius of the gradient, where its last [`ColorStop`] is reached.
+    pub radius: f32,
+
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
 
-            offsets[index] =
-                stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; 8],
         }
+    }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        let (colors, offsets) = pack_stops(&self.stops);
+
+        let direction = [self.center.x, self.center.y, self.radius, 0.0];
 
         Packed {
             colors,
             offsets,
             direction,
+            kind: RADIAL,
+        }
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
         }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn pack_stops(stops: &[Option<ColorStop>; 8]) -> ([[u32; 2]; 8], [u32; 4]) {
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
+
+    for (index, stop) in stops.iter().enumerate() {
+        let [r, g, b, a] =
+            color::pack(stop.map_or(Color::default(), |s| s.color))
+                .components();
+
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
+
+        offsets[index] =
+            stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
     }
+
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
+
+    (colors, offsets)
 }
 
+const LINEAR: u32 = 0;
+const RADIAL: u32 = 1;
+
 /// Packed [`Gradient`] data for use in shader code.
 #[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
 #[repr(C)]
@@ -138,36 +225,17 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // The start and end points of a linear gradient; or the center and
+    // radius of a radial one
     direction: [f32; 4],
+    kind: u32,
 }
 
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
-
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
-
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
+            let (colors, offsets) = pack_stops(&linear.stops);
 
             let (start, end) = linear.angle.to_distance(&bounds);
 
@@ -177,6 +245,7 @@ pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
                 colors,
                 offsets,
                 direction,
+                kind: LINEAR,
             }
         }
     }
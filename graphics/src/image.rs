@@ -134,3 +134,17 @@ pub fn load(
         ))
     }
 }
+
+#[cfg(feature = "image")]
+/// Generates a [`Palette`] from the dominant colors of the image with the
+/// given [`Handle`].
+///
+/// Returns `None` if the image cannot be loaded or has no opaque pixels.
+///
+/// [`Handle`]: image::Handle
+/// [`Palette`]: crate::core::theme::Palette
+pub fn palette(handle: &image::Handle) -> Option<crate::core::theme::Palette> {
+    let image = load(handle).ok()?;
+
+    crate::core::theme::Palette::from_pixels(image.as_raw())
+}
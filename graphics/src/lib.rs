@@ -9,6 +9,7 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod antialiasing;
+mod blend_mode;
 mod settings;
 mod viewport;
 
@@ -27,6 +28,7 @@ pub mod text;
 pub mod geometry;
 
 pub use antialiasing::Antialiasing;
+pub use blend_mode::BlendMode;
 pub use cache::Cache;
 pub use compositor::Compositor;
 pub use error::Error;
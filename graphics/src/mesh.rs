@@ -1,7 +1,9 @@
 //! Draw triangles!
 use crate::color;
+use crate::core::image;
 use crate::core::{Rectangle, Transformation};
 use crate::gradient;
+use crate::BlendMode;
 
 use bytemuck::{Pod, Zeroable};
 
@@ -18,6 +20,9 @@ pub enum Mesh {
 
         /// The clip bounds of the [`Mesh`].
         clip_bounds: Rectangle,
+
+        /// The [`BlendMode`] of the [`Mesh`].
+        blend_mode: BlendMode,
     },
     /// A mesh with a gradient.
     Gradient {
@@ -29,6 +34,26 @@ pub enum Mesh {
 
         /// The clip bounds of the [`Mesh`].
         clip_bounds: Rectangle,
+
+        /// The [`BlendMode`] of the [`Mesh`].
+        blend_mode: BlendMode,
+    },
+    /// A mesh with a repeated image.
+    Pattern {
+        /// The vertices and indices of the mesh.
+        buffers: Indexed<PatternVertex2D>,
+
+        /// The handle of the repeated image.
+        handle: image::Handle,
+
+        /// The [`Transformation`] for the vertices of the [`Mesh`].
+        transformation: Transformation,
+
+        /// The clip bounds of the [`Mesh`].
+        clip_bounds: Rectangle,
+
+        /// The [`BlendMode`] of the [`Mesh`].
+        blend_mode: BlendMode,
     },
 }
 
@@ -38,6 +63,7 @@ impl Mesh {
         match self {
             Self::Solid { buffers, .. } => &buffers.indices,
             Self::Gradient { buffers, .. } => &buffers.indices,
+            Self::Pattern { buffers, .. } => &buffers.indices,
         }
     }
 
@@ -45,7 +71,17 @@ impl Mesh {
     pub fn transformation(&self) -> Transformation {
         match self {
             Self::Solid { transformation, .. }
-            | Self::Gradient { transformation, .. } => *transformation,
+            | Self::Gradient { transformation, .. }
+            | Self::Pattern { transformation, .. } => *transformation,
+        }
+    }
+
+    /// Returns the [`BlendMode`] of the [`Mesh`].
+    pub fn blend_mode(&self) -> BlendMode {
+        match self {
+            Self::Solid { blend_mode, .. }
+            | Self::Gradient { blend_mode, .. }
+            | Self::Pattern { blend_mode, .. } => *blend_mode,
         }
     }
 
@@ -61,6 +97,11 @@ impl Mesh {
                 clip_bounds,
                 transformation,
                 ..
+            }
+            | Self::Pattern {
+                clip_bounds,
+                transformation,
+                ..
             } => *clip_bounds * *transformation,
         }
     }
@@ -100,6 +141,23 @@ pub struct GradientVertex2D {
     pub gradient: gradient::Packed,
 }
 
+/// A vertex which contains 2D position & the layout of a pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PatternVertex2D {
+    /// The vertex position in 2D space.
+    pub position: [f32; 2],
+
+    /// The rows of the affine transformation that maps the position of the
+    /// vertex to the space of the pattern.
+    pub transform: [f32; 6],
+
+    /// The size of a single tile of the pattern.
+    ///
+    /// It is zero when the dimensions of the image should be used.
+    pub tile: [f32; 2],
+}
+
 /// The result of counting the attributes of a set of meshes.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct AttributeCount {
@@ -115,6 +173,12 @@ pub struct AttributeCount {
     /// The total amount of gradient meshes.
     pub gradients: usize,
 
+    /// The total amount of pattern vertices.
+    pub pattern_vertices: usize,
+
+    /// The total amount of pattern meshes.
+    pub patterns: usize,
+
     /// The total amount of indices.
     pub indices: usize,
 }
@@ -135,6 +199,11 @@ pub fn attribute_count_of(meshes: &[Mesh]) -> AttributeCount {
                     count.gradient_vertices += buffers.vertices.len();
                     count.indices += buffers.indices.len();
                 }
+                Mesh::Pattern { buffers, .. } => {
+                    count.patterns += 1;
+                    count.pattern_vertices += buffers.vertices.len();
+                    count.indices += buffers.indices.len();
+                }
             }
 
             count
@@ -1,4 +1,4 @@
-use crate::core::{Font, Pixels};
+use crate::core::{Density, Font, Pixels};
 use crate::Antialiasing;
 
 /// The settings of a renderer.
@@ -16,6 +16,24 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The [`Density`] of the built-in widgets.
+    ///
+    /// By default, it is [`Density::Comfortable`].
+    pub density: Density,
+
+    /// The rendering backends to try, in order of preference.
+    ///
+    /// The first backend that can be initialized is used. The `ICED_BACKEND`
+    /// environment variable overrides it with a list of comma-separated
+    /// backends.
+    ///
+    /// The available backends are `wgpu`, `tiny-skia`, and `wgpu-{name}` to
+    /// use only the given `wgpu` backend; like `wgpu-vulkan`, `wgpu-metal`,
+    /// `wgpu-dx12`, or `wgpu-gl`.
+    ///
+    /// By default, it is empty; which tries every backend enabled in order.
+    pub backends: &'static [&'static str],
 }
 
 impl Default for Settings {
@@ -24,6 +42,8 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            density: Density::default(),
+            backends: &[],
         }
     }
 }
@@ -11,11 +11,12 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::Shaping;
+use crate::core::text::{Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::{Arc, RwLock, Weak};
 
 /// A text primitive.
@@ -251,6 +252,86 @@ pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
         .style(to_style(font.style))
 }
 
+/// Splits the given content into ranges with the attributes that follow the
+/// fallback families of the given [`Font`].
+///
+/// Each grapheme is assigned to the first family that contains all of its
+/// characters, or to the main family if there is none.
+pub fn to_spans(
+    font_system: &mut cosmic_text::FontSystem,
+    content: &str,
+    font: Font,
+    attrs: cosmic_text::Attrs<'static>,
+) -> Vec<(Range<usize>, cosmic_text::Attrs<'static>)> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    if font.fallback.is_empty() {
+        return vec![(0..content.len(), attrs)];
+    }
+
+    let families: Vec<_> = std::iter::once(font.family)
+        .chain(font.fallback.iter().copied())
+        .map(|family| {
+            let id = font_system.db().query(&cosmic_text::fontdb::Query {
+                families: &[to_family(family)],
+                weight: to_weight(font.weight),
+                stretch: to_stretch(font.stretch),
+                style: to_style(font.style),
+            });
+
+            (family, id.and_then(|id| font_system.get_font(id)))
+        })
+        .collect();
+
+    let contains = |font: &cosmic_text::Font, grapheme: &str| {
+        let charmap = font.as_swash().charmap();
+
+        grapheme.chars().all(|c| {
+            // Joiners and variation selectors do not need glyphs
+            c.is_control()
+                || matches!(c, '\u{200d}' | '\u{fe00}'..='\u{fe0f}')
+                || charmap.map(c) != 0
+        })
+    };
+
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut current = 0;
+
+    for (offset, grapheme) in content.grapheme_indices(true) {
+        // Whitespace stays with the current family to avoid splitting runs
+        if grapheme.chars().all(char::is_whitespace) {
+            continue;
+        }
+
+        let family = families
+            .iter()
+            .position(|(_, font)| {
+                font.as_ref().is_some_and(|font| contains(font, grapheme))
+            })
+            .unwrap_or(0);
+
+        if family != current {
+            if offset > start {
+                spans.push((
+                    start..offset,
+                    attrs.family(to_family(families[current].0)),
+                ));
+            }
+
+            start = offset;
+            current = family;
+        }
+    }
+
+    spans.push((
+        start..content.len(),
+        attrs.family(to_family(families[current].0)),
+    ));
+
+    spans
+}
+
 fn to_family(family: font::Family) -> cosmic_text::Family<'static> {
     match family {
         font::Family::Name(name) => cosmic_text::Family::Name(name),
@@ -298,14 +379,27 @@ fn to_style(style: font::Style) -> cosmic_text::Style {
     }
 }
 
-/// Converts some [`Shaping`] strategy to a [`cosmic_text::Shaping`] strategy.
-pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
+/// Converts some [`Shaping`] strategy to a [`cosmic_text::Shaping`] strategy
+/// for the given text.
+pub fn to_shaping(shaping: Shaping, text: &str) -> cosmic_text::Shaping {
     match shaping {
+        Shaping::Auto if text.is_ascii() => cosmic_text::Shaping::Basic,
+        Shaping::Auto => cosmic_text::Shaping::Advanced,
         Shaping::Basic => cosmic_text::Shaping::Basic,
         Shaping::Advanced => cosmic_text::Shaping::Advanced,
     }
 }
 
+/// Converts some [`Wrapping`] strategy to a [`cosmic_text::Wrap`] strategy.
+pub fn to_wrap(wrapping: Wrapping) -> cosmic_text::Wrap {
+    match wrapping {
+        Wrapping::None => cosmic_text::Wrap::None,
+        Wrapping::Word => cosmic_text::Wrap::Word,
+        Wrapping::Glyph => cosmic_text::Wrap::Glyph,
+        Wrapping::WordOrGlyph => cosmic_text::Wrap::WordOrGlyph,
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
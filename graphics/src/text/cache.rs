@@ -51,11 +51,21 @@ impl Cache {
                 Some(key.bounds.width),
                 Some(key.bounds.height.max(key.line_height)),
             );
-            buffer.set_text(
+
+            let spans = text::to_spans(
                 font_system,
                 key.content,
+                key.font,
+                text::to_attributes(key.font),
+            );
+
+            buffer.set_rich_text(
+                font_system,
+                spans
+                    .into_iter()
+                    .map(|(range, attrs)| (&key.content[range], attrs)),
                 text::to_attributes(key.font),
-                text::to_shaping(key.shaping),
+                text::to_shaping(key.shaping, key.content),
             );
 
             let bounds = text::measure(&buffer);
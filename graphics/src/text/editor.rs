@@ -3,7 +3,7 @@ use crate::core::text::editor::{
     self, Action, Cursor, Direction, Edit, Motion,
 };
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Wrapping};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
@@ -111,50 +111,11 @@ impl editor::Editor for Editor {
         let buffer = buffer_from_editor(&internal.editor);
 
         match internal.editor.selection_bounds() {
-            Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
-
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height
-                                    - buffer.scroll().vertical,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                Cursor::Selection(regions)
-            }
+            Some((start, end)) => Cursor::Selection(highlight_range(
+                buffer,
+                (start.line, start.index),
+                (end.line, end.index),
+            )),
             _ => {
                 let line_height = buffer.metrics().line_height;
 
@@ -239,6 +200,34 @@ impl editor::Editor for Editor {
         (cursor.line, cursor.index)
     }
 
+    fn range_bounds(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        highlight_range(buffer_from_editor(&internal.editor), start, end)
+    }
+
+    fn hit_test(&self, point: Point) -> Option<(usize, usize)> {
+        let cursor = self.buffer().hit(point.x, point.y)?;
+
+        Some((cursor.line, cursor.index))
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        let mut lines: Vec<(usize, f32)> = Vec::new();
+
+        for run in self.buffer().layout_runs() {
+            if lines.last().map(|(line, _)| *line) != Some(run.line_i) {
+                lines.push((run.line_i, run.line_top));
+            }
+        }
+
+        lines
+    }
+
     fn perform(&mut self, action: Action) {
         let mut font_system =
             text::font_system().write().expect("Write font system");
@@ -342,6 +331,27 @@ impl editor::Editor for Editor {
                     );
                 }
             }
+            Action::SelectRange { start, end } => {
+                let buffer = buffer_from_editor(editor);
+
+                let to_cursor = |(line, index): (usize, usize)| {
+                    let line = line.min(buffer.lines.len().saturating_sub(1));
+                    let index = index.min(
+                        buffer
+                            .lines
+                            .get(line)
+                            .map_or(0, |line| line.text().len()),
+                    );
+
+                    cosmic_text::Cursor::new(line, index)
+                };
+
+                let start = to_cursor(start);
+                let end = to_cursor(end);
+
+                editor.set_selection(cosmic_text::Selection::Normal(start));
+                editor.set_cursor(end);
+            }
 
             // Editing events
             Action::Edit(edit) => {
@@ -437,6 +447,7 @@ impl editor::Editor for Editor {
         new_font: Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     ) {
         let editor =
@@ -492,6 +503,15 @@ impl editor::Editor for Editor {
             );
         }
 
+        let new_wrap = text::to_wrap(new_wrapping);
+
+        if new_wrap != buffer_from_editor(&internal.editor).wrap() {
+            log::trace!("Updating `Wrap` strategy of `Editor`...");
+
+            buffer_mut_from_editor(&mut internal.editor)
+                .set_wrap(font_system.raw(), new_wrap);
+        }
+
         if new_bounds != internal.bounds {
             log::trace!("Updating size of `Editor`...");
 
@@ -664,6 +684,61 @@ impl PartialEq for Weak {
     }
 }
 
+fn highlight_range(
+    buffer: &cosmic_text::Buffer,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Vec<Rectangle> {
+    let (start, end) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+
+    if start.0 >= buffer.lines.len() {
+        return Vec::new();
+    }
+
+    let line_height = buffer.metrics().line_height;
+    let selected_lines = end.0 - start.0 + 1;
+
+    let visual_lines_offset = visual_lines_offset(start.0, buffer);
+
+    buffer
+        .lines
+        .iter()
+        .skip(start.0)
+        .take(selected_lines)
+        .enumerate()
+        .flat_map(|(i, line)| {
+            highlight_line(
+                line,
+                if i == 0 { start.1 } else { 0 },
+                if i == selected_lines - 1 {
+                    end.1
+                } else {
+                    line.text().len()
+                },
+            )
+        })
+        .enumerate()
+        .filter_map(|(visual_line, (x, width))| {
+            if width > 0.0 {
+                Some(Rectangle {
+                    x,
+                    width,
+                    y: (visual_line as i32 + visual_lines_offset) as f32
+                        * line_height
+                        - buffer.scroll().vertical,
+                    height: line_height,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -1,11 +1,12 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, Shaping, Span, Text};
+use crate::core::text::{Hit, Justification, Shaping, Span, Text};
 use crate::core::{Font, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -17,6 +18,7 @@ struct Internal {
     buffer: cosmic_text::Buffer,
     font: Font,
     shaping: Shaping,
+    justification: Justification,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     bounds: Size,
@@ -79,13 +81,24 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_text(
+        let spans = text::to_spans(
             font_system.raw(),
             text.content,
+            text.font,
             text::to_attributes(text.font),
-            text::to_shaping(text.shaping),
         );
 
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans
+                .into_iter()
+                .map(|(range, attrs)| (&text.content[range], attrs)),
+            text::to_attributes(text.font),
+            text::to_shaping(text.shaping, text.content),
+        );
+
+        justify(&mut buffer, font_system.raw(), text.justification);
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
@@ -94,6 +107,7 @@ impl core::text::Paragraph for Paragraph {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            justification: text.justification,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -120,10 +134,22 @@ impl core::text::Paragraph for Paragraph {
             Some(text.bounds.height),
         );
 
-        buffer.set_rich_text(
-            font_system.raw(),
-            text.content.iter().enumerate().map(|(i, span)| {
-                let attrs = text::to_attributes(span.font.unwrap_or(text.font));
+        let shaping = match text.shaping {
+            Shaping::Auto
+                if text.content.iter().any(|span| !span.text.is_ascii()) =>
+            {
+                cosmic_text::Shaping::Advanced
+            }
+            shaping => text::to_shaping(shaping, ""),
+        };
+
+        let spans: Vec<_> = text
+            .content
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| {
+                let font = span.font.unwrap_or(text.font);
+                let attrs = text::to_attributes(font);
 
                 let attrs = match (span.size, span.line_height) {
                     (None, None) => attrs,
@@ -146,12 +172,23 @@ impl core::text::Paragraph for Paragraph {
                     attrs
                 };
 
-                (span.text.as_ref(), attrs.metadata(i))
-            }),
+                text::to_spans(font_system.raw(), &span.text, font, attrs)
+                    .into_iter()
+                    .map(move |(range, attrs)| {
+                        (&span.text[range], attrs.metadata(i))
+                    })
+            })
+            .collect();
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans,
             text::to_attributes(text.font),
-            text::to_shaping(text.shaping),
+            shaping,
         );
 
+        justify(&mut buffer, font_system.raw(), text.justification);
+
         let min_bounds = text::measure(&buffer);
 
         Self(Arc::new(Internal {
@@ -160,6 +197,7 @@ impl core::text::Paragraph for Paragraph {
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            justification: text.justification,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -178,6 +216,14 @@ impl core::text::Paragraph for Paragraph {
             Some(new_bounds.height),
         );
 
+        // The alignment of the lines survives resizing, but justified lines
+        // need to be laid out again to fill the new width
+        justify(
+            &mut paragraph.buffer,
+            font_system.raw(),
+            paragraph.justification,
+        );
+
         paragraph.bounds = new_bounds;
         paragraph.min_bounds = text::measure(&paragraph.buffer);
     }
@@ -192,6 +238,7 @@ impl core::text::Paragraph for Paragraph {
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
+            || paragraph.justification != text.justification
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
         {
@@ -221,6 +268,16 @@ impl core::text::Paragraph for Paragraph {
         Some(Hit::CharOffset(cursor.index))
     }
 
+    fn hit_index(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+        let cursor = buffer.hit(point.x, point.y)?;
+
+        let offset: usize =
+            buffer.lines[..cursor.line].iter().map(line_length).sum();
+
+        Some(offset + cursor.index)
+    }
+
     fn hit_span(&self, point: Point) -> Option<usize> {
         let internal = self.internal();
 
@@ -302,6 +359,61 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let line_offsets: Vec<usize> = internal
+            .buffer
+            .lines
+            .iter()
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line_length(line);
+
+                Some(start)
+            })
+            .collect();
+
+        let mut bounds = Vec::new();
+
+        for run in internal.buffer.layout_runs() {
+            let offset = line_offsets[run.line_i];
+            let mut current_bounds: Option<Rectangle> = None;
+
+            let glyphs = run.glyphs.iter().filter(|glyph| {
+                offset + glyph.start < range.end
+                    && range.start < offset + glyph.end
+            });
+
+            for glyph in glyphs {
+                match current_bounds.as_mut() {
+                    None => {
+                        current_bounds = Some(Rectangle::new(
+                            Point::new(glyph.x, run.line_top + glyph.y),
+                            Size::new(
+                                glyph.w,
+                                glyph
+                                    .line_height_opt
+                                    .unwrap_or(run.line_height),
+                            ),
+                        ));
+                    }
+                    Some(current_bounds) => {
+                        let right = (current_bounds.x + current_bounds.width)
+                            .max(glyph.x + glyph.w);
+
+                        current_bounds.x = current_bounds.x.min(glyph.x);
+                        current_bounds.width = right - current_bounds.x;
+                    }
+                }
+            }
+
+            bounds.extend(current_bounds);
+        }
+
+        bounds
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -358,6 +470,7 @@ impl fmt::Debug for Paragraph {
         f.debug_struct("Paragraph")
             .field("font", &paragraph.font)
             .field("shaping", &paragraph.shaping)
+            .field("justification", &paragraph.justification)
             .field("horizontal_alignment", &paragraph.horizontal_alignment)
             .field("vertical_alignment", &paragraph.vertical_alignment)
             .field("bounds", &paragraph.bounds)
@@ -370,6 +483,7 @@ impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.font == other.font
             && self.shaping == other.shaping
+            && self.justification == other.justification
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
@@ -378,6 +492,31 @@ impl PartialEq for Internal {
     }
 }
 
+/// Returns the length in bytes of the given [`cosmic_text::BufferLine`] in
+/// the original text, including its line ending.
+fn line_length(line: &cosmic_text::BufferLine) -> usize {
+    line.text().len() + line.ending().as_str().len()
+}
+
+/// Stretches the lines of the given [`cosmic_text::Buffer`] to its width, if
+/// the [`Justification`] asks for it.
+fn justify(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    justification: Justification,
+) {
+    match justification {
+        Justification::None => {}
+        Justification::Full => {
+            for line in &mut buffer.lines {
+                let _ = line.set_align(Some(cosmic_text::Align::Justified));
+            }
+
+            buffer.shape_until_scroll(font_system, false);
+        }
+    }
+}
+
 impl Default for Internal {
     fn default() -> Self {
         Self {
@@ -387,6 +526,7 @@ impl Default for Internal {
             }),
             font: Font::default(),
             shaping: Shaping::default(),
+            justification: Justification::default(),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
@@ -2,6 +2,7 @@
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
+use crate::core::window;
 use crate::core::{
     self, Background, Color, Image, Point, Rectangle, Size, Svg, Transformation,
 };
@@ -68,6 +69,40 @@ where
     fn end_transformation(&mut self) {
         delegate!(self, renderer, renderer.end_transformation());
     }
+
+    fn density(&self) -> core::Density {
+        delegate!(self, renderer, renderer.density())
+    }
+}
+
+impl<A, B> renderer::Headless for Renderer<A, B>
+where
+    A: renderer::Headless,
+    B: renderer::Headless,
+{
+    fn new(
+        default_font: core::Font,
+        default_text_size: core::Pixels,
+    ) -> Option<Self> {
+        A::new(default_font, default_text_size)
+            .map(Self::Primary)
+            .or_else(|| {
+                B::new(default_font, default_text_size).map(Self::Secondary)
+            })
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        delegate!(
+            self,
+            renderer,
+            renderer.screenshot(size, scale_factor, background_color)
+        )
+    }
 }
 
 impl<A, B> core::text::Renderer for Renderer<A, B>
@@ -217,46 +252,23 @@ where
         compatible_window: W,
         backend: Option<&str>,
     ) -> Result<Self, graphics::Error> {
-        use std::env;
-
-        let backends = backend
-            .map(str::to_owned)
-            .or_else(|| env::var("ICED_BACKEND").ok());
-
-        let mut candidates: Vec<_> = backends
-            .map(|backends| {
-                backends
-                    .split(',')
-                    .filter(|candidate| !candidate.is_empty())
-                    .map(str::to_owned)
-                    .map(Some)
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        if candidates.is_empty() {
-            candidates.push(None);
-        }
-
         let mut errors = vec![];
 
-        for backend in candidates.iter().map(Option::as_deref) {
-            match A::with_backend(settings, compatible_window.clone(), backend)
-                .await
-            {
-                Ok(compositor) => return Ok(Self::Primary(compositor)),
-                Err(error) => {
-                    errors.push(error);
-                }
+        match A::with_backend(settings, compatible_window.clone(), backend)
+            .await
+        {
+            Ok(compositor) => return Ok(Self::Primary(compositor)),
+            Err(error) => {
+                errors.push(error);
             }
+        }
 
-            match B::with_backend(settings, compatible_window.clone(), backend)
-                .await
-            {
-                Ok(compositor) => return Ok(Self::Secondary(compositor)),
-                Err(error) => {
-                    errors.push(error);
-                }
+        match B::with_backend(settings, compatible_window.clone(), backend)
+            .await
+        {
+            Ok(compositor) => return Ok(Self::Secondary(compositor)),
+            Err(error) => {
+                errors.push(error);
             }
         }
 
@@ -274,6 +286,20 @@ where
         }
     }
 
+    fn create_renderer_with(
+        &self,
+        text_antialiasing: core::text::Antialiasing,
+    ) -> Self::Renderer {
+        match self {
+            Self::Primary(compositor) => Renderer::Primary(
+                compositor.create_renderer_with(text_antialiasing),
+            ),
+            Self::Secondary(compositor) => Renderer::Secondary(
+                compositor.create_renderer_with(text_antialiasing),
+            ),
+        }
+    }
+
     fn create_surface<W: compositor::Window + Clone>(
         &mut self,
         window: W,
@@ -307,6 +333,49 @@ where
         }
     }
 
+    fn configure_surface_with(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        present_mode: window::PresentMode,
+    ) {
+        match (self, surface) {
+            (Self::Primary(compositor), Surface::Primary(surface)) => {
+                compositor.configure_surface_with(
+                    surface,
+                    width,
+                    height,
+                    present_mode,
+                );
+            }
+            (Self::Secondary(compositor), Surface::Secondary(surface)) => {
+                compositor.configure_surface_with(
+                    surface,
+                    width,
+                    height,
+                    present_mode,
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn present_modes(
+        &self,
+        surface: &Self::Surface,
+    ) -> Vec<window::PresentMode> {
+        match (self, surface) {
+            (Self::Primary(compositor), Surface::Primary(surface)) => {
+                compositor.present_modes(surface)
+            }
+            (Self::Secondary(compositor), Surface::Secondary(surface)) => {
+                compositor.present_modes(surface)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         delegate!(self, compositor, compositor.load_font(font));
     }
@@ -315,6 +384,18 @@ where
         delegate!(self, compositor, compositor.fetch_information())
     }
 
+    fn statistics(&self, renderer: &Self::Renderer) -> compositor::Statistics {
+        match (self, renderer) {
+            (Self::Primary(compositor), Renderer::Primary(renderer)) => {
+                compositor.statistics(renderer)
+            }
+            (Self::Secondary(compositor), Renderer::Secondary(renderer)) => {
+                compositor.statistics(renderer)
+            }
+            _ => compositor::Statistics::default(),
+        }
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -413,9 +494,15 @@ where
 #[cfg(feature = "geometry")]
 mod geometry {
     use super::Renderer;
-    use crate::core::{Point, Radians, Rectangle, Size, Svg, Vector};
+    use crate::core::image;
+    use crate::core::{
+        Point, Radians, Rectangle, Size, Svg, Transformation, Vector,
+    };
     use crate::graphics::cache::{self, Cached};
-    use crate::graphics::geometry::{self, Fill, Image, Path, Stroke, Text};
+    use crate::graphics::geometry::{
+        self, BlendMode, Fill, Image, Path, Stroke, Text, TexturedVertex,
+        Vertex,
+    };
 
     impl<A, B> geometry::Renderer for Renderer<A, B>
     where
@@ -544,6 +631,23 @@ mod geometry {
             delegate!(self, frame, frame.fill_text(text));
         }
 
+        fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]) {
+            delegate!(self, frame, frame.draw_mesh(vertices, indices));
+        }
+
+        fn draw_textured_mesh(
+            &mut self,
+            vertices: &[TexturedVertex],
+            indices: &[u32],
+            handle: image::Handle,
+        ) {
+            delegate!(
+                self,
+                frame,
+                frame.draw_textured_mesh(vertices, indices, handle)
+            );
+        }
+
         fn draw_image(&mut self, bounds: Rectangle, image: impl Into<Image>) {
             delegate!(self, frame, frame.draw_image(bounds, image));
         }
@@ -560,6 +664,10 @@ mod geometry {
             delegate!(self, frame, frame.pop_transform());
         }
 
+        fn transform(&self) -> Transformation {
+            delegate!(self, frame, frame.transform())
+        }
+
         fn draft(&mut self, bounds: Rectangle) -> Self {
             match self {
                 Self::Primary(frame) => Self::Primary(frame.draft(bounds)),
@@ -567,6 +675,37 @@ mod geometry {
             }
         }
 
+        fn draft_clip_path(&mut self, path: &Path) -> Self {
+            match self {
+                Self::Primary(frame) => {
+                    Self::Primary(frame.draft_clip_path(path))
+                }
+                Self::Secondary(frame) => {
+                    Self::Secondary(frame.draft_clip_path(path))
+                }
+            }
+        }
+
+        fn draft_blend(&mut self, blend_mode: BlendMode) -> Self {
+            match self {
+                Self::Primary(frame) => {
+                    Self::Primary(frame.draft_blend(blend_mode))
+                }
+                Self::Secondary(frame) => {
+                    Self::Secondary(frame.draft_blend(blend_mode))
+                }
+            }
+        }
+
+        fn draft_blur(&mut self, radius: f32) -> Self {
+            match self {
+                Self::Primary(frame) => Self::Primary(frame.draft_blur(radius)),
+                Self::Secondary(frame) => {
+                    Self::Secondary(frame.draft_blur(radius))
+                }
+            }
+        }
+
         fn paste(&mut self, frame: Self) {
             match (self, frame) {
                 (Self::Primary(target), Self::Primary(source)) => {
@@ -0,0 +1,360 @@
+//! Inspect the widget tree of a running application.
+//!
+//! A [`Snapshot`] traverses the widget tree of every window and collects
+//! a [`Node`] for each widget that takes part in operations; together with
+//! its [`widget::Id`], its bounds, and its key properties. The nodes can be
+//! encoded as JSON with [`to_json`], so they can be consumed by external
+//! tools like inspectors or test runners.
+//!
+//! Applications running on `iced_winit` with the `debug` feature can be
+//! inspected by setting the `ICED_INSPECT` environment variable to an
+//! address to listen on; like `127.0.0.1:9229`. Every `snapshot` line sent
+//! to that socket is answered with a line containing the JSON of the
+//! widget tree.
+use crate::core::widget::operation::{
+    Adjustable, Focusable, Outcome, Scrollable, TextInput, Toggleable,
+};
+use crate::core::widget::{self, Operation};
+use crate::core::{Rectangle, Vector};
+use crate::task::{self, Task};
+
+use std::any::Any;
+use std::fmt::Write;
+
+/// A widget of the widget tree of an application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Kind`] of the widget.
+    pub kind: Kind,
+    /// The [`widget::Id`] of the widget, if any.
+    pub id: Option<widget::Id>,
+    /// The bounds of the widget, if known.
+    pub bounds: Option<Rectangle>,
+    /// The children of the widget.
+    pub children: Vec<Node>,
+}
+
+/// The kind of a [`Node`], as reported by the operations it supports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// A widget that contains other widgets.
+    Container,
+    /// A widget that can be scrolled.
+    Scrollable {
+        /// The current translation of the contents.
+        translation: Vector,
+    },
+    /// A widget that can be focused.
+    Focusable {
+        /// Whether the widget is focused.
+        is_focused: bool,
+    },
+    /// A widget that has text input.
+    TextInput,
+    /// A widget that can be toggled.
+    Toggleable {
+        /// Whether the widget is toggled.
+        is_toggled: bool,
+    },
+    /// A widget that can be adjusted to a value.
+    Adjustable {
+        /// The current value of the widget.
+        value: f64,
+    },
+    /// A widget with some custom state.
+    Custom,
+}
+
+impl Kind {
+    /// Returns the name of the [`Kind`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Container => "container",
+            Kind::Scrollable { .. } => "scrollable",
+            Kind::Focusable { .. } => "focusable",
+            Kind::TextInput => "text_input",
+            Kind::Toggleable { .. } => "toggleable",
+            Kind::Adjustable { .. } => "adjustable",
+            Kind::Custom => "custom",
+        }
+    }
+}
+
+/// An [`Operation`] that collects the [`Node`]s of a widget tree.
+///
+/// The root nodes of every window are collected in order.
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    nodes: Vec<Node>,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn leaf(&mut self, kind: Kind, id: Option<&widget::Id>) {
+        self.leaf_with_bounds(kind, id, None);
+    }
+
+    fn leaf_with_bounds(
+        &mut self,
+        kind: Kind,
+        id: Option<&widget::Id>,
+        bounds: Option<Rectangle>,
+    ) {
+        self.nodes.push(Node {
+            kind,
+            id: id.cloned(),
+            bounds,
+            children: Vec::new(),
+        });
+    }
+}
+
+impl Operation<Vec<Node>> for Snapshot {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<Node>>),
+    ) {
+        let siblings = std::mem::take(&mut self.nodes);
+
+        operate_on_children(self);
+
+        let children = std::mem::replace(&mut self.nodes, siblings);
+
+        self.nodes.push(Node {
+            kind: Kind::Container,
+            id: id.cloned(),
+            bounds: Some(bounds),
+            children,
+        });
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.leaf_with_bounds(
+            Kind::Scrollable { translation },
+            id,
+            Some(bounds),
+        );
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.leaf(
+            Kind::Focusable {
+                is_focused: state.is_focused(),
+            },
+            id,
+        );
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.leaf(Kind::TextInput, id);
+    }
+
+    fn toggleable(
+        &mut self,
+        state: &mut dyn Toggleable,
+        id: Option<&widget::Id>,
+    ) {
+        self.leaf(
+            Kind::Toggleable {
+                is_toggled: state.is_toggled(),
+            },
+            id,
+        );
+    }
+
+    fn adjustable(
+        &mut self,
+        state: &mut dyn Adjustable,
+        id: Option<&widget::Id>,
+    ) {
+        self.leaf(
+            Kind::Adjustable {
+                value: state.value(),
+            },
+            id,
+        );
+    }
+
+    fn custom(&mut self, _state: &mut dyn Any, id: Option<&widget::Id>) {
+        self.leaf(Kind::Custom, id);
+    }
+
+    fn finish(&self) -> Outcome<Vec<Node>> {
+        Outcome::Some(self.nodes.clone())
+    }
+}
+
+/// Produces a [`Task`] that takes a [`Snapshot`] of the widget tree.
+pub fn snapshot() -> Task<Vec<Node>> {
+    task::widget(Snapshot::new())
+}
+
+/// Encodes the given [`Node`]s as a JSON array.
+///
+/// Every node is an object with its `kind`, `id`, `bounds`, `children`,
+/// and the properties of its [`Kind`].
+pub fn to_json(nodes: &[Node]) -> String {
+    let mut json = String::new();
+
+    encode_nodes(&mut json, nodes);
+
+    json
+}
+
+fn encode_nodes(json: &mut String, nodes: &[Node]) {
+    json.push('[');
+
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+
+        encode_node(json, node);
+    }
+
+    json.push(']');
+}
+
+fn encode_node(json: &mut String, node: &Node) {
+    let _ = write!(json, "{{\"kind\":\"{}\",\"id\":", node.kind.name());
+
+    match &node.id {
+        Some(id) => encode_string(json, &id.to_string()),
+        None => json.push_str("null"),
+    }
+
+    json.push_str(",\"bounds\":");
+
+    match node.bounds {
+        Some(bounds) => {
+            json.push_str("{\"x\":");
+            encode_number(json, bounds.x.into());
+            json.push_str(",\"y\":");
+            encode_number(json, bounds.y.into());
+            json.push_str(",\"width\":");
+            encode_number(json, bounds.width.into());
+            json.push_str(",\"height\":");
+            encode_number(json, bounds.height.into());
+            json.push('}');
+        }
+        None => json.push_str("null"),
+    }
+
+    match node.kind {
+        Kind::Scrollable { translation } => {
+            json.push_str(",\"translation\":{\"x\":");
+            encode_number(json, translation.x.into());
+            json.push_str(",\"y\":");
+            encode_number(json, translation.y.into());
+            json.push('}');
+        }
+        Kind::Focusable { is_focused } => {
+            let _ = write!(json, ",\"is_focused\":{is_focused}");
+        }
+        Kind::Toggleable { is_toggled } => {
+            let _ = write!(json, ",\"is_toggled\":{is_toggled}");
+        }
+        Kind::Adjustable { value } => {
+            json.push_str(",\"value\":");
+            encode_number(json, value);
+        }
+        Kind::Container | Kind::TextInput | Kind::Custom => {}
+    }
+
+    json.push_str(",\"children\":");
+    encode_nodes(json, &node.children);
+    json.push('}');
+}
+
+fn encode_string(json: &mut String, string: &str) {
+    json.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
+
+fn encode_number(json: &mut String, number: f64) {
+    // JSON has no representation for infinities or NaN
+    if number.is_finite() {
+        let _ = write!(json, "{number}");
+    } else {
+        json.push_str("null");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_nodes_as_json() {
+        let nodes = [Node {
+            kind: Kind::Container,
+            id: Some(widget::Id::new("form \"main\"")),
+            bounds: Some(Rectangle::new(
+                crate::core::Point::new(0.0, 10.0),
+                crate::core::Size::new(100.0, 50.5),
+            )),
+            children: vec![
+                Node {
+                    kind: Kind::Toggleable { is_toggled: true },
+                    id: None,
+                    bounds: None,
+                    children: Vec::new(),
+                },
+                Node {
+                    kind: Kind::Adjustable {
+                        value: f64::INFINITY,
+                    },
+                    id: None,
+                    bounds: None,
+                    children: Vec::new(),
+                },
+            ],
+        }];
+
+        assert_eq!(
+            to_json(&nodes),
+            "[{\"kind\":\"container\",\"id\":\"form \\\"main\\\"\",\
+            \"bounds\":{\"x\":0,\"y\":10,\"width\":100,\"height\":50.5},\
+            \"children\":[\
+            {\"kind\":\"toggleable\",\"id\":null,\"bounds\":null,\
+            \"is_toggled\":true,\"children\":[]},\
+            {\"kind\":\"adjustable\",\"id\":null,\"bounds\":null,\
+            \"value\":null,\"children\":[]}]}]"
+        );
+    }
+}
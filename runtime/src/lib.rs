@@ -11,9 +11,11 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod clipboard;
 pub mod font;
+pub mod inspector;
 pub mod keyboard;
 pub mod overlay;
 pub mod program;
+pub mod replay;
 pub mod system;
 pub mod task;
 pub mod user_interface;
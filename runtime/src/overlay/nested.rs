@@ -80,13 +80,15 @@ where
             if let Some(layout) = layouts.next() {
                 let nested_layout = layouts.next();
 
+                // Any overlay nested deeper hides the cursor, too
                 let is_over = cursor
                     .position()
                     .zip(nested_layout)
                     .and_then(|(cursor_position, nested_layout)| {
-                        element.overlay(layout, renderer).map(|nested| {
-                            nested.is_over(
-                                nested_layout.children().next().unwrap(),
+                        element.overlay(layout, renderer).map(|mut nested| {
+                            is_over(
+                                &mut nested,
+                                nested_layout,
                                 renderer,
                                 cursor_position,
                             )
@@ -271,29 +273,21 @@ where
             let layout = layouts.next()?;
             let cursor_position = cursor.position()?;
 
-            if !element.is_over(layout, renderer, cursor_position) {
-                return None;
+            // Nested overlays are on top and may lie outside of their parent
+            let nested_interaction = element
+                .overlay(layout, renderer)
+                .zip(layouts.next())
+                .and_then(|(mut overlay, layout)| {
+                    recurse(&mut overlay, layout, cursor, viewport, renderer)
+                });
+
+            if nested_interaction.is_some() {
+                return nested_interaction;
             }
 
-            Some(
-                element
-                    .overlay(layout, renderer)
-                    .zip(layouts.next())
-                    .and_then(|(mut overlay, layout)| {
-                        recurse(
-                            &mut overlay,
-                            layout,
-                            cursor,
-                            viewport,
-                            renderer,
-                        )
-                    })
-                    .unwrap_or_else(|| {
-                        element.mouse_interaction(
-                            layout, cursor, viewport, renderer,
-                        )
-                    }),
-            )
+            element.is_over(layout, renderer, cursor_position).then(|| {
+                element.mouse_interaction(layout, cursor, viewport, renderer)
+            })
         }
 
         recurse(&mut self.overlay, layout, cursor, viewport, renderer)
@@ -307,39 +301,36 @@ where
         renderer: &Renderer,
         cursor_position: Point,
     ) -> bool {
-        fn recurse<Message, Theme, Renderer>(
-            element: &mut overlay::Element<'_, Message, Theme, Renderer>,
-            layout: Layout<'_>,
-            renderer: &Renderer,
-            cursor_position: Point,
-        ) -> bool
-        where
-            Renderer: renderer::Renderer,
-        {
-            let mut layouts = layout.children();
+        is_over(&mut self.overlay, layout, renderer, cursor_position)
+    }
+}
 
-            if let Some(layout) = layouts.next() {
-                if element.is_over(layout, renderer, cursor_position) {
-                    return true;
-                }
+/// Returns true if the cursor is over the given [`overlay::Element`] or any of
+/// its nested overlays.
+fn is_over<Message, Theme, Renderer>(
+    element: &mut overlay::Element<'_, Message, Theme, Renderer>,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    cursor_position: Point,
+) -> bool
+where
+    Renderer: renderer::Renderer,
+{
+    let mut layouts = layout.children();
 
-                if let Some((mut nested, nested_layout)) =
-                    element.overlay(layout, renderer).zip(layouts.next())
-                {
-                    recurse(
-                        &mut nested,
-                        nested_layout,
-                        renderer,
-                        cursor_position,
-                    )
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
+    if let Some(layout) = layouts.next() {
+        if element.is_over(layout, renderer, cursor_position) {
+            return true;
         }
 
-        recurse(&mut self.overlay, layout, renderer, cursor_position)
+        if let Some((mut nested, nested_layout)) =
+            element.overlay(layout, renderer).zip(layouts.next())
+        {
+            is_over(&mut nested, nested_layout, renderer, cursor_position)
+        } else {
+            false
+        }
+    } else {
+        false
     }
 }
@@ -0,0 +1,824 @@
+//! Record the events of an application and replay them deterministically.
+//!
+//! A [`Recorder`] writes every input event received by an application,
+//! grouped in the frames they were processed in and annotated with their
+//! timing, to a plain text file. A [`Replay`] reads such a file and feeds
+//! the exact same frames back, in order, so interaction bugs can be
+//! reproduced and turned into tests.
+//!
+//! Only mouse, touch, and keyboard events are recorded. Window events are
+//! left to the windowing system; therefore, a recording should be replayed
+//! with the same window sizes it was recorded with.
+//!
+//! Applications running on `iced_winit` can be recorded by setting the
+//! `ICED_RECORD` environment variable to the path of a file, and replayed
+//! by setting the `ICED_REPLAY` environment variable to the path of a
+//! recording. Live input is ignored while a recording is replayed.
+use crate::core::keyboard::key::Named;
+use crate::core::keyboard::{self, Modifiers};
+use crate::core::mouse;
+use crate::core::touch;
+use crate::core::{Event, Point, SmolStr};
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+const HEADER: &str = "# iced event recording v1";
+
+/// A group of events processed together, at a specific point in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// The time elapsed since the start of the application.
+    pub time: Duration,
+    /// The events of the [`Frame`], together with the index of the window
+    /// that received them, in order of creation.
+    pub events: Vec<(usize, Event)>,
+}
+
+/// Writes the events of an application to a file as they happen.
+#[derive(Debug)]
+pub struct Recorder {
+    writer: io::BufWriter<fs::File>,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] writing to the file at the given path.
+    ///
+    /// The file will be truncated if it already exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut writer = io::BufWriter::new(fs::File::create(path)?);
+
+        writeln!(writer, "{HEADER}")?;
+
+        Ok(Self { writer })
+    }
+
+    /// Records a new [`Frame`] with the given time and events.
+    ///
+    /// Events that cannot be recorded are skipped. Nothing is written if
+    /// none of the events can be recorded.
+    pub fn record<'a>(
+        &mut self,
+        time: Duration,
+        events: impl IntoIterator<Item = (usize, &'a Event)>,
+    ) -> io::Result<()> {
+        let mut lines = events
+            .into_iter()
+            .filter_map(|(window, event)| {
+                encode(event).map(|event| format!("{window} {event}"))
+            })
+            .peekable();
+
+        if lines.peek().is_none() {
+            return Ok(());
+        }
+
+        writeln!(self.writer, "frame {}", time.as_micros())?;
+
+        for line in lines {
+            writeln!(self.writer, "{line}")?;
+        }
+
+        // Flush every frame, so recordings survive crashes
+        self.writer.flush()
+    }
+}
+
+/// A recorded sequence of [`Frame`]s.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Recording {
+    frames: Vec<Frame>,
+}
+
+impl Recording {
+    /// Loads a [`Recording`] from the file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses a [`Recording`] produced by a [`Recorder`].
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        let mut frames: Vec<Frame> = Vec::new();
+
+        for (n, line) in contents.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid recording at line {}: {line}", n + 1),
+                )
+            };
+
+            if let Some(time) = line.strip_prefix("frame ") {
+                let micros: u64 = time.parse().map_err(|_| invalid())?;
+
+                frames.push(Frame {
+                    time: Duration::from_micros(micros),
+                    events: Vec::new(),
+                });
+
+                continue;
+            }
+
+            let (window, event) = line.split_once(' ').ok_or_else(invalid)?;
+            let window = window.parse().map_err(|_| invalid())?;
+            let event = decode(event).ok_or_else(invalid)?;
+
+            frames
+                .last_mut()
+                .ok_or_else(invalid)?
+                .events
+                .push((window, event));
+        }
+
+        Ok(Self { frames })
+    }
+
+    /// Returns the [`Frame`]s of the [`Recording`].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+}
+
+/// Feeds the [`Frame`]s of a [`Recording`] back in order.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    frames: VecDeque<Frame>,
+}
+
+impl Replay {
+    /// Creates a new [`Replay`] of the given [`Recording`].
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            frames: recording.frames.into(),
+        }
+    }
+
+    /// Returns the time of the next [`Frame`] to be replayed, if any.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.frames.front().map(|frame| frame.time)
+    }
+
+    /// Returns the next [`Frame`] if it is due at the given elapsed time.
+    ///
+    /// A single [`Frame`] is returned per call, even if more are due, so
+    /// the events are processed in exactly the same batches they were
+    /// recorded in.
+    pub fn next(&mut self, elapsed: Duration) -> Option<Frame> {
+        if self.deadline()? <= elapsed {
+            self.frames.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if all the [`Frame`]s have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+/// Encodes a recordable [`Event`] as a single line of text.
+///
+/// Returns `None` if the [`Event`] cannot be recorded.
+pub fn encode(event: &Event) -> Option<String> {
+    let line = match event {
+        Event::Mouse(event) => match event {
+            mouse::Event::CursorEntered => "mouse entered".to_owned(),
+            mouse::Event::CursorLeft => "mouse left".to_owned(),
+            mouse::Event::CursorMoved { position } => {
+                format!("mouse moved {} {}", position.x, position.y)
+            }
+            mouse::Event::ButtonPressed(button) => {
+                format!("mouse pressed {}", encode_button(*button))
+            }
+            mouse::Event::ButtonReleased(button) => {
+                format!("mouse released {}", encode_button(*button))
+            }
+            mouse::Event::WheelScrolled { delta } => match delta {
+                mouse::ScrollDelta::Lines { x, y } => {
+                    format!("mouse scrolled lines {x} {y}")
+                }
+                mouse::ScrollDelta::Pixels { x, y } => {
+                    format!("mouse scrolled pixels {x} {y}")
+                }
+            },
+        },
+        Event::Touch(event) => {
+            let (kind, id, position) = match event {
+                touch::Event::FingerPressed { id, position } => {
+                    ("pressed", id, position)
+                }
+                touch::Event::FingerMoved { id, position } => {
+                    ("moved", id, position)
+                }
+                touch::Event::FingerLifted { id, position } => {
+                    ("lifted", id, position)
+                }
+                touch::Event::FingerLost { id, position } => {
+                    ("lost", id, position)
+                }
+            };
+
+            format!("touch {kind} {} {} {}", id.0, position.x, position.y)
+        }
+        Event::Keyboard(event) => match event {
+            keyboard::Event::KeyPressed {
+                key,
+                location,
+                modifiers,
+                text,
+            } => {
+                let mut line = format!(
+                    "keyboard pressed {} {} {}",
+                    encode_key(key),
+                    encode_location(*location),
+                    modifiers.bits()
+                );
+
+                if let Some(text) = text {
+                    line.push(' ');
+                    line.push_str(&escape(text));
+                }
+
+                line
+            }
+            keyboard::Event::KeyReleased {
+                key,
+                location,
+                modifiers,
+            } => format!(
+                "keyboard released {} {} {}",
+                encode_key(key),
+                encode_location(*location),
+                modifiers.bits()
+            ),
+            keyboard::Event::ModifiersChanged(modifiers) => {
+                format!("keyboard modifiers {}", modifiers.bits())
+            }
+        },
+        Event::Window(_) | Event::Pen(_) => return None,
+    };
+
+    Some(line)
+}
+
+/// Decodes an [`Event`] previously encoded with [`encode`].
+pub fn decode(line: &str) -> Option<Event> {
+    let mut tokens = line.split(' ');
+
+    let mut next = || tokens.next();
+
+    let event = match (next()?, next()?) {
+        ("mouse", kind) => Event::Mouse(match kind {
+            "entered" => mouse::Event::CursorEntered,
+            "left" => mouse::Event::CursorLeft,
+            "moved" => mouse::Event::CursorMoved {
+                position: Point::new(
+                    next()?.parse().ok()?,
+                    next()?.parse().ok()?,
+                ),
+            },
+            "pressed" => mouse::Event::ButtonPressed(decode_button(next()?)?),
+            "released" => mouse::Event::ButtonReleased(decode_button(next()?)?),
+            "scrolled" => {
+                let unit = next()?;
+                let x = next()?.parse().ok()?;
+                let y = next()?.parse().ok()?;
+
+                mouse::Event::WheelScrolled {
+                    delta: match unit {
+                        "lines" => mouse::ScrollDelta::Lines { x, y },
+                        "pixels" => mouse::ScrollDelta::Pixels { x, y },
+                        _ => return None,
+                    },
+                }
+            }
+            _ => return None,
+        }),
+        ("touch", kind) => {
+            let id = touch::Finger(next()?.parse().ok()?);
+            let position =
+                Point::new(next()?.parse().ok()?, next()?.parse().ok()?);
+
+            Event::Touch(match kind {
+                "pressed" => touch::Event::FingerPressed { id, position },
+                "moved" => touch::Event::FingerMoved { id, position },
+                "lifted" => touch::Event::FingerLifted { id, position },
+                "lost" => touch::Event::FingerLost { id, position },
+                _ => return None,
+            })
+        }
+        ("keyboard", "modifiers") => Event::Keyboard(
+            keyboard::Event::ModifiersChanged(decode_modifiers(next()?)?),
+        ),
+        ("keyboard", kind) => {
+            let key = decode_key(next()?)?;
+            let location = decode_location(next()?)?;
+            let modifiers = decode_modifiers(next()?)?;
+
+            Event::Keyboard(match kind {
+                "pressed" => keyboard::Event::KeyPressed {
+                    key,
+                    location,
+                    modifiers,
+                    text: next().map(unescape),
+                },
+                "released" => keyboard::Event::KeyReleased {
+                    key,
+                    location,
+                    modifiers,
+                },
+                _ => return None,
+            })
+        }
+        _ => return None,
+    };
+
+    next().is_none().then_some(event)
+}
+
+fn encode_button(button: mouse::Button) -> String {
+    match button {
+        mouse::Button::Left => "left".to_owned(),
+        mouse::Button::Right => "right".to_owned(),
+        mouse::Button::Middle => "middle".to_owned(),
+        mouse::Button::Back => "back".to_owned(),
+        mouse::Button::Forward => "forward".to_owned(),
+        mouse::Button::Other(other) => other.to_string(),
+    }
+}
+
+fn decode_button(token: &str) -> Option<mouse::Button> {
+    Some(match token {
+        "left" => mouse::Button::Left,
+        "right" => mouse::Button::Right,
+        "middle" => mouse::Button::Middle,
+        "back" => mouse::Button::Back,
+        "forward" => mouse::Button::Forward,
+        other => mouse::Button::Other(other.parse().ok()?),
+    })
+}
+
+fn encode_key(key: &keyboard::Key) -> String {
+    match key {
+        keyboard::Key::Named(named) => format!("named:{named:?}"),
+        keyboard::Key::Character(c) => format!("char:{}", escape(c)),
+        keyboard::Key::Unidentified => "unidentified".to_owned(),
+    }
+}
+
+fn decode_key(token: &str) -> Option<keyboard::Key> {
+    if token == "unidentified" {
+        return Some(keyboard::Key::Unidentified);
+    }
+
+    if let Some(c) = token.strip_prefix("char:") {
+        return Some(keyboard::Key::Character(unescape(c)));
+    }
+
+    let name = token.strip_prefix("named:")?;
+
+    NAMED_KEYS
+        .iter()
+        .find(|named| format!("{named:?}") == name)
+        .copied()
+        .map(keyboard::Key::Named)
+}
+
+fn encode_location(location: keyboard::Location) -> &'static str {
+    match location {
+        keyboard::Location::Standard => "standard",
+        keyboard::Location::Left => "left",
+        keyboard::Location::Right => "right",
+        keyboard::Location::Numpad => "numpad",
+    }
+}
+
+fn decode_location(token: &str) -> Option<keyboard::Location> {
+    Some(match token {
+        "standard" => keyboard::Location::Standard,
+        "left" => keyboard::Location::Left,
+        "right" => keyboard::Location::Right,
+        "numpad" => keyboard::Location::Numpad,
+        _ => return None,
+    })
+}
+
+fn decode_modifiers(token: &str) -> Option<Modifiers> {
+    Some(Modifiers::from_bits_truncate(token.parse().ok()?))
+}
+
+fn escape(text: &str) -> String {
+    if text.is_empty() {
+        return "\\e".to_owned();
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ' ' => escaped.push_str("\\s"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape(token: &str) -> SmolStr {
+    if token == "\\e" {
+        return SmolStr::default();
+    }
+
+    let mut text = String::with_capacity(token.len());
+    let mut chars = token.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => text.push(' '),
+            Some('n') => text.push('\n'),
+            Some('r') => text.push('\r'),
+            Some('t') => text.push('\t'),
+            Some(c) => text.push(c),
+            None => {}
+        }
+    }
+
+    SmolStr::new(text)
+}
+
+const NAMED_KEYS: &[Named] = &[
+    Named::Alt,
+    Named::AltGraph,
+    Named::CapsLock,
+    Named::Control,
+    Named::Fn,
+    Named::FnLock,
+    Named::NumLock,
+    Named::ScrollLock,
+    Named::Shift,
+    Named::Symbol,
+    Named::SymbolLock,
+    Named::Meta,
+    Named::Hyper,
+    Named::Super,
+    Named::Enter,
+    Named::Tab,
+    Named::Space,
+    Named::ArrowDown,
+    Named::ArrowLeft,
+    Named::ArrowRight,
+    Named::ArrowUp,
+    Named::End,
+    Named::Home,
+    Named::PageDown,
+    Named::PageUp,
+    Named::Backspace,
+    Named::Clear,
+    Named::Copy,
+    Named::CrSel,
+    Named::Cut,
+    Named::Delete,
+    Named::EraseEof,
+    Named::ExSel,
+    Named::Insert,
+    Named::Paste,
+    Named::Redo,
+    Named::Undo,
+    Named::Accept,
+    Named::Again,
+    Named::Attn,
+    Named::Cancel,
+    Named::ContextMenu,
+    Named::Escape,
+    Named::Execute,
+    Named::Find,
+    Named::Help,
+    Named::Pause,
+    Named::Play,
+    Named::Props,
+    Named::Select,
+    Named::ZoomIn,
+    Named::ZoomOut,
+    Named::BrightnessDown,
+    Named::BrightnessUp,
+    Named::Eject,
+    Named::LogOff,
+    Named::Power,
+    Named::PowerOff,
+    Named::PrintScreen,
+    Named::Hibernate,
+    Named::Standby,
+    Named::WakeUp,
+    Named::AllCandidates,
+    Named::Alphanumeric,
+    Named::CodeInput,
+    Named::Compose,
+    Named::Convert,
+    Named::FinalMode,
+    Named::GroupFirst,
+    Named::GroupLast,
+    Named::GroupNext,
+    Named::GroupPrevious,
+    Named::ModeChange,
+    Named::NextCandidate,
+    Named::NonConvert,
+    Named::PreviousCandidate,
+    Named::Process,
+    Named::SingleCandidate,
+    Named::HangulMode,
+    Named::HanjaMode,
+    Named::JunjaMode,
+    Named::Eisu,
+    Named::Hankaku,
+    Named::Hiragana,
+    Named::HiraganaKatakana,
+    Named::KanaMode,
+    Named::KanjiMode,
+    Named::Katakana,
+    Named::Romaji,
+    Named::Zenkaku,
+    Named::ZenkakuHankaku,
+    Named::Soft1,
+    Named::Soft2,
+    Named::Soft3,
+    Named::Soft4,
+    Named::ChannelDown,
+    Named::ChannelUp,
+    Named::Close,
+    Named::MailForward,
+    Named::MailReply,
+    Named::MailSend,
+    Named::MediaClose,
+    Named::MediaFastForward,
+    Named::MediaPause,
+    Named::MediaPlay,
+    Named::MediaPlayPause,
+    Named::MediaRecord,
+    Named::MediaRewind,
+    Named::MediaStop,
+    Named::MediaTrackNext,
+    Named::MediaTrackPrevious,
+    Named::New,
+    Named::Open,
+    Named::Print,
+    Named::Save,
+    Named::SpellCheck,
+    Named::Key11,
+    Named::Key12,
+    Named::AudioBalanceLeft,
+    Named::AudioBalanceRight,
+    Named::AudioBassBoostDown,
+    Named::AudioBassBoostToggle,
+    Named::AudioBassBoostUp,
+    Named::AudioFaderFront,
+    Named::AudioFaderRear,
+    Named::AudioSurroundModeNext,
+    Named::AudioTrebleDown,
+    Named::AudioTrebleUp,
+    Named::AudioVolumeDown,
+    Named::AudioVolumeUp,
+    Named::AudioVolumeMute,
+    Named::MicrophoneToggle,
+    Named::MicrophoneVolumeDown,
+    Named::MicrophoneVolumeUp,
+    Named::MicrophoneVolumeMute,
+    Named::SpeechCorrectionList,
+    Named::SpeechInputToggle,
+    Named::LaunchApplication1,
+    Named::LaunchApplication2,
+    Named::LaunchCalendar,
+    Named::LaunchContacts,
+    Named::LaunchMail,
+    Named::LaunchMediaPlayer,
+    Named::LaunchMusicPlayer,
+    Named::LaunchPhone,
+    Named::LaunchScreenSaver,
+    Named::LaunchSpreadsheet,
+    Named::LaunchWebBrowser,
+    Named::LaunchWebCam,
+    Named::LaunchWordProcessor,
+    Named::BrowserBack,
+    Named::BrowserFavorites,
+    Named::BrowserForward,
+    Named::BrowserHome,
+    Named::BrowserRefresh,
+    Named::BrowserSearch,
+    Named::BrowserStop,
+    Named::AppSwitch,
+    Named::Call,
+    Named::Camera,
+    Named::CameraFocus,
+    Named::EndCall,
+    Named::GoBack,
+    Named::GoHome,
+    Named::HeadsetHook,
+    Named::LastNumberRedial,
+    Named::Notification,
+    Named::MannerMode,
+    Named::VoiceDial,
+    Named::TV,
+    Named::TV3DMode,
+    Named::TVAntennaCable,
+    Named::TVAudioDescription,
+    Named::TVAudioDescriptionMixDown,
+    Named::TVAudioDescriptionMixUp,
+    Named::TVContentsMenu,
+    Named::TVDataService,
+    Named::TVInput,
+    Named::TVInputComponent1,
+    Named::TVInputComponent2,
+    Named::TVInputComposite1,
+    Named::TVInputComposite2,
+    Named::TVInputHDMI1,
+    Named::TVInputHDMI2,
+    Named::TVInputHDMI3,
+    Named::TVInputHDMI4,
+    Named::TVInputVGA1,
+    Named::TVMediaContext,
+    Named::TVNetwork,
+    Named::TVNumberEntry,
+    Named::TVPower,
+    Named::TVRadioService,
+    Named::TVSatellite,
+    Named::TVSatelliteBS,
+    Named::TVSatelliteCS,
+    Named::TVSatelliteToggle,
+    Named::TVTerrestrialAnalog,
+    Named::TVTerrestrialDigital,
+    Named::TVTimer,
+    Named::AVRInput,
+    Named::AVRPower,
+    Named::ColorF0Red,
+    Named::ColorF1Green,
+    Named::ColorF2Yellow,
+    Named::ColorF3Blue,
+    Named::ColorF4Grey,
+    Named::ColorF5Brown,
+    Named::ClosedCaptionToggle,
+    Named::Dimmer,
+    Named::DisplaySwap,
+    Named::DVR,
+    Named::Exit,
+    Named::FavoriteClear0,
+    Named::FavoriteClear1,
+    Named::FavoriteClear2,
+    Named::FavoriteClear3,
+    Named::FavoriteRecall0,
+    Named::FavoriteRecall1,
+    Named::FavoriteRecall2,
+    Named::FavoriteRecall3,
+    Named::FavoriteStore0,
+    Named::FavoriteStore1,
+    Named::FavoriteStore2,
+    Named::FavoriteStore3,
+    Named::Guide,
+    Named::GuideNextDay,
+    Named::GuidePreviousDay,
+    Named::Info,
+    Named::InstantReplay,
+    Named::Link,
+    Named::ListProgram,
+    Named::LiveContent,
+    Named::Lock,
+    Named::MediaApps,
+    Named::MediaAudioTrack,
+    Named::MediaLast,
+    Named::MediaSkipBackward,
+    Named::MediaSkipForward,
+    Named::MediaStepBackward,
+    Named::MediaStepForward,
+    Named::MediaTopMenu,
+    Named::NavigateIn,
+    Named::NavigateNext,
+    Named::NavigateOut,
+    Named::NavigatePrevious,
+    Named::NextFavoriteChannel,
+    Named::NextUserProfile,
+    Named::OnDemand,
+    Named::Pairing,
+    Named::PinPDown,
+    Named::PinPMove,
+    Named::PinPToggle,
+    Named::PinPUp,
+    Named::PlaySpeedDown,
+    Named::PlaySpeedReset,
+    Named::PlaySpeedUp,
+    Named::RandomToggle,
+    Named::RcLowBattery,
+    Named::RecordSpeedNext,
+    Named::RfBypass,
+    Named::ScanChannelsToggle,
+    Named::ScreenModeNext,
+    Named::Settings,
+    Named::SplitScreenToggle,
+    Named::STBInput,
+    Named::STBPower,
+    Named::Subtitle,
+    Named::Teletext,
+    Named::VideoModeNext,
+    Named::Wink,
+    Named::ZoomToggle,
+    Named::F1,
+    Named::F2,
+    Named::F3,
+    Named::F4,
+    Named::F5,
+    Named::F6,
+    Named::F7,
+    Named::F8,
+    Named::F9,
+    Named::F10,
+    Named::F11,
+    Named::F12,
+    Named::F13,
+    Named::F14,
+    Named::F15,
+    Named::F16,
+    Named::F17,
+    Named::F18,
+    Named::F19,
+    Named::F20,
+    Named::F21,
+    Named::F22,
+    Named::F23,
+    Named::F24,
+    Named::F25,
+    Named::F26,
+    Named::F27,
+    Named::F28,
+    Named::F29,
+    Named::F30,
+    Named::F31,
+    Named::F32,
+    Named::F33,
+    Named::F34,
+    Named::F35,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_round_trip() {
+        let events = [
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(12.5, 40.0),
+            }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -3.25 },
+            }),
+            Event::Touch(touch::Event::FingerMoved {
+                id: touch::Finger(7),
+                position: Point::new(1.0, 2.0),
+            }),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(SmolStr::new(" ")),
+                location: keyboard::Location::Standard,
+                modifiers: Modifiers::SHIFT,
+                text: Some(SmolStr::new(" ")),
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key: keyboard::Key::Named(Named::ArrowLeft),
+                location: keyboard::Location::Standard,
+                modifiers: Modifiers::empty(),
+            }),
+        ];
+
+        let mut contents = format!("{HEADER}\nframe 1500\n");
+
+        for event in &events {
+            contents.push_str(&format!("0 {}\n", encode(event).unwrap()));
+        }
+
+        let recording = Recording::parse(&contents).unwrap();
+
+        assert_eq!(
+            recording.frames(),
+            &[Frame {
+                time: Duration::from_micros(1500),
+                events: events.into_iter().map(|event| (0, event)).collect(),
+            }]
+        );
+    }
+}
@@ -1,11 +1,38 @@
 //! Access the native system.
 use crate::futures::futures::channel::oneshot;
+use crate::task::{self, Task};
 
 /// An operation to be performed on the system.
 #[derive(Debug)]
 pub enum Action {
     /// Query system information and produce `T` with the result.
     QueryInformation(oneshot::Sender<Information>),
+
+    /// Hint whether the application should save power; like when the system
+    /// runs on battery.
+    SetLowPower(bool),
+
+    /// Query the graphics used to render the application.
+    QueryGraphics(oneshot::Sender<Graphics>),
+}
+
+/// Contains information about the graphics used to render an application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Graphics {
+    /// The graphics backend selected for rendering.
+    pub backend: String,
+    /// Model information for the graphics adapter.
+    pub adapter: String,
+}
+
+/// Queries the [`Graphics`] selected to render the application.
+///
+/// Useful to report the backend chosen among the fallbacks configured in the
+/// settings of the application.
+pub fn fetch_graphics() -> Task<Graphics> {
+    task::oneshot(|channel| {
+        crate::Action::System(Action::QueryGraphics(channel))
+    })
 }
 
 /// Contains informations about the system (e.g. system name, processor, memory, graphics adapter).
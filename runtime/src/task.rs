@@ -2,6 +2,8 @@
 use crate::core::widget;
 use crate::futures::futures::channel::mpsc;
 use crate::futures::futures::channel::oneshot;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::futures::futures::executor::ThreadPool;
 use crate::futures::futures::future::{self, FutureExt};
 use crate::futures::futures::never::Never;
 use crate::futures::futures::stream::{self, Stream, StreamExt};
@@ -9,6 +11,8 @@ use crate::futures::{boxed_stream, BoxStream, MaybeSend};
 use crate::Action;
 
 use std::future::Future;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
 
 /// A set of concurrent actions to be performed by the iced runtime.
 ///
@@ -43,6 +47,43 @@ impl<T> Task<T> {
         Self::future(future.map(f))
     }
 
+    /// Creates a [`Task`] that runs the given blocking closure on a dedicated
+    /// thread pool and maps its output with the given closure.
+    ///
+    /// Unlike the futures of [`perform`], the closure does not run on the
+    /// executor of the runtime; so heavy computations—like decoding an image
+    /// or parsing a large file—will not starve timers and I/O.
+    ///
+    /// The [`Task`] produces nothing if the closure panics.
+    ///
+    /// [`perform`]: Self::perform
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform_blocking<A>(
+        work: impl FnOnce() -> A + Send + 'static,
+        f: impl Fn(A) -> T + MaybeSend + 'static,
+    ) -> Self
+    where
+        T: MaybeSend + 'static,
+        A: Send + 'static,
+    {
+        Self::stream(
+            stream::once(async move {
+                let (sender, receiver) = oneshot::channel();
+
+                blocking_pool().spawn_ok(async move {
+                    if let Ok(output) = std::panic::catch_unwind(
+                        std::panic::AssertUnwindSafe(work),
+                    ) {
+                        let _ = sender.send(output);
+                    }
+                });
+
+                receiver.await.ok()
+            })
+            .filter_map(move |output| future::ready(output.map(&f))),
+        )
+    }
+
     /// Creates a [`Task`] that runs the given [`Stream`] to completion and maps each
     /// item with the given closure.
     pub fn run<A>(
@@ -216,6 +257,20 @@ impl<T> Task<T> {
     }
 }
 
+/// Returns the thread pool used to run the closures of
+/// [`Task::perform_blocking`].
+#[cfg(not(target_arch = "wasm32"))]
+fn blocking_pool() -> &'static ThreadPool {
+    static POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+    POOL.get_or_init(|| {
+        ThreadPool::builder()
+            .name_prefix("iced-blocking-")
+            .create()
+            .expect("Create thread pool for blocking tasks")
+    })
+}
+
 /// A handle to a [`Task`] that can be used for aborting it.
 #[derive(Debug, Clone)]
 pub struct Handle {
@@ -292,6 +347,55 @@ impl<T, E> Task<Result<T, E>> {
     {
         self.then(move |option| option.map_or_else(|_| Task::none(), &f))
     }
+
+    /// Executes a new fallible [`Task`] after this one, only when it succeeds
+    /// with an `Ok` value; forwarding any error otherwise.
+    ///
+    /// This allows sequencing multiple fallible steps without going through
+    /// `update` after each one:
+    ///
+    /// ```no_run
+    /// # use iced_runtime::Task;
+    /// # struct Session;
+    /// # struct Profile;
+    /// # struct Error;
+    /// # async fn log_in() -> Result<Session, Error> { unimplemented!() }
+    /// # async fn fetch_profile(_: Session) -> Result<Profile, Error> { unimplemented!() }
+    /// enum Message {
+    ///     ProfileFetched(Result<Profile, Error>),
+    /// }
+    ///
+    /// let task: Task<Message> = Task::future(log_in())
+    ///     .try_then(|session| Task::future(fetch_profile(session)))
+    ///     .map(Message::ProfileFetched);
+    /// ```
+    pub fn try_then<A>(
+        self,
+        f: impl Fn(T) -> Task<Result<A, E>> + MaybeSend + 'static,
+    ) -> Task<Result<A, E>>
+    where
+        T: MaybeSend + 'static,
+        E: MaybeSend + 'static,
+        A: MaybeSend + 'static,
+    {
+        self.then(move |result| match result {
+            Ok(value) => f(value),
+            Err(error) => Task::done(Err(error)),
+        })
+    }
+
+    /// Maps the error of a fallible [`Task`] with the given closure.
+    pub fn map_err<F>(
+        self,
+        f: impl Fn(E) -> F + MaybeSend + 'static,
+    ) -> Task<Result<T, F>>
+    where
+        T: MaybeSend + 'static,
+        E: MaybeSend + 'static,
+        F: MaybeSend + 'static,
+    {
+        self.map(move |result| result.map_err(&f))
+    }
 }
 
 impl<T> From<()> for Task<T> {
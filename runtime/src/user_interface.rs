@@ -3,6 +3,7 @@ use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::widget;
 use crate::core::window;
 use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
@@ -27,6 +28,7 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    received_at: Option<Instant>,
 }
 
 impl<'a, Message, Theme, Renderer> UserInterface<'a, Message, Theme, Renderer>
@@ -95,11 +97,10 @@ where
         let Cache { mut state } = cache;
         state.diff(root.as_widget());
 
-        let base = root.as_widget().layout(
-            &mut state,
-            renderer,
-            &layout::Limits::new(Size::ZERO, bounds),
-        );
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let base = root.as_widget().layout(&mut state, renderer, &limits);
+
+        check_layout(&base, &limits);
 
         UserInterface {
             root,
@@ -107,9 +108,20 @@ where
             state,
             overlay: None,
             bounds,
+            received_at: None,
         }
     }
 
+    /// Sets the [`Instant`] when the events of the next [`update`] entered
+    /// the runtime; usually, the time of the earliest input among them.
+    ///
+    /// Widgets can obtain it with [`Shell::received_at`].
+    ///
+    /// [`update`]: Self::update
+    pub fn set_received_at(&mut self, received_at: Option<Instant>) {
+        self.received_at = received_at;
+    }
+
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
     ///
     /// It returns __messages__ that may have been produced as a result of user
@@ -187,6 +199,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let received_at = self.received_at;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -208,7 +221,8 @@ where
             let mut event_statuses = Vec::new();
 
             for event in events.iter().cloned() {
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_received_at(received_at);
 
                 let event_status = overlay.on_event(
                     event,
@@ -234,12 +248,16 @@ where
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
+                    let limits = layout::Limits::new(Size::ZERO, self.bounds);
+
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
                         renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
+                        &limits,
                     );
 
+                    check_layout(&self.base, &limits);
+
                     manual_overlay = ManuallyDrop::new(
                         self.root
                             .as_widget_mut()
@@ -306,7 +324,8 @@ where
                     return overlay_status;
                 }
 
-                let mut shell = Shell::new(messages);
+                let mut shell =
+                    Shell::new(messages).with_received_at(received_at);
 
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
@@ -334,12 +353,16 @@ where
                 }
 
                 shell.revalidate_layout(|| {
+                    let limits = layout::Limits::new(Size::ZERO, self.bounds);
+
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
                         renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
+                        &limits,
                     );
 
+                    check_layout(&self.base, &limits);
+
                     self.overlay = None;
                 });
 
@@ -648,3 +671,30 @@ pub enum State {
         redraw_request: Option<window::RedrawRequest>,
     },
 }
+
+/// Checks the invariants of a freshly computed layout in debug builds.
+///
+/// Invalid bounds are always a bug and trigger a debug assertion. Any
+/// other violation may be caused by contents that simply do not fit; so it
+/// is only logged as a warning if the `ICED_CHECK_LAYOUT` environment
+/// variable is set.
+#[cfg(debug_assertions)]
+fn check_layout(node: &layout::Node, limits: &layout::Limits) {
+    use std::sync::OnceLock;
+
+    static IS_VERBOSE: OnceLock<bool> = OnceLock::new();
+
+    let is_verbose = *IS_VERBOSE
+        .get_or_init(|| std::env::var_os("ICED_CHECK_LAYOUT").is_some());
+
+    for violation in layout::invariant::check(node, limits) {
+        debug_assert!(!violation.is_fatal(), "Invalid layout: {violation}");
+
+        if is_verbose {
+            log::warn!("Layout invariant broken: {violation}");
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+fn check_layout(_node: &layout::Node, _limits: &layout::Limits) {}
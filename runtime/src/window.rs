@@ -1,13 +1,15 @@
 //! Build window-based GUI applications.
 pub mod screenshot;
+pub mod statistics;
 
 pub use screenshot::Screenshot;
+pub use statistics::Statistics;
 
 use crate::core::time::Instant;
 use crate::core::window::{
-    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+    Event, Icon, Id, Level, Mode, PresentMode, Settings, UserAttention,
 };
-use crate::core::{Point, Size};
+use crate::core::{Color, Point, Size};
 use crate::futures::event;
 use crate::futures::futures::channel::oneshot;
 use crate::futures::Subscription;
@@ -114,6 +116,33 @@ pub enum Action {
     /// Change the window [`Level`].
     ChangeLevel(Id, Level),
 
+    /// Set whether the window should be hidden from the taskbar.
+    ///
+    /// ## Platform-specific
+    /// - **Android / iOS / macOS / Linux / Web:** Unsupported.
+    SetSkipTaskbar(Id, bool),
+
+    /// Set whether the window should let mouse input pass through it to
+    /// the windows behind.
+    ///
+    /// ## Platform-specific
+    /// - **Android / iOS / Web / X11:** Unsupported.
+    SetMousePassthrough(Id, bool),
+
+    /// Set whether the content behind a transparent window should be blurred.
+    ///
+    /// ## Platform-specific
+    /// - **Wayland:** Requires the `org_kde_kwin_blur_manager` protocol.
+    /// - **Windows / X11 / Web:** Unsupported.
+    SetBlur(Id, bool),
+
+    /// Set whether the window should have a drop shadow.
+    ///
+    /// ## Platform-specific
+    /// - **Windows:** Only has an effect on undecorated windows.
+    /// - **Linux / Web:** Unsupported.
+    SetShadow(Id, bool),
+
     /// Show the system menu at cursor position.
     ///
     /// ## Platform-specific
@@ -144,6 +173,19 @@ pub enum Action {
 
     /// Screenshot the viewport of the window.
     Screenshot(Id, oneshot::Sender<Screenshot>),
+
+    /// Get the [`Statistics`] of the last frame rendered by the window.
+    GetStatistics(Id, oneshot::Sender<Statistics>),
+
+    /// Change the [`PresentMode`] of the window.
+    ChangePresentMode(Id, PresentMode),
+
+    /// Get the [`PresentMode`]s supported by the window.
+    GetPresentModes(Id, oneshot::Sender<Vec<PresentMode>>),
+
+    /// Pick a [`Color`] anywhere on the screen with the eyedropper of the
+    /// platform; falling back to the color under the cursor in the window.
+    PickScreenColor(Id, oneshot::Sender<Option<Color>>),
 }
 
 /// Subscribes to the frames of the window of the running application.
@@ -350,6 +392,31 @@ pub fn change_level<T>(id: Id, level: Level) -> Task<T> {
     task::effect(crate::Action::Window(Action::ChangeLevel(id, level)))
 }
 
+/// Sets whether the window should be hidden from the taskbar.
+pub fn set_skip_taskbar<T>(id: Id, skip: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetSkipTaskbar(id, skip)))
+}
+
+/// Sets whether the window should let mouse input pass through it to the
+/// windows behind; which, combined with a transparent window and
+/// [`Level::AlwaysOnTop`], is useful for overlays and HUDs.
+pub fn set_mouse_passthrough<T>(id: Id, passthrough: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetMousePassthrough(
+        id,
+        passthrough,
+    )))
+}
+
+/// Sets whether the content behind a transparent window should be blurred.
+pub fn set_blur<T>(id: Id, blur: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetBlur(id, blur)))
+}
+
+/// Sets whether the window should have a drop shadow.
+pub fn set_shadow<T>(id: Id, shadow: bool) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetShadow(id, shadow)))
+}
+
 /// Show the [system menu] at cursor position.
 ///
 /// [system menu]: https://en.wikipedia.org/wiki/Common_menus_in_Microsoft_Windows#System_menu
@@ -391,8 +458,101 @@ where
 }
 
 /// Captures a [`Screenshot`] from the window.
+///
+/// The [`Screenshot`] contains the last frame presented in the window, as
+/// `RGBA` pixels; which can be displayed with an [`image::Handle`]:
+///
+/// ```no_run
+/// # use iced_runtime::core::image;
+/// # use iced_runtime::window;
+/// # use iced_runtime::Task;
+/// # #[derive(Debug, Clone)]
+/// # enum Message { Captured(image::Handle) }
+/// # fn capture(id: window::Id) -> Task<Message> {
+/// window::screenshot(id)
+///     .map(image::Handle::from)
+///     .map(Message::Captured)
+/// # }
+/// ```
+///
+/// [`image::Handle`]: crate::core::image::Handle
 pub fn screenshot(id: Id) -> Task<Screenshot> {
     task::oneshot(move |channel| {
         crate::Action::Window(Action::Screenshot(id, channel))
     })
 }
+
+/// Gets the [`Statistics`] of the last frame rendered by the window.
+///
+/// Combined with [`frames`], it can be used to display diagnostics or to
+/// adapt the quality of the rendering dynamically.
+pub fn statistics(id: Id) -> Task<Statistics> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetStatistics(id, channel))
+    })
+}
+
+/// Changes the [`PresentMode`] of the window.
+///
+/// An unsupported [`PresentMode`] falls back to [`PresentMode::Vsync`].
+pub fn change_present_mode<T>(id: Id, present_mode: PresentMode) -> Task<T> {
+    task::effect(crate::Action::Window(Action::ChangePresentMode(
+        id,
+        present_mode,
+    )))
+}
+
+/// Gets the [`PresentMode`]s supported by the window.
+pub fn present_modes(id: Id) -> Task<Vec<PresentMode>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::GetPresentModes(id, channel))
+    })
+}
+
+/// Picks the [`Color`] of the window at the given position, in logical
+/// coordinates; like an eyedropper.
+///
+/// The color is sampled from the last frame presented in the window, so it
+/// is exactly what the user sees—gradients, images, and all. A common
+/// flow enters an "eyedropper mode" where the next click in the window
+/// calls this function with the position of the cursor.
+///
+/// Use [`pick_screen_color`] to pick colors outside of the windows of the
+/// application.
+pub fn pick_color(id: Id, position: Point) -> Task<Option<Color>> {
+    screenshot(id).map(move |screenshot| {
+        let scale_factor = screenshot.scale_factor as f32;
+
+        if position.x < 0.0 || position.y < 0.0 {
+            return None;
+        }
+
+        screenshot.pixel(
+            (position.x * scale_factor) as u32,
+            (position.y * scale_factor) as u32,
+        )
+    })
+}
+
+/// Picks a [`Color`] anywhere on the screen; like an eyedropper.
+///
+/// It produces `None` if the user cancels the picking.
+///
+/// The eyedropper of the platform needs the `eyedropper` feature.
+///
+/// ## Platform-specific
+/// - **Linux:** Lets the user pick the color with the `PickColor` method of
+///   the screenshot portal of the desktop.
+/// - **macOS:** Lets the user pick the color with the color sampler of the
+///   system.
+/// - **Windows:** Produces the color of the pixel under the cursor when the
+///   [`Task`] runs; it does not wait for the user to click.
+/// - **Others:** Samples the color under the cursor in the last frame of
+///   the window with the given [`Id`]; like [`pick_color`]. This is also
+///   the fallback when the `eyedropper` feature is disabled or the
+///   eyedropper of the platform is unavailable.
+pub fn pick_screen_color(id: Id) -> Task<Option<Color>> {
+    task::oneshot(move |channel| {
+        crate::Action::Window(Action::PickScreenColor(id, channel))
+    })
+}
@@ -1,5 +1,6 @@
 //! Take screenshots of a window.
-use crate::core::{Rectangle, Size};
+use crate::core::image;
+use crate::core::{Color, Rectangle, Size};
 
 use bytes::Bytes;
 use std::fmt::{Debug, Formatter};
@@ -7,6 +8,9 @@ use std::fmt::{Debug, Formatter};
 /// Data of a screenshot, captured with `window::screenshot()`.
 ///
 /// The `bytes` of this screenshot will always be ordered as `RGBA` in the `sRGB` color space.
+///
+/// A [`Screenshot`] can be turned into an [`image::Handle`] to be displayed
+/// right away—or into [`Bytes`] to be encoded and saved.
 #[derive(Clone)]
 pub struct Screenshot {
     /// The bytes of the [`Screenshot`].
@@ -84,6 +88,26 @@ impl Screenshot {
     }
 }
 
+impl Screenshot {
+    /// Returns the [`Color`] of the pixel of the [`Screenshot`] at the given
+    /// physical position, if it is in bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.size.width || y >= self.size.height {
+            return None;
+        }
+
+        let index = (y as usize * self.size.width as usize + x as usize) * 4;
+        let pixel = self.bytes.get(index..index + 4)?;
+
+        Some(Color::from_rgba8(
+            pixel[0],
+            pixel[1],
+            pixel[2],
+            f32::from(pixel[3]) / 255.0,
+        ))
+    }
+}
+
 impl AsRef<[u8]> for Screenshot {
     fn as_ref(&self) -> &[u8] {
         &self.bytes
@@ -96,6 +120,16 @@ impl From<Screenshot> for Bytes {
     }
 }
 
+impl From<Screenshot> for image::Handle {
+    fn from(screenshot: Screenshot) -> Self {
+        image::Handle::from_rgba(
+            screenshot.size.width,
+            screenshot.size.height,
+            screenshot.bytes,
+        )
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 /// Errors that can occur when cropping a [`Screenshot`].
 pub enum CropError {
@@ -0,0 +1,25 @@
+//! Measure the frames rendered by a window.
+use crate::core::time::Duration;
+
+/// The statistics of the last frame rendered by a window, obtained with
+/// `window::statistics()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    /// The time spent on the CPU drawing and presenting the frame.
+    pub frame_time: Duration,
+
+    /// The time between the earliest input event handled by the last frame
+    /// with input entering the runtime and that frame being presented.
+    ///
+    /// Input that neither produces messages nor requests a redraw is not
+    /// measured. It is `None` if no input has been presented yet.
+    pub input_latency: Option<Duration>,
+
+    /// The number of primitives in the frame.
+    pub primitives: usize,
+
+    /// The number of draw calls issued to the GPU for the frame.
+    ///
+    /// It is zero for software renderers.
+    pub draw_calls: usize,
+}
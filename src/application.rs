@@ -30,9 +30,14 @@
 //!     ]
 //! }
 //! ```
+use crate::core::text;
+use crate::gesture;
 use crate::program::{self, Program};
 use crate::window;
-use crate::{Element, Font, Result, Settings, Size, Subscription, Task};
+use crate::{
+    Density, Direction, Element, Font, Result, Settings, Size, Subscription,
+    Task,
+};
 
 use std::borrow::Cow;
 
@@ -191,6 +196,50 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`Settings::density`] of the [`Application`].
+    pub fn density(self, density: Density) -> Self {
+        Self {
+            settings: Settings {
+                density,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::direction`] of the [`Application`].
+    pub fn direction(self, direction: Direction) -> Self {
+        Self {
+            settings: Settings {
+                direction,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::gestures`] of the [`Application`].
+    pub fn gestures(self, gestures: gesture::Thresholds) -> Self {
+        Self {
+            settings: Settings {
+                gestures,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`Settings::backends`] of the [`Application`].
+    pub fn backends(self, backends: &'static [&'static str]) -> Self {
+        Self {
+            settings: Settings {
+                backends,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Application`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -237,6 +286,17 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::present_mode`] of the [`Application`].
+    pub fn present_mode(self, present_mode: window::PresentMode) -> Self {
+        Self {
+            window: window::Settings {
+                present_mode: Some(present_mode),
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::size`] of the [`Application`].
     pub fn window_size(self, size: impl Into<Size>) -> Self {
         Self {
@@ -259,6 +319,42 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the [`window::Settings::text_antialiasing`] of the [`Application`].
+    pub fn text_antialiasing(
+        self,
+        text_antialiasing: text::Antialiasing,
+    ) -> Self {
+        Self {
+            window: window::Settings {
+                text_antialiasing,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`window::Settings::blur`] of the [`Application`].
+    pub fn blur(self, blur: bool) -> Self {
+        Self {
+            window: window::Settings {
+                blur,
+                ..self.window
+            },
+            ..self
+        }
+    }
+
+    /// Sets the [`window::Settings::shadow`] of the [`Application`].
+    pub fn shadow(self, shadow: bool) -> Self {
+        Self {
+            window: window::Settings {
+                shadow: Some(shadow),
+                ..self.window
+            },
+            ..self
+        }
+    }
+
     /// Sets the [`window::Settings::resizable`] of the [`Application`].
     pub fn resizable(self, resizable: bool) -> Self {
         Self {
@@ -361,6 +457,156 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the function that produces the payload to persist when the
+    /// [`Application`] exits.
+    ///
+    /// The payload is given back to the function set with [`on_restore`]
+    /// the next time the [`Application`] starts. Check out the
+    /// [`persistence`] module to learn where it is stored.
+    ///
+    /// [`on_restore`]: Self::on_restore
+    /// [`persistence`]: crate::persistence
+    #[cfg(feature = "persistence")]
+    pub fn on_save<T>(
+        self,
+        f: impl Fn(&P::State) -> T,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        T: serde::Serialize,
+    {
+        Application {
+            raw: program::with_save(self.raw, f),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
+    /// Sets the function that restores the state of the [`Application`]
+    /// from the payload persisted by [`on_save`], right after it has been
+    /// initialized.
+    ///
+    /// It is not called if there is no payload; or if it cannot be read as
+    /// a `T`, like after changing its type.
+    ///
+    /// [`on_save`]: Self::on_save
+    #[cfg(feature = "persistence")]
+    pub fn on_restore<T>(
+        self,
+        f: impl Fn(&mut P::State, T),
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        Application {
+            raw: program::with_restore(self.raw, f),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
+    /// Makes the [`Application`] resolve its view from the `view` function
+    /// of the given dynamic library; reloading it every time it changes and
+    /// producing the given message right after.
+    ///
+    /// The `view` function must have the signature of the view closure of
+    /// the [`Application`]; and the library must be compiled with the same
+    /// compiler and dependencies. The original view is used until the
+    /// library is loaded.
+    ///
+    /// Check out the [`hot`] module to learn more.
+    ///
+    /// # Safety
+    /// The library must export a `view` function with the exact signature
+    /// `for<'a> fn(&'a State) -> Element<'a, Message, Theme, Renderer>` of
+    /// the [`Application`], compiled with the same compiler and versions of
+    /// its dependencies. Otherwise, calling it is undefined behavior.
+    ///
+    /// The same must hold for every version of the library loaded while the
+    /// [`Application`] runs.
+    ///
+    /// [`hot`]: crate::hot
+    #[cfg(all(feature = "hot", not(target_arch = "wasm32")))]
+    #[allow(unsafe_code)]
+    pub unsafe fn hot_view(
+        self,
+        library: impl Into<std::path::PathBuf>,
+        on_reload: P::Message,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    >
+    where
+        P::Message: Clone + Send + 'static,
+    {
+        // SAFETY: The contract of the library is forwarded to the caller
+        let raw = unsafe {
+            program::with_hot_view(
+                self.raw,
+                crate::hot::Library::new(library),
+                on_reload,
+            )
+        };
+
+        Application {
+            raw,
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
+    /// Makes the [`Application`] run as a single instance.
+    ///
+    /// Launching the [`Application`] while it is already running forwards the
+    /// [`Launch`] to the running instance and exits right away. The running
+    /// instance turns it into a message with the given closure.
+    ///
+    /// Check out the [`single_instance`] module to learn more.
+    ///
+    /// [`Launch`]: crate::single_instance::Launch
+    /// [`single_instance`]: crate::single_instance
+    #[cfg(all(feature = "single-instance", not(target_arch = "wasm32")))]
+    pub fn single_instance(
+        self,
+        f: impl Fn(crate::single_instance::Launch) -> P::Message
+            + Send
+            + Clone
+            + 'static,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_single_instance(self.raw, f),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
+    /// Sets the function that produces a message when the [`Application`] is
+    /// opened through a URL with the given `scheme`, like `myapp://…`.
+    ///
+    /// The [`Application`] runs as a single instance; so URLs opened while it is
+    /// running are delivered to the running instance. Check out the
+    /// [`deep_link`] module to learn how to register the scheme.
+    ///
+    /// [`deep_link`]: crate::deep_link
+    #[cfg(all(feature = "deep-link", not(target_arch = "wasm32")))]
+    pub fn on_deep_link(
+        self,
+        scheme: &'static str,
+        f: impl Fn(String) -> P::Message + Send + Clone + 'static,
+    ) -> Application<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Application {
+            raw: program::with_deep_link(self.raw, scheme, f),
+            settings: self.settings,
+            window: self.window,
+        }
+    }
+
     /// Sets the scale factor of the [`Application`].
     pub fn scale_factor(
         self,
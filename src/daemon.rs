@@ -140,6 +140,17 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the [`Settings::backends`] of the [`Daemon`].
+    pub fn backends(self, backends: &'static [&'static str]) -> Self {
+        Self {
+            settings: Settings {
+                backends,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Sets the default [`Font`] of the [`Daemon`].
     pub fn default_font(self, default_font: Font) -> Self {
         Self {
@@ -211,6 +222,54 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Makes the [`Daemon`] run as a single instance.
+    ///
+    /// Launching the [`Daemon`] while it is already running forwards the
+    /// [`Launch`] to the running instance and exits right away. The running
+    /// instance turns it into a message with the given closure.
+    ///
+    /// Check out the [`single_instance`] module to learn more.
+    ///
+    /// [`Launch`]: crate::single_instance::Launch
+    /// [`single_instance`]: crate::single_instance
+    #[cfg(all(feature = "single-instance", not(target_arch = "wasm32")))]
+    pub fn single_instance(
+        self,
+        f: impl Fn(crate::single_instance::Launch) -> P::Message
+            + Send
+            + Clone
+            + 'static,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_single_instance(self.raw, f),
+            settings: self.settings,
+        }
+    }
+
+    /// Sets the function that produces a message when the [`Daemon`] is
+    /// opened through a URL with the given `scheme`, like `myapp://…`.
+    ///
+    /// The [`Daemon`] runs as a single instance; so URLs opened while it is
+    /// running are delivered to the running instance. Check out the
+    /// [`deep_link`] module to learn how to register the scheme.
+    ///
+    /// [`deep_link`]: crate::deep_link
+    #[cfg(all(feature = "deep-link", not(target_arch = "wasm32")))]
+    pub fn on_deep_link(
+        self,
+        scheme: &'static str,
+        f: impl Fn(String) -> P::Message + Send + Clone + 'static,
+    ) -> Daemon<
+        impl Program<State = P::State, Message = P::Message, Theme = P::Theme>,
+    > {
+        Daemon {
+            raw: program::with_deep_link(self.raw, scheme, f),
+            settings: self.settings,
+        }
+    }
+
     /// Sets the scale factor of the [`Daemon`].
     pub fn scale_factor(
         self,
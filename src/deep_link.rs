@@ -0,0 +1,222 @@
+//! Open your application through custom URL schemes, like `myapp://…`.
+//!
+//! Once the current executable is [`register`]ed as the handler of a scheme,
+//! the platform launches it with the activated URL as a command line argument.
+//! An application handling deep links runs as a [single instance]; so a URL
+//! activated while it is running is forwarded to the running instance.
+//! Handle the URLs with [`Application::on_deep_link`].
+//!
+//! Registration is supported on Windows and on Linux and BSD desktops that
+//! follow the XDG specifications. On macOS, schemes are declared with the
+//! `CFBundleURLTypes` key in the `Info.plist` of the application bundle; and
+//! the system delivers the URLs to the running application directly.
+//!
+//! [single instance]: crate::single_instance
+//! [`Application::on_deep_link`]: crate::Application::on_deep_link
+use crate::futures::future;
+use crate::futures::stream::{self, StreamExt};
+use crate::single_instance;
+use crate::Subscription;
+
+use iced_futures::subscription;
+use iced_futures::BoxStream;
+
+use std::io;
+
+/// Registers the current executable as the handler of the given URL
+/// `scheme` for the current user.
+///
+/// Registration is usually performed on installation, or on the first run of
+/// the application.
+pub fn register(scheme: &str) -> io::Result<()> {
+    let is_valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+
+    if !is_valid {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid URL scheme: {scheme:?}"),
+        ));
+    }
+
+    platform::register(scheme)
+}
+
+/// Returns a [`Subscription`] that produces a message with every URL with the
+/// given `scheme` activated for the current instance; both on launch and
+/// while it is running.
+pub(crate) fn subscription<Message>(
+    scheme: &'static str,
+    on_url: impl Fn(String) -> Message + Send + 'static,
+) -> Subscription<Message>
+where
+    Message: Send + 'static,
+{
+    subscription::from_recipe(DeepLinks { scheme, on_url })
+}
+
+struct DeepLinks<F> {
+    scheme: &'static str,
+    on_url: F,
+}
+
+impl<F, Message> subscription::Recipe for DeepLinks<F>
+where
+    F: Fn(String) -> Message + Send + 'static,
+    Message: Send + 'static,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut subscription::Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.scheme.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: subscription::EventStream,
+    ) -> BoxStream<Self::Output> {
+        let scheme = self.scheme;
+
+        // URLs are always valid UTF-8; so any other argument is skipped
+        let initial: Vec<String> = std::env::args_os()
+            .skip(1)
+            .filter_map(|argument| argument.into_string().ok())
+            .filter(|argument| matches(scheme, argument))
+            .collect();
+
+        let forwarded = single_instance::launches().flat_map(move |launch| {
+            stream::iter(
+                launch
+                    .arguments
+                    .into_iter()
+                    .filter_map(|argument| argument.into_string().ok()),
+            )
+            .filter(move |argument| future::ready(matches(scheme, argument)))
+        });
+
+        // On macOS, the system delivers URLs to the running instance directly
+        let received = input.filter_map(move |event| {
+            future::ready(match event {
+                subscription::Event::PlatformSpecific(
+                    subscription::PlatformSpecific::MacOS(
+                        subscription::MacOS::ReceivedUrl(url),
+                    ),
+                ) if matches(scheme, &url) => Some(url),
+                _ => None,
+            })
+        });
+
+        Box::pin(
+            stream::iter(initial)
+                .chain(stream::select(forwarded, received))
+                .map(self.on_url),
+        )
+    }
+}
+
+fn matches(scheme: &str, argument: &str) -> bool {
+    argument
+        .split_once(':')
+        .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(scheme))
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::io;
+    use std::process::Command;
+
+    pub fn register(scheme: &str) -> io::Result<()> {
+        let executable = std::env::current_exe()?;
+        let key = format!(r"HKCU\Software\Classes\{scheme}");
+
+        let description = format!("URL:{scheme}");
+        let command = format!("\"{}\" \"%1\"", executable.display());
+
+        reg(&[&key, "/ve", "/d", &description])?;
+        reg(&[&key, "/v", "URL Protocol", "/d", ""])?;
+        reg(&[&format!(r"{key}\shell\open\command"), "/ve", "/d", &command])
+    }
+
+    fn reg(arguments: &[&str]) -> io::Result<()> {
+        let status = Command::new("reg")
+            .arg("add")
+            .args(arguments)
+            .arg("/f")
+            .status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!("reg add failed with {status}")))
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use std::io;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    pub fn register(scheme: &str) -> io::Result<()> {
+        let executable = std::env::current_exe()?;
+
+        let name = executable
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or("iced");
+
+        let applications = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".local/share"))
+            })
+            .ok_or_else(|| io::Error::other("no data directory found"))?
+            .join("applications");
+
+        let file_name = format!("{name}-{scheme}-handler.desktop");
+
+        std::fs::create_dir_all(&applications)?;
+        std::fs::write(
+            applications.join(&file_name),
+            format!(
+                "[Desktop Entry]\n\
+                 Type=Application\n\
+                 Name={name}\n\
+                 Exec=\"{}\" %u\n\
+                 NoDisplay=true\n\
+                 MimeType=x-scheme-handler/{scheme};\n",
+                executable.display(),
+            ),
+        )?;
+
+        let status = Command::new("xdg-mime")
+            .args(["default", &file_name])
+            .arg(format!("x-scheme-handler/{scheme}"))
+            .status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!("xdg-mime failed with {status}")))
+        }
+    }
+}
+
+#[cfg(not(any(windows, all(unix, not(target_os = "macos")))))]
+mod platform {
+    use std::io;
+
+    pub fn register(_scheme: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "URL schemes cannot be registered at runtime on this platform",
+        ))
+    }
+}
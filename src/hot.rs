@@ -0,0 +1,186 @@
+//! Reload the view of an application while it is running.
+//!
+//! The view logic is compiled into a dynamic library which exports a `view`
+//! function with the same signature as the view of the application; while
+//! the state and messages live in a crate shared by both:
+//!
+//! ```ignore
+//! // In a crate with `crate-type = ["dylib"]`
+//! #[no_mangle]
+//! pub fn view(counter: &Counter) -> Element<'_, Message> {
+//!     // ...
+//! }
+//! ```
+//!
+//! An [`Application`] that runs with [`hot_view`] resolves the `view` of
+//! the [`Library`] every time it is rebuilt; and picks up the changes to
+//! the library as soon as it is compiled again, without restarting or
+//! losing its state.
+//!
+//! Since the libraries are loaded with the Rust ABI, they must be compiled
+//! with the same compiler and dependencies as the application; which is why
+//! [`hot_view`] is `unsafe`. Libraries are never unloaded, because widgets
+//! built by a previous version may be alive; so this is only meant for
+//! development.
+//!
+//! [`Application`]: crate::Application
+//! [`hot_view`]: crate::Application::hot_view
+use crate::futures::channel::mpsc;
+use crate::futures::stream::{self, Stream, StreamExt};
+
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The interval between checks for changes of a [`Library`].
+const INTERVAL: Duration = Duration::from_millis(250);
+
+/// A dynamic library that is reloaded when it changes.
+#[derive(Debug)]
+pub struct Library {
+    path: PathBuf,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    modified: Option<SystemTime>,
+    loaded: Vec<libloading::Library>,
+}
+
+/// An error that occurred while reloading a [`Library`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The library could not be read or copied.
+    #[error("the library could not be read: {0}")]
+    Io(#[from] io::Error),
+
+    /// The library could not be loaded.
+    #[error("the library could not be loaded: {0}")]
+    Load(#[from] libloading::Error),
+}
+
+impl Library {
+    /// Creates a new [`Library`] at the given path.
+    ///
+    /// The library is loaded on the first [`reload`](Self::reload).
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Returns the path of the [`Library`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the latest version of the [`Library`] if it changed since it
+    /// was last loaded, returning whether it was loaded.
+    ///
+    /// Every version is loaded from its own copy; so the library can be
+    /// overwritten by the compiler while it is in use. The copy is made in a
+    /// new directory that only the current user can access; so no one else
+    /// can replace it before it is loaded.
+    pub fn reload(&self) -> Result<bool, Error> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+
+        if self.state.lock().expect("Lock library state").modified
+            == Some(modified)
+        {
+            return Ok(false);
+        }
+
+        let directory =
+            tempfile::Builder::new().prefix("iced-hot-").tempdir()?;
+
+        let copy = directory
+            .path()
+            .join(self.path.file_name().unwrap_or(OsStr::new("library")));
+
+        let _ = fs::copy(&self.path, &copy)?;
+
+        // SAFETY: Loading a library runs its initialization routines;
+        // which is as safe as the library itself.
+        #[allow(unsafe_code)]
+        let library = unsafe { libloading::Library::new(&copy) }?;
+
+        // The copy can be removed once loaded
+        drop(directory);
+
+        let mut state = self.state.lock().expect("Lock library state");
+
+        state.loaded.push(library);
+        state.modified = Some(modified);
+
+        Ok(true)
+    }
+
+    /// Resolves the symbol with the given name in the latest loaded version
+    /// of the [`Library`].
+    ///
+    /// # Safety
+    /// The type `T` must be the exact type of the symbol; usually, a
+    /// function pointer.
+    #[allow(unsafe_code)]
+    pub unsafe fn get<T: Copy>(&self, symbol: &str) -> Option<T> {
+        let state = self.state.lock().expect("Lock library state");
+        let library = state.loaded.last()?;
+
+        // SAFETY: The type of the symbol is a contract of the caller
+        unsafe { library.get::<T>(symbol.as_bytes()) }
+            .ok()
+            .map(|symbol| *symbol)
+    }
+
+    /// Returns a [`Stream`] that produces a value every time the file of the
+    /// [`Library`] changes.
+    pub fn changes(&self) -> impl Stream<Item = ()> {
+        let path = self.path.clone();
+
+        stream::once(async move {
+            let (sender, receiver) = mpsc::unbounded();
+
+            let _ = thread::Builder::new()
+                .name("iced-hot-reload".to_owned())
+                .spawn(move || {
+                    let modified = |path: &Path| {
+                        fs::metadata(path).and_then(|file| file.modified()).ok()
+                    };
+
+                    let mut last = modified(&path);
+
+                    loop {
+                        thread::sleep(INTERVAL);
+
+                        let current = modified(&path);
+
+                        if current != last {
+                            last = current;
+
+                            if sender.unbounded_send(()).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                });
+
+            receiver
+        })
+        .flatten()
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        // Widgets built by any version may still be alive
+        for library in self.loaded.drain(..) {
+            std::mem::forget(library);
+        }
+    }
+}
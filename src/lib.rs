@@ -273,6 +273,41 @@
 //! Most widgets provide styling functions for your convenience in their respective modules;
 //! like [`container::rounded_box`], [`button::primary`], or [`text::danger`].
 //!
+//! Styling functions can be layered on top of each other with [`style::Compose`].
+//! Since a [`style::Layer`] can be applied to the appearance of many different
+//! widgets, the same tweaks can be shared across your whole application:
+//!
+//! ```rust
+//! # struct State;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! use iced::style::{Compose, Layer, Layered};
+//! use iced::widget::{button, column, text_input};
+//! use iced::{Element, Theme};
+//!
+//! fn accent<Style: Layered>(theme: &Theme, style: Style) -> Style {
+//!     style.layer(&Layer::new().border_color(theme.palette().primary).width(2))
+//! }
+//!
+//! fn view(state: &State) -> Element<Message> {
+//!     column![
+//!         button("Save").style(button::secondary.then(|theme, status, style| {
+//!             match status {
+//!                 button::Status::Hovered => accent(theme, style),
+//!                 _ => style,
+//!             }
+//!         })),
+//!         text_input("Name", "").style(text_input::default.then(
+//!             |theme, status, style| match status {
+//!                 text_input::Status::Focused => accent(theme, style),
+//!                 _ => style,
+//!             },
+//!         )),
+//!     ]
+//!     .into()
+//! }
+//! ```
+//!
 //! [`Status`]: widget::button::Status
 //! [`palette`]: Theme::palette
 //! [`extended_palette`]: Theme::extended_palette
@@ -473,16 +508,32 @@ pub mod window;
 #[cfg(feature = "advanced")]
 pub mod advanced;
 
+#[cfg(feature = "persistence")]
+pub mod persistence;
+
+#[cfg(all(feature = "hot", not(target_arch = "wasm32")))]
+pub mod hot;
+
+#[cfg(all(feature = "deep-link", not(target_arch = "wasm32")))]
+pub mod deep_link;
+
+#[cfg(all(feature = "single-instance", not(target_arch = "wasm32")))]
+pub mod single_instance;
+
 pub use crate::core::alignment;
 pub use crate::core::border;
 pub use crate::core::color;
+pub use crate::core::density;
+pub use crate::core::direction;
+pub use crate::core::gesture;
 pub use crate::core::gradient;
 pub use crate::core::padding;
+pub use crate::core::style;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Background, Border, Color, ContentFit, Degrees, Gradient,
-    Length, Padding, Pixels, Point, Radians, Rectangle, Rotation, Shadow, Size,
-    Theme, Transformation, Vector,
+    Alignment, Background, Border, Color, ContentFit, Degrees, Density,
+    Direction, Gradient, Length, Padding, Pixels, Point, Radians, Rectangle,
+    Rotation, Shadow, Size, Theme, Transformation, Vector,
 };
 pub use crate::runtime::exit;
 pub use iced_futures::Subscription;
@@ -549,10 +600,14 @@ pub mod mouse {
     };
 }
 
-#[cfg(feature = "system")]
 pub mod system {
     //! Retrieve system information.
+    pub use crate::runtime::system::{fetch_graphics, Graphics};
+
+    #[cfg(feature = "system")]
     pub use crate::runtime::system::Information;
+
+    #[cfg(feature = "system")]
     pub use crate::shell::system::*;
 }
 
@@ -574,6 +629,11 @@ pub mod overlay {
     pub use iced_widget::overlay::*;
 }
 
+pub mod pen {
+    //! Listen and react to pen and stylus events.
+    pub use crate::core::pen::{Event, Pen, Sample};
+}
+
 pub mod touch {
     //! Listen and react to touch events.
     pub use crate::core::touch::{Event, Finger};
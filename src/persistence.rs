@@ -0,0 +1,97 @@
+//! Save the state of your application on exit and restore it on startup.
+//!
+//! The state is stored as JSON in the configuration directory of the
+//! platform, in a folder named after the [`Settings::id`] of the application
+//! or, if it is not set, after its executable.
+//!
+//! [`Settings::id`]: crate::Settings::id
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+static PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Returns the path of the file where the state of the running application
+/// is persisted, if the platform has a configuration directory.
+pub fn path() -> Option<&'static Path> {
+    PATH.get().and_then(Option::as_deref)
+}
+
+pub(crate) fn init(id: Option<&str>) {
+    let _ = PATH.set(locate(id));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn locate(id: Option<&str>) -> Option<PathBuf> {
+    let name = match id {
+        Some(id) => id.to_owned(),
+        None => std::env::current_exe()
+            .ok()?
+            .file_stem()?
+            .to_str()?
+            .to_owned(),
+    };
+
+    let directories = directories::ProjectDirs::from("", "", &name)?;
+
+    Some(directories.config_dir().join("state.json"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn locate(_id: Option<&str>) -> Option<PathBuf> {
+    // There is no file system to persist the state to
+    None
+}
+
+pub(crate) fn load<T: DeserializeOwned>() -> Option<T> {
+    let path = path()?;
+
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        // Nothing has been persisted yet
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return None,
+        Err(error) => {
+            log::warn!(
+                "The state at {} could not be read: {error}",
+                path.display()
+            );
+
+            return None;
+        }
+    };
+
+    match serde_json::from_slice(&bytes) {
+        Ok(payload) => Some(payload),
+        Err(error) => {
+            log::warn!(
+                "The state at {} could not be decoded: {error}",
+                path.display()
+            );
+
+            None
+        }
+    }
+}
+
+pub(crate) fn store<T: Serialize>(payload: &T) -> io::Result<()> {
+    let Some(path) = path() else {
+        return Ok(());
+    };
+
+    let json = serde_json::to_vec_pretty(payload)?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file first; so an interrupted write never
+    // replaces the previous state with a truncated one
+    let temporary = path.with_extension("json.tmp");
+
+    fs::write(&temporary, json)?;
+    fs::rename(temporary, path)
+}
@@ -62,6 +62,14 @@ pub trait Program: Sized {
         1.0
     }
 
+    fn save(&self, _state: &Self::State) {}
+
+    fn restore(&self, _state: &mut Self::State) {}
+
+    fn single_instance(&self) -> bool {
+        false
+    }
+
     /// Runs the [`Program`].
     ///
     /// The state of the [`Program`] must implement [`Default`].
@@ -114,7 +122,8 @@ pub trait Program: Sized {
             fn new(
                 (program, initialize): Self::Flags,
             ) -> (Self, Task<Self::Message>) {
-                let (state, task) = initialize();
+                let (mut state, task) = initialize();
+                program.restore(&mut state);
 
                 (
                     Self {
@@ -162,6 +171,29 @@ pub trait Program: Sized {
             }
         }
 
+        impl<P: Program, I> Drop for Instance<P, I> {
+            fn drop(&mut self) {
+                // A panicking program may have been left in an inconsistent
+                // state; which is better not persisted
+                if !std::thread::panicking() {
+                    self.program.save(&self.state);
+                }
+            }
+        }
+
+        #[cfg(all(feature = "single-instance", not(target_arch = "wasm32")))]
+        if self.single_instance()
+            && crate::single_instance::forward(settings.id.as_deref())
+        {
+            return Ok(());
+        }
+
+        #[cfg(feature = "persistence")]
+        crate::persistence::init(settings.id.as_deref());
+
+        crate::core::direction::set(settings.direction);
+        crate::core::gesture::set(settings.gestures);
+
         #[allow(clippy::needless_update)]
         let renderer_settings = crate::graphics::Settings {
             default_font: settings.default_font,
@@ -171,6 +203,8 @@ pub trait Program: Sized {
             } else {
                 None
             },
+            density: settings.density,
+            backends: settings.backends,
             ..crate::graphics::Settings::default()
         };
 
@@ -184,6 +218,10 @@ pub trait Program: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 antialiasing: settings.antialiasing,
+                density: settings.density,
+                direction: settings.direction,
+                gestures: settings.gestures,
+                backends: settings.backends,
             }
             .into(),
             renderer_settings,
@@ -259,6 +297,18 @@ pub fn with_title<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
     }
 
     WithTitle { program, title }
@@ -329,6 +379,18 @@ pub fn with_subscription<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
     }
 
     WithSubscription {
@@ -402,6 +464,18 @@ pub fn with_theme<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
     }
 
     WithTheme { program, theme: f }
@@ -472,6 +546,18 @@ pub fn with_style<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             self.program.scale_factor(state, window)
         }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
     }
 
     WithStyle { program, style: f }
@@ -542,6 +628,18 @@ pub fn with_scale_factor<P: Program>(
         fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
             (self.scale_factor)(state, window)
         }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
     }
 
     WithScaleFactor {
@@ -550,6 +648,526 @@ pub fn with_scale_factor<P: Program>(
     }
 }
 
+#[cfg(feature = "persistence")]
+pub fn with_save<P: Program, T: serde::Serialize>(
+    program: P,
+    f: impl Fn(&P::State) -> T,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithSave<P, F, T> {
+        program: P,
+        save: F,
+        _payload: std::marker::PhantomData<fn() -> T>,
+    }
+
+    impl<P: Program, F, T> Program for WithSave<P, F, T>
+    where
+        F: Fn(&P::State) -> T,
+        T: serde::Serialize,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+
+            if let Err(error) = crate::persistence::store(&(self.save)(state)) {
+                log::warn!("The state could not be persisted: {error}");
+            }
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
+    }
+
+    WithSave {
+        program,
+        save: f,
+        _payload: std::marker::PhantomData,
+    }
+}
+
+#[cfg(feature = "persistence")]
+pub fn with_restore<P: Program, T: serde::de::DeserializeOwned>(
+    program: P,
+    f: impl Fn(&mut P::State, T),
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithRestore<P, F, T> {
+        program: P,
+        restore: F,
+        _payload: std::marker::PhantomData<fn() -> T>,
+    }
+
+    impl<P: Program, F, T> Program for WithRestore<P, F, T>
+    where
+        F: Fn(&mut P::State, T),
+        T: serde::de::DeserializeOwned,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            self.program.subscription(state)
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+
+            if let Some(payload) = crate::persistence::load() {
+                (self.restore)(state, payload);
+            }
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
+    }
+
+    WithRestore {
+        program,
+        restore: f,
+        _payload: std::marker::PhantomData,
+    }
+}
+
+/// # Safety
+/// The `view` symbol of every version of the library must have the exact
+/// type of the view of the [`Program`].
+#[cfg(all(feature = "hot", not(target_arch = "wasm32")))]
+#[allow(unsafe_code)]
+pub unsafe fn with_hot_view<P: Program>(
+    program: P,
+    library: crate::hot::Library,
+    on_reload: P::Message,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme>
+where
+    P::Message: Clone + Send + 'static,
+{
+    type View<State, Message, Theme, Renderer> =
+        for<'a> fn(&'a State) -> Element<'a, Message, Theme, Renderer>;
+
+    struct WithHotView<P: Program> {
+        program: P,
+        library: std::sync::Arc<crate::hot::Library>,
+        on_reload: P::Message,
+    }
+
+    impl<P: Program> Program for WithHotView<P>
+    where
+        P::Message: Clone + Send + 'static,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            // SAFETY: The type of the symbol is a contract of the unsafe
+            // `with_hot_view` constructor; upheld by the caller of `hot_view`
+            #[allow(unsafe_code)]
+            let view = unsafe {
+                self.library.get::<View<
+                    Self::State,
+                    Self::Message,
+                    Self::Theme,
+                    Self::Renderer,
+                >>("view")
+            };
+
+            match view {
+                Some(view) => view(state),
+                None => self.program.view(state, window),
+            }
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            use crate::futures::StreamExt;
+
+            let library = self.library.clone();
+            let on_reload = self.on_reload.clone();
+
+            // The previous version keeps running until the new one loads
+            let changes = Subscription::run_with_id(
+                self.library.path().to_path_buf(),
+                self.library.changes().map(move |()| {
+                    if let Err(error) = library.reload() {
+                        log::warn!(
+                            "The library at {} could not be reloaded: {error}",
+                            library.path().display()
+                        );
+                    }
+
+                    on_reload.clone()
+                }),
+            );
+
+            Subscription::batch([self.program.subscription(state), changes])
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            self.program.single_instance()
+        }
+    }
+
+    if let Err(error) = library.reload() {
+        log::warn!(
+            "The library at {} could not be loaded: {error}",
+            library.path().display()
+        );
+    }
+
+    WithHotView {
+        program,
+        library: std::sync::Arc::new(library),
+        on_reload,
+    }
+}
+
+#[cfg(all(feature = "single-instance", not(target_arch = "wasm32")))]
+pub fn with_single_instance<P: Program>(
+    program: P,
+    f: impl Fn(crate::single_instance::Launch) -> P::Message
+        + Send
+        + Clone
+        + 'static,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithSingleInstance<P, F> {
+        program: P,
+        on_launch: F,
+    }
+
+    impl<P: Program, F> Program for WithSingleInstance<P, F>
+    where
+        F: Fn(crate::single_instance::Launch) -> P::Message
+            + Send
+            + Clone
+            + 'static,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            use crate::futures::StreamExt;
+
+            let launches = Subscription::run_with_id(
+                std::any::TypeId::of::<crate::single_instance::Launch>(),
+                crate::single_instance::launches().map(self.on_launch.clone()),
+            );
+
+            Subscription::batch([self.program.subscription(state), launches])
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            true
+        }
+    }
+
+    WithSingleInstance {
+        program,
+        on_launch: f,
+    }
+}
+
+#[cfg(all(feature = "deep-link", not(target_arch = "wasm32")))]
+pub fn with_deep_link<P: Program>(
+    program: P,
+    scheme: &'static str,
+    f: impl Fn(String) -> P::Message + Send + Clone + 'static,
+) -> impl Program<State = P::State, Message = P::Message, Theme = P::Theme> {
+    struct WithDeepLink<P, F> {
+        program: P,
+        scheme: &'static str,
+        on_url: F,
+    }
+
+    impl<P: Program, F> Program for WithDeepLink<P, F>
+    where
+        F: Fn(String) -> P::Message + Send + Clone + 'static,
+    {
+        type State = P::State;
+        type Message = P::Message;
+        type Theme = P::Theme;
+        type Renderer = P::Renderer;
+        type Executor = P::Executor;
+
+        fn title(&self, state: &Self::State, window: window::Id) -> String {
+            self.program.title(state, window)
+        }
+
+        fn update(
+            &self,
+            state: &mut Self::State,
+            message: Self::Message,
+        ) -> Task<Self::Message> {
+            self.program.update(state, message)
+        }
+
+        fn view<'a>(
+            &self,
+            state: &'a Self::State,
+            window: window::Id,
+        ) -> Element<'a, Self::Message, Self::Theme, Self::Renderer> {
+            self.program.view(state, window)
+        }
+
+        fn subscription(
+            &self,
+            state: &Self::State,
+        ) -> Subscription<Self::Message> {
+            let urls = crate::deep_link::subscription(
+                self.scheme,
+                self.on_url.clone(),
+            );
+
+            Subscription::batch([self.program.subscription(state), urls])
+        }
+
+        fn theme(
+            &self,
+            state: &Self::State,
+            window: window::Id,
+        ) -> Self::Theme {
+            self.program.theme(state, window)
+        }
+
+        fn style(
+            &self,
+            state: &Self::State,
+            theme: &Self::Theme,
+        ) -> Appearance {
+            self.program.style(state, theme)
+        }
+
+        fn scale_factor(&self, state: &Self::State, window: window::Id) -> f64 {
+            self.program.scale_factor(state, window)
+        }
+
+        fn save(&self, state: &Self::State) {
+            self.program.save(state);
+        }
+
+        fn restore(&self, state: &mut Self::State) {
+            self.program.restore(state);
+        }
+
+        fn single_instance(&self) -> bool {
+            true
+        }
+    }
+
+    WithDeepLink {
+        program,
+        scheme,
+        on_url: f,
+    }
+}
+
 /// The renderer of some [`Program`].
 pub trait Renderer: text::Renderer + compositor::Default {}
 
@@ -1,5 +1,6 @@
 //! Configure your application.
-use crate::{Font, Pixels};
+use crate::gesture;
+use crate::{Density, Direction, Font, Pixels};
 
 use std::borrow::Cow;
 
@@ -18,6 +19,9 @@ pub struct Settings {
     /// The default [`Font`] to be used.
     ///
     /// By default, it uses [`Family::SansSerif`](crate::font::Family::SansSerif).
+    ///
+    /// Its [`fallback`](Font::fallback) families are used by any text without
+    /// a [`Font`] of its own.
     pub default_font: Font,
 
     /// The text size that will be used by default.
@@ -35,6 +39,39 @@ pub struct Settings {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
+
+    /// The [`Density`] of the built-in widgets.
+    ///
+    /// It scales their default paddings and control sizes. [`Density::Touch`]
+    /// also ensures their hit targets are large enough for touch input.
+    ///
+    /// By default, it is [`Density::Comfortable`].
+    pub density: Density,
+
+    /// The [`Direction`] of the built-in widgets.
+    ///
+    /// In [`Direction::RightToLeft`], widgets mirror their directional
+    /// visuals, unless a direction has been set for them explicitly.
+    ///
+    /// By default, it is [`Direction::LeftToRight`].
+    pub direction: Direction,
+
+    /// The [`gesture::Thresholds`] used by the built-in widgets to recognize
+    /// double clicks, drags, and long presses.
+    ///
+    /// By default, it follows the conventions of the current platform.
+    pub gestures: gesture::Thresholds,
+
+    /// The rendering backends to try, in order of preference.
+    ///
+    /// This lets a single binary fall back to a more compatible backend,
+    /// like `&["wgpu-vulkan", "wgpu-gl", "tiny-skia"]`. The selected backend
+    /// can be queried with [`system::fetch_graphics`].
+    ///
+    /// By default, it is empty; which tries every enabled backend in order.
+    ///
+    /// [`system::fetch_graphics`]: crate::system::fetch_graphics
+    pub backends: &'static [&'static str],
 }
 
 impl Default for Settings {
@@ -45,6 +82,10 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
+            density: Density::default(),
+            direction: Direction::default(),
+            gestures: gesture::Thresholds::default(),
+            backends: &[],
         }
     }
 }
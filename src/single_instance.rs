@@ -0,0 +1,373 @@
+//! Run a single instance of your application at a time.
+//!
+//! When an application runs in single-instance mode, launching it while
+//! another instance is already running forwards a [`Launch`] to the running
+//! instance and exits right away. This way, opening a file with an application
+//! that is already running can reuse its windows.
+//!
+//! The running instance listens on a local socket, which is advertised in a
+//! file named after the [`Settings::id`] of the application or, if it is not
+//! set, after its executable. The file lives in the runtime directory of the
+//! user or, if the platform has none, in its cache directory; and it is only
+//! readable by its owner.
+//!
+//! [`Settings::id`]: crate::Settings::id
+use crate::futures::channel::mpsc;
+use crate::futures::stream::{self, Stream, StreamExt};
+
+use std::ffi::{OsStr, OsString};
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// A launch of the application forwarded to its running instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Launch {
+    /// The command line arguments of the launch, without the name of the
+    /// executable.
+    pub arguments: Vec<OsString>,
+
+    /// The working directory of the launch.
+    ///
+    /// Relative paths in the [`arguments`](Self::arguments) should be
+    /// resolved against it; since it may differ from the one of the running
+    /// instance.
+    pub directory: PathBuf,
+}
+
+static SUBSCRIBERS: Mutex<Vec<mpsc::UnboundedSender<Launch>>> =
+    Mutex::new(Vec::new());
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+const ACKNOWLEDGEMENT: u8 = 1;
+
+/// Forwards the current launch to the running instance of the application,
+/// returning `true` if it was delivered.
+///
+/// Otherwise, the current process becomes the running instance and starts
+/// listening for forwarded launches.
+pub(crate) fn forward(id: Option<&str>) -> bool {
+    let Some(path) = locate(id) else {
+        return false;
+    };
+
+    if send(&path, &current()).is_ok() {
+        return true;
+    }
+
+    // We can still run without receiving launches, like a regular program
+    let _ = listen(&path);
+
+    false
+}
+
+/// Returns the [`Stream`] of launches forwarded to the current instance.
+///
+/// It produces the launches forwarded after it is first polled.
+pub(crate) fn launches() -> impl Stream<Item = Launch> {
+    stream::once(async {
+        let (sender, receiver) = mpsc::unbounded();
+
+        if let Ok(mut subscribers) = SUBSCRIBERS.lock() {
+            subscribers.push(sender);
+        }
+
+        receiver
+    })
+    .flatten()
+}
+
+fn locate(id: Option<&str>) -> Option<PathBuf> {
+    let name = match id {
+        Some(id) => id.to_owned(),
+        None => std::env::current_exe()
+            .ok()?
+            .file_stem()?
+            .to_str()?
+            .to_owned(),
+    };
+
+    let directories = directories::BaseDirs::new()?;
+
+    let directory = directories
+        .runtime_dir()
+        .unwrap_or_else(|| directories.cache_dir());
+
+    fs::create_dir_all(directory).ok()?;
+
+    Some(directory.join(format!("{name}.instance")))
+}
+
+/// Returns whether the file at the given path can be trusted to advertise
+/// an instance of the current user.
+///
+/// The file must be owned by the owner of its directory, which is a
+/// directory of the current user; and it must not be accessible by anyone
+/// else.
+#[cfg(unix)]
+fn is_trusted(path: &Path) -> io::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+
+    let file = fs::symlink_metadata(path)?;
+    let directory = fs::metadata(path.parent().unwrap_or(Path::new("/")))?;
+
+    Ok(file.is_file()
+        && file.uid() == directory.uid()
+        && file.mode() & 0o077 == 0)
+}
+
+#[cfg(not(unix))]
+fn is_trusted(path: &Path) -> io::Result<bool> {
+    // The directories of the user are only accessible by the user
+    Ok(fs::symlink_metadata(path)?.is_file())
+}
+
+fn current() -> Launch {
+    Launch {
+        arguments: std::env::args_os().skip(1).collect(),
+        directory: std::env::current_dir().unwrap_or_default(),
+    }
+}
+
+fn send(path: &Path, launch: &Launch) -> io::Result<()> {
+    if !is_trusted(path)? {
+        return Err(io::ErrorKind::PermissionDenied.into());
+    }
+
+    let contents = fs::read_to_string(path)?;
+
+    let (port, token) = contents
+        .trim()
+        .split_once(' ')
+        .and_then(|(port, token)| Some((port.parse().ok()?, token)))
+        .ok_or(io::ErrorKind::InvalidData)?;
+
+    let mut stream = TcpStream::connect_timeout(
+        &SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+        TIMEOUT,
+    )?;
+
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    writeln!(stream, "{token}")?;
+    write_field(&mut stream, launch.directory.as_os_str())?;
+
+    for argument in &launch.arguments {
+        write_field(&mut stream, argument)?;
+    }
+
+    stream.shutdown(std::net::Shutdown::Write)?;
+
+    // A stale file may point to a port that is now used by a different
+    // process; so the launch is only delivered once the instance confirms it
+    let mut acknowledgement = [0];
+    stream.read_exact(&mut acknowledgement)?;
+
+    if acknowledgement[0] == ACKNOWLEDGEMENT {
+        Ok(())
+    } else {
+        Err(io::ErrorKind::InvalidData.into())
+    }
+}
+
+fn listen(path: &Path) -> io::Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+    let port = listener.local_addr()?.port();
+    let token = token()?;
+
+    // Any previous file is stale or untrusted, since it could not be used
+    // to forward the launch
+    let _ = fs::remove_file(path);
+
+    let mut options = OpenOptions::new();
+    let _ = options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        let _ = options.mode(0o600);
+    }
+
+    write!(options.open(path)?, "{port} {token}")?;
+
+    let _ = thread::Builder::new()
+        .name("iced-single-instance".to_owned())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+
+                let Ok(launch) = receive(stream, &token) else {
+                    continue;
+                };
+
+                if let Ok(mut subscribers) = SUBSCRIBERS.lock() {
+                    subscribers.retain(|subscriber| {
+                        subscriber.unbounded_send(launch.clone()).is_ok()
+                    });
+                }
+            }
+        })?;
+
+    Ok(())
+}
+
+fn receive(stream: TcpStream, token: &str) -> io::Result<Launch> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut reader = BufReader::new(&stream);
+    let mut line = String::new();
+
+    let _ = reader.read_line(&mut line)?;
+
+    if line.trim_end() != token {
+        return Err(io::ErrorKind::PermissionDenied.into());
+    }
+
+    let mut rest = Vec::new();
+    let _ = reader.read_to_end(&mut rest)?;
+
+    let mut fields = Vec::new();
+    let mut rest = rest.as_slice();
+
+    while !rest.is_empty() {
+        let (field, tail) =
+            read_field(rest).ok_or(io::ErrorKind::InvalidData)?;
+
+        fields.push(field);
+        rest = tail;
+    }
+
+    let mut fields = fields.into_iter();
+    let directory =
+        PathBuf::from(fields.next().ok_or(io::ErrorKind::InvalidData)?);
+
+    (&stream).write_all(&[ACKNOWLEDGEMENT])?;
+
+    Ok(Launch {
+        arguments: fields.collect(),
+        directory,
+    })
+}
+
+/// Generates a random token that authenticates the launches sent to the
+/// running instance.
+fn token() -> io::Result<String> {
+    let mut bytes = [0; 16];
+
+    getrandom::getrandom(&mut bytes)
+        .map_err(|error| io::Error::other(error.to_string()))?;
+
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Writes a field of a launch prefixed with its length in bytes; since it
+/// may contain any character.
+fn write_field(stream: &mut impl Write, field: &OsStr) -> io::Result<()> {
+    let bytes = encode(field);
+
+    write!(stream, "{} ", bytes.len())?;
+    stream.write_all(&bytes)
+}
+
+/// Reads a field written by [`write_field`], returning it along with the
+/// remaining bytes.
+fn read_field(bytes: &[u8]) -> Option<(OsString, &[u8])> {
+    let separator = bytes.iter().position(|byte| *byte == b' ')?;
+
+    let length: usize = std::str::from_utf8(&bytes[..separator])
+        .ok()?
+        .parse()
+        .ok()?;
+
+    let tail = &bytes[separator + 1..];
+    let field = tail.get(..length)?;
+
+    Some((decode(field.to_vec())?, &tail[length..]))
+}
+
+#[cfg(unix)]
+fn encode(string: &OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    string.as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn decode(bytes: Vec<u8>) -> Option<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    Some(OsString::from_vec(bytes))
+}
+
+#[cfg(windows)]
+fn encode(string: &OsStr) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+
+    string.encode_wide().flat_map(u16::to_le_bytes).collect()
+}
+
+#[cfg(windows)]
+fn decode(bytes: Vec<u8>) -> Option<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    Some(OsString::from_wide(&wide))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn encode(string: &OsStr) -> Vec<u8> {
+    string.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn decode(bytes: Vec<u8>) -> Option<OsString> {
+    String::from_utf8(bytes).ok().map(OsString::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_are_read_back() {
+        let mut bytes = Vec::new();
+
+        for field in ["/home/user", "with\nnew line", "", "ünïcode"] {
+            write_field(&mut bytes, OsStr::new(field)).unwrap();
+        }
+
+        let mut fields = Vec::new();
+        let mut rest = bytes.as_slice();
+
+        while !rest.is_empty() {
+            let (field, tail) = read_field(rest).unwrap();
+
+            fields.push(field);
+            rest = tail;
+        }
+
+        assert_eq!(fields, ["/home/user", "with\nnew line", "", "ünïcode"]);
+    }
+
+    #[test]
+    fn truncated_fields_are_rejected() {
+        assert!(read_field(b"10 short").is_none());
+        assert!(read_field(b"no-length").is_none());
+    }
+}
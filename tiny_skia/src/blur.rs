@@ -0,0 +1,110 @@
+//! Approximate a gaussian blur with successive box blurs.
+
+/// The amount of passes of the box blur.
+const PASSES: usize = 3;
+
+/// Returns the distance a blur with the given radius spreads its contents.
+pub fn extent(radius: f32) -> f32 {
+    // Three standard deviations
+    radius.max(0.0) * 1.5
+}
+
+/// Blurs the given [`tiny_skia::Pixmap`] with the given radius, in pixels.
+///
+/// Like the shadows of quads, the radius is twice the standard deviation of
+/// the blur. Everything outside of the pixmap is considered transparent.
+pub fn apply(pixmap: &mut tiny_skia::Pixmap, radius: f32) {
+    let sigma = radius / 2.0;
+
+    if sigma < 0.5 {
+        return;
+    }
+
+    let width = pixmap.width() as usize;
+    let height = pixmap.height() as usize;
+
+    // Premultiplied colors can be averaged as they are
+    let pixels = pixmap.data_mut();
+    let mut buffer = vec![0; pixels.len()];
+
+    for size in box_sizes(sigma) {
+        let radius = (size - 1) / 2;
+
+        box_blur(pixels, &mut buffer, width, height, radius, 4, width * 4);
+        box_blur(&buffer, pixels, height, width, radius, width * 4, 4);
+    }
+}
+
+/// Returns the sizes of the boxes that approximate a gaussian blur with the
+/// given standard deviation.
+fn box_sizes(sigma: f32) -> [usize; PASSES] {
+    let n = PASSES as f32;
+    let ideal = (12.0 * sigma * sigma / n + 1.0).sqrt();
+
+    let mut lower = ideal.floor() as usize;
+
+    if lower % 2 == 0 {
+        lower = lower.saturating_sub(1).max(1);
+    }
+
+    let upper = lower + 2;
+    let l = lower as f32;
+
+    let m = ((12.0 * sigma * sigma - n * l * l - 4.0 * n * l - 3.0 * n)
+        / (-4.0 * l - 4.0))
+        .round() as usize;
+
+    let mut sizes = [upper; PASSES];
+
+    for size in sizes.iter_mut().take(m) {
+        *size = lower;
+    }
+
+    sizes
+}
+
+/// Blurs every line of `source` along one axis into `target`.
+///
+/// `step` is the distance between two pixels of a line, while `stride` is
+/// the distance between two lines; both in bytes.
+fn box_blur(
+    source: &[u8],
+    target: &mut [u8],
+    lines: usize,
+    length: usize,
+    radius: usize,
+    step: usize,
+    stride: usize,
+) {
+    let scale = 1.0 / (2 * radius + 1) as f32;
+
+    for line in 0..lines {
+        let start = line * stride;
+        let pixel = |i: usize, channel: usize| {
+            u32::from(source[start + i * step + channel])
+        };
+
+        let mut sums = [0u32; 4];
+
+        for i in 0..radius.min(length) {
+            for (channel, sum) in sums.iter_mut().enumerate() {
+                *sum += pixel(i, channel);
+            }
+        }
+
+        for i in 0..length {
+            for (channel, sum) in sums.iter_mut().enumerate() {
+                if i + radius < length {
+                    *sum += pixel(i + radius, channel);
+                }
+
+                if i > radius {
+                    *sum -= pixel(i - radius - 1, channel);
+                }
+
+                target[start + i * step + channel] =
+                    (*sum as f32 * scale).round().min(255.0) as u8;
+            }
+        }
+    }
+}
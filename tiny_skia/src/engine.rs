@@ -1,6 +1,8 @@
+use crate::blur;
 use crate::core::renderer::Quad;
+use crate::core::text::Antialiasing;
 use crate::core::{
-    Background, Color, Gradient, Rectangle, Size, Transformation, Vector,
+    Background, Color, Gradient, Point, Rectangle, Size, Transformation, Vector,
 };
 use crate::graphics::{Image, Text};
 use crate::text;
@@ -326,6 +328,10 @@ impl Engine {
         }
     }
 
+    pub fn set_text_antialiasing(&mut self, antialiasing: Antialiasing) {
+        self.text_pipeline.set_antialiasing(antialiasing);
+    }
+
     pub fn draw_text(
         &mut self,
         text: &Text,
@@ -473,6 +479,25 @@ impl Engine {
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: &mut tiny_skia::Mask,
         layer_bounds: Rectangle,
+    ) {
+        self.draw_masked_primitive(
+            primitive,
+            transformation,
+            pixels,
+            clip_mask,
+            layer_bounds,
+            false,
+        );
+    }
+
+    fn draw_masked_primitive(
+        &mut self,
+        primitive: &Primitive,
+        transformation: Transformation,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_mask: &mut tiny_skia::Mask,
+        layer_bounds: Rectangle,
+        is_masked: bool,
     ) {
         match primitive {
             Primitive::Fill { path, paint, rule } => {
@@ -493,8 +518,8 @@ impl Engine {
                     return;
                 };
 
-                let clip_mask =
-                    (physical_bounds != clip_bounds).then_some(clip_mask as &_);
+                let clip_mask = (is_masked || physical_bounds != clip_bounds)
+                    .then_some(clip_mask as &_);
 
                 pixels.fill_path(
                     path,
@@ -526,8 +551,8 @@ impl Engine {
                     return;
                 };
 
-                let clip_mask =
-                    (physical_bounds != clip_bounds).then_some(clip_mask as &_);
+                let clip_mask = (is_masked || physical_bounds != clip_bounds)
+                    .then_some(clip_mask as &_);
 
                 pixels.stroke_path(
                     path,
@@ -537,6 +562,162 @@ impl Engine {
                     clip_mask,
                 );
             }
+            #[cfg(feature = "image")]
+            Primitive::Pattern {
+                path,
+                handle,
+                tile,
+                transform,
+                stroke,
+                rule,
+                blend_mode,
+            } => {
+                let physical_bounds = {
+                    let bounds = path.bounds();
+
+                    Rectangle {
+                        x: bounds.x(),
+                        y: bounds.y(),
+                        width: bounds.width(),
+                        height: bounds.height(),
+                    } * transformation
+                };
+
+                let Some(clip_bounds) =
+                    layer_bounds.intersection(&physical_bounds)
+                else {
+                    return;
+                };
+
+                let clip_mask = (is_masked || physical_bounds != clip_bounds)
+                    .then_some(clip_mask as &_);
+
+                self.raster_pipeline.draw_pattern(
+                    handle,
+                    *tile,
+                    *transform,
+                    path,
+                    stroke.as_ref(),
+                    *rule,
+                    *blend_mode,
+                    pixels,
+                    into_transform(transformation),
+                    clip_mask,
+                );
+            }
+            #[cfg(not(feature = "image"))]
+            Primitive::Pattern { .. } => {
+                log::warn!(
+                    "Unsupported primitive in `iced_tiny_skia`: {primitive:?}",
+                );
+            }
+            Primitive::Clip { path, primitives } => {
+                let physical_bounds = {
+                    let bounds = path.bounds();
+
+                    Rectangle {
+                        x: bounds.x(),
+                        y: bounds.y(),
+                        width: bounds.width(),
+                        height: bounds.height(),
+                    } * transformation
+                };
+
+                let Some(clip_bounds) =
+                    layer_bounds.intersection(&physical_bounds)
+                else {
+                    return;
+                };
+
+                let mut mask = clip_mask.clone();
+
+                mask.intersect_path(
+                    path,
+                    tiny_skia::FillRule::Winding,
+                    true,
+                    into_transform(transformation),
+                );
+
+                for primitive in primitives {
+                    self.draw_masked_primitive(
+                        primitive,
+                        transformation,
+                        pixels,
+                        &mut mask,
+                        clip_bounds,
+                        true,
+                    );
+                }
+            }
+            Primitive::Blur {
+                radius,
+                primitives,
+                blend_mode,
+            } => {
+                let physical_bounds =
+                    primitive.visible_bounds() * transformation;
+
+                let Some(clip_bounds) =
+                    layer_bounds.intersection(&physical_bounds)
+                else {
+                    return;
+                };
+
+                // The contents outside of the layer can still be blurred
+                // into it
+                let extent =
+                    blur::extent(*radius * transformation.scale_factor());
+
+                let Some(bounds) =
+                    clip_bounds.expand(extent).intersection(&physical_bounds)
+                else {
+                    return;
+                };
+
+                let (x, y) = (bounds.x.floor(), bounds.y.floor());
+                let width = (bounds.x + bounds.width).ceil() - x;
+                let height = (bounds.y + bounds.height).ceil() - y;
+
+                let (Some(mut group), Some(mut group_mask)) = (
+                    tiny_skia::Pixmap::new(width as u32, height as u32),
+                    tiny_skia::Mask::new(width as u32, height as u32),
+                ) else {
+                    return;
+                };
+
+                group_mask.data_mut().fill(u8::MAX);
+
+                let group_transformation =
+                    Transformation::translate(-x, -y) * transformation;
+
+                for primitive in primitives {
+                    self.draw_masked_primitive(
+                        primitive,
+                        group_transformation,
+                        &mut group.as_mut(),
+                        &mut group_mask,
+                        Rectangle::new(Point::ORIGIN, Size::new(width, height)),
+                        false,
+                    );
+                }
+
+                blur::apply(
+                    &mut group,
+                    *radius * transformation.scale_factor(),
+                );
+
+                pixels.draw_pixmap(
+                    x as i32,
+                    y as i32,
+                    group.as_ref(),
+                    &tiny_skia::PixmapPaint {
+                        blend_mode: *blend_mode,
+                        ..tiny_skia::PixmapPaint::default()
+                    },
+                    tiny_skia::Transform::default(),
+                    Some(clip_mask),
+                );
+            }
         }
     }
 
@@ -574,6 +755,7 @@ impl Engine {
                     raster.filter_method,
                     *bounds,
                     raster.opacity,
+                    raster.tint,
                     _pixels,
                     transform,
                     clip_mask,
@@ -1,9 +1,16 @@
+use crate::core::gradient::ColorStop;
+use crate::core::image;
 use crate::core::text::LineHeight;
-use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::core::{
+    self, Color, Pixels, Point, Radians, Rectangle, Size, Svg, Transformation,
+    Vector,
+};
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
-use crate::graphics::geometry::{self, Path, Style};
+use crate::graphics::geometry::{
+    self, vertex, BlendMode, Path, Style, TexturedVertex, Tiling, Vertex,
+};
 use crate::graphics::{self, Gradient, Image, Text};
 use crate::Primitive;
 
@@ -13,7 +20,7 @@ use std::rc::Rc;
 pub enum Geometry {
     Live {
         text: Vec<Text>,
-        images: Vec<graphics::Image>,
+        images: Vec<(graphics::Image, Rectangle)>,
         primitives: Vec<Primitive>,
         clip_bounds: Rectangle,
     },
@@ -23,7 +30,7 @@ pub enum Geometry {
 #[derive(Debug, Clone)]
 pub struct Cache {
     pub text: Rc<[Text]>,
-    pub images: Rc<[graphics::Image]>,
+    pub images: Rc<[(graphics::Image, Rectangle)]>,
     pub primitives: Rc<[Primitive]>,
     pub clip_bounds: Rectangle,
 }
@@ -59,8 +66,19 @@ pub struct Frame {
     transform: tiny_skia::Transform,
     stack: Vec<tiny_skia::Transform>,
     primitives: Vec<Primitive>,
-    images: Vec<graphics::Image>,
+    images: Vec<(graphics::Image, Rectangle)>,
     text: Vec<Text>,
+    clip_path: Option<ClipPath>,
+    blend_mode: tiny_skia::BlendMode,
+    blur: Option<f32>,
+}
+
+/// The path a drafted [`Frame`] is clipped to.
+#[derive(Debug)]
+enum ClipPath {
+    /// A path without any segments, clipping everything.
+    Empty,
+    Path(tiny_skia::Path),
 }
 
 impl Frame {
@@ -75,12 +93,36 @@ impl Frame {
             primitives: Vec::new(),
             images: Vec::new(),
             text: Vec::new(),
+            clip_path: None,
+            blend_mode: tiny_skia::BlendMode::default(),
+            blur: None,
             transform: tiny_skia::Transform::from_translate(
                 clip_bounds.x,
                 clip_bounds.y,
             ),
         }
     }
+
+    fn pattern(
+        &mut self,
+        path: tiny_skia::Path,
+        handle: image::Handle,
+        tiling: Tiling,
+        stroke: Option<tiny_skia::Stroke>,
+        rule: tiny_skia::FillRule,
+    ) {
+        self.primitives.push(Primitive::Pattern {
+            path,
+            handle,
+            tile: tiling.size,
+            transform: self
+                .transform
+                .pre_translate(tiling.origin.x, tiling.origin.y),
+            stroke,
+            rule,
+            blend_mode: self.blend_mode,
+        });
+    }
 }
 
 impl geometry::frame::Backend for Frame {
@@ -111,8 +153,14 @@ impl geometry::frame::Backend for Frame {
 
         let fill = fill.into();
 
+        if let Style::Pattern(handle, tiling) = fill.style {
+            self.pattern(path, handle, tiling, None, into_fill_rule(fill.rule));
+            return;
+        }
+
         let mut paint = into_paint(fill.style);
         paint.shader.transform(self.transform);
+        paint.blend_mode = self.blend_mode;
 
         self.primitives.push(Primitive::Fill {
             path,
@@ -135,11 +183,17 @@ impl geometry::frame::Backend for Frame {
 
         let fill = fill.into();
 
+        if let Style::Pattern(handle, tiling) = fill.style {
+            self.pattern(path, handle, tiling, None, into_fill_rule(fill.rule));
+            return;
+        }
+
         let mut paint = tiny_skia::Paint {
             anti_alias: false,
             ..into_paint(fill.style)
         };
         paint.shader.transform(self.transform);
+        paint.blend_mode = self.blend_mode;
 
         self.primitives.push(Primitive::Fill {
             path,
@@ -158,8 +212,20 @@ impl geometry::frame::Backend for Frame {
         let stroke = stroke.into();
         let skia_stroke = into_stroke(&stroke);
 
+        if let Style::Pattern(handle, tiling) = stroke.style {
+            self.pattern(
+                path,
+                handle,
+                tiling,
+                Some(skia_stroke),
+                tiny_skia::FillRule::Winding,
+            );
+            return;
+        }
+
         let mut paint = into_paint(stroke.style);
         paint.shader.transform(self.transform);
+        paint.blend_mode = self.blend_mode;
 
         self.primitives.push(Primitive::Stroke {
             path,
@@ -240,13 +306,111 @@ impl geometry::frame::Backend for Frame {
         self.transform = self.stack.pop().expect("Pop transform");
     }
 
+    fn transform(&self) -> Transformation {
+        let t = self.transform;
+
+        Transformation::affine(t.sx, t.ky, t.kx, t.sy, t.tx, t.ty)
+    }
+
     fn draft(&mut self, clip_bounds: Rectangle) -> Self {
-        Self::with_clip(clip_bounds)
+        let mut frame = Self::with_clip(clip_bounds);
+
+        frame.blend_mode = self.blend_mode;
+
+        frame
     }
 
-    fn paste(&mut self, frame: Self) {
-        self.primitives.extend(frame.primitives);
+    fn draft_clip_path(&mut self, path: &Path) -> Self {
+        let path =
+            convert_path(path).and_then(|path| path.transform(self.transform));
+
+        let mut frame = Self::with_clip(self.clip_bounds);
+
+        frame.transform = self.transform;
+        frame.clip_path = Some(path.map_or(ClipPath::Empty, ClipPath::Path));
+        frame.blend_mode = self.blend_mode;
+
+        frame
+    }
+
+    fn draft_blend(&mut self, blend_mode: BlendMode) -> Self {
+        let mut frame = Self::with_clip(self.clip_bounds);
+
+        frame.transform = self.transform;
+        frame.blend_mode = into_blend_mode(blend_mode);
+
+        frame
+    }
+
+    fn draft_blur(&mut self, radius: f32) -> Self {
+        let mut frame = Self::with_clip(self.clip_bounds);
+
+        // The radius follows the scale of the current transform
+        let scale = (self.transform.sx * self.transform.sy
+            - self.transform.kx * self.transform.ky)
+            .abs()
+            .sqrt();
+
+        frame.transform = self.transform;
+        frame.blur = Some(radius * scale);
+
+        frame
+    }
+
+    fn paste(&mut self, mut frame: Self) {
+        let mut bounds = self.clip_bounds;
+
+        if let Some(radius) = frame.blur {
+            if !frame.primitives.is_empty() {
+                frame.primitives = vec![Primitive::Blur {
+                    radius,
+                    primitives: std::mem::take(&mut frame.primitives),
+                    blend_mode: self.blend_mode,
+                }];
+            }
+        }
+
+        match frame.clip_path {
+            None => {
+                self.primitives.extend(frame.primitives);
+            }
+            Some(ClipPath::Empty) => {
+                return;
+            }
+            Some(ClipPath::Path(path)) => {
+                let path_bounds = path.bounds();
+
+                bounds = bounds
+                    .intersection(&Rectangle {
+                        x: path_bounds.x(),
+                        y: path_bounds.y(),
+                        width: path_bounds.width(),
+                        height: path_bounds.height(),
+                    })
+                    .unwrap_or_default();
+
+                for text in &mut frame.text {
+                    if let Text::Cached { clip_bounds, .. } = text {
+                        *clip_bounds = clip_bounds
+                            .intersection(&bounds)
+                            .unwrap_or_default();
+                    }
+                }
+
+                self.primitives.push(Primitive::Clip {
+                    path,
+                    primitives: frame.primitives,
+                });
+            }
+        }
+
         self.text.extend(frame.text);
+
+        self.images.extend(frame.images.into_iter().filter_map(
+            |(image, clip_bounds)| {
+                Some((image, clip_bounds.intersection(&bounds)?))
+            },
+        ));
     }
 
     fn translate(&mut self, translation: Vector) {
@@ -281,6 +445,87 @@ impl geometry::frame::Backend for Frame {
         }
     }
 
+    fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]) {
+        // Colors cannot be interpolated; so every triangle is filled with
+        // the average color of its vertices
+        for triangle in vertex::triangles(vertices, indices) {
+            let Some(path) = triangle_path(triangle.map(|v| v.position))
+                .and_then(|path| path.transform(self.transform))
+            else {
+                continue;
+            };
+
+            let [a, b, c] = triangle.map(|v| v.color);
+
+            let color = Color {
+                r: (a.r + b.r + c.r) / 3.0,
+                g: (a.g + b.g + c.g) / 3.0,
+                b: (a.b + b.b + c.b) / 3.0,
+                a: (a.a + b.a + c.a) / 3.0,
+            };
+
+            // Anti-aliasing would show the seams between triangles
+            let mut paint = tiny_skia::Paint {
+                anti_alias: false,
+                ..into_paint(Style::Solid(color))
+            };
+            paint.blend_mode = self.blend_mode;
+
+            self.primitives.push(Primitive::Fill {
+                path,
+                paint,
+                rule: tiny_skia::FillRule::Winding,
+            });
+        }
+    }
+
+    fn draw_textured_mesh(
+        &mut self,
+        vertices: &[TexturedVertex],
+        indices: &[u32],
+        handle: image::Handle,
+    ) {
+        let basis = |[a, b, c]: [Point; 3]| {
+            tiny_skia::Transform::from_row(
+                b.x - a.x,
+                b.y - a.y,
+                c.x - a.x,
+                c.y - a.y,
+                a.x,
+                a.y,
+            )
+        };
+
+        for triangle in vertex::triangles(vertices, indices) {
+            let positions = triangle.map(|v| v.position);
+
+            let Some(path) = triangle_path(positions)
+                .and_then(|path| path.transform(self.transform))
+            else {
+                continue;
+            };
+
+            // Maps the UV coordinates of the triangle to its positions
+            let Some(uv) = basis(triangle.map(|v| v.uv)).invert() else {
+                continue;
+            };
+
+            self.primitives.push(Primitive::Pattern {
+                path,
+                handle: handle.clone(),
+                // A unit tile maps the image to the whole UV range
+                tile: Some(Size::new(1.0, 1.0)),
+                transform: self
+                    .transform
+                    .pre_concat(basis(positions))
+                    .pre_concat(uv),
+                stroke: None,
+                rule: tiny_skia::FillRule::Winding,
+                blend_mode: self.blend_mode,
+            });
+        }
+    }
+
     fn draw_image(&mut self, bounds: Rectangle, image: impl Into<core::Image>) {
         let mut image = image.into();
 
@@ -289,7 +534,8 @@ impl geometry::frame::Backend for Frame {
 
         image.rotation += external_rotation;
 
-        self.images.push(graphics::Image::Raster(image, bounds));
+        self.images
+            .push((graphics::Image::Raster(image, bounds), self.clip_bounds));
     }
 
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
@@ -300,7 +546,8 @@ impl geometry::frame::Backend for Frame {
 
         svg.rotation += external_rotation;
 
-        self.images.push(Image::Vector(svg, bounds));
+        self.images
+            .push((Image::Vector(svg, bounds), self.clip_bounds));
     }
 }
 
@@ -334,6 +581,17 @@ fn transform_rectangle(
     )
 }
 
+fn triangle_path([a, b, c]: [Point; 3]) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    builder.move_to(a.x, a.y);
+    builder.line_to(b.x, b.y);
+    builder.line_to(c.x, c.y);
+    builder.close();
+
+    builder.finish()
+}
+
 fn convert_path(path: &Path) -> Option<tiny_skia::Path> {
     use iced_graphics::geometry::path::lyon_path;
 
@@ -406,54 +664,94 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                     .expect("Create color"),
             ),
             Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point {
+                        x: linear.start.x,
+                        y: linear.start.y,
+                    },
+                    tiny_skia::Point {
+                        x: linear.end.x,
+                        y: linear.end.y,
+                    },
+                    into_gradient_stops(&linear.stops),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )
+                .expect("Create linear gradient"),
+                Gradient::Radial(radial) => {
+                    let center = tiny_skia::Point {
+                        x: radial.center.x,
+                        y: radial.center.y,
+                    };
+
+                    let stops = into_gradient_stops(&radial.stops);
+
+                    // A radial gradient without area shows its last stop
+                    let fallback = tiny_skia::Shader::SolidColor(
+                        radial
+                            .stops
+                            .into_iter()
+                            .flatten()
+                            .last()
+                            .and_then(|stop| {
                                 tiny_skia::Color::from_rgba(
                                     stop.color.b,
                                     stop.color.g,
                                     stop.color.r,
                                     stop.color.a,
                                 )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(
-                                0.0,
-                                tiny_skia::Color::BLACK,
-                            )]
-                        } else {
-                            stops
-                        },
+                            })
+                            .unwrap_or(tiny_skia::Color::BLACK),
+                    );
+
+                    tiny_skia::RadialGradient::new(
+                        center,
+                        center,
+                        radial.radius,
+                        stops,
                         tiny_skia::SpreadMode::Pad,
                         tiny_skia::Transform::identity(),
                     )
-                    .expect("Create linear gradient")
+                    .unwrap_or(fallback)
                 }
             },
+            // Patterns are drawn with their own primitive
+            Style::Pattern(..) => {
+                tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT)
+            }
         },
         anti_alias: true,
         ..Default::default()
     }
 }
 
+fn into_gradient_stops(
+    stops: &[Option<ColorStop>; 8],
+) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<tiny_skia::GradientStop> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(
+                stop.offset,
+                tiny_skia::Color::from_rgba(
+                    stop.color.b,
+                    stop.color.g,
+                    stop.color.r,
+                    stop.color.a,
+                )
+                .expect("Create color"),
+            )
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
 pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
     match rule {
         fill::Rule::EvenOdd => tiny_skia::FillRule::EvenOdd,
@@ -461,6 +759,28 @@ pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
     }
 }
 
+pub fn into_blend_mode(blend_mode: BlendMode) -> tiny_skia::BlendMode {
+    match blend_mode {
+        BlendMode::Clear => tiny_skia::BlendMode::Clear,
+        BlendMode::Source => tiny_skia::BlendMode::Source,
+        BlendMode::Destination => tiny_skia::BlendMode::Destination,
+        BlendMode::SourceOver => tiny_skia::BlendMode::SourceOver,
+        BlendMode::DestinationOver => tiny_skia::BlendMode::DestinationOver,
+        BlendMode::SourceIn => tiny_skia::BlendMode::SourceIn,
+        BlendMode::DestinationIn => tiny_skia::BlendMode::DestinationIn,
+        BlendMode::SourceOut => tiny_skia::BlendMode::SourceOut,
+        BlendMode::DestinationOut => tiny_skia::BlendMode::DestinationOut,
+        BlendMode::SourceAtop => tiny_skia::BlendMode::SourceAtop,
+        BlendMode::DestinationAtop => tiny_skia::BlendMode::DestinationAtop,
+        BlendMode::Xor => tiny_skia::BlendMode::Xor,
+        BlendMode::Plus => tiny_skia::BlendMode::Plus,
+        BlendMode::Multiply => tiny_skia::BlendMode::Multiply,
+        BlendMode::Screen => tiny_skia::BlendMode::Screen,
+        BlendMode::Darken => tiny_skia::BlendMode::Darken,
+        BlendMode::Lighten => tiny_skia::BlendMode::Lighten,
+    }
+}
+
 pub fn into_stroke(stroke: &Stroke<'_>) -> tiny_skia::Stroke {
     tiny_skia::Stroke {
         width: stroke.width,
@@ -477,9 +797,16 @@ pub fn into_stroke(stroke: &Stroke<'_>) -> tiny_skia::Stroke {
         dash: if stroke.line_dash.segments.is_empty() {
             None
         } else {
+            let segments = stroke.line_dash.segments;
+
+            // Tiny-skia only supports an even number of segments
             tiny_skia::StrokeDash::new(
-                stroke.line_dash.segments.into(),
-                stroke.line_dash.offset as f32,
+                if segments.len() % 2 == 1 {
+                    [segments, segments].concat()
+                } else {
+                    segments.to_vec()
+                },
+                stroke.line_dash.offset,
             )
         },
         ..Default::default()
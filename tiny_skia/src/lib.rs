@@ -2,6 +2,7 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod window;
 
+mod blur;
 mod engine;
 mod layer;
 mod primitive;
@@ -29,7 +30,8 @@ pub use geometry::Geometry;
 
 use crate::core::renderer;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Transformation,
+    Background, Color, Density, Font, Pixels, Point, Rectangle, Size,
+    Transformation,
 };
 use crate::engine::Engine;
 use crate::graphics::compositor;
@@ -44,8 +46,11 @@ use crate::graphics::Viewport;
 pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
+    density: Density,
     layers: layer::Stack,
     engine: Engine, // TODO: Shared engine
+    statistics: compositor::Statistics,
+    text_antialiasing: core::text::Antialiasing,
 }
 
 impl Renderer {
@@ -53,11 +58,29 @@ impl Renderer {
         Self {
             default_font,
             default_text_size,
+            density: Density::default(),
             layers: layer::Stack::new(),
             engine: Engine::new(),
+            statistics: compositor::Statistics::default(),
+            text_antialiasing: core::text::Antialiasing::default(),
         }
     }
 
+    /// Sets the [`core::text::Antialiasing`] strategy of the [`Renderer`].
+    ///
+    /// Text is still drawn in grayscale over transparent backgrounds.
+    pub fn set_text_antialiasing(
+        &mut self,
+        text_antialiasing: core::text::Antialiasing,
+    ) {
+        self.text_antialiasing = text_antialiasing;
+    }
+
+    /// Sets the [`Density`] of the built-in widgets drawn by the [`Renderer`].
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+    }
+
     pub fn layers(&mut self) -> &[Layer] {
         self.layers.flush();
         self.layers.as_slice()
@@ -106,6 +129,29 @@ impl Renderer {
 
         self.layers.flush();
 
+        // Subpixel coverage can only be blended over known colors
+        let text_antialiasing = if background_color.a < 1.0 {
+            core::text::Antialiasing::Grayscale
+        } else {
+            self.text_antialiasing
+        };
+
+        self.engine.set_text_antialiasing(text_antialiasing);
+
+        self.statistics = compositor::Statistics {
+            primitives: self
+                .layers
+                .iter()
+                .map(|layer| {
+                    layer.quads.len()
+                        + layer.primitives.len()
+                        + layer.text.len()
+                        + layer.images.len()
+                })
+                .sum(),
+            draw_calls: 0,
+        };
+
         for &region in damage {
             let region = region * scale_factor;
 
@@ -224,6 +270,39 @@ impl Renderer {
 
         self.engine.trim();
     }
+
+    #[cfg(feature = "geometry")]
+    fn draw_geometry_images(
+        &mut self,
+        images: impl IntoIterator<Item = (graphics::Image, Rectangle)>,
+    ) {
+        let mut clip = None;
+
+        for (image, clip_bounds) in images {
+            // Only images overflowing their clip need a dedicated layer
+            let required = (!image.bounds().is_within(&clip_bounds))
+                .then_some(clip_bounds);
+
+            if clip != required {
+                if clip.is_some() {
+                    self.layers.pop_clip();
+                }
+
+                if let Some(bounds) = required {
+                    self.layers.push_clip(bounds);
+                }
+
+                clip = required;
+            }
+
+            let (layer, transformation) = self.layers.current_mut();
+            layer.draw_image(image, transformation);
+        }
+
+        if clip.is_some() {
+            self.layers.pop_clip();
+        }
+    }
 }
 
 impl core::Renderer for Renderer {
@@ -255,6 +334,37 @@ impl core::Renderer for Renderer {
     fn clear(&mut self) {
         self.layers.clear();
     }
+
+    fn density(&self) -> Density {
+        self.density
+    }
+}
+
+impl renderer::Headless for Renderer {
+    fn new(default_font: Font, default_text_size: Pixels) -> Option<Self> {
+        Some(Self::new(default_font, default_text_size))
+    }
+
+    fn screenshot(
+        &mut self,
+        size: Size<u32>,
+        scale_factor: f32,
+        background_color: Color,
+    ) -> Vec<u8> {
+        let viewport =
+            Viewport::with_physical_size(size, f64::from(scale_factor));
+
+        let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)
+            .expect("Create clip mask");
+
+        window::compositor::screenshot_with_mask(
+            self,
+            &mut clip_mask,
+            &viewport,
+            background_color,
+            &[] as &[String],
+        )
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -340,11 +450,9 @@ impl graphics::geometry::Renderer for Renderer {
                     transformation,
                 );
 
-                for image in images {
-                    layer.draw_image(image, transformation);
-                }
-
                 layer.draw_text_group(text, clip_bounds, transformation);
+
+                self.draw_geometry_images(images);
             }
             Geometry::Cache(cache) => {
                 layer.draw_primitive_cache(
@@ -353,15 +461,13 @@ impl graphics::geometry::Renderer for Renderer {
                     transformation,
                 );
 
-                for image in cache.images.iter() {
-                    layer.draw_image(image.clone(), transformation);
-                }
-
                 layer.draw_text_cache(
                     cache.text,
                     cache.clip_bounds,
                     transformation,
                 );
+
+                self.draw_geometry_images(cache.images.iter().cloned());
             }
         }
     }
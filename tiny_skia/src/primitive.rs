@@ -1,4 +1,6 @@
-use crate::core::Rectangle;
+use crate::blur;
+use crate::core::image;
+use crate::core::{Rectangle, Size};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Primitive {
@@ -20,6 +22,40 @@ pub enum Primitive {
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
     },
+    /// A path filled or stroked with a repeated image.
+    Pattern {
+        /// The path to draw.
+        path: tiny_skia::Path,
+        /// The handle of the repeated image.
+        handle: image::Handle,
+        /// The size of a single tile, or `None` to use the dimensions of
+        /// the image.
+        tile: Option<Size>,
+        /// The transform of the pattern.
+        transform: tiny_skia::Transform,
+        /// The stroke settings, if the path is stroked instead of filled.
+        stroke: Option<tiny_skia::Stroke>,
+        /// The fill rule to follow.
+        rule: tiny_skia::FillRule,
+        /// The blend mode to use.
+        blend_mode: tiny_skia::BlendMode,
+    },
+    /// A group of primitives clipped to a path.
+    Clip {
+        /// The path to clip to.
+        path: tiny_skia::Path,
+        /// The primitives of the group.
+        primitives: Vec<Primitive>,
+    },
+    /// A group of primitives blurred together.
+    Blur {
+        /// The radius of the blur.
+        radius: f32,
+        /// The primitives of the group.
+        primitives: Vec<Primitive>,
+        /// The blend mode to use for the blurred group.
+        blend_mode: tiny_skia::BlendMode,
+    },
 }
 
 impl Primitive {
@@ -28,6 +64,18 @@ impl Primitive {
         let bounds = match self {
             Primitive::Fill { path, .. } => path.bounds(),
             Primitive::Stroke { path, .. } => path.bounds(),
+            Primitive::Pattern { path, .. } => path.bounds(),
+            Primitive::Clip { path, .. } => path.bounds(),
+            Primitive::Blur {
+                radius, primitives, ..
+            } => {
+                return primitives
+                    .iter()
+                    .map(Primitive::visible_bounds)
+                    .reduce(|a, b| a.union(&b))
+                    .unwrap_or(Rectangle::with_size(Size::ZERO))
+                    .expand(blur::extent(*radius));
+            }
         };
 
         Rectangle {
@@ -1,5 +1,5 @@
 use crate::core::image as raster;
-use crate::core::{Rectangle, Size};
+use crate::core::{Color, Rectangle, Size};
 use crate::graphics;
 
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -32,6 +32,7 @@ impl Pipeline {
         filter_method: raster::FilterMethod,
         bounds: Rectangle,
         opacity: f32,
+        tint: Option<Color>,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: tiny_skia::Transform,
         clip_mask: Option<&tiny_skia::Mask>,
@@ -42,6 +43,12 @@ impl Pipeline {
 
             let transform = transform.pre_scale(width_scale, height_scale);
 
+            let tinted = tint.map(|tint| tinted(image, tint));
+            let image = tinted
+                .as_ref()
+                .map(tiny_skia::Pixmap::as_ref)
+                .unwrap_or(image);
+
             let quality = match filter_method {
                 raster::FilterMethod::Linear => {
                     tiny_skia::FilterQuality::Bilinear
@@ -66,11 +73,78 @@ impl Pipeline {
         }
     }
 
+    pub fn draw_pattern(
+        &mut self,
+        handle: &raster::Handle,
+        tile: Option<Size>,
+        pattern: tiny_skia::Transform,
+        path: &tiny_skia::Path,
+        stroke: Option<&tiny_skia::Stroke>,
+        rule: tiny_skia::FillRule,
+        blend_mode: tiny_skia::BlendMode,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        transform: tiny_skia::Transform,
+        clip_mask: Option<&tiny_skia::Mask>,
+    ) {
+        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
+            let (scale_x, scale_y) = tile.map_or((1.0, 1.0), |tile| {
+                (
+                    tile.width / image.width() as f32,
+                    tile.height / image.height() as f32,
+                )
+            });
+
+            let paint = tiny_skia::Paint {
+                shader: tiny_skia::Pattern::new(
+                    image,
+                    tiny_skia::SpreadMode::Repeat,
+                    tiny_skia::FilterQuality::Bilinear,
+                    1.0,
+                    pattern.pre_scale(scale_x, scale_y),
+                ),
+                blend_mode,
+                ..Default::default()
+            };
+
+            match stroke {
+                Some(stroke) => {
+                    pixels.stroke_path(
+                        path, &paint, stroke, transform, clip_mask,
+                    );
+                }
+                None => {
+                    pixels.fill_path(path, &paint, rule, transform, clip_mask);
+                }
+            }
+        }
+    }
+
     pub fn trim_cache(&mut self) {
         self.cache.borrow_mut().trim();
     }
 }
 
+fn tinted(image: tiny_skia::PixmapRef<'_>, tint: Color) -> tiny_skia::Pixmap {
+    let mut tinted = image.to_owned();
+
+    for pixel in tinted.pixels_mut() {
+        let channel = |value: u8, factor: f32| {
+            (f32::from(value) * factor * tint.a).round() as u8
+        };
+
+        if let Some(color) = tiny_skia::PremultipliedColorU8::from_rgba(
+            channel(pixel.red(), tint.r),
+            channel(pixel.green(), tint.g),
+            channel(pixel.blue(), tint.b),
+            channel(pixel.alpha(), 1.0),
+        ) {
+            *pixel = color;
+        }
+    }
+
+    tinted
+}
+
 #[derive(Debug, Default)]
 struct Cache {
     entries: FxHashMap<raster::Id, Option<Entry>>,
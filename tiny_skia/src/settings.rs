@@ -1,4 +1,4 @@
-use crate::core::{Font, Pixels};
+use crate::core::{Density, Font, Pixels};
 use crate::graphics;
 
 /// The settings of a [`Compositor`].
@@ -13,6 +13,11 @@ pub struct Settings {
     ///
     /// By default, it will be set to `16.0`.
     pub default_text_size: Pixels,
+
+    /// The [`Density`] of the built-in widgets.
+    ///
+    /// By default, it is [`Density::Comfortable`].
+    pub density: Density,
 }
 
 impl Default for Settings {
@@ -20,6 +25,7 @@ impl Default for Settings {
         Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
+            density: Density::default(),
         }
     }
 }
@@ -29,6 +35,7 @@ impl From<graphics::Settings> for Settings {
         Self {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
+            density: settings.density,
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::text::Shaping;
+use crate::core::text::{Antialiasing, Shaping};
 use crate::core::{
     Color, Font, Pixels, Point, Rectangle, Size, Transformation,
 };
@@ -17,6 +17,7 @@ use std::collections::hash_map;
 pub struct Pipeline {
     glyph_cache: GlyphCache,
     cache: RefCell<Cache>,
+    antialiasing: Antialiasing,
 }
 
 impl Pipeline {
@@ -24,9 +25,14 @@ impl Pipeline {
         Pipeline {
             glyph_cache: GlyphCache::new(),
             cache: RefCell::new(Cache::new()),
+            antialiasing: Antialiasing::default(),
         }
     }
 
+    pub fn set_antialiasing(&mut self, antialiasing: Antialiasing) {
+        self.antialiasing = antialiasing;
+    }
+
     // TODO: Shared engine
     #[allow(dead_code)]
     pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
@@ -58,6 +64,7 @@ impl Pipeline {
         draw(
             font_system.raw(),
             &mut self.glyph_cache,
+            self.antialiasing,
             paragraph.buffer(),
             Rectangle::new(position, paragraph.min_bounds()),
             color,
@@ -89,6 +96,7 @@ impl Pipeline {
         draw(
             font_system.raw(),
             &mut self.glyph_cache,
+            self.antialiasing,
             editor.buffer(),
             Rectangle::new(position, editor.bounds()),
             color,
@@ -137,6 +145,7 @@ impl Pipeline {
         draw(
             font_system,
             &mut self.glyph_cache,
+            self.antialiasing,
             &entry.buffer,
             Rectangle {
                 width,
@@ -168,6 +177,7 @@ impl Pipeline {
         draw(
             font_system.raw(),
             &mut self.glyph_cache,
+            self.antialiasing,
             buffer,
             Rectangle::new(
                 position,
@@ -194,6 +204,7 @@ impl Pipeline {
 fn draw(
     font_system: &mut cosmic_text::FontSystem,
     glyph_cache: &mut GlyphCache,
+    antialiasing: Antialiasing,
     buffer: &cosmic_text::Buffer,
     bounds: Rectangle,
     color: Color,
@@ -224,30 +235,53 @@ fn draw(
             let physical_glyph =
                 glyph.physical((x, y), transformation.scale_factor());
 
-            if let Some((buffer, placement)) = glyph_cache.allocate(
-                physical_glyph.cache_key,
-                glyph.color_opt.map(from_color).unwrap_or(color),
-                font_system,
-                &mut swash,
-            ) {
-                let pixmap = tiny_skia::PixmapRef::from_bytes(
-                    buffer,
-                    placement.width,
-                    placement.height,
-                )
-                .expect("Create glyph pixel map");
+            let glyph_color = glyph.color_opt.map(from_color).unwrap_or(color);
 
+            if let Some((buffer, placement, is_subpixel)) = glyph_cache
+                .allocate(
+                    physical_glyph.cache_key,
+                    glyph_color,
+                    antialiasing,
+                    font_system,
+                    &mut swash,
+                )
+            {
                 let opacity = color.a
                     * glyph
                         .color_opt
                         .map(|c| c.a() as f32 / 255.0)
                         .unwrap_or(1.0);
 
+                let x = physical_glyph.x + placement.left;
+                let y = physical_glyph.y - placement.top
+                    + (run.line_y * transformation.scale_factor()).round()
+                        as i32;
+
+                if is_subpixel {
+                    draw_subpixel(
+                        pixels,
+                        x,
+                        y,
+                        buffer,
+                        placement,
+                        glyph_color,
+                        opacity,
+                        clip_mask,
+                    );
+
+                    continue;
+                }
+
+                let pixmap = tiny_skia::PixmapRef::from_bytes(
+                    buffer,
+                    placement.width,
+                    placement.height,
+                )
+                .expect("Create glyph pixel map");
+
                 pixels.draw_pixmap(
-                    physical_glyph.x + placement.left,
-                    physical_glyph.y - placement.top
-                        + (run.line_y * transformation.scale_factor()).round()
-                            as i32,
+                    x,
+                    y,
                     pixmap,
                     &tiny_skia::PixmapPaint {
                         opacity,
@@ -261,6 +295,115 @@ fn draw(
     }
 }
 
+/// Blends a subpixel mask of coverages with the given [`Color`] into the
+/// pixels, channel by channel.
+fn draw_subpixel(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    x: i32,
+    y: i32,
+    coverage: &[u8],
+    placement: cosmic_text::Placement,
+    color: Color,
+    opacity: f32,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let width = pixels.width() as i32;
+    let height = pixels.height() as i32;
+
+    // Pixels are stored in BGRA order
+    let [r, g, b, _a] = color.into_rgba8();
+    let color = [b, g, r];
+
+    let pixels = pixels.pixels_mut();
+
+    for row in 0..placement.height as i32 {
+        let target_y = y + row;
+
+        if target_y < 0 || target_y >= height {
+            continue;
+        }
+
+        for column in 0..placement.width as i32 {
+            let target_x = x + column;
+
+            if target_x < 0 || target_x >= width {
+                continue;
+            }
+
+            let target = (target_y * width + target_x) as usize;
+
+            let mask = clip_mask
+                .map_or(1.0, |mask| f32::from(mask.data()[target]) / 255.0);
+
+            let source = (row * placement.width as i32 + column) as usize * 4;
+            let [r, g, b] =
+                [coverage[source], coverage[source + 1], coverage[source + 2]];
+
+            let factor =
+                |coverage: u8| f32::from(coverage) / 255.0 * opacity * mask;
+            let coverage = [factor(b), factor(g), factor(r)];
+            let alpha = coverage[0].max(coverage[1]).max(coverage[2]);
+
+            if alpha <= 0.0 {
+                continue;
+            }
+
+            let pixel = pixels[target];
+            let destination = [pixel.red(), pixel.green(), pixel.blue()];
+
+            let channel = |i: usize| {
+                (f32::from(color[i]) * coverage[i]
+                    + f32::from(destination[i]) * (1.0 - coverage[i]))
+                    .round() as u8
+            };
+
+            let alpha = (alpha * 255.0
+                + f32::from(pixel.alpha()) * (1.0 - alpha))
+                .round() as u8;
+
+            if let Some(blended) = tiny_skia::PremultipliedColorU8::from_rgba(
+                channel(0).min(alpha),
+                channel(1).min(alpha),
+                channel(2).min(alpha),
+                alpha,
+            ) {
+                pixels[target] = blended;
+            }
+        }
+    }
+}
+
+/// Rasterizes a glyph with the coverage of every subpixel, instead of the
+/// grayscale mask of [`cosmic_text::SwashCache`].
+fn subpixel_image(
+    font_system: &mut cosmic_text::FontSystem,
+    cache_key: cosmic_text::CacheKey,
+) -> Option<swash::scale::image::Image> {
+    use swash::scale::{Render, ScaleContext, Source, StrikeWith};
+    use swash::zeno::{Format, Vector};
+
+    let font = font_system.get_font(cache_key.font_id)?;
+
+    let mut context = ScaleContext::new();
+    let mut scaler = context
+        .builder(font.as_swash())
+        .size(f32::from_bits(cache_key.font_size_bits))
+        .hint(true)
+        .build();
+
+    Render::new(&[
+        Source::ColorOutline(0),
+        Source::ColorBitmap(StrikeWith::BestFit),
+        Source::Outline,
+    ])
+    .format(Format::Subpixel)
+    .offset(Vector::new(
+        cache_key.x_bin.as_float(),
+        cache_key.y_bin.as_float(),
+    ))
+    .render(&mut scaler, cache_key.glyph_id)
+}
+
 fn from_color(color: cosmic_text::Color) -> Color {
     let [r, g, b, a] = color.as_rgba();
 
@@ -270,10 +413,10 @@ fn from_color(color: cosmic_text::Color) -> Color {
 #[derive(Debug, Clone, Default)]
 struct GlyphCache {
     entries: FxHashMap<
-        (cosmic_text::CacheKey, [u8; 3]),
-        (Vec<u32>, cosmic_text::Placement),
+        (cosmic_text::CacheKey, [u8; 3], Antialiasing),
+        (Vec<u32>, cosmic_text::Placement, bool),
     >,
-    recently_used: FxHashSet<(cosmic_text::CacheKey, [u8; 3])>,
+    recently_used: FxHashSet<(cosmic_text::CacheKey, [u8; 3], Antialiasing)>,
     trim_count: usize,
 }
 
@@ -289,15 +432,23 @@ impl GlyphCache {
         &mut self,
         cache_key: cosmic_text::CacheKey,
         color: Color,
+        antialiasing: Antialiasing,
         font_system: &mut cosmic_text::FontSystem,
         swash: &mut cosmic_text::SwashCache,
-    ) -> Option<(&[u8], cosmic_text::Placement)> {
+    ) -> Option<(&[u8], cosmic_text::Placement, bool)> {
         let [r, g, b, _a] = color.into_rgba8();
-        let key = (cache_key, [r, g, b]);
+        let key = (cache_key, [r, g, b], antialiasing);
 
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(key) {
             // TODO: Outline support
-            let image = swash.get_image_uncached(font_system, cache_key)?;
+            let image = match antialiasing {
+                Antialiasing::Grayscale => {
+                    swash.get_image_uncached(font_system, cache_key)?
+                }
+                Antialiasing::Subpixel => {
+                    subpixel_image(font_system, cache_key)?
+                }
+            };
 
             let glyph_size = image.placement.width as usize
                 * image.placement.height as usize;
@@ -351,18 +502,40 @@ impl GlyphCache {
                     }
                 }
                 cosmic_text::SwashContent::SubpixelMask => {
-                    // TODO
+                    // The coverages are kept as they are; since they are
+                    // blended channel by channel
+                    for (pixel, coverage) in
+                        buffer.iter_mut().zip(image.data.chunks_exact(4))
+                    {
+                        *pixel = u32::from_ne_bytes([
+                            coverage[0],
+                            coverage[1],
+                            coverage[2],
+                            coverage[3],
+                        ]);
+                    }
                 }
             }
 
-            let _ = entry.insert((buffer, image.placement));
+            let is_subpixel = matches!(
+                image.content,
+                cosmic_text::SwashContent::SubpixelMask
+            );
+
+            let _ = entry.insert((buffer, image.placement, is_subpixel));
         }
 
         let _ = self.recently_used.insert(key);
 
-        self.entries.get(&key).map(|(buffer, placement)| {
-            (bytemuck::cast_slice(buffer.as_slice()), *placement)
-        })
+        self.entries
+            .get(&key)
+            .map(|(buffer, placement, is_subpixel)| {
+                (
+                    bytemuck::cast_slice(buffer.as_slice()),
+                    *placement,
+                    *is_subpixel,
+                )
+            })
     }
 
     pub fn trim(&mut self) {
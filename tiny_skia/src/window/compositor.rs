@@ -1,3 +1,4 @@
+use crate::core::text;
 use crate::core::{Color, Rectangle, Size};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
@@ -49,10 +50,23 @@ impl crate::graphics::Compositor for Compositor {
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+        renderer.set_density(self.settings.density);
+
+        renderer
+    }
+
+    fn create_renderer_with(
+        &self,
+        text_antialiasing: text::Antialiasing,
+    ) -> Self::Renderer {
+        let mut renderer = self.create_renderer();
+        renderer.set_text_antialiasing(text_antialiasing);
+
+        renderer
     }
 
     fn create_surface<W: compositor::Window + Clone>(
@@ -107,6 +121,10 @@ impl crate::graphics::Compositor for Compositor {
         }
     }
 
+    fn statistics(&self, renderer: &Self::Renderer) -> compositor::Statistics {
+        renderer.statistics
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -216,6 +234,22 @@ pub fn screenshot<T: AsRef<str>>(
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
+) -> Vec<u8> {
+    screenshot_with_mask(
+        renderer,
+        &mut surface.clip_mask,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+pub(crate) fn screenshot_with_mask<T: AsRef<str>>(
+    renderer: &mut Renderer,
+    clip_mask: &mut tiny_skia::Mask,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
@@ -229,7 +263,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        clip_mask,
         viewport,
         &[Rectangle::with_size(Size::new(
             size.width as f32,
@@ -1,4 +1,5 @@
 //! Build and draw geometry.
+use crate::core::image;
 use crate::core::text::LineHeight;
 use crate::core::{
     self, Pixels, Point, Radians, Rectangle, Size, Svg, Transformation, Vector,
@@ -7,11 +8,12 @@ use crate::graphics::cache::{self, Cached};
 use crate::graphics::color;
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::{
-    self, LineCap, LineDash, LineJoin, Path, Stroke, Style,
+    self, vertex, LineCap, LineDash, LineJoin, Path, Stroke, Style,
+    TexturedVertex, Tiling, Vertex,
 };
 use crate::graphics::gradient::{self, Gradient};
 use crate::graphics::mesh::{self, Mesh};
-use crate::graphics::{Image, Text};
+use crate::graphics::{BlendMode, Image, Text};
 use crate::text;
 use crate::triangle;
 
@@ -25,7 +27,7 @@ use std::sync::Arc;
 pub enum Geometry {
     Live {
         meshes: Vec<Mesh>,
-        images: Vec<Image>,
+        images: Vec<(Image, Rectangle)>,
         text: Vec<Text>,
     },
     Cached(Cache),
@@ -34,7 +36,7 @@ pub enum Geometry {
 #[derive(Debug, Clone)]
 pub struct Cache {
     pub meshes: Option<triangle::Cache>,
-    pub images: Option<Arc<[Image]>>,
+    pub images: Option<Arc<[(Image, Rectangle)]>>,
     pub text: Option<text::Cache>,
 }
 
@@ -97,8 +99,11 @@ pub struct Frame {
     clip_bounds: Rectangle,
     buffers: BufferStack,
     meshes: Vec<Mesh>,
-    images: Vec<Image>,
+    images: Vec<(Image, Rectangle)>,
     text: Vec<Text>,
+    clip: Option<Clip>,
+    blend_mode: BlendMode,
+    blur: Option<f32>,
     transforms: Transforms,
     fill_tessellator: tessellation::FillTessellator,
     stroke_tessellator: tessellation::StrokeTessellator,
@@ -118,6 +123,9 @@ impl Frame {
             meshes: Vec::new(),
             images: Vec::new(),
             text: Vec::new(),
+            clip: None,
+            blend_mode: BlendMode::default(),
+            blur: None,
             transforms: Transforms {
                 previous: Vec::new(),
                 current: Transform(lyon::math::Transform::translation(
@@ -156,9 +164,10 @@ impl geometry::frame::Backend for Frame {
     fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
         let Fill { style, rule } = fill.into();
 
-        let mut buffer = self
-            .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+        let mut buffer = self.buffers.get_fill(
+            &self.transforms.current.transform_style(style),
+            &self.transforms.current,
+        );
 
         let options = tessellation::FillOptions::default()
             .with_fill_rule(into_fill_rule(rule));
@@ -189,9 +198,10 @@ impl geometry::frame::Backend for Frame {
     ) {
         let Fill { style, rule } = fill.into();
 
-        let mut buffer = self
-            .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+        let mut buffer = self.buffers.get_fill(
+            &self.transforms.current.transform_style(style),
+            &self.transforms.current,
+        );
 
         let top_left = self
             .transforms
@@ -219,9 +229,10 @@ impl geometry::frame::Backend for Frame {
     fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
         let stroke = stroke.into();
 
-        let mut buffer = self
-            .buffers
-            .get_stroke(&self.transforms.current.transform_style(stroke.style));
+        let mut buffer = self.buffers.get_stroke(
+            &self.transforms.current.transform_style(stroke.style),
+            &self.transforms.current,
+        );
 
         let mut options = tessellation::StrokeOptions::default();
         options.line_width = stroke.width;
@@ -352,21 +363,115 @@ impl geometry::frame::Backend for Frame {
         self.transforms.current = self.transforms.previous.pop().unwrap();
     }
 
+    fn transform(&self) -> Transformation {
+        let t = &self.transforms.current.0;
+
+        Transformation::affine(t.m11, t.m12, t.m21, t.m22, t.m31, t.m32)
+    }
+
     fn draft(&mut self, clip_bounds: Rectangle) -> Frame {
-        Frame::with_clip(clip_bounds)
+        let mut frame = Frame::with_clip(clip_bounds);
+
+        frame.blend_mode = self.blend_mode;
+
+        frame
+    }
+
+    fn draft_clip_path(&mut self, path: &Path) -> Frame {
+        let path = path.transform(&self.transforms.current.0);
+
+        let mut buffers = tessellation::VertexBuffers::new();
+
+        self.fill_tessellator
+            .tessellate_path(
+                path.raw(),
+                &tessellation::FillOptions::default(),
+                &mut tessellation::BuffersBuilder::new(
+                    &mut buffers,
+                    PointBuilder,
+                ),
+            )
+            .expect("Tessellate path.");
+
+        let mut frame = Frame::with_clip(self.clip_bounds);
+
+        frame.transforms.current = self.transforms.current;
+        frame.clip = Some(Clip::new(buffers));
+        frame.blend_mode = self.blend_mode;
+
+        frame
+    }
+
+    fn draft_blend(&mut self, blend_mode: BlendMode) -> Frame {
+        let mut frame = Frame::with_clip(self.clip_bounds);
+
+        frame.transforms.current = self.transforms.current;
+        frame.blend_mode = blend_mode;
+
+        frame
+    }
+
+    fn draft_blur(&mut self, radius: f32) -> Frame {
+        let mut frame = Frame::with_clip(self.clip_bounds);
+
+        // The radius follows the scale of the current transform
+        let scale = self.transforms.current.0.determinant().abs().sqrt();
+
+        frame.transforms.current = self.transforms.current;
+        frame.blend_mode = self.blend_mode;
+        frame.blur = Some(radius * scale);
+
+        frame
     }
 
-    fn paste(&mut self, frame: Frame) {
-        self.meshes
-            .extend(frame.buffers.into_meshes(frame.clip_bounds));
+    fn paste(&mut self, mut frame: Frame) {
+        frame.meshes.extend(
+            frame
+                .buffers
+                .into_meshes(frame.clip_bounds, frame.blend_mode),
+        );
+
+        if let Some(radius) = frame.blur {
+            frame.meshes = frame
+                .meshes
+                .into_iter()
+                .map(|mesh| blur(mesh, radius))
+                .collect();
+        }
+
+        let mut bounds = self.clip_bounds;
+
+        if let Some(clip) = &frame.clip {
+            frame.meshes = frame
+                .meshes
+                .into_iter()
+                .filter_map(|mesh| clip.mesh(mesh))
+                .collect();
 
-        self.images.extend(frame.images);
+            bounds = bounds.intersection(&clip.bounds).unwrap_or_default();
+
+            for text in &mut frame.text {
+                if let Text::Cached { clip_bounds, .. } = text {
+                    *clip_bounds =
+                        clip_bounds.intersection(&bounds).unwrap_or_default();
+                }
+            }
+        }
+
+        self.meshes.extend(frame.meshes);
+
+        self.images.extend(frame.images.into_iter().filter_map(
+            |(image, clip_bounds)| {
+                Some((image, clip_bounds.intersection(&bounds)?))
+            },
+        ));
         self.text.extend(frame.text);
     }
 
     fn into_geometry(mut self) -> Self::Geometry {
-        self.meshes
-            .extend(self.buffers.into_meshes(self.clip_bounds));
+        self.meshes.extend(
+            self.buffers.into_meshes(self.clip_bounds, self.blend_mode),
+        );
 
         Geometry::Live {
             meshes: self.meshes,
@@ -375,6 +480,78 @@ impl geometry::frame::Backend for Frame {
         }
     }
 
+    fn draw_mesh(&mut self, vertices: &[Vertex], indices: &[u32]) {
+        let transform = self.transforms.current;
+
+        let Buffer::Solid(buffer) =
+            self.buffers.get_mut(&Style::Solid(core::Color::BLACK))
+        else {
+            unreachable!()
+        };
+
+        let base = buffer.vertices.len() as u32;
+        let count = vertices.len() as u32;
+
+        buffer.vertices.extend(vertices.iter().map(|vertex| {
+            let position = transform.transform_point(vertex.position);
+
+            mesh::SolidVertex2D {
+                position: [position.x, position.y],
+                color: color::pack(vertex.color),
+            }
+        }));
+
+        buffer.indices.extend(
+            indices
+                .chunks_exact(3)
+                .filter(|triangle| triangle.iter().all(|&i| i < count))
+                .flatten()
+                .map(|i| base + i),
+        );
+    }
+
+    fn draw_textured_mesh(
+        &mut self,
+        vertices: &[TexturedVertex],
+        indices: &[u32],
+        handle: image::Handle,
+    ) {
+        let transform = self.transforms.current;
+
+        let Buffer::Pattern(buffer, _) = self
+            .buffers
+            .get_mut(&Style::Pattern(handle, Tiling::default()))
+        else {
+            unreachable!()
+        };
+
+        // Every triangle maps its positions to the image on its own; so
+        // vertices cannot be shared
+        for triangle in vertex::triangles(vertices, indices) {
+            let positions = triangle
+                .map(|vertex| transform.transform_point(vertex.position));
+
+            let Some(uv) =
+                uv_transform(positions, triangle.map(|vertex| vertex.uv))
+            else {
+                continue;
+            };
+
+            let base = buffer.vertices.len() as u32;
+
+            buffer.vertices.extend(positions.map(|position| {
+                mesh::PatternVertex2D {
+                    position: [position.x, position.y],
+                    transform: uv,
+                    // A unit tile maps the image to the whole UV range
+                    tile: [1.0, 1.0],
+                }
+            }));
+
+            buffer.indices.extend([base, base + 1, base + 2]);
+        }
+    }
+
     fn draw_image(&mut self, bounds: Rectangle, image: impl Into<core::Image>) {
         let mut image = image.into();
 
@@ -383,7 +560,8 @@ impl geometry::frame::Backend for Frame {
 
         image.rotation += external_rotation;
 
-        self.images.push(Image::Raster(image, bounds));
+        self.images
+            .push((Image::Raster(image, bounds), self.clip_bounds));
     }
 
     fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
@@ -394,13 +572,18 @@ impl geometry::frame::Backend for Frame {
 
         svg.rotation += external_rotation;
 
-        self.images.push(Image::Vector(svg, bounds));
+        self.images
+            .push((Image::Vector(svg, bounds), self.clip_bounds));
     }
 }
 
 enum Buffer {
     Solid(tessellation::VertexBuffers<mesh::SolidVertex2D, u32>),
     Gradient(tessellation::VertexBuffers<mesh::GradientVertex2D, u32>),
+    Pattern(
+        tessellation::VertexBuffers<mesh::PatternVertex2D, u32>,
+        image::Handle,
+    ),
 }
 
 struct BufferStack {
@@ -430,6 +613,15 @@ impl BufferStack {
                     ));
                 }
             },
+            Style::Pattern(handle, _) => match self.stack.last() {
+                Some(Buffer::Pattern(_, last)) if last.id() == handle.id() => {}
+                _ => {
+                    self.stack.push(Buffer::Pattern(
+                        tessellation::VertexBuffers::new(),
+                        handle.clone(),
+                    ));
+                }
+            },
         }
 
         self.stack.last_mut().unwrap()
@@ -438,6 +630,7 @@ impl BufferStack {
     fn get_fill<'a>(
         &'a mut self,
         style: &Style,
+        transform: &Transform,
     ) -> Box<dyn tessellation::FillGeometryBuilder + 'a> {
         match (style, self.get_mut(style)) {
             (Style::Solid(color), Buffer::Solid(buffer)) => {
@@ -454,6 +647,12 @@ impl BufferStack {
                     },
                 ))
             }
+            (Style::Pattern(_, tiling), Buffer::Pattern(buffer, _)) => {
+                Box::new(tessellation::BuffersBuilder::new(
+                    buffer,
+                    transform.pattern(tiling),
+                ))
+            }
             _ => unreachable!(),
         }
     }
@@ -461,6 +660,7 @@ impl BufferStack {
     fn get_stroke<'a>(
         &'a mut self,
         style: &Style,
+        transform: &Transform,
     ) -> Box<dyn tessellation::StrokeGeometryBuilder + 'a> {
         match (style, self.get_mut(style)) {
             (Style::Solid(color), Buffer::Solid(buffer)) => {
@@ -477,11 +677,21 @@ impl BufferStack {
                     },
                 ))
             }
+            (Style::Pattern(_, tiling), Buffer::Pattern(buffer, _)) => {
+                Box::new(tessellation::BuffersBuilder::new(
+                    buffer,
+                    transform.pattern(tiling),
+                ))
+            }
             _ => unreachable!(),
         }
     }
 
-    fn into_meshes(self, clip_bounds: Rectangle) -> impl Iterator<Item = Mesh> {
+    fn into_meshes(
+        self,
+        clip_bounds: Rectangle,
+        blend_mode: BlendMode,
+    ) -> impl Iterator<Item = Mesh> {
         self.stack
             .into_iter()
             .filter_map(move |buffer| match buffer {
@@ -493,6 +703,7 @@ impl BufferStack {
                         },
                         clip_bounds,
                         transformation: Transformation::IDENTITY,
+                        blend_mode,
                     })
                 }
                 Buffer::Gradient(buffer) if !buffer.indices.is_empty() => {
@@ -503,6 +714,21 @@ impl BufferStack {
                         },
                         clip_bounds,
                         transformation: Transformation::IDENTITY,
+                        blend_mode,
+                    })
+                }
+                Buffer::Pattern(buffer, handle)
+                    if !buffer.indices.is_empty() =>
+                {
+                    Some(Mesh::Pattern {
+                        buffers: mesh::Indexed {
+                            vertices: buffer.vertices,
+                            indices: buffer.indices,
+                        },
+                        handle,
+                        clip_bounds,
+                        transformation: Transformation::IDENTITY,
+                        blend_mode,
                     })
                 }
                 _ => None,
@@ -550,6 +776,34 @@ impl Transform {
             Style::Gradient(gradient) => {
                 Style::Gradient(self.transform_gradient(gradient))
             }
+            Style::Pattern(handle, tiling) => Style::Pattern(handle, tiling),
+        }
+    }
+
+    fn pattern(&self, tiling: &Tiling) -> PatternVertex2DBuilder {
+        // Vertices are already transformed; so we map them back to the
+        // space of the pattern
+        let inverse = self
+            .0
+            .inverse()
+            .unwrap_or(lyon::math::Transform::identity())
+            .then_translate(lyon::math::Vector::new(
+                -tiling.origin.x,
+                -tiling.origin.y,
+            ));
+
+        PatternVertex2DBuilder {
+            transform: [
+                inverse.m11,
+                inverse.m21,
+                inverse.m31,
+                inverse.m12,
+                inverse.m22,
+                inverse.m32,
+            ],
+            tile: tiling
+                .size
+                .map_or([0.0, 0.0], |size| [size.width, size.height]),
         }
     }
 
@@ -559,6 +813,14 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                let edge = self.transform_point(
+                    radial.center + Vector::new(radial.radius, 0.0),
+                );
+
+                radial.center = self.transform_point(radial.center);
+                radial.radius = radial.center.distance(edge);
+            }
         }
 
         gradient
@@ -579,10 +841,310 @@ impl Transform {
         Rectangle::with_vertices(top_left, top_right, bottom_left)
     }
 }
+
+/// Approximates a gaussian blur of the given [`Mesh`] with the given radius,
+/// by drawing it several times around its position with translucent colors.
+///
+/// The triangle pipeline has no intermediate targets to blur a mesh
+/// properly; so only solid meshes are blurred, and gradients and patterns
+/// are drawn normally.
+fn blur(mesh: Mesh, radius: f32) -> Mesh {
+    /// The amount of copies drawn in each ring around the mesh.
+    const TAPS: usize = 8;
+
+    /// The amount of rings around the mesh.
+    const RINGS: usize = 2;
+
+    let Mesh::Solid {
+        buffers,
+        transformation,
+        clip_bounds,
+        blend_mode,
+    } = mesh
+    else {
+        return mesh;
+    };
+
+    if radius <= 0.0 {
+        return Mesh::Solid {
+            buffers,
+            transformation,
+            clip_bounds,
+            blend_mode,
+        };
+    }
+
+    // The standard deviation of the blur
+    let sigma = radius / 2.0;
+
+    let offsets: Vec<Vector> = std::iter::once(Vector::ZERO)
+        .chain((1..=RINGS).flat_map(|ring| {
+            let distance = sigma * ring as f32;
+
+            (0..TAPS).map(move |tap| {
+                let angle = std::f32::consts::TAU
+                    * (tap as f32 + 0.5 * ring as f32)
+                    / TAPS as f32;
+
+                Vector::new(angle.cos() * distance, angle.sin() * distance)
+            })
+        }))
+        .collect();
+
+    let copies = offsets.len() as f32;
+    let count = buffers.vertices.len() as u32;
+
+    let vertices = offsets
+        .iter()
+        .flat_map(|offset| {
+            buffers.vertices.iter().map(move |vertex| {
+                let alpha = vertex.color.components()[3];
+
+                mesh::SolidVertex2D {
+                    position: [
+                        vertex.position[0] + offset.x,
+                        vertex.position[1] + offset.y,
+                    ],
+                    // Every copy is drawn over the others; so the blur
+                    // reaches the alpha of the vertex where all of them
+                    // overlap, short of opaque to stay translucent
+                    color: vertex.color.with_alpha(
+                        1.0 - (1.0 - alpha.min(0.95)).powf(1.0 / copies),
+                    ),
+                }
+            })
+        })
+        .collect();
+
+    let indices = (0..offsets.len() as u32)
+        .flat_map(|copy| buffers.indices.iter().map(move |i| copy * count + i))
+        .collect();
+
+    Mesh::Solid {
+        buffers: mesh::Indexed { vertices, indices },
+        transformation,
+        clip_bounds,
+        blend_mode,
+    }
+}
+
+/// Returns the rows of the affine transformation that maps the positions of
+/// a triangle to its UV coordinates, if the triangle is not degenerate.
+fn uv_transform(positions: [Point; 3], uvs: [Point; 3]) -> Option<[f32; 6]> {
+    let basis = |[a, b, c]: [Point; 3]| {
+        lyon::math::Transform::new(
+            b.x - a.x,
+            b.y - a.y,
+            c.x - a.x,
+            c.y - a.y,
+            a.x,
+            a.y,
+        )
+    };
+
+    let transform = basis(positions).inverse()?.then(&basis(uvs));
+
+    Some([
+        transform.m11,
+        transform.m21,
+        transform.m31,
+        transform.m12,
+        transform.m22,
+        transform.m32,
+    ])
+}
+
 struct GradientVertex2DBuilder {
     gradient: gradient::Packed,
 }
 
+struct PointBuilder;
+
+impl tessellation::FillVertexConstructor<Point> for PointBuilder {
+    fn new_vertex(&mut self, vertex: tessellation::FillVertex<'_>) -> Point {
+        let position = vertex.position();
+
+        Point::new(position.x, position.y)
+    }
+}
+
+/// A clip path, tessellated into triangles.
+#[derive(Debug)]
+struct Clip {
+    triangles: Vec<[Point; 3]>,
+    bounds: Rectangle,
+}
+
+impl Clip {
+    fn new(buffers: tessellation::VertexBuffers<Point, u32>) -> Self {
+        let triangles: Vec<_> = buffers
+            .indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                [0, 1, 2].map(|i| buffers.vertices[triangle[i] as usize])
+            })
+            .collect();
+
+        let bounds = triangles
+            .iter()
+            .flatten()
+            .fold(None, |bounds: Option<Rectangle>, &point| {
+                let point = Rectangle::new(point, Size::ZERO);
+
+                Some(bounds.map_or(point, |bounds| bounds.union(&point)))
+            })
+            .unwrap_or_default();
+
+        Self { triangles, bounds }
+    }
+
+    fn mesh(&self, mesh: Mesh) -> Option<Mesh> {
+        let mesh = match mesh {
+            Mesh::Solid {
+                buffers,
+                clip_bounds,
+                transformation,
+                blend_mode,
+            } => Mesh::Solid {
+                buffers: self.buffers(buffers, |vertex| &mut vertex.position),
+                clip_bounds,
+                transformation,
+                blend_mode,
+            },
+            Mesh::Gradient {
+                buffers,
+                clip_bounds,
+                transformation,
+                blend_mode,
+            } => Mesh::Gradient {
+                buffers: self.buffers(buffers, |vertex| &mut vertex.position),
+                clip_bounds,
+                transformation,
+                blend_mode,
+            },
+            Mesh::Pattern {
+                buffers,
+                handle,
+                clip_bounds,
+                transformation,
+                blend_mode,
+            } => Mesh::Pattern {
+                buffers: self.buffers(buffers, |vertex| &mut vertex.position),
+                handle,
+                clip_bounds,
+                transformation,
+                blend_mode,
+            },
+        };
+
+        (!mesh.indices().is_empty()).then_some(mesh)
+    }
+
+    fn buffers<T: Copy>(
+        &self,
+        buffers: mesh::Indexed<T>,
+        position: impl Fn(&mut T) -> &mut [f32; 2],
+    ) -> mesh::Indexed<T> {
+        let mut clipped = mesh::Indexed {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        for triangle in buffers.indices.chunks_exact(3) {
+            // Vertices only differ in position within the same triangle
+            let vertex = buffers.vertices[triangle[0] as usize];
+
+            let subject: Vec<Point> = triangle
+                .iter()
+                .map(|&index| {
+                    let mut vertex = buffers.vertices[index as usize];
+                    let [x, y] = *position(&mut vertex);
+
+                    Point::new(x, y)
+                })
+                .collect();
+
+            for clip in &self.triangles {
+                let polygon = clip_polygon(subject.clone(), clip);
+
+                if polygon.len() < 3 {
+                    continue;
+                }
+
+                let base = clipped.vertices.len() as u32;
+
+                clipped.vertices.extend(polygon.iter().map(|point| {
+                    let mut vertex = vertex;
+                    *position(&mut vertex) = [point.x, point.y];
+
+                    vertex
+                }));
+
+                clipped.indices.extend(
+                    (1..polygon.len() as u32 - 1)
+                        .flat_map(|i| [base, base + i, base + i + 1]),
+                );
+            }
+        }
+
+        clipped
+    }
+}
+
+/// Clips the given convex polygon to a triangle, using the
+/// Sutherland–Hodgman algorithm.
+fn clip_polygon(mut polygon: Vec<Point>, triangle: &[Point; 3]) -> Vec<Point> {
+    fn cross(a: Vector, b: Vector) -> f32 {
+        a.x * b.y - a.y * b.x
+    }
+
+    let area = cross(triangle[1] - triangle[0], triangle[2] - triangle[0]);
+
+    if area.abs() <= f32::EPSILON {
+        return Vec::new();
+    }
+
+    let orientation = area.signum();
+
+    for i in 0..3 {
+        if polygon.is_empty() {
+            break;
+        }
+
+        let start = triangle[i];
+        let edge = triangle[(i + 1) % 3] - start;
+
+        let is_inside =
+            |point: Point| orientation * cross(edge, point - start) >= 0.0;
+
+        let intersection = |from: Point, to: Point| {
+            let t = cross(edge, start - from) / cross(edge, to - from);
+
+            from + (to - from) * t
+        };
+
+        let input = std::mem::take(&mut polygon);
+
+        for (j, &current) in input.iter().enumerate() {
+            let previous = input[(j + input.len() - 1) % input.len()];
+
+            match (is_inside(previous), is_inside(current)) {
+                (true, true) => polygon.push(current),
+                (true, false) => {
+                    polygon.push(intersection(previous, current));
+                }
+                (false, true) => {
+                    polygon.push(intersection(previous, current));
+                    polygon.push(current);
+                }
+                (false, false) => {}
+            }
+        }
+    }
+
+    polygon
+}
+
 impl tessellation::FillVertexConstructor<mesh::GradientVertex2D>
     for GradientVertex2DBuilder
 {
@@ -615,6 +1177,45 @@ impl tessellation::StrokeVertexConstructor<mesh::GradientVertex2D>
     }
 }
 
+struct PatternVertex2DBuilder {
+    transform: [f32; 6],
+    tile: [f32; 2],
+}
+
+impl tessellation::FillVertexConstructor<mesh::PatternVertex2D>
+    for PatternVertex2DBuilder
+{
+    fn new_vertex(
+        &mut self,
+        vertex: tessellation::FillVertex<'_>,
+    ) -> mesh::PatternVertex2D {
+        let position = vertex.position();
+
+        mesh::PatternVertex2D {
+            position: [position.x, position.y],
+            transform: self.transform,
+            tile: self.tile,
+        }
+    }
+}
+
+impl tessellation::StrokeVertexConstructor<mesh::PatternVertex2D>
+    for PatternVertex2DBuilder
+{
+    fn new_vertex(
+        &mut self,
+        vertex: tessellation::StrokeVertex<'_, '_>,
+    ) -> mesh::PatternVertex2D {
+        let position = vertex.position();
+
+        mesh::PatternVertex2D {
+            position: [position.x, position.y],
+            transform: self.transform,
+            tile: self.tile,
+        }
+    }
+}
+
 struct TriangleVertex2DBuilder(color::Packed);
 
 impl tessellation::FillVertexConstructor<mesh::SolidVertex2D>
@@ -673,44 +1274,77 @@ fn into_fill_rule(rule: fill::Rule) -> lyon::tessellation::FillRule {
 }
 
 pub(super) fn dashed(path: &Path, line_dash: LineDash<'_>) -> Path {
-    use lyon::algorithms::walk::{
-        walk_along_path, RepeatedPattern, WalkerEvent,
-    };
+    use lyon::algorithms::walk::{walk_along_path, Pattern, WalkerEvent};
     use lyon::path::iterator::PathIterator;
 
-    Path::new(|builder| {
-        let segments_odd = (line_dash.segments.len() % 2 == 1)
-            .then(|| [line_dash.segments, line_dash.segments].concat());
+    struct Dashes<'a, 'b> {
+        builder: &'b mut geometry::path::Builder,
+        intervals: &'a [f32],
+        index: usize,
+        remainder: Option<f32>,
+    }
+
+    impl Pattern for Dashes<'_, '_> {
+        fn next(&mut self, event: WalkerEvent<'_>) -> Option<f32> {
+            let point = Point {
+                x: event.position.x,
+                y: event.position.y,
+            };
+
+            // The walk starts in the middle of the interval given by the
+            // offset of the pattern
+            let is_start = self.remainder.is_some();
+
+            let distance = self.remainder.take().unwrap_or_else(|| {
+                self.index = (self.index + 1) % self.intervals.len();
+                self.intervals[self.index]
+            });
+
+            if self.index % 2 == 0 {
+                self.builder.move_to(point);
+            } else if !is_start {
+                self.builder.line_to(point);
+            }
+
+            Some(distance)
+        }
+    }
 
-        let mut draw_line = false;
+    let intervals = if line_dash.segments.len() % 2 == 1 {
+        Cow::Owned([line_dash.segments, line_dash.segments].concat())
+    } else {
+        Cow::Borrowed(line_dash.segments)
+    };
+
+    let length: f32 = intervals.iter().sum();
+
+    if !length.is_finite()
+        || length <= 0.0
+        || intervals.iter().any(|interval| *interval < 0.0)
+    {
+        return path.clone();
+    }
+
+    let mut phase = line_dash.offset.rem_euclid(length);
+    let mut index = 0;
+
+    while index + 1 < intervals.len() && phase >= intervals[index] {
+        phase -= intervals[index];
+        index += 1;
+    }
 
+    Path::new(|builder| {
         walk_along_path(
             path.raw().iter().flattened(
                 lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
             ),
             0.0,
             lyon::tessellation::StrokeOptions::DEFAULT_TOLERANCE,
-            &mut RepeatedPattern {
-                callback: |event: WalkerEvent<'_>| {
-                    let point = Point {
-                        x: event.position.x,
-                        y: event.position.y,
-                    };
-
-                    if draw_line {
-                        builder.line_to(point);
-                    } else {
-                        builder.move_to(point);
-                    }
-
-                    draw_line = !draw_line;
-
-                    true
-                },
-                index: line_dash.offset,
-                intervals: segments_odd
-                    .as_deref()
-                    .unwrap_or(line_dash.segments),
+            &mut Dashes {
+                builder,
+                intervals: &intervals,
+                index,
+                remainder: Some((intervals[index] - phase).max(0.0)),
             },
         );
     })
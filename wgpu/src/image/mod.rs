@@ -9,7 +9,8 @@ mod raster;
 #[cfg(feature = "svg")]
 mod vector;
 
-use crate::core::{Rectangle, Size, Transformation};
+use crate::core::{Color, Rectangle, Size, Transformation};
+use crate::graphics::color;
 use crate::Buffer;
 
 use bytemuck::{Pod, Zeroable};
@@ -151,6 +152,8 @@ impl Pipeline {
                             7 => Sint32,
                             // Snap
                             8 => Uint32,
+                            // Tint
+                            9 => Float32x4,
                         ),
                     }],
                 },
@@ -230,6 +233,7 @@ impl Pipeline {
                             f32::from(image.rotation),
                             image.opacity,
                             image.snap,
+                            image.tint.unwrap_or(Color::WHITE),
                             atlas_entry,
                             match image.filter_method {
                                 crate::core::image::FilterMethod::Nearest => {
@@ -263,6 +267,7 @@ impl Pipeline {
                             f32::from(svg.rotation),
                             svg.opacity,
                             true,
+                            Color::WHITE,
                             atlas_entry,
                             nearest_instances,
                         );
@@ -492,6 +497,7 @@ struct Instance {
     _size_in_atlas: [f32; 2],
     _layer: u32,
     _snap: u32,
+    _tint: [f32; 4],
 }
 
 impl Instance {
@@ -514,6 +520,7 @@ fn add_instances(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    tint: Color,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
@@ -531,6 +538,7 @@ fn add_instances(
                 rotation,
                 opacity,
                 snap,
+                tint,
                 allocation,
                 instances,
             );
@@ -560,7 +568,7 @@ fn add_instances(
                 ];
 
                 add_instance(
-                    position, center, size, rotation, opacity, snap,
+                    position, center, size, rotation, opacity, snap, tint,
                     allocation, instances,
                 );
             }
@@ -576,6 +584,7 @@ fn add_instance(
     rotation: f32,
     opacity: f32,
     snap: bool,
+    tint: Color,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -599,6 +608,7 @@ fn add_instance(
         ],
         _layer: layer as u32,
         _snap: snap as u32,
+        _tint: color::pack(tint).components(),
     };
 
     instances.push(instance);
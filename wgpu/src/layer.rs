@@ -158,6 +158,10 @@ impl Layer {
             | Mesh::Gradient {
                 transformation: local_transformation,
                 ..
+            }
+            | Mesh::Pattern {
+                transformation: local_transformation,
+                ..
             } => {
                 *local_transformation = *local_transformation * transformation;
             }
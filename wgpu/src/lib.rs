@@ -61,8 +61,8 @@ pub use settings::Settings;
 pub use geometry::Geometry;
 
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation,
-    Vector,
+    Background, Color, Density, Font, Pixels, Point, Rectangle, Size,
+    Transformation, Vector,
 };
 use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::Viewport;
@@ -75,6 +75,7 @@ use crate::graphics::Viewport;
 pub struct Renderer {
     default_font: Font,
     default_text_size: Pixels,
+    density: Density,
     layers: layer::Stack,
 
     triangle_storage: triangle::Storage,
@@ -84,6 +85,8 @@ pub struct Renderer {
     // TODO: Centralize all the image feature handling
     #[cfg(any(feature = "svg", feature = "image"))]
     image_cache: std::cell::RefCell<image::Cache>,
+
+    statistics: graphics::compositor::Statistics,
 }
 
 impl Renderer {
@@ -96,6 +99,7 @@ impl Renderer {
         Self {
             default_font,
             default_text_size,
+            density: Density::default(),
             layers: layer::Stack::new(),
 
             triangle_storage: triangle::Storage::new(),
@@ -106,9 +110,16 @@ impl Renderer {
             image_cache: std::cell::RefCell::new(
                 engine.create_image_cache(device),
             ),
+
+            statistics: graphics::compositor::Statistics::default(),
         }
     }
 
+    /// Sets the [`Density`] of the built-in widgets drawn by the [`Renderer`].
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+    }
+
     pub fn present<T: AsRef<str>>(
         &mut self,
         engine: &mut Engine,
@@ -267,6 +278,8 @@ impl Renderer {
 
         let scale = Transformation::scale(scale_factor);
 
+        let mut statistics = graphics::compositor::Statistics::default();
+
         for layer in self.layers.iter() {
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale))
@@ -274,6 +287,12 @@ impl Renderer {
                 continue;
             };
 
+            statistics.primitives += layer.quads.len()
+                + layer.triangles.len()
+                + layer.primitives.len()
+                + layer.images.len()
+                + layer.text.len();
+
             let Some(scissor_rect) = physical_bounds.snap() else {
                 continue;
             };
@@ -287,12 +306,13 @@ impl Renderer {
                 );
 
                 quad_layer += 1;
+                statistics.draw_calls += layer.quads.draws();
             }
 
             if !layer.triangles.is_empty() {
                 let _ = ManuallyDrop::into_inner(render_pass);
 
-                mesh_layer += engine.triangle_pipeline.render(
+                let meshes = engine.triangle_pipeline.render(
                     encoder,
                     frame,
                     &self.triangle_storage,
@@ -302,6 +322,9 @@ impl Renderer {
                     scale,
                 );
 
+                mesh_layer += meshes;
+                statistics.draw_calls += meshes;
+
                 render_pass = ManuallyDrop::new(encoder.begin_render_pass(
                     &wgpu::RenderPassDescriptor {
                         label: Some("iced_wgpu render pass"),
@@ -336,6 +359,8 @@ impl Renderer {
                             frame,
                             &clip_bounds,
                         );
+
+                        statistics.draw_calls += 1;
                     }
                 }
 
@@ -369,10 +394,11 @@ impl Renderer {
                 );
 
                 image_layer += 1;
+                statistics.draw_calls += 1;
             }
 
             if !layer.text.is_empty() {
-                text_layer += engine.text_pipeline.render(
+                let text = engine.text_pipeline.render(
                     &self.text_viewport,
                     &self.text_storage,
                     text_layer,
@@ -380,10 +406,15 @@ impl Renderer {
                     scissor_rect,
                     &mut render_pass,
                 );
+
+                text_layer += text;
+                statistics.draw_calls += text;
             }
         }
 
         let _ = ManuallyDrop::into_inner(render_pass);
+
+        self.statistics = statistics;
     }
 
     fn draw_overlay(
@@ -408,6 +439,7 @@ impl Renderer {
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: core::text::Shaping::Basic,
+                        justification: core::text::Justification::None,
                     };
 
                     renderer.fill_text(
@@ -428,6 +460,39 @@ impl Renderer {
             },
         );
     }
+
+    #[cfg(feature = "geometry")]
+    fn draw_geometry_images(
+        &mut self,
+        images: impl IntoIterator<Item = (graphics::Image, Rectangle)>,
+    ) {
+        let mut clip = None;
+
+        for (image, clip_bounds) in images {
+            // Only images overflowing their clip need a dedicated layer
+            let required = (!image.bounds().is_within(&clip_bounds))
+                .then_some(clip_bounds);
+
+            if clip != required {
+                if clip.is_some() {
+                    self.layers.pop_clip();
+                }
+
+                if let Some(bounds) = required {
+                    self.layers.push_clip(bounds);
+                }
+
+                clip = required;
+            }
+
+            let (layer, transformation) = self.layers.current_mut();
+            layer.draw_image(image, transformation);
+        }
+
+        if clip.is_some() {
+            self.layers.pop_clip();
+        }
+    }
 }
 
 impl core::Renderer for Renderer {
@@ -459,6 +524,27 @@ impl core::Renderer for Renderer {
     fn clear(&mut self) {
         self.layers.clear();
     }
+
+    fn density(&self) -> Density {
+        self.density
+    }
+}
+
+impl core::renderer::Headless for Renderer {
+    fn new(_default_font: Font, _default_text_size: Pixels) -> Option<Self> {
+        // The renderer draws with the device of a compositor, which needs
+        // a window to be created
+        None
+    }
+
+    fn screenshot(
+        &mut self,
+        _size: Size<u32>,
+        _scale_factor: f32,
+        _background_color: Color,
+    ) -> Vec<u8> {
+        Vec::new()
+    }
 }
 
 impl core::text::Renderer for Renderer {
@@ -571,27 +657,22 @@ impl graphics::geometry::Renderer for Renderer {
                 text,
             } => {
                 layer.draw_mesh_group(meshes, transformation);
-
-                for image in images {
-                    layer.draw_image(image, transformation);
-                }
-
                 layer.draw_text_group(text, transformation);
+
+                self.draw_geometry_images(images);
             }
             Geometry::Cached(cache) => {
                 if let Some(meshes) = cache.meshes {
                     layer.draw_mesh_cache(meshes, transformation);
                 }
 
-                if let Some(images) = cache.images {
-                    for image in images.iter().cloned() {
-                        layer.draw_image(image, transformation);
-                    }
-                }
-
                 if let Some(text) = cache.text {
                     layer.draw_text_cache(text, transformation);
                 }
+
+                if let Some(images) = cache.images {
+                    self.draw_geometry_images(images.iter().cloned());
+                }
             }
         }
     }
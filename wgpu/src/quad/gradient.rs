@@ -142,6 +142,8 @@ impl Pipeline {
                                 4 => Uint32x4,
                                 // Direction
                                 5 => Float32x4,
+                                // Kind (linear backgrounds only)
+                                10 => Uint32,
                                 // Position & Scale
                                 6 => Float32x4,
                                 // Border color
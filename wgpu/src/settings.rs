@@ -1,5 +1,5 @@
 //! Configure a renderer.
-use crate::core::{Font, Pixels};
+use crate::core::{Density, Font, Pixels};
 use crate::graphics::{self, Antialiasing};
 
 /// The settings of a [`Renderer`].
@@ -27,6 +27,11 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The [`Density`] of the built-in widgets.
+    ///
+    /// By default, it is [`Density::Comfortable`].
+    pub density: Density,
 }
 
 impl Default for Settings {
@@ -37,6 +42,7 @@ impl Default for Settings {
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: None,
+            density: Density::default(),
         }
     }
 }
@@ -47,6 +53,7 @@ impl From<graphics::Settings> for Settings {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             antialiasing: settings.antialiasing,
+            density: settings.density,
             ..Settings::default()
         }
     }
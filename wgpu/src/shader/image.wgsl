@@ -18,6 +18,7 @@ struct VertexInput {
     @location(6) atlas_scale: vec2<f32>,
     @location(7) layer: i32,
     @location(8) snap: u32,
+    @location(9) tint: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -25,6 +26,7 @@ struct VertexOutput {
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
     @location(2) opacity: f32,
+    @location(3) tint: vec4<f32>,
 }
 
 @vertex
@@ -38,6 +40,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.uv = vec2<f32>(v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
     out.opacity = input.opacity;
+    out.tint = input.tint;
 
     // Calculate the vertex position and move the center to the origin
     v_pos = input.pos + v_pos * input.scale - input.center;
@@ -67,5 +70,5 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Sample the texture at the given UV coordinate and layer.
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
+    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * input.tint * vec4<f32>(1.0, 1.0, 1.0, input.opacity);
 }
@@ -3,3 +3,8 @@ struct Globals {
 }
 
 @group(0) @binding(0) var<uniform> globals: Globals;
+
+// Fragments are blended with premultiplied alpha
+fn premultiply(color: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(color.rgb * color.a, color.a);
+}
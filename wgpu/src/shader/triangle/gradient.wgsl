@@ -6,6 +6,7 @@ struct GradientVertexInput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
+    @location(7) @interpolate(flat) kind: u32,
 }
 
 struct GradientVertexOutput {
@@ -17,6 +18,7 @@ struct GradientVertexOutput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
+    @location(7) @interpolate(flat) kind: u32,
 }
 
 @vertex
@@ -31,6 +33,7 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     output.colors_4 = input.colors_4;
     output.offsets = input.offsets;
     output.direction = input.direction;
+    output.kind = input.kind;
 
     return output;
 }
@@ -39,17 +42,25 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    kind: u32,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    var coord_offset: f32;
+
+    if (kind == 1u) {
+        // Radial: the center is stored in `xy` and the radius in `z`
+        coord_offset = length(raw_position - direction.xy) / direction.z;
+    } else {
+        let start = direction.xy;
+        let end = direction.zw;
+
+        let v1 = end - start;
+        let v2 = raw_position - start;
+        let unit = normalize(v1);
+        coord_offset = dot(unit, v2) / length(v1);
+    }
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -119,7 +130,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    return gradient(input.raw_position, input.direction, colors, offsets, last_index);
+    return premultiply(gradient(input.raw_position, input.direction, input.kind, colors, offsets, last_index));
 }
 
 fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
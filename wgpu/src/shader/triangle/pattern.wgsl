@@ -0,0 +1,44 @@
+@group(1) @binding(0) var u_sampler: sampler;
+@group(1) @binding(1) var u_texture: texture_2d<f32>;
+
+struct PatternVertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) transform_x: vec3<f32>,
+    @location(2) transform_y: vec3<f32>,
+    @location(3) tile: vec2<f32>,
+}
+
+struct PatternVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) local: vec2<f32>,
+    @location(1) tile: vec2<f32>,
+}
+
+@vertex
+fn pattern_vs_main(input: PatternVertexInput) -> PatternVertexOutput {
+    var out: PatternVertexOutput;
+
+    let position = vec3<f32>(input.position, 1.0);
+
+    out.local = vec2<f32>(
+        dot(input.transform_x, position),
+        dot(input.transform_y, position),
+    );
+    out.tile = input.tile;
+    out.position = globals.transform * vec4<f32>(input.position, 0.0, 1.0);
+
+    return out;
+}
+
+@fragment
+fn pattern_fs_main(input: PatternVertexOutput) -> @location(0) vec4<f32> {
+    var tile = input.tile;
+
+    // An empty tile uses the dimensions of the image
+    if tile.x <= 0.0 || tile.y <= 0.0 {
+        tile = vec2<f32>(textureDimensions(u_texture));
+    }
+
+    // The sampler repeats the texture outside of the first tile
+    return premultiply(textureSample(u_texture, u_sampler, input.local / tile));
+}
@@ -3,7 +3,7 @@ mod msaa;
 
 use crate::core::{Rectangle, Size, Transformation};
 use crate::graphics::mesh::{self, Mesh};
-use crate::graphics::Antialiasing;
+use crate::graphics::{Antialiasing, BlendMode};
 use crate::Buffer;
 
 use rustc_hash::FxHashMap;
@@ -92,6 +92,7 @@ impl Storage {
         belt: &mut wgpu::util::StagingBelt,
         solid: &solid::Pipeline,
         gradient: &gradient::Pipeline,
+        pattern: &mut pattern::Pipeline,
         cache: &Cache,
         new_transformation: Transformation,
     ) {
@@ -109,6 +110,7 @@ impl Storage {
                         belt,
                         solid,
                         gradient,
+                        pattern,
                         &cache.batch,
                         new_transformation,
                     );
@@ -119,7 +121,7 @@ impl Storage {
                 }
             }
             hash_map::Entry::Vacant(entry) => {
-                let mut layer = Layer::new(device, solid, gradient);
+                let mut layer = Layer::new(device, solid, gradient, pattern);
 
                 layer.prepare(
                     device,
@@ -127,6 +129,7 @@ impl Storage {
                     belt,
                     solid,
                     gradient,
+                    pattern,
                     &cache.batch,
                     new_transformation,
                 );
@@ -158,6 +161,7 @@ pub struct Pipeline {
     blit: Option<msaa::Blit>,
     solid: solid::Pipeline,
     gradient: gradient::Pipeline,
+    pattern: pattern::Pipeline,
     layers: Vec<Layer>,
     prepare_layer: usize,
 }
@@ -172,6 +176,7 @@ impl Pipeline {
             blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
             solid: solid::Pipeline::new(device, format, antialiasing),
             gradient: gradient::Pipeline::new(device, format, antialiasing),
+            pattern: pattern::Pipeline::new(device, format, antialiasing),
             layers: Vec::new(),
             prepare_layer: 0,
         }
@@ -195,6 +200,25 @@ impl Pipeline {
         };
 
         for item in items {
+            let meshes = match item {
+                Item::Group { meshes, .. } => meshes.as_slice(),
+                Item::Cached { cache, .. } => &cache.batch,
+            };
+
+            for mesh in meshes {
+                match mesh {
+                    Mesh::Solid { blend_mode, .. } => {
+                        self.solid.prepare(device, *blend_mode);
+                    }
+                    Mesh::Gradient { blend_mode, .. } => {
+                        self.gradient.prepare(device, *blend_mode);
+                    }
+                    Mesh::Pattern { blend_mode, .. } => {
+                        self.pattern.prepare(device, *blend_mode);
+                    }
+                }
+            }
+
             match item {
                 Item::Group {
                     transformation,
@@ -205,6 +229,7 @@ impl Pipeline {
                             device,
                             &self.solid,
                             &self.gradient,
+                            &self.pattern,
                         ));
                     }
 
@@ -215,6 +240,7 @@ impl Pipeline {
                         belt,
                         &self.solid,
                         &self.gradient,
+                        &mut self.pattern,
                         meshes,
                         projection * *transformation,
                     );
@@ -231,6 +257,7 @@ impl Pipeline {
                         belt,
                         &self.solid,
                         &self.gradient,
+                        &mut self.pattern,
                         cache,
                         projection * *transformation,
                     );
@@ -285,6 +312,7 @@ impl Pipeline {
             self.blit.as_mut(),
             &self.solid,
             &self.gradient,
+            &self.pattern,
             bounds,
             items,
         );
@@ -293,6 +321,7 @@ impl Pipeline {
     }
 
     pub fn end_frame(&mut self) {
+        self.pattern.trim();
         self.prepare_layer = 0;
     }
 }
@@ -303,6 +332,7 @@ fn render<'a>(
     mut blit: Option<&mut msaa::Blit>,
     solid: &solid::Pipeline,
     gradient: &gradient::Pipeline,
+    pattern: &pattern::Pipeline,
     bounds: Rectangle,
     group: impl Iterator<Item = (&'a Layer, &'a [Mesh], Transformation)>,
 ) {
@@ -339,6 +369,7 @@ fn render<'a>(
             layer.render(
                 solid,
                 gradient,
+                pattern,
                 meshes,
                 bounds,
                 transformation,
@@ -358,6 +389,7 @@ pub struct Layer {
     index_strides: Vec<u32>,
     solid: solid::Layer,
     gradient: gradient::Layer,
+    pattern: pattern::Layer,
 }
 
 impl Layer {
@@ -365,6 +397,7 @@ impl Layer {
         device: &wgpu::Device,
         solid: &solid::Pipeline,
         gradient: &gradient::Pipeline,
+        pattern: &pattern::Pipeline,
     ) -> Self {
         Self {
             index_buffer: Buffer::new(
@@ -376,6 +409,7 @@ impl Layer {
             index_strides: Vec::new(),
             solid: solid::Layer::new(device, &solid.constants_layout),
             gradient: gradient::Layer::new(device, &gradient.constants_layout),
+            pattern: pattern::Layer::new(device, &pattern.constants_layout),
         }
     }
 
@@ -386,6 +420,7 @@ impl Layer {
         belt: &mut wgpu::util::StagingBelt,
         solid: &solid::Pipeline,
         gradient: &gradient::Pipeline,
+        pattern: &mut pattern::Pipeline,
         meshes: &[Mesh],
         transformation: Transformation,
     ) {
@@ -402,6 +437,7 @@ impl Layer {
             .gradient
             .vertices
             .resize(device, count.gradient_vertices);
+        let _ = self.pattern.vertices.resize(device, count.pattern_vertices);
 
         if self.solid.uniforms.resize(device, count.solids) {
             self.solid.constants = solid::Layer::bind_group(
@@ -419,17 +455,30 @@ impl Layer {
             );
         }
 
+        if self.pattern.uniforms.resize(device, count.patterns) {
+            self.pattern.constants = pattern::Layer::bind_group(
+                device,
+                &self.pattern.uniforms.raw,
+                &pattern.constants_layout,
+            );
+        }
+
         self.index_strides.clear();
         self.index_buffer.clear();
         self.solid.vertices.clear();
         self.solid.uniforms.clear();
         self.gradient.vertices.clear();
         self.gradient.uniforms.clear();
+        self.pattern.vertices.clear();
+        self.pattern.uniforms.clear();
+        self.pattern.textures.clear();
 
         let mut solid_vertex_offset = 0;
         let mut solid_uniform_offset = 0;
         let mut gradient_vertex_offset = 0;
         let mut gradient_uniform_offset = 0;
+        let mut pattern_vertex_offset = 0;
+        let mut pattern_uniform_offset = 0;
         let mut index_offset = 0;
 
         for mesh in meshes {
@@ -483,6 +532,29 @@ impl Layer {
                         &[uniforms],
                     );
                 }
+                Mesh::Pattern {
+                    buffers, handle, ..
+                } => {
+                    pattern_vertex_offset += self.pattern.vertices.write(
+                        device,
+                        encoder,
+                        belt,
+                        pattern_vertex_offset,
+                        &buffers.vertices,
+                    );
+
+                    pattern_uniform_offset += self.pattern.uniforms.write(
+                        device,
+                        encoder,
+                        belt,
+                        pattern_uniform_offset,
+                        &[uniforms],
+                    );
+
+                    self.pattern
+                        .textures
+                        .push(pattern.texture(device, encoder, handle));
+                }
             }
         }
     }
@@ -491,6 +563,7 @@ impl Layer {
         &'a self,
         solid: &'a solid::Pipeline,
         gradient: &'a gradient::Pipeline,
+        pattern: &'a pattern::Pipeline,
         meshes: &[Mesh],
         bounds: Rectangle,
         transformation: Transformation,
@@ -498,7 +571,8 @@ impl Layer {
     ) {
         let mut num_solids = 0;
         let mut num_gradients = 0;
-        let mut last_is_solid = None;
+        let mut num_patterns = 0;
+        let mut last_pipeline = None;
 
         for (index, mesh) in meshes.iter().enumerate() {
             let Some(clip_bounds) = bounds
@@ -516,11 +590,11 @@ impl Layer {
             );
 
             match mesh {
-                Mesh::Solid { .. } => {
-                    if !last_is_solid.unwrap_or(false) {
-                        render_pass.set_pipeline(&solid.pipeline);
+                Mesh::Solid { blend_mode, .. } => {
+                    if last_pipeline != Some((Kind::Solid, *blend_mode)) {
+                        render_pass.set_pipeline(solid.get(*blend_mode));
 
-                        last_is_solid = Some(true);
+                        last_pipeline = Some((Kind::Solid, *blend_mode));
                     }
 
                     render_pass.set_bind_group(
@@ -537,11 +611,11 @@ impl Layer {
 
                     num_solids += 1;
                 }
-                Mesh::Gradient { .. } => {
-                    if last_is_solid.unwrap_or(true) {
-                        render_pass.set_pipeline(&gradient.pipeline);
+                Mesh::Gradient { blend_mode, .. } => {
+                    if last_pipeline != Some((Kind::Gradient, *blend_mode)) {
+                        render_pass.set_pipeline(gradient.get(*blend_mode));
 
-                        last_is_solid = Some(false);
+                        last_pipeline = Some((Kind::Gradient, *blend_mode));
                     }
 
                     render_pass.set_bind_group(
@@ -558,6 +632,36 @@ impl Layer {
 
                     num_gradients += 1;
                 }
+                Mesh::Pattern { blend_mode, .. } => {
+                    let pattern_index = num_patterns;
+                    num_patterns += 1;
+
+                    // The image of the pattern could not be loaded
+                    let Some(texture) = &self.pattern.textures[pattern_index]
+                    else {
+                        continue;
+                    };
+
+                    if last_pipeline != Some((Kind::Pattern, *blend_mode)) {
+                        render_pass.set_pipeline(pattern.get(*blend_mode));
+
+                        last_pipeline = Some((Kind::Pattern, *blend_mode));
+                    }
+
+                    render_pass.set_bind_group(
+                        0,
+                        &self.pattern.constants,
+                        &[(pattern_index * std::mem::size_of::<Uniforms>())
+                            as u32],
+                    );
+
+                    render_pass.set_bind_group(1, texture, &[]);
+
+                    render_pass.set_vertex_buffer(
+                        0,
+                        self.pattern.vertices.slice_from_index(pattern_index),
+                    );
+                }
             };
 
             render_pass.set_index_buffer(
@@ -570,16 +674,143 @@ impl Layer {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Solid,
+    Gradient,
+    Pattern,
+}
+
+/// The render pipelines of a kind of [`Mesh`]; one for each [`BlendMode`]
+/// in use, created on demand.
+#[derive(Debug)]
+struct Pipelines {
+    layout: wgpu::PipelineLayout,
+    shader: wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    antialiasing: Option<Antialiasing>,
+    raw: FxHashMap<BlendMode, wgpu::RenderPipeline>,
+}
+
+type Create = fn(
+    &wgpu::Device,
+    &wgpu::PipelineLayout,
+    &wgpu::ShaderModule,
+    wgpu::ColorTargetState,
+    wgpu::MultisampleState,
+) -> wgpu::RenderPipeline;
+
+impl Pipelines {
+    fn new(
+        device: &wgpu::Device,
+        layout: wgpu::PipelineLayout,
+        shader: wgpu::ShaderModule,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<Antialiasing>,
+        create: Create,
+    ) -> Self {
+        let mut pipelines = Self {
+            layout,
+            shader,
+            format,
+            antialiasing,
+            raw: FxHashMap::default(),
+        };
+
+        pipelines.prepare(device, BlendMode::default(), create);
+        pipelines
+    }
+
+    fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        blend_mode: BlendMode,
+        create: Create,
+    ) {
+        if let hash_map::Entry::Vacant(entry) = self.raw.entry(blend_mode) {
+            let _ = entry.insert(create(
+                device,
+                &self.layout,
+                &self.shader,
+                fragment_target(self.format, blend_mode),
+                multisample_state(self.antialiasing),
+            ));
+        }
+    }
+
+    fn get(&self, blend_mode: BlendMode) -> &wgpu::RenderPipeline {
+        self.raw
+            .get(&blend_mode)
+            .expect("Prepare blend mode pipeline")
+    }
+}
+
 fn fragment_target(
     texture_format: wgpu::TextureFormat,
+    blend_mode: BlendMode,
 ) -> wgpu::ColorTargetState {
     wgpu::ColorTargetState {
         format: texture_format,
-        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+        blend: Some(blend_state(blend_mode)),
         write_mask: wgpu::ColorWrites::ALL,
     }
 }
 
+/// Returns the [`wgpu::BlendState`] of the given [`BlendMode`], for fragments
+/// with premultiplied alpha.
+///
+/// The separable modes are exact only over an opaque background.
+fn blend_state(blend_mode: BlendMode) -> wgpu::BlendState {
+    use wgpu::BlendFactor::{
+        Dst, DstAlpha, One, OneMinusDst, OneMinusDstAlpha, OneMinusSrcAlpha,
+        SrcAlpha, Zero,
+    };
+
+    let component = |src_factor, dst_factor| wgpu::BlendComponent {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    let porter_duff = |src_factor, dst_factor| wgpu::BlendState {
+        color: component(src_factor, dst_factor),
+        alpha: component(src_factor, dst_factor),
+    };
+
+    let separable = |color| wgpu::BlendState {
+        color,
+        alpha: component(One, OneMinusSrcAlpha),
+    };
+
+    match blend_mode {
+        BlendMode::Clear => porter_duff(Zero, Zero),
+        BlendMode::Source => porter_duff(One, Zero),
+        BlendMode::Destination => porter_duff(Zero, One),
+        BlendMode::SourceOver => porter_duff(One, OneMinusSrcAlpha),
+        BlendMode::DestinationOver => porter_duff(OneMinusDstAlpha, One),
+        BlendMode::SourceIn => porter_duff(DstAlpha, Zero),
+        BlendMode::DestinationIn => porter_duff(Zero, SrcAlpha),
+        BlendMode::SourceOut => porter_duff(OneMinusDstAlpha, Zero),
+        BlendMode::DestinationOut => porter_duff(Zero, OneMinusSrcAlpha),
+        BlendMode::SourceAtop => porter_duff(DstAlpha, OneMinusSrcAlpha),
+        BlendMode::DestinationAtop => porter_duff(OneMinusDstAlpha, SrcAlpha),
+        BlendMode::Xor => porter_duff(OneMinusDstAlpha, OneMinusSrcAlpha),
+        BlendMode::Plus => porter_duff(One, One),
+        BlendMode::Multiply => separable(component(Dst, OneMinusSrcAlpha)),
+        BlendMode::Screen => separable(component(OneMinusDst, One)),
+        BlendMode::Darken => separable(wgpu::BlendComponent {
+            src_factor: One,
+            dst_factor: One,
+            operation: wgpu::BlendOperation::Min,
+        }),
+        BlendMode::Lighten => separable(wgpu::BlendComponent {
+            src_factor: One,
+            dst_factor: One,
+            operation: wgpu::BlendOperation::Max,
+        }),
+    }
+}
+
 fn primitive_state() -> wgpu::PrimitiveState {
     wgpu::PrimitiveState {
         topology: wgpu::PrimitiveTopology::TriangleList,
@@ -637,13 +868,13 @@ impl Uniforms {
 
 mod solid {
     use crate::graphics::mesh;
-    use crate::graphics::Antialiasing;
+    use crate::graphics::{Antialiasing, BlendMode};
     use crate::triangle;
     use crate::Buffer;
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        pipelines: triangle::Pipelines,
         pub constants_layout: wgpu::BindGroupLayout,
     }
 
@@ -738,59 +969,82 @@ mod solid {
                     ),
                 });
 
-            let pipeline =
-                device.create_render_pipeline(
-                    &wgpu::RenderPipelineDescriptor {
-                        label: Some("iced_wgpu::triangle::solid pipeline"),
-                        layout: Some(&layout),
-                        vertex: wgpu::VertexState {
-                            module: &shader,
-                            entry_point: "solid_vs_main",
-                            buffers: &[wgpu::VertexBufferLayout {
-                                array_stride: std::mem::size_of::<
-                                    mesh::SolidVertex2D,
-                                >(
-                                )
-                                    as u64,
-                                step_mode: wgpu::VertexStepMode::Vertex,
-                                attributes: &wgpu::vertex_attr_array!(
-                                    // Position
-                                    0 => Float32x2,
-                                    // Color
-                                    1 => Float32x4,
-                                ),
-                            }],
-                        },
-                        fragment: Some(wgpu::FragmentState {
-                            module: &shader,
-                            entry_point: "solid_fs_main",
-                            targets: &[Some(triangle::fragment_target(format))],
-                        }),
-                        primitive: triangle::primitive_state(),
-                        depth_stencil: None,
-                        multisample: triangle::multisample_state(antialiasing),
-                        multiview: None,
-                    },
-                );
+            let pipelines = triangle::Pipelines::new(
+                device,
+                layout,
+                shader,
+                format,
+                antialiasing,
+                create,
+            );
 
             Self {
-                pipeline,
+                pipelines,
                 constants_layout,
             }
         }
+
+        pub fn prepare(
+            &mut self,
+            device: &wgpu::Device,
+            blend_mode: BlendMode,
+        ) {
+            self.pipelines.prepare(device, blend_mode, create);
+        }
+
+        pub fn get(&self, blend_mode: BlendMode) -> &wgpu::RenderPipeline {
+            self.pipelines.get(blend_mode)
+        }
+    }
+
+    fn create(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+        target: wgpu::ColorTargetState,
+        multisample: wgpu::MultisampleState,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu::triangle::solid pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "solid_vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<mesh::SolidVertex2D>()
+                        as u64,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Position
+                        0 => Float32x2,
+                        // Color
+                        1 => Float32x4,
+                    ),
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "solid_fs_main",
+                targets: &[Some(target)],
+            }),
+            primitive: triangle::primitive_state(),
+            depth_stencil: None,
+            multisample,
+            multiview: None,
+        })
     }
 }
 
 mod gradient {
     use crate::graphics::color;
     use crate::graphics::mesh;
-    use crate::graphics::Antialiasing;
+    use crate::graphics::{Antialiasing, BlendMode};
     use crate::triangle;
     use crate::Buffer;
 
     #[derive(Debug)]
     pub struct Pipeline {
-        pub pipeline: wgpu::RenderPipeline,
+        pipelines: triangle::Pipelines,
         pub constants_layout: wgpu::BindGroupLayout,
     }
 
@@ -907,53 +1161,447 @@ mod gradient {
                     ),
                 });
 
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
-                    label: Some("iced_wgpu.triangle.gradient.pipeline"),
-                    layout: Some(&layout),
-                    vertex: wgpu::VertexState {
-                        module: &shader,
-                        entry_point: "gradient_vs_main",
-                        buffers: &[wgpu::VertexBufferLayout {
-                            array_stride: std::mem::size_of::<
-                                mesh::GradientVertex2D,
-                            >()
-                                as u64,
-                            step_mode: wgpu::VertexStepMode::Vertex,
-                            attributes: &wgpu::vertex_attr_array!(
-                                // Position
-                                0 => Float32x2,
-                                // Colors 1-2
-                                1 => Uint32x4,
-                                // Colors 3-4
-                                2 => Uint32x4,
-                                // Colors 5-6
-                                3 => Uint32x4,
-                                // Colors 7-8
-                                4 => Uint32x4,
-                                // Offsets
-                                5 => Uint32x4,
-                                // Direction
-                                6 => Float32x4
+            let pipelines = triangle::Pipelines::new(
+                device,
+                layout,
+                shader,
+                format,
+                antialiasing,
+                create,
+            );
+
+            Self {
+                pipelines,
+                constants_layout,
+            }
+        }
+
+        pub fn prepare(
+            &mut self,
+            device: &wgpu::Device,
+            blend_mode: BlendMode,
+        ) {
+            self.pipelines.prepare(device, blend_mode, create);
+        }
+
+        pub fn get(&self, blend_mode: BlendMode) -> &wgpu::RenderPipeline {
+            self.pipelines.get(blend_mode)
+        }
+    }
+
+    fn create(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+        target: wgpu::ColorTargetState,
+        multisample: wgpu::MultisampleState,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu.triangle.gradient.pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "gradient_vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<mesh::GradientVertex2D>()
+                        as u64,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Position
+                        0 => Float32x2,
+                        // Colors 1-2
+                        1 => Uint32x4,
+                        // Colors 3-4
+                        2 => Uint32x4,
+                        // Colors 5-6
+                        3 => Uint32x4,
+                        // Colors 7-8
+                        4 => Uint32x4,
+                        // Offsets
+                        5 => Uint32x4,
+                        // Direction
+                        6 => Float32x4,
+                        // Kind
+                        7 => Uint32
+                    ),
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "gradient_fs_main",
+                targets: &[Some(target)],
+            }),
+            primitive: triangle::primitive_state(),
+            depth_stencil: None,
+            multisample,
+            multiview: None,
+        })
+    }
+}
+
+mod pattern {
+    use crate::core::image;
+    use crate::graphics::color;
+    use crate::graphics::mesh;
+    use crate::graphics::{Antialiasing, BlendMode};
+    use crate::triangle;
+    use crate::Buffer;
+
+    use rustc_hash::FxHashMap;
+    use std::rc::{self, Rc};
+
+    #[derive(Debug)]
+    pub struct Pipeline {
+        pipelines: triangle::Pipelines,
+        pub constants_layout: wgpu::BindGroupLayout,
+        texture_layout: wgpu::BindGroupLayout,
+        sampler: wgpu::Sampler,
+        textures: FxHashMap<image::Id, rc::Weak<wgpu::BindGroup>>,
+    }
+
+    #[derive(Debug)]
+    pub struct Layer {
+        pub vertices: Buffer<mesh::PatternVertex2D>,
+        pub uniforms: Buffer<triangle::Uniforms>,
+        pub constants: wgpu::BindGroup,
+        pub textures: Vec<Option<Rc<wgpu::BindGroup>>>,
+    }
+
+    impl Layer {
+        pub fn new(
+            device: &wgpu::Device,
+            constants_layout: &wgpu::BindGroupLayout,
+        ) -> Self {
+            let vertices = Buffer::new(
+                device,
+                "iced_wgpu.triangle.pattern.vertex_buffer",
+                triangle::INITIAL_VERTEX_COUNT,
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            );
+
+            let uniforms = Buffer::new(
+                device,
+                "iced_wgpu.triangle.pattern.uniforms",
+                1,
+                wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            );
+
+            let constants =
+                Self::bind_group(device, &uniforms.raw, constants_layout);
+
+            Self {
+                vertices,
+                uniforms,
+                constants,
+                textures: Vec::new(),
+            }
+        }
+
+        pub fn bind_group(
+            device: &wgpu::Device,
+            uniform_buffer: &wgpu::Buffer,
+            layout: &wgpu::BindGroupLayout,
+        ) -> wgpu::BindGroup {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu.triangle.pattern.bind_group"),
+                layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(
+                        wgpu::BufferBinding {
+                            buffer: uniform_buffer,
+                            offset: 0,
+                            size: triangle::Uniforms::min_size(),
+                        },
+                    ),
+                }],
+            })
+        }
+    }
+
+    impl Pipeline {
+        pub fn new(
+            device: &wgpu::Device,
+            format: wgpu::TextureFormat,
+            antialiasing: Option<Antialiasing>,
+        ) -> Self {
+            let constants_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("iced_wgpu.triangle.pattern.bind_group_layout"),
+                    entries: &[triangle::Uniforms::entry()],
+                },
+            );
+
+            let texture_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("iced_wgpu.triangle.pattern.texture_layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Sampler(
+                                wgpu::SamplerBindingType::Filtering,
                             ),
-                        }],
-                    },
-                    fragment: Some(wgpu::FragmentState {
-                        module: &shader,
-                        entry_point: "gradient_fs_main",
-                        targets: &[Some(triangle::fragment_target(format))],
-                    }),
-                    primitive: triangle::primitive_state(),
-                    depth_stencil: None,
-                    multisample: triangle::multisample_state(antialiasing),
-                    multiview: None,
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                sample_type: wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                                view_dimension: wgpu::TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                    ],
                 },
             );
 
+            // Tiles are repeated by the sampler
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                label: Some("iced_wgpu.triangle.pattern.sampler"),
+                address_mode_u: wgpu::AddressMode::Repeat,
+                address_mode_v: wgpu::AddressMode::Repeat,
+                mag_filter: wgpu::FilterMode::Linear,
+                min_filter: wgpu::FilterMode::Linear,
+                ..Default::default()
+            });
+
+            let layout = device.create_pipeline_layout(
+                &wgpu::PipelineLayoutDescriptor {
+                    label: Some("iced_wgpu.triangle.pattern.pipeline_layout"),
+                    bind_group_layouts: &[&constants_layout, &texture_layout],
+                    push_constant_ranges: &[],
+                },
+            );
+
+            let shader =
+                device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some("iced_wgpu.triangle.pattern.shader"),
+                    source: wgpu::ShaderSource::Wgsl(
+                        std::borrow::Cow::Borrowed(concat!(
+                            include_str!("shader/triangle.wgsl"),
+                            "\n",
+                            include_str!("shader/triangle/pattern.wgsl"),
+                        )),
+                    ),
+                });
+
+            let pipelines = triangle::Pipelines::new(
+                device,
+                layout,
+                shader,
+                format,
+                antialiasing,
+                create,
+            );
+
             Self {
-                pipeline,
+                pipelines,
                 constants_layout,
+                texture_layout,
+                sampler,
+                textures: FxHashMap::default(),
+            }
+        }
+
+        pub fn prepare(
+            &mut self,
+            device: &wgpu::Device,
+            blend_mode: BlendMode,
+        ) {
+            self.pipelines.prepare(device, blend_mode, create);
+        }
+
+        pub fn get(&self, blend_mode: BlendMode) -> &wgpu::RenderPipeline {
+            self.pipelines.get(blend_mode)
+        }
+
+        /// Returns the texture bind group of the image with the given handle,
+        /// uploading it if necessary.
+        ///
+        /// The texture is kept alive as long as any layer references it.
+        pub fn texture(
+            &mut self,
+            device: &wgpu::Device,
+            encoder: &mut wgpu::CommandEncoder,
+            handle: &image::Handle,
+        ) -> Option<Rc<wgpu::BindGroup>> {
+            if let Some(bind_group) =
+                self.textures.get(&handle.id()).and_then(rc::Weak::upgrade)
+            {
+                return Some(bind_group);
             }
+
+            let (width, height, pixels) = load(handle)?;
+
+            let extent = wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            };
+
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("iced_wgpu.triangle.pattern.texture"),
+                size: extent,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: if color::GAMMA_CORRECTION {
+                    wgpu::TextureFormat::Rgba8UnormSrgb
+                } else {
+                    wgpu::TextureFormat::Rgba8Unorm
+                },
+                usage: wgpu::TextureUsages::COPY_DST
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            });
+
+            upload(device, encoder, &texture, width, height, &pixels);
+
+            let view =
+                texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            let bind_group =
+                Rc::new(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some(
+                        "iced_wgpu.triangle.pattern.texture_bind_group",
+                    ),
+                    layout: &self.texture_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::Sampler(
+                                &self.sampler,
+                            ),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(&view),
+                        },
+                    ],
+                }));
+
+            let _ = self
+                .textures
+                .insert(handle.id(), Rc::downgrade(&bind_group));
+
+            Some(bind_group)
         }
+
+        pub fn trim(&mut self) {
+            self.textures
+                .retain(|_id, bind_group| bind_group.strong_count() > 0);
+        }
+    }
+
+    fn upload(
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) {
+        use wgpu::util::DeviceExt;
+
+        // Rows must be aligned to `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padding = (align - (4 * width) % align) % align;
+        let padded_width = (4 * width + padding) as usize;
+
+        let mut padded_data = vec![0; padded_width * height as usize];
+
+        for (row, pixels) in pixels.chunks_exact(4 * width as usize).enumerate()
+        {
+            let offset = row * padded_width;
+
+            padded_data[offset..offset + pixels.len()].copy_from_slice(pixels);
+        }
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu.triangle.pattern.upload_buffer"),
+                contents: &padded_data,
+                usage: wgpu::BufferUsages::COPY_SRC,
+            });
+
+        encoder.copy_buffer_to_texture(
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_width as u32),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::default(),
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
+    #[cfg(feature = "image")]
+    fn load(handle: &image::Handle) -> Option<(u32, u32, image::Bytes)> {
+        let image = crate::graphics::image::load(handle).ok()?;
+
+        Some((image.width(), image.height(), image.into_raw()))
+    }
+
+    #[cfg(not(feature = "image"))]
+    fn load(_handle: &image::Handle) -> Option<(u32, u32, image::Bytes)> {
+        None
+    }
+
+    fn create(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        shader: &wgpu::ShaderModule,
+        target: wgpu::ColorTargetState,
+        multisample: wgpu::MultisampleState,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu.triangle.pattern.pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "pattern_vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: std::mem::size_of::<mesh::PatternVertex2D>()
+                        as u64,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Position
+                        0 => Float32x2,
+                        // Transform row X
+                        1 => Float32x3,
+                        // Transform row Y
+                        2 => Float32x3,
+                        // Tile
+                        3 => Float32x2,
+                    ),
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "pattern_fs_main",
+                targets: &[Some(target)],
+            }),
+            primitive: triangle::primitive_state(),
+            depth_stencil: None,
+            multisample,
+            multiview: None,
+        })
     }
 }
@@ -1,4 +1,5 @@
 //! Connect a window with a renderer.
+use crate::core::window;
 use crate::core::{Color, Size};
 use crate::graphics::color;
 use crate::graphics::compositor;
@@ -7,6 +8,9 @@ use crate::graphics::{self, Viewport};
 use crate::settings::{self, Settings};
 use crate::{Engine, Renderer};
 
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::Arc;
+
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
@@ -18,6 +22,7 @@ pub struct Compositor {
     alpha_mode: wgpu::CompositeAlphaMode,
     engine: Engine,
     settings: Settings,
+    is_lost: Arc<AtomicBool>,
 }
 
 /// A compositor error.
@@ -76,12 +81,7 @@ impl Compositor {
             .and_then(|window| instance.create_surface(window).ok());
 
         let adapter_options = wgpu::RequestAdapterOptions {
-            power_preference: wgpu::util::power_preference_from_env()
-                .unwrap_or(if settings.antialiasing.is_none() {
-                    wgpu::PowerPreference::LowPower
-                } else {
-                    wgpu::PowerPreference::HighPerformance
-                }),
+            power_preference: power_preference(&settings),
             compatible_surface: compatible_surface.as_ref(),
             force_fallback_adapter: false,
         };
@@ -138,64 +138,162 @@ impl Compositor {
             "Selected format: {format:?} with alpha mode: {alpha_mode:?}"
         );
 
-        #[cfg(target_arch = "wasm32")]
-        let limits = [wgpu::Limits::downlevel_webgl2_defaults()
-            .using_resolution(adapter.limits())];
+        let (device, queue) = request_device(&adapter).await?;
+        let is_lost = watch(&device);
 
-        #[cfg(not(target_arch = "wasm32"))]
-        let limits =
-            [wgpu::Limits::default(), wgpu::Limits::downlevel_defaults()];
+        let engine = Engine::new(
+            &adapter,
+            &device,
+            &queue,
+            format,
+            settings.antialiasing,
+        );
 
-        let limits = limits.into_iter().map(|limits| wgpu::Limits {
-            max_bind_groups: 2,
-            ..limits
-        });
+        Ok(Compositor {
+            instance,
+            adapter,
+            device,
+            queue,
+            format,
+            alpha_mode,
+            engine,
+            settings,
+            is_lost,
+        })
+    }
 
-        let mut errors = Vec::new();
-
-        for required_limits in limits {
-            let result = adapter
-                .request_device(
-                    &wgpu::DeviceDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::compositor device descriptor",
-                        ),
-                        required_features: wgpu::Features::empty(),
-                        required_limits: required_limits.clone(),
-                    },
-                    None,
-                )
-                .await;
-
-            match result {
-                Ok((device, queue)) => {
-                    let engine = Engine::new(
-                        &adapter,
-                        &device,
-                        &queue,
-                        format,
-                        settings.antialiasing,
-                    );
-
-                    return Ok(Compositor {
-                        instance,
-                        adapter,
-                        device,
-                        queue,
-                        format,
-                        alpha_mode,
-                        engine,
-                        settings,
-                    });
-                }
-                Err(error) => {
-                    errors.push((required_limits, error));
-                }
+    /// Recreates the graphics device of the [`Compositor`] after it was lost;
+    /// like after a driver reset.
+    ///
+    /// Any existing [`Renderer`] must be recreated and any surface configured
+    /// afterwards.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn recover(
+        &mut self,
+        surface: &wgpu::Surface<'static>,
+    ) -> Result<(), Error> {
+        futures::executor::block_on(async {
+            let adapter_options = wgpu::RequestAdapterOptions {
+                power_preference: power_preference(&self.settings),
+                compatible_surface: Some(surface),
+                force_fallback_adapter: false,
+            };
+
+            let adapter = self
+                .instance
+                .request_adapter(&adapter_options)
+                .await
+                .ok_or(Error::NoAdapterFound(format!(
+                    "{:?}",
+                    adapter_options
+                )))?;
+
+            let (device, queue) = request_device(&adapter).await?;
+
+            log::info!("Recovered with: {:#?}", adapter.get_info());
+
+            self.engine = Engine::new(
+                &adapter,
+                &device,
+                &queue,
+                self.format,
+                self.settings.antialiasing,
+            );
+
+            self.is_lost = watch(&device);
+            self.adapter = adapter;
+            self.device = device;
+            self.queue = queue;
+
+            Ok(())
+        })
+    }
+}
+
+fn power_preference(settings: &Settings) -> wgpu::PowerPreference {
+    wgpu::util::power_preference_from_env().unwrap_or(
+        if settings.antialiasing.is_none() {
+            wgpu::PowerPreference::LowPower
+        } else {
+            wgpu::PowerPreference::HighPerformance
+        },
+    )
+}
+
+async fn request_device(
+    adapter: &wgpu::Adapter,
+) -> Result<(wgpu::Device, wgpu::Queue), Error> {
+    #[cfg(target_arch = "wasm32")]
+    let limits = [wgpu::Limits::downlevel_webgl2_defaults()
+        .using_resolution(adapter.limits())];
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let limits = [wgpu::Limits::default(), wgpu::Limits::downlevel_defaults()];
+
+    let limits = limits.into_iter().map(|limits| wgpu::Limits {
+        max_bind_groups: 2,
+        ..limits
+    });
+
+    let mut errors = Vec::new();
+
+    for required_limits in limits {
+        let result = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some(
+                        "iced_wgpu::window::compositor device descriptor",
+                    ),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: required_limits.clone(),
+                },
+                None,
+            )
+            .await;
+
+        match result {
+            Ok(device) => return Ok(device),
+            Err(error) => {
+                errors.push((required_limits, error));
             }
         }
-
-        Err(Error::RequestDeviceFailed(errors))
     }
+
+    Err(Error::RequestDeviceFailed(errors))
+}
+
+/// Returns a flag that is set once the given device is lost.
+fn watch(device: &wgpu::Device) -> Arc<AtomicBool> {
+    let is_lost = Arc::new(AtomicBool::new(false));
+
+    device.set_device_lost_callback({
+        let is_lost = is_lost.clone();
+
+        move |reason, message| {
+            if let wgpu::DeviceLostReason::Dropped = reason {
+                return;
+            }
+
+            log::error!("Graphics device lost ({reason:?}): {message}");
+
+            is_lost.store(true, atomic::Ordering::Relaxed);
+        }
+    });
+
+    // Operations on a lost device produce errors until it is recreated
+    device.on_uncaptured_error(Box::new({
+        let is_lost = is_lost.clone();
+
+        move |error| {
+            if is_lost.load(atomic::Ordering::Relaxed) {
+                log::warn!("Ignored error of lost graphics device: {error}");
+            } else {
+                panic!("wgpu error: {error}");
+            }
+        }
+    }));
+
+    is_lost
 }
 
 /// Creates a [`Compositor`] with the given [`Settings`] and window.
@@ -206,6 +304,30 @@ pub async fn new<W: compositor::Window>(
     Compositor::request(settings, Some(compatible_window)).await
 }
 
+/// Configures the given surface with the given dimensions and
+/// [`wgpu::PresentMode`].
+fn configure(
+    compositor: &Compositor,
+    surface: &mut wgpu::Surface<'static>,
+    width: u32,
+    height: u32,
+    present_mode: wgpu::PresentMode,
+) {
+    surface.configure(
+        &compositor.device,
+        &wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: compositor.format,
+            present_mode,
+            width,
+            height,
+            alpha_mode: compositor.alpha_mode,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 1,
+        },
+    );
+}
+
 /// Presents the given primitives with the given [`Compositor`].
 pub fn present<T: AsRef<str>>(
     compositor: &mut Compositor,
@@ -215,6 +337,18 @@ pub fn present<T: AsRef<str>>(
     background_color: Color,
     overlay: &[T],
 ) -> Result<(), compositor::SurfaceError> {
+    if compositor.is_lost.load(atomic::Ordering::Relaxed) {
+        #[cfg(not(target_arch = "wasm32"))]
+        match compositor.recover(surface) {
+            Ok(()) => return Err(compositor::SurfaceError::DeviceLost),
+            Err(error) => {
+                log::error!("Failed to recover graphics device: {error}");
+            }
+        }
+
+        return Err(compositor::SurfaceError::Lost);
+    }
+
     match surface.get_current_texture() {
         Ok(frame) => {
             let mut encoder = compositor.device.create_command_encoder(
@@ -270,36 +404,52 @@ impl graphics::Compositor for Compositor {
         compatible_window: W,
         backend: Option<&str>,
     ) -> Result<Self, graphics::Error> {
-        match backend {
-            None | Some("wgpu") => {
-                let mut settings = Settings::from(settings);
+        let backends = match backend {
+            None | Some("wgpu") => wgpu::util::backend_bits_from_env(),
+            Some(backend) => {
+                // A specific backend of `wgpu` can be requested; like
+                // `wgpu-vulkan`
+                let backends = backend
+                    .strip_prefix("wgpu-")
+                    .map(wgpu::util::parse_backends_from_comma_list)
+                    .filter(|backends| !backends.is_empty());
+
+                let Some(backends) = backends else {
+                    return Err(graphics::Error::GraphicsAdapterNotFound {
+                        backend: "wgpu",
+                        reason: error::Reason::DidNotMatch {
+                            preferred_backend: backend.to_owned(),
+                        },
+                    });
+                };
 
-                if let Some(backends) = wgpu::util::backend_bits_from_env() {
-                    settings.backends = backends;
-                }
+                Some(backends)
+            }
+        };
 
-                if let Some(present_mode) = settings::present_mode_from_env() {
-                    settings.present_mode = present_mode;
-                }
+        let mut settings = Settings::from(settings);
 
-                Ok(new(settings, compatible_window).await?)
-            }
-            Some(backend) => Err(graphics::Error::GraphicsAdapterNotFound {
-                backend: "wgpu",
-                reason: error::Reason::DidNotMatch {
-                    preferred_backend: backend.to_owned(),
-                },
-            }),
+        if let Some(backends) = backends {
+            settings.backends = backends;
+        }
+
+        if let Some(present_mode) = settings::present_mode_from_env() {
+            settings.present_mode = present_mode;
         }
+
+        Ok(new(settings, compatible_window).await?)
     }
 
     fn create_renderer(&self) -> Self::Renderer {
-        Renderer::new(
+        let mut renderer = Renderer::new(
             &self.device,
             &self.engine,
             self.settings.default_font,
             self.settings.default_text_size,
-        )
+        );
+        renderer.set_density(self.settings.density);
+
+        renderer
     }
 
     fn create_surface<W: compositor::Window>(
@@ -326,19 +476,54 @@ impl graphics::Compositor for Compositor {
         width: u32,
         height: u32,
     ) {
-        surface.configure(
-            &self.device,
-            &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                format: self.format,
-                present_mode: self.settings.present_mode,
-                width,
-                height,
-                alpha_mode: self.alpha_mode,
-                view_formats: vec![],
-                desired_maximum_frame_latency: 1,
-            },
-        );
+        configure(self, surface, width, height, self.settings.present_mode);
+    }
+
+    fn configure_surface_with(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+        present_mode: window::PresentMode,
+    ) {
+        let present_mode = match present_mode {
+            window::PresentMode::Vsync => wgpu::PresentMode::AutoVsync,
+            window::PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
+            window::PresentMode::Immediate => wgpu::PresentMode::Immediate,
+        };
+
+        let is_supported = surface
+            .get_capabilities(&self.adapter)
+            .present_modes
+            .contains(&present_mode);
+
+        let present_mode = if is_supported {
+            present_mode
+        } else {
+            wgpu::PresentMode::AutoVsync
+        };
+
+        configure(self, surface, width, height, present_mode);
+    }
+
+    fn present_modes(
+        &self,
+        surface: &Self::Surface,
+    ) -> Vec<window::PresentMode> {
+        let capabilities = surface.get_capabilities(&self.adapter);
+
+        let mut present_modes = vec![window::PresentMode::Vsync];
+
+        for (present_mode, mode) in [
+            (wgpu::PresentMode::Mailbox, window::PresentMode::Mailbox),
+            (wgpu::PresentMode::Immediate, window::PresentMode::Immediate),
+        ] {
+            if capabilities.present_modes.contains(&present_mode) {
+                present_modes.push(mode);
+            }
+        }
+
+        present_modes
     }
 
     fn fetch_information(&self) -> compositor::Information {
@@ -350,6 +535,10 @@ impl graphics::Compositor for Compositor {
         }
     }
 
+    fn statistics(&self, renderer: &Self::Renderer) -> compositor::Statistics {
+        renderer.statistics
+    }
+
     fn present<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -5,6 +5,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
@@ -56,7 +57,7 @@ where
     on_press: Option<OnPress<'a, Message>>,
     width: Length,
     height: Length,
-    padding: Padding,
+    padding: Option<Padding>,
     clip: bool,
     class: Theme::Class<'a>,
 }
@@ -92,7 +93,7 @@ where
             on_press: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
-            padding: DEFAULT_PADDING,
+            padding: None,
             clip: false,
             class: Theme::default(),
         }
@@ -112,7 +113,7 @@ where
 
     /// Sets the [`Padding`] of the [`Button`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
-        self.padding = padding.into();
+        self.padding = Some(padding.into());
         self
     }
 
@@ -216,11 +217,14 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::padded(
+        let density = renderer.density();
+
+        let node = layout::padded(
             limits,
             self.width,
             self.height,
-            self.padding,
+            self.padding
+                .unwrap_or_else(|| density.padding(DEFAULT_PADDING)),
             |limits| {
                 self.content.as_widget().layout(
                     &mut tree.children[0],
@@ -228,7 +232,15 @@ where
                     limits,
                 )
             },
-        )
+        );
+
+        let node = density.expand(limits, node);
+
+        if self.clip {
+            node.overflowing()
+        } else {
+            node
+        }
     }
 
     fn operate(
@@ -461,6 +473,17 @@ pub struct Style {
     pub shadow: Shadow,
 }
 
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            background: layer.background.or(self.background),
+            text_color: layer.text_color.unwrap_or(self.text_color),
+            border: layer.border(self.border),
+            shadow: layer.shadow.unwrap_or(self.shadow),
+        }
+    }
+}
+
 impl Style {
     /// Updates the [`Style`] with the given [`Background`].
     pub fn with_background(self, background: impl Into<Background>) -> Self {
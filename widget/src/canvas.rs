@@ -1,26 +1,36 @@
 //! Draw 2D graphics for your users.
 pub mod event;
+pub mod scene;
 
+mod export;
 mod program;
 
 pub use event::Event;
+pub use export::{render_to_image, render_to_svg};
 pub use program::Program;
+pub use scene::Scene;
 
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
-    fill, gradient, path, stroke, Fill, Gradient, Image, LineCap, LineDash,
-    LineJoin, Path, Stroke, Style, Text,
+    fill, freehand, gradient, path, stroke, vertex, Along, BlendMode, Fill,
+    Freehand, Gradient, Image, Insets, LineCap, LineDash, LineJoin, Path,
+    Stroke, Style, Text, TexturedVertex, Tiling, Vertex,
 };
 
 use crate::core;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Clipboard, Element, Length, Rectangle, Shell, Size, Vector, Widget,
 };
 use crate::graphics::geometry;
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
 
 use std::marker::PhantomData;
 
@@ -30,6 +40,12 @@ use std::marker::PhantomData;
 /// change or it is explicitly cleared.
 pub type Cache<Renderer = crate::Renderer> = geometry::Cache<Renderer>;
 
+/// A stack of [`Cache`] layers that can be cleared independently.
+pub type Layers<Renderer = crate::Renderer> = geometry::Layers<Renderer>;
+
+/// A grid of [`Cache`] tiles that can be damaged by region.
+pub type Tiles<Renderer = crate::Renderer> = geometry::Tiles<Renderer>;
+
 /// The geometry supported by a renderer.
 pub type Geometry<Renderer = crate::Renderer> =
     <Renderer as geometry::Renderer>::Geometry;
@@ -76,12 +92,21 @@ pub type Frame<Renderer = crate::Renderer> = geometry::Frame<Renderer>;
 /// // Finally, we simply use our `Circle` to create the `Canvas`!
 /// let canvas = Canvas::new(Circle { radius: 50.0 });
 /// ```
+///
+/// ## Focus
+/// A [`Canvas`] is focused when clicked, and unfocused when something else
+/// is clicked. Its [`Program`] only receives keyboard events while focused.
+///
+/// A [`Canvas`] with an [`Id`] can also be focused with [`focus`], and
+/// takes part in keyboard navigation between focusable widgets. A [`Canvas`]
+/// without an [`Id`] is skipped by focus operations.
 #[derive(Debug)]
 pub struct Canvas<P, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
     Renderer: geometry::Renderer,
     P: Program<Message, Theme, Renderer>,
 {
+    id: Option<Id>,
     width: Length,
     height: Length,
     program: P,
@@ -100,6 +125,7 @@ where
     /// Creates a new [`Canvas`].
     pub fn new(program: P) -> Self {
         Canvas {
+            id: None,
             width: Length::Fixed(Self::DEFAULT_SIZE),
             height: Length::Fixed(Self::DEFAULT_SIZE),
             program,
@@ -109,6 +135,12 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`Canvas`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Canvas`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -129,12 +161,14 @@ where
     P: Program<Message, Theme, Renderer>,
 {
     fn tag(&self) -> tree::Tag {
-        struct Tag<T>(T);
-        tree::Tag::of::<Tag<P::State>>()
+        tree::Tag::of::<State<P::State>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(P::State::default())
+        tree::State::new(State {
+            program: P::State::default(),
+            is_focused: false,
+        })
     }
 
     fn size(&self) -> Size<Length> {
@@ -153,6 +187,24 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // Only a canvas with an id takes part in focus operations; so
+        // decorative canvases are skipped when cycling through widgets
+        let Some(id) = &self.id else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<P::State>>();
+
+        operation.focusable(state, Some(&id.0));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -165,21 +217,31 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State<P::State>>();
+
+        if let core::Event::Mouse(mouse::Event::ButtonPressed(_))
+        | core::Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = cursor.is_over(bounds);
+        }
 
         let canvas_event = match event {
             core::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
             core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Pen(pen_event) => Some(Event::Pen(pen_event)),
             core::Event::Keyboard(keyboard_event) => {
-                Some(Event::Keyboard(keyboard_event))
+                state.is_focused.then_some(Event::Keyboard(keyboard_event))
             }
             core::Event::Window(_) => None,
         };
 
         if let Some(canvas_event) = canvas_event {
-            let state = tree.state.downcast_mut::<P::State>();
-
-            let (event_status, message) =
-                self.program.update(state, canvas_event, bounds, cursor);
+            let (event_status, message) = self.program.update(
+                &mut state.program,
+                canvas_event,
+                bounds,
+                cursor,
+            );
 
             if let Some(message) = message {
                 shell.publish(message);
@@ -200,9 +262,10 @@ where
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
 
-        self.program.mouse_interaction(state, bounds, cursor)
+        self.program
+            .mouse_interaction(&state.program, bounds, cursor)
     }
 
     fn draw(
@@ -221,13 +284,18 @@ where
             return;
         }
 
-        let state = tree.state.downcast_ref::<P::State>();
+        let state = tree.state.downcast_ref::<State<P::State>>();
 
         renderer.with_translation(
             Vector::new(bounds.x, bounds.y),
             |renderer| {
-                let layers =
-                    self.program.draw(state, renderer, theme, bounds, cursor);
+                let layers = self.program.draw(
+                    &state.program,
+                    renderer,
+                    theme,
+                    bounds,
+                    cursor,
+                );
 
                 for layer in layers {
                     renderer.draw_geometry(layer);
@@ -251,3 +319,52 @@ where
         Element::new(canvas)
     }
 }
+
+/// The identifier of a [`Canvas`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Task`] that focuses the [`Canvas`] with the given [`Id`];
+/// so its [`Program`] receives keyboard events.
+pub fn focus<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus(id.0)))
+}
+
+struct State<T> {
+    program: T,
+    is_focused: bool,
+}
+
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
@@ -1,6 +1,7 @@
 //! Handle events of a canvas.
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::pen;
 use crate::core::touch;
 
 pub use crate::core::event::Status;
@@ -11,11 +12,19 @@ pub use crate::core::event::Status;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A mouse event.
+    ///
+    /// Scrolling produces [`mouse::Event::WheelScrolled`]; with precise
+    /// [`mouse::ScrollDelta::Pixels`] for trackpads and similar devices.
     Mouse(mouse::Event),
 
     /// A touch event.
     Touch(touch::Event),
 
+    /// A pen event.
+    Pen(pen::Event),
+
     /// A keyboard event.
+    ///
+    /// Keyboard events are only produced while the [`Canvas`] is focused.
     Keyboard(keyboard::Event),
 }
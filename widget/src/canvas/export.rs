@@ -0,0 +1,82 @@
+use crate::canvas::Program;
+use crate::core::mouse;
+use crate::core::renderer::Headless;
+use crate::core::{Color, Font, Pixels, Rectangle, Size};
+use crate::graphics::geometry::{self, export};
+use crate::runtime::window::Screenshot;
+
+/// Draws the given [`Program`] offscreen, producing a [`Screenshot`] of the
+/// given logical [`Size`] with its RGBA pixels.
+///
+/// The cursor is considered unavailable while drawing. Returns `None` if
+/// the `Renderer` cannot draw without a window.
+pub fn render_to_image<Message, Theme, Renderer, P>(
+    program: &P,
+    state: &P::State,
+    theme: &Theme,
+    size: Size,
+    scale_factor: f32,
+) -> Option<Screenshot>
+where
+    Renderer: geometry::Renderer + Headless,
+    P: Program<Message, Theme, Renderer>,
+{
+    let mut renderer = Renderer::new(Font::DEFAULT, Pixels(16.0))?;
+
+    let geometry = program.draw(
+        state,
+        &renderer,
+        theme,
+        Rectangle::with_size(size),
+        mouse::Cursor::Unavailable,
+    );
+
+    for geometry in geometry {
+        renderer.draw_geometry(geometry);
+    }
+
+    let physical_size = Size::new(
+        (size.width * scale_factor).ceil() as u32,
+        (size.height * scale_factor).ceil() as u32,
+    );
+
+    let bytes =
+        renderer.screenshot(physical_size, scale_factor, Color::TRANSPARENT);
+
+    Some(Screenshot::new(
+        bytes,
+        physical_size,
+        f64::from(scale_factor),
+    ))
+}
+
+/// Draws the given [`Program`] as an SVG document of the given [`Size`].
+///
+/// The [`Program`] must be generic over its `Renderer` to be drawn with the
+/// export [`Renderer`](export::Renderer). The cursor is considered
+/// unavailable while drawing.
+pub fn render_to_svg<Message, Theme, P>(
+    program: &P,
+    state: &P::State,
+    theme: &Theme,
+    size: Size,
+) -> String
+where
+    P: Program<Message, Theme, export::Renderer>,
+{
+    let mut renderer = export::Renderer::new();
+
+    let geometry = program.draw(
+        state,
+        &renderer,
+        theme,
+        Rectangle::with_size(size),
+        mouse::Cursor::Unavailable,
+    );
+
+    for geometry in geometry {
+        geometry::Renderer::draw_geometry(&mut renderer, geometry);
+    }
+
+    renderer.to_svg(size)
+}
@@ -0,0 +1,467 @@
+//! Retain a graph of transformed nodes to draw on a [`Canvas`].
+//!
+//! [`Canvas`]: crate::Canvas
+use crate::canvas::{Cache, Fill, Frame, Geometry, Path, Stroke};
+use crate::core::{Point, Radians, Rectangle, Size, Vector};
+use crate::graphics::geometry;
+
+/// A retained graph of nodes, each with its own transform, shapes, and
+/// cached geometry.
+///
+/// Every node caches its geometry independently. Changing a node only
+/// redraws that node and its descendants; the rest of the [`Scene`] stays
+/// cached. This is ideal for structured editors—like diagrams or node
+/// graphs—where most of the content stays the same between frames.
+///
+/// Children are drawn after—and, therefore, on top of—their parents.
+#[allow(missing_debug_implementations)]
+pub struct Scene<Renderer = crate::Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    entries: Vec<Option<Entry<Renderer>>>,
+    roots: Vec<Id>,
+}
+
+struct Entry<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    node: Node,
+    parent: Option<Id>,
+    children: Vec<Id>,
+    cache: Cache<Renderer>,
+}
+
+/// The identifier of a [`Node`] in a [`Scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(usize);
+
+impl<Renderer> Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Creates a new empty [`Scene`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            roots: Vec::new(),
+        }
+    }
+
+    /// Inserts a [`Node`] in the [`Scene`] as the last child of the given
+    /// parent—or as a root, if there is none—and returns its [`Id`].
+    ///
+    /// If the parent does not exist, the [`Node`] becomes a root.
+    pub fn insert(&mut self, parent: Option<Id>, node: Node) -> Id {
+        let id = Id(self.entries.len());
+        let parent = parent.filter(|parent| self.contains(*parent));
+
+        self.entries.push(Some(Entry {
+            node,
+            parent,
+            children: Vec::new(),
+            cache: Cache::new(),
+        }));
+
+        match parent.and_then(|parent| self.entry_mut(parent)) {
+            Some(parent) => parent.children.push(id),
+            None => self.roots.push(id),
+        }
+
+        id
+    }
+
+    /// Removes the [`Node`] with the given [`Id`] and all of its descendants
+    /// from the [`Scene`].
+    pub fn remove(&mut self, id: Id) {
+        let Some(entry) = self.entries.get_mut(id.0).and_then(Option::take)
+        else {
+            return;
+        };
+
+        match entry.parent.and_then(|parent| self.entry_mut(parent)) {
+            Some(parent) => parent.children.retain(|child| *child != id),
+            None => self.roots.retain(|root| *root != id),
+        }
+
+        let mut pending = entry.children;
+
+        while let Some(child) = pending.pop() {
+            if let Some(entry) =
+                self.entries.get_mut(child.0).and_then(Option::take)
+            {
+                pending.extend(entry.children);
+            }
+        }
+    }
+
+    /// Returns true if the [`Scene`] contains a [`Node`] with the given
+    /// [`Id`].
+    pub fn contains(&self, id: Id) -> bool {
+        self.entry(id).is_some()
+    }
+
+    /// Returns the [`Node`] with the given [`Id`], if any.
+    pub fn get(&self, id: Id) -> Option<&Node> {
+        self.entry(id).map(|entry| &entry.node)
+    }
+
+    /// Returns the [`Node`] with the given [`Id`] mutably, if any.
+    ///
+    /// The cached geometry of the [`Node`] and its descendants is cleared.
+    pub fn get_mut(&mut self, id: Id) -> Option<&mut Node> {
+        self.clear(id);
+        self.entry_mut(id).map(|entry| &mut entry.node)
+    }
+
+    /// Returns the parent of the [`Node`] with the given [`Id`], if any.
+    pub fn parent(&self, id: Id) -> Option<Id> {
+        self.entry(id).and_then(|entry| entry.parent)
+    }
+
+    /// Returns the children of the [`Node`] with the given [`Id`].
+    pub fn children(&self, id: Id) -> &[Id] {
+        self.entry(id)
+            .map(|entry| entry.children.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the roots of the [`Scene`].
+    pub fn roots(&self) -> &[Id] {
+        &self.roots
+    }
+
+    /// Returns the topmost visible [`Node`] whose bounds contain the given
+    /// point, if any.
+    pub fn hit_test(&self, point: Point) -> Option<Id> {
+        self.roots
+            .iter()
+            .rev()
+            .find_map(|root| self.hit_test_node(*root, point))
+    }
+
+    /// Draws the visible nodes of the [`Scene`], reusing the cached geometry
+    /// of the nodes that did not change.
+    pub fn draw(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut geometries = Vec::new();
+        let mut ancestors = Vec::new();
+
+        for root in &self.roots {
+            self.draw_node(
+                *root,
+                renderer,
+                bounds,
+                &mut ancestors,
+                &mut geometries,
+            );
+        }
+
+        geometries
+    }
+
+    fn draw_node(
+        &self,
+        id: Id,
+        renderer: &Renderer,
+        bounds: Size,
+        ancestors: &mut Vec<Transform>,
+        geometries: &mut Vec<Geometry<Renderer>>,
+    ) {
+        let Some(entry) = self.entry(id) else {
+            return;
+        };
+
+        if !entry.node.is_visible {
+            return;
+        }
+
+        ancestors.push(entry.node.transform);
+
+        if !entry.node.shapes.is_empty() {
+            geometries.push(entry.cache.draw(renderer, bounds, |frame| {
+                for transform in ancestors.iter() {
+                    transform.apply(frame);
+                }
+
+                for shape in &entry.node.shapes {
+                    match shape {
+                        Shape::Fill(path, fill) => frame.fill(path, *fill),
+                        Shape::Stroke(path, stroke) => {
+                            frame.stroke(path, *stroke);
+                        }
+                    }
+                }
+            }));
+        }
+
+        for child in &entry.children {
+            self.draw_node(*child, renderer, bounds, ancestors, geometries);
+        }
+
+        let _ = ancestors.pop();
+    }
+
+    fn hit_test_node(&self, id: Id, point: Point) -> Option<Id> {
+        let entry = self.entry(id)?;
+
+        if !entry.node.is_visible {
+            return None;
+        }
+
+        let local = entry.node.transform.to_local(point);
+
+        entry
+            .children
+            .iter()
+            .rev()
+            .find_map(|child| self.hit_test_node(*child, local))
+            .or_else(|| {
+                entry
+                    .node
+                    .bounds
+                    .is_some_and(|bounds| bounds.contains(local))
+                    .then_some(id)
+            })
+    }
+
+    fn clear(&self, id: Id) {
+        let mut pending = vec![id];
+
+        while let Some(id) = pending.pop() {
+            if let Some(entry) = self.entry(id) {
+                entry.cache.clear();
+                pending.extend(&entry.children);
+            }
+        }
+    }
+
+    fn entry(&self, id: Id) -> Option<&Entry<Renderer>> {
+        self.entries.get(id.0).and_then(Option::as_ref)
+    }
+
+    fn entry_mut(&mut self, id: Id) -> Option<&mut Entry<Renderer>> {
+        self.entries.get_mut(id.0).and_then(Option::as_mut)
+    }
+}
+
+impl<Renderer> Default for Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A node of a [`Scene`].
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// The [`Transform`] of the [`Node`], relative to its parent.
+    pub transform: Transform,
+
+    /// Whether the [`Node`] and its descendants are drawn and hit tested.
+    pub is_visible: bool,
+
+    /// The bounds used to hit test the [`Node`], in its local coordinates.
+    ///
+    /// A [`Node`] without bounds is never hit, but its children may be.
+    pub bounds: Option<Rectangle>,
+
+    shapes: Vec<Shape>,
+}
+
+#[derive(Debug, Clone)]
+enum Shape {
+    Fill(Path, Fill),
+    Stroke(Path, Stroke<'static>),
+}
+
+impl Node {
+    /// Creates a new visible [`Node`] with no shapes.
+    pub fn new() -> Self {
+        Self {
+            transform: Transform::IDENTITY,
+            is_visible: true,
+            bounds: None,
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Transform`] of the [`Node`].
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Sets the hit testing bounds of the [`Node`].
+    pub fn bounds(mut self, bounds: Rectangle) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets whether the [`Node`] is visible.
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = is_visible;
+        self
+    }
+
+    /// Adds a filled [`Path`] to the shapes of the [`Node`].
+    pub fn fill(mut self, path: Path, fill: impl Into<Fill>) -> Self {
+        self.shapes.push(Shape::Fill(path, fill.into()));
+        self
+    }
+
+    /// Adds a stroked [`Path`] to the shapes of the [`Node`].
+    pub fn stroke(
+        mut self,
+        path: Path,
+        stroke: impl Into<Stroke<'static>>,
+    ) -> Self {
+        self.shapes.push(Shape::Stroke(path, stroke.into()));
+        self
+    }
+
+    /// Removes all the shapes of the [`Node`].
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The transform of a [`Node`], relative to its parent.
+///
+/// The local coordinates of a [`Node`] are scaled first, then rotated, and
+/// finally translated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// The translation of the [`Transform`].
+    pub translation: Vector,
+
+    /// The clockwise rotation of the [`Transform`].
+    pub rotation: Radians,
+
+    /// The uniform scale of the [`Transform`].
+    pub scale: f32,
+}
+
+impl Transform {
+    /// The identity [`Transform`].
+    pub const IDENTITY: Self = Self {
+        translation: Vector::new(0.0, 0.0),
+        rotation: Radians(0.0),
+        scale: 1.0,
+    };
+
+    /// Creates a [`Transform`] that only translates.
+    pub fn translate(translation: Vector) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Maps a point in the local coordinates of a [`Node`] to the
+    /// coordinates of its parent.
+    pub fn to_parent(&self, point: Point) -> Point {
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let x = point.x * self.scale;
+        let y = point.y * self.scale;
+
+        Point::new(x * cos - y * sin, x * sin + y * cos) + self.translation
+    }
+
+    /// Maps a point in the coordinates of the parent of a [`Node`] to its
+    /// local coordinates.
+    pub fn to_local(&self, point: Point) -> Point {
+        if self.scale == 0.0 {
+            return Point::new(f32::NAN, f32::NAN);
+        }
+
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let point = point - self.translation;
+
+        Point::new(
+            (point.x * cos + point.y * sin) / self.scale,
+            (point.y * cos - point.x * sin) / self.scale,
+        )
+    }
+
+    fn apply<Renderer>(&self, frame: &mut Frame<Renderer>)
+    where
+        Renderer: geometry::Renderer,
+    {
+        frame.translate(self.translation);
+        frame.rotate(self.rotation);
+        frame.scale(self.scale);
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_test() {
+        let mut scene = Scene::<()>::new();
+
+        let parent = scene.insert(
+            None,
+            Node::new()
+                .transform(Transform::translate(Vector::new(10.0, 10.0)))
+                .bounds(Rectangle::new(Point::ORIGIN, Size::new(20.0, 20.0))),
+        );
+
+        let child = scene.insert(
+            Some(parent),
+            Node::new()
+                .transform(Transform {
+                    scale: 2.0,
+                    ..Transform::translate(Vector::new(5.0, 5.0))
+                })
+                .bounds(Rectangle::new(Point::ORIGIN, Size::new(2.0, 2.0))),
+        );
+
+        assert_eq!(scene.hit_test(Point::new(16.0, 16.0)), Some(child));
+        assert_eq!(scene.hit_test(Point::new(12.0, 12.0)), Some(parent));
+        assert_eq!(scene.hit_test(Point::new(40.0, 40.0)), None);
+
+        scene.get_mut(child).unwrap().is_visible = false;
+
+        assert_eq!(scene.hit_test(Point::new(16.0, 16.0)), Some(parent));
+
+        scene.remove(parent);
+
+        assert!(!scene.contains(child));
+        assert!(scene.roots().is_empty());
+    }
+
+    #[test]
+    fn transform_round_trip() {
+        let transform = Transform {
+            translation: Vector::new(3.0, -4.0),
+            rotation: Radians(0.7),
+            scale: 1.5,
+        };
+
+        let point = Point::new(2.0, 5.0);
+        let local = transform.to_local(transform.to_parent(point));
+
+        assert!(local.distance(point) < 1e-4);
+    }
+}
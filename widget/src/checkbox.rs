@@ -4,15 +4,19 @@ use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
 
 /// A box that can be checked.
 ///
@@ -41,12 +45,13 @@ pub struct Checkbox<
     Renderer: text::Renderer,
     Theme: Catalog,
 {
+    id: Option<Id>,
     is_checked: bool,
     on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
     label: String,
     width: Length,
-    size: f32,
-    spacing: f32,
+    size: Option<f32>,
+    spacing: Option<f32>,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
@@ -73,15 +78,16 @@ where
     ///   * a boolean describing whether the [`Checkbox`] is checked or not
     pub fn new(label: impl Into<String>, is_checked: bool) -> Self {
         Checkbox {
+            id: None,
             is_checked,
             on_toggle: None,
             label: label.into(),
             width: Length::Shrink,
-            size: Self::DEFAULT_SIZE,
-            spacing: Self::DEFAULT_SPACING,
+            size: None,
+            spacing: None,
             text_size: None,
             text_line_height: text::LineHeight::default(),
-            text_shaping: text::Shaping::Basic,
+            text_shaping: text::Shaping::default(),
             font: None,
             icon: Icon {
                 font: Renderer::ICON_FONT,
@@ -119,9 +125,15 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`Checkbox`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the size of the [`Checkbox`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
-        self.size = size.into().0;
+        self.size = Some(size.into().0);
         self
     }
 
@@ -133,7 +145,7 @@ where
 
     /// Sets the spacing between the [`Checkbox`] and the text.
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
-        self.spacing = spacing.into().0;
+        self.spacing = Some(spacing.into().0);
         self
     }
 
@@ -198,11 +210,11 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::new(self.is_checked))
     }
 
     fn size(&self) -> Size<Length> {
@@ -218,17 +230,24 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::next_to_each_other(
+        let density = renderer.density();
+        let size = self
+            .size
+            .unwrap_or_else(|| Self::DEFAULT_SIZE * density.scale());
+        let spacing = self
+            .spacing
+            .unwrap_or_else(|| Self::DEFAULT_SPACING * density.scale());
+
+        let node = layout::next_to_each_other(
             &limits.width(self.width),
-            self.spacing,
-            |_| layout::Node::new(Size::new(self.size, self.size)),
+            spacing,
+            |_| layout::Node::new(Size::new(size, size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -240,14 +259,30 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Justification::None,
                 )
             },
-        )
+        );
+
+        density.expand(limits, node)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.is_checked = self.is_checked;
+
+        operation.toggleable(state, self.id.as_ref().map(|id| &id.0));
     }
 
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -256,6 +291,16 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Some(is_checked) = state.requested.take() {
+            if let Some(on_toggle) = &self.on_toggle {
+                if is_checked != self.is_checked {
+                    shell.publish((on_toggle)(is_checked));
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -348,6 +393,7 @@ where
                         horizontal_alignment: alignment::Horizontal::Center,
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
+                        justification: text::Justification::None,
                     },
                     bounds.center(),
                     style.icon_color,
@@ -358,16 +404,16 @@ where
 
         {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
             crate::text::draw(
                 renderer,
                 defaults,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    ..crate::text::Style::default()
                 },
                 viewport,
             );
@@ -389,6 +435,73 @@ where
     }
 }
 
+/// The identifier of a [`Checkbox`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Task`] that toggles the [`Checkbox`] with the given [`Id`],
+/// as if it was clicked.
+pub fn toggle<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::toggleable::toggle(id.0)))
+}
+
+/// Produces a [`Task`] that checks or unchecks the [`Checkbox`] with the
+/// given [`Id`].
+///
+/// The [`Checkbox`] only produces a message if it changes.
+pub fn set_checked<T>(id: Id, is_checked: bool) -> Task<T> {
+    task::effect(Action::widget(operation::toggleable::set_toggled(
+        id.0, is_checked,
+    )))
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_checked: bool,
+    requested: Option<bool>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new(is_checked: bool) -> Self {
+        Self {
+            label: widget::text::State::default(),
+            is_checked,
+            requested: None,
+        }
+    }
+}
+
+impl<P: text::Paragraph> operation::Toggleable for State<P> {
+    fn is_toggled(&self) -> bool {
+        self.requested.unwrap_or(self.is_checked)
+    }
+
+    fn set_toggled(&mut self, is_toggled: bool) {
+        self.requested = Some(is_toggled);
+    }
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -437,6 +550,17 @@ pub struct Style {
     pub text_color: Option<Color>,
 }
 
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            background: layer.background.unwrap_or(self.background),
+            border: layer.border(self.border),
+            text_color: layer.text_color.or(self.text_color),
+            ..self
+        }
+    }
+}
+
 /// The theme catalog of a [`Checkbox`].
 pub trait Catalog: Sized {
     /// The item class of the [`Catalog`].
@@ -203,7 +203,7 @@ where
     ) -> layout::Node {
         let limits = limits.max_width(self.max_width);
 
-        layout::flex::resolve(
+        let node = layout::flex::resolve(
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
@@ -214,7 +214,13 @@ where
             self.align,
             &self.children,
             &mut tree.children,
-        )
+        );
+
+        if self.clip {
+            node.overflowing()
+        } else {
+            node
+        }
     }
 
     fn operate(
@@ -13,6 +13,7 @@ use crate::core::{
     Clipboard, Element, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
 };
 use crate::overlay::menu;
+use crate::overlay::Toggle;
 use crate::text::LineHeight;
 use crate::text_input::{self, TextInput};
 
@@ -41,6 +42,7 @@ pub struct ComboBox<
     selection: text_input::Value,
     on_selected: Box<dyn Fn(T) -> Message>,
     on_option_hovered: Option<Box<dyn Fn(T) -> Message>>,
+    on_open: Option<Message>,
     on_close: Option<Message>,
     on_input: Option<Box<dyn Fn(String) -> Message>>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
@@ -77,6 +79,7 @@ where
             on_selected: Box::new(on_selected),
             on_option_hovered: None,
             on_input: None,
+            on_open: None,
             on_close: None,
             menu_class: <Theme as Catalog>::default_menu(),
             padding: text_input::DEFAULT_PADDING,
@@ -104,6 +107,13 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`ComboBox`] is
+    /// focused and its menu opens.
+    pub fn on_open(mut self, message: Message) -> Self {
+        self.on_open = Some(message);
+        self
+    }
+
     /// Sets the message that will be produced when the outside area
     /// of the [`ComboBox`] is pressed.
     pub fn on_close(mut self, message: Message) -> Self {
@@ -394,6 +404,28 @@ where
         vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        let text_input_state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        let mut toggle = Toggle {
+            is_open: text_input_state.is_focused(),
+        };
+
+        operation.custom(&mut toggle, None);
+
+        if !toggle.is_open {
+            text_input_state.unfocus();
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
@@ -420,7 +452,8 @@ where
 
         // Create a new list of local messages
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = Shell::new(&mut local_messages)
+            .with_received_at(shell.received_at());
 
         // Provide it to the widget
         let mut event_status = self.text_input.on_event(
@@ -434,6 +467,11 @@ where
             viewport,
         );
 
+        // Keep the animations of the text input—like its blinking cursor—going
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
         // Then finally react to them here
         for message in local_messages {
             let TextInputEvent::TextChanged(new_value) = message;
@@ -638,6 +676,12 @@ where
             }
         }
 
+        if !started_focused && is_focused {
+            if let Some(message) = self.on_open.take() {
+                shell.publish(message);
+            }
+        }
+
         // Focus changed, invalidate widget tree to force a fresh `view`
         if started_focused != is_focused {
             shell.invalidate_widgets();
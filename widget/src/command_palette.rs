@@ -0,0 +1,1283 @@
+//! Search and trigger actions from a list displayed on top of your application.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Span, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::fmt::Display;
+
+/// The maximum amount of recently selected options remembered by a [`State`].
+const MAX_RECENT: usize = 10;
+
+/// A widget that displays a fuzzy-searchable list of actions on top of
+/// its contents.
+///
+/// The palette can be opened with a keyboard shortcut—<kbd>Ctrl</kbd> + <kbd>P</kbd>
+/// by default—or programmatically with [`State::open`].
+///
+/// Options are ranked by how well they match the query and how recently
+/// they were selected.
+#[allow(missing_debug_implementations)]
+pub struct CommandPalette<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a State<T>,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_close: Option<Message>,
+    shortcut: (keyboard::Modifiers, keyboard::Key),
+    placeholder: String,
+    width: f32,
+    max_items: usize,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: Display + Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of each row of a [`CommandPalette`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        bottom: 6.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`CommandPalette`] with the given [`State`], the content
+    /// it will be displayed on top of, and the message to produce when an
+    /// option is selected.
+    pub fn new(
+        state: &'a State<T>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            state,
+            content: content.into(),
+            on_selected: Box::new(on_selected),
+            on_close: None,
+            shortcut: (
+                keyboard::Modifiers::COMMAND,
+                keyboard::Key::Character("p".into()),
+            ),
+            placeholder: String::from("Type a command..."),
+            width: 500.0,
+            max_items: 10,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`CommandPalette`]
+    /// is dismissed without selecting an option.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the keyboard shortcut that toggles the [`CommandPalette`].
+    pub fn shortcut(
+        mut self,
+        modifiers: keyboard::Modifiers,
+        key: keyboard::Key,
+    ) -> Self {
+        self.shortcut = (modifiers, key);
+        self
+    }
+
+    /// Sets the placeholder of the search query of the [`CommandPalette`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`CommandPalette`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the maximum amount of options visible at once in the
+    /// [`CommandPalette`].
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items.max(1);
+        self
+    }
+
+    /// Sets the [`Padding`] of each row of the [`CommandPalette`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`CommandPalette`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`CommandPalette`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`CommandPalette`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`CommandPalette`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CommandPalette`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The local state of a [`CommandPalette`].
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    options: Vec<T>,
+    labels: Vec<String>,
+    inner: RefCell<Inner>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Inner {
+    is_open: bool,
+    query: String,
+    matches: Vec<Match>,
+    recent: Vec<usize>,
+    hovered: usize,
+    scroll: usize,
+}
+
+impl<T> State<T>
+where
+    T: Display,
+{
+    /// Creates a new [`State`] for a [`CommandPalette`] with the given list
+    /// of options.
+    pub fn new(options: Vec<T>) -> Self {
+        let labels = options.iter().map(T::to_string).collect();
+
+        Self {
+            options,
+            labels,
+            inner: RefCell::new(Inner::default()),
+        }
+    }
+
+    /// Returns the options of the [`State`].
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// Returns the recently selected options of the [`State`], starting
+    /// with the most recent one.
+    pub fn recent(&self) -> Vec<&T> {
+        self.inner
+            .borrow()
+            .recent
+            .iter()
+            .filter_map(|&index| self.options.get(index))
+            .collect()
+    }
+
+    /// Returns whether the [`CommandPalette`] is open or not.
+    pub fn is_open(&self) -> bool {
+        self.inner.borrow().is_open
+    }
+
+    /// Opens the [`CommandPalette`] with an empty query.
+    pub fn open(&mut self) {
+        self.inner.get_mut().open(&self.labels);
+    }
+
+    /// Closes the [`CommandPalette`].
+    pub fn close(&mut self) {
+        self.inner.get_mut().close();
+    }
+}
+
+impl<T> Default for State<T>
+where
+    T: Display,
+{
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Inner {
+    fn open(&mut self, labels: &[String]) {
+        self.is_open = true;
+        self.query.clear();
+        self.search(labels);
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+        self.matches.clear();
+    }
+
+    fn search(&mut self, labels: &[String]) {
+        self.matches = search(labels, &self.query, &self.recent);
+        self.hovered = 0;
+        self.scroll = 0;
+    }
+
+    fn remember(&mut self, index: usize) {
+        self.recent.retain(|&recent| recent != index);
+        self.recent.insert(0, index);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    fn hover(&mut self, hovered: usize, rows: usize) {
+        self.hovered = hovered.min(self.matches.len().saturating_sub(1));
+
+        if self.hovered < self.scroll {
+            self.scroll = self.hovered;
+        } else if self.hovered >= self.scroll + rows {
+            self.scroll = self.hovered + 1 - rows;
+        }
+    }
+
+    fn hover_previous(&mut self, rows: usize) {
+        let hovered = if self.hovered == 0 {
+            self.matches.len().saturating_sub(1)
+        } else {
+            self.hovered - 1
+        };
+
+        self.hover(hovered, rows);
+    }
+
+    fn hover_next(&mut self, rows: usize) {
+        let hovered = if self.hovered + 1 >= self.matches.len() {
+            0
+        } else {
+            self.hovered + 1
+        };
+
+        self.hover(hovered, rows);
+    }
+}
+
+/// An option matching the query of a [`CommandPalette`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Match {
+    index: usize,
+    score: i32,
+    positions: Vec<usize>,
+}
+
+struct Memory<P: Paragraph> {
+    query: P,
+    items: Vec<Item<P>>,
+}
+
+struct Item<P: Paragraph> {
+    paragraph: P,
+    highlights: Vec<usize>,
+}
+
+fn is_shortcut(
+    shortcut: &(keyboard::Modifiers, keyboard::Key),
+    key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+) -> bool {
+    let (shortcut_modifiers, shortcut_key) = shortcut;
+
+    modifiers == *shortcut_modifiers
+        && match (key.as_ref(), shortcut_key.as_ref()) {
+            (keyboard::Key::Character(a), keyboard::Key::Character(b)) => {
+                a.eq_ignore_ascii_case(b)
+            }
+            (a, b) => a == b,
+        }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: Display + Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Memory<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Memory::<Renderer::Paragraph> {
+            query: Renderer::Paragraph::default(),
+            items: Vec::new(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            modifiers,
+            ..
+        }) = &event
+        {
+            if is_shortcut(&self.shortcut, key, *modifiers) {
+                let mut inner = self.state.inner.borrow_mut();
+
+                if !inner.is_open {
+                    inner.open(&self.state.labels);
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if self.state.is_open() {
+            let memory =
+                tree.state.downcast_mut::<Memory<Renderer::Paragraph>>();
+
+            return Some(overlay::Element::new(Box::new(Overlay {
+                state: self.state,
+                memory,
+                on_selected: self.on_selected.as_ref(),
+                on_close: self.on_close.as_ref(),
+                shortcut: &self.shortcut,
+                placeholder: &self.placeholder,
+                width: self.width,
+                max_items: self.max_items,
+                padding: self.padding,
+                text_size: self.text_size,
+                text_line_height: self.text_line_height,
+                font: self.font,
+                class: &self.class,
+            })));
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<CommandPalette<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Display + Clone + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        command_palette: CommandPalette<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(command_palette)
+    }
+}
+
+struct Overlay<'a, 'b, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a State<T>,
+    memory: &'a mut Memory<Renderer::Paragraph>,
+    on_selected: &'a dyn Fn(T) -> Message,
+    on_close: Option<&'a Message>,
+    shortcut: &'a (keyboard::Modifiers, keyboard::Key),
+    placeholder: &'a str,
+    width: f32,
+    max_items: usize,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: &'a Theme::Class<'b>,
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer>
+    Overlay<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+
+    /// Returns the number of items that fit in the given bounds of the
+    /// overlay, below the query.
+    fn visible_rows(&self, bounds: Rectangle, row_height: f32) -> usize {
+        ((bounds.height / row_height).round() as usize)
+            .saturating_sub(1)
+            .max(1)
+    }
+
+    fn row_at(
+        &self,
+        inner: &Inner,
+        bounds: Rectangle,
+        row_height: f32,
+        position: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let row = ((position.y - bounds.y) / row_height) as usize;
+
+        if row == 0 {
+            return None;
+        }
+
+        let index = inner.scroll + row - 1;
+
+        (index < inner.matches.len()).then_some(index)
+    }
+
+    fn select(&self, inner: &mut Inner, shell: &mut Shell<'_, Message>) {
+        let Some(index) = inner.matches.get(inner.hovered).map(|m| m.index)
+        else {
+            return;
+        };
+
+        inner.remember(index);
+        inner.close();
+
+        shell.publish((self.on_selected)(self.state.options[index].clone()));
+        shell.invalidate_layout();
+    }
+
+    fn close(&self, inner: &mut Inner, shell: &mut Shell<'_, Message>) {
+        inner.close();
+
+        if let Some(on_close) = self.on_close {
+            shell.publish(on_close.clone());
+        }
+
+        shell.invalidate_layout();
+    }
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let inner = self.state.inner.borrow();
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let row_height = self.row_height(renderer);
+
+        let width = self.width.min(bounds.width);
+        let top = bounds.height / 10.0;
+
+        let available_rows = (((bounds.height - top) / row_height).floor()
+            as usize)
+            .saturating_sub(1);
+
+        let visible = inner
+            .matches
+            .iter()
+            .skip(inner.scroll)
+            .take(self.max_items.min(available_rows));
+
+        let text_bounds =
+            Size::new(width - self.padding.horizontal(), row_height);
+
+        self.memory.query = Renderer::Paragraph::with_text(Text {
+            content: if inner.query.is_empty() {
+                self.placeholder
+            } else {
+                inner.query.as_str()
+            },
+            bounds: text_bounds,
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            justification: text::Justification::None,
+        });
+
+        self.memory.items = visible
+            .map(|option| {
+                let label = &self.state.labels[option.index];
+                let (spans, highlights) =
+                    highlight::<Renderer::Font>(label, &option.positions);
+
+                Item {
+                    paragraph: Renderer::Paragraph::with_spans(Text {
+                        content: spans.as_slice(),
+                        bounds: text_bounds,
+                        size: text_size,
+                        line_height: self.text_line_height,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Advanced,
+                        justification: text::Justification::None,
+                    }),
+                    highlights,
+                }
+            })
+            .collect();
+
+        let height = row_height * (self.memory.items.len() + 1) as f32;
+
+        layout::Node::new(Size::new(width, height))
+            .move_to(Point::new((bounds.width - width) / 2.0, top))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let row_height = self.row_height(renderer);
+        let rows = self.visible_rows(bounds, row_height);
+        let mut inner = self.state.inner.borrow_mut();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                if is_shortcut(self.shortcut, &key, modifiers) {
+                    self.close(&mut inner, shell);
+
+                    return event::Status::Captured;
+                }
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        self.close(&mut inner, shell);
+                    }
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        self.select(&mut inner, shell);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        inner.hover_previous(rows);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        inner.hover_next(rows);
+                    }
+                    keyboard::Key::Named(key::Named::Tab) => {
+                        if modifiers.shift() {
+                            inner.hover_previous(rows);
+                        } else {
+                            inner.hover_next(rows);
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        if inner.query.pop().is_some() {
+                            inner.search(&self.state.labels);
+                        }
+                    }
+                    _ => {
+                        if let Some(text) = text {
+                            if !modifiers.command() {
+                                let length = inner.query.len();
+
+                                inner.query.extend(
+                                    text.chars().filter(|c| !c.is_control()),
+                                );
+
+                                if inner.query.len() != length {
+                                    inner.search(&self.state.labels);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(_) => {
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(index) =
+                    self.row_at(&inner, bounds, row_height, position)
+                {
+                    inner.hover(index, rows);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 {
+                    inner.hover_previous(rows);
+                } else if y < 0.0 {
+                    inner.hover_next(rows);
+                }
+
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let position = match event {
+                    Event::Touch(touch::Event::FingerPressed {
+                        position,
+                        ..
+                    }) => Some(position),
+                    _ => cursor.position(),
+                };
+
+                match position {
+                    Some(position) if bounds.contains(position) => {
+                        if let Some(index) =
+                            self.row_at(&inner, bounds, row_height, position)
+                        {
+                            inner.hover(index, rows);
+                            self.select(&mut inner, shell);
+                        }
+                    }
+                    _ => {
+                        self.close(&mut inner, shell);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let inner = self.state.inner.borrow();
+
+        let is_over_option = cursor.position().is_some_and(|position| {
+            self.row_at(
+                &inner,
+                layout.bounds(),
+                self.row_height(renderer),
+                position,
+            )
+            .is_some()
+        });
+
+        if is_over_option {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Idle
+        }
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The palette is modal; the contents below must not be hovered.
+        true
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let inner = self.state.inner.borrow();
+        let bounds = layout.bounds();
+        let style = Catalog::style(theme, self.class);
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height =
+            f32::from(self.text_line_height.to_absolute(text_size));
+        let row_height = line_height + self.padding.vertical();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        let query_position = Point::new(
+            bounds.x + self.padding.left,
+            bounds.y + self.padding.top,
+        );
+
+        renderer.fill_paragraph(
+            &self.memory.query,
+            query_position,
+            if inner.query.is_empty() {
+                style.placeholder_color
+            } else {
+                style.text_color
+            },
+            bounds,
+        );
+
+        let cursor_x = if inner.query.is_empty() {
+            query_position.x
+        } else {
+            query_position.x + self.memory.query.min_width()
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(cursor_x, query_position.y),
+                    Size::new(1.0, line_height),
+                ),
+                ..renderer::Quad::default()
+            },
+            style.text_color,
+        );
+
+        if self.memory.items.is_empty() {
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + row_height,
+                    width: bounds.width,
+                    height: style.border.width.max(1.0),
+                },
+                ..renderer::Quad::default()
+            },
+            style.border.color,
+        );
+
+        for (i, item) in self.memory.items.iter().enumerate() {
+            let is_selected = inner.scroll + i == inner.hovered;
+
+            let row = Rectangle {
+                x: bounds.x,
+                y: bounds.y + row_height * (i + 1) as f32,
+                width: bounds.width,
+                height: row_height,
+            };
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: row.x + style.border.width,
+                            width: row.width - style.border.width * 2.0,
+                            ..row
+                        },
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            let position =
+                Point::new(row.x + self.padding.left, row.y + self.padding.top);
+            let translation = position - Point::ORIGIN;
+
+            for span in &item.highlights {
+                for region in item.paragraph.span_bounds(*span) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: region + translation,
+                            border: border::rounded(2),
+                            ..renderer::Quad::default()
+                        },
+                        style.match_highlight,
+                    );
+                }
+            }
+
+            renderer.fill_paragraph(
+                &item.paragraph,
+                position,
+                if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                bounds,
+            );
+        }
+    }
+}
+
+/// Splits the `label` into spans, separating the characters at the given
+/// `positions`.
+///
+/// Returns the spans and the indices of the spans that should be highlighted.
+fn highlight<'a, Font>(
+    label: &'a str,
+    positions: &[usize],
+) -> (Vec<Span<'a, (), Font>>, Vec<usize>) {
+    let mut spans = Vec::new();
+    let mut highlights = Vec::new();
+
+    let mut start = 0;
+    let mut is_highlighted = false;
+    let mut positions = positions.iter().peekable();
+
+    for (i, (offset, _)) in label.char_indices().enumerate() {
+        let is_match = positions.next_if_eq(&&i).is_some();
+
+        if is_match != is_highlighted {
+            if offset > start {
+                if is_highlighted {
+                    highlights.push(spans.len());
+                }
+
+                spans.push(Span::new(&label[start..offset]));
+            }
+
+            start = offset;
+            is_highlighted = is_match;
+        }
+    }
+
+    if start < label.len() {
+        if is_highlighted {
+            highlights.push(spans.len());
+        }
+
+        spans.push(Span::new(&label[start..]));
+    }
+
+    (spans, highlights)
+}
+
+/// Ranks the `labels` matching the `query`, favoring the `recent` ones.
+fn search(labels: &[String], query: &str, recent: &[usize]) -> Vec<Match> {
+    let mut matches: Vec<Match> = labels
+        .iter()
+        .enumerate()
+        .filter_map(|(index, label)| {
+            let (score, positions) = fuzzy_match(query, label)?;
+
+            let bonus = recent
+                .iter()
+                .position(|&recent| recent == index)
+                .map_or(0, |rank| (MAX_RECENT - rank) as i32 * 2);
+
+            Some(Match {
+                index,
+                score: score + bonus,
+                positions,
+            })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.score.cmp(&a.score));
+    matches
+}
+
+/// Matches the characters of the `query` in order against the `candidate`,
+/// ignoring case and whitespace.
+///
+/// Returns the score of the match and the character positions of the
+/// `candidate` that were matched, if every character of the `query` is
+/// found. Consecutive matches and matches at the start of a word score
+/// higher, while gaps between matches are penalized.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+
+    let mut score = 0;
+    let mut positions = Vec::new();
+    let mut previous = None;
+
+    for (i, c) in candidate.chars().enumerate() {
+        let Some(&target) = query.peek() else {
+            break;
+        };
+
+        let is_match = c.to_lowercase().next() == Some(target);
+
+        if is_match {
+            let is_word_start = match previous {
+                None => true,
+                Some(previous) => {
+                    !char::is_alphanumeric(previous)
+                        || (previous.is_lowercase() && c.is_uppercase())
+                }
+            };
+
+            let gap = match positions.last() {
+                Some(&last) => i - last - 1,
+                None => i,
+            };
+
+            score += 1;
+
+            if gap == 0 && !positions.is_empty() {
+                score += 5;
+            }
+
+            if is_word_start {
+                score += 8;
+            }
+
+            score -= gap.min(3) as i32;
+
+            positions.push(i);
+            let _ = query.next();
+        }
+
+        previous = Some(c);
+    }
+
+    query.peek().is_none().then_some((score, positions))
+}
+
+/// The appearance of a [`CommandPalette`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Background`] of the palette.
+    pub background: Background,
+    /// The [`Border`] of the palette.
+    pub border: Border,
+    /// The [`Shadow`] of the palette.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the palette.
+    pub text_color: Color,
+    /// The [`Color`] of the placeholder of the query.
+    pub placeholder_color: Color,
+    /// The text [`Color`] of the selected option.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the selected option.
+    pub selected_background: Background,
+    /// The [`Background`] of the characters matching the query.
+    pub match_highlight: Background,
+}
+
+/// The theme catalog of a [`CommandPalette`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CommandPalette`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CommandPalette`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 4.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 16.0,
+        },
+        text_color: palette.background.base.text,
+        placeholder_color: palette.background.strong.color,
+        selected_text_color: palette.primary.weak.text,
+        selected_background: palette.primary.weak.color.into(),
+        match_highlight: palette.primary.strong.color.scale_alpha(0.4).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_requires_every_character_in_order() {
+        assert!(fuzzy_match("opn", "Open File").is_some());
+        assert!(fuzzy_match("of", "Open File").is_some());
+        assert!(fuzzy_match("fo", "Open File").is_none());
+        assert!(fuzzy_match("", "Open File").is_some());
+    }
+
+    #[test]
+    fn fuzzy_match_returns_matched_positions() {
+        let (_, positions) = fuzzy_match("of", "Open File").unwrap();
+
+        assert_eq!(positions, vec![0, 5]);
+    }
+
+    #[test]
+    fn fuzzy_match_favors_word_starts_and_consecutive_characters() {
+        let (prefix, _) = fuzzy_match("save", "Save All").unwrap();
+        let (scattered, _) =
+            fuzzy_match("save", "Show Advanced View Editor").unwrap();
+
+        assert!(prefix > scattered);
+    }
+
+    #[test]
+    fn search_ranks_recent_options_first() {
+        let labels = vec![
+            String::from("Open File"),
+            String::from("Close File"),
+            String::from("Save File"),
+        ];
+
+        let matches = search(&labels, "", &[2, 1]);
+        let order: Vec<_> = matches.iter().map(|m| m.index).collect();
+
+        assert_eq!(order, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn hover_scrolls_to_keep_the_hovered_item_visible() {
+        let labels: Vec<_> = (0..10).map(|i| format!("Option {i}")).collect();
+
+        let mut inner = Inner::default();
+        inner.open(&labels);
+
+        for _ in 0..4 {
+            inner.hover_next(3);
+        }
+
+        assert_eq!(inner.hovered, 4);
+        assert_eq!(inner.scroll, 2);
+
+        inner.hover(0, 3);
+
+        assert_eq!(inner.scroll, 0);
+    }
+
+    #[test]
+    fn highlight_splits_matched_characters() {
+        let (spans, highlights) = highlight::<()>("Open File", &[0, 5]);
+
+        let texts: Vec<_> = spans.iter().map(|span| &*span.text).collect();
+
+        assert_eq!(texts, vec!["O", "pen ", "F", "ile"]);
+        assert_eq!(highlights, vec![0, 2]);
+    }
+}
@@ -7,6 +7,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
@@ -227,7 +228,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
+        let node = layout(
             limits,
             self.width,
             self.height,
@@ -237,7 +238,13 @@ where
             self.horizontal_alignment,
             self.vertical_alignment,
             |limits| self.content.as_widget().layout(tree, renderer, limits),
-        )
+        );
+
+        if self.clip {
+            node.overflowing()
+        } else {
+            node
+        }
     }
 
     fn operate(
@@ -447,90 +454,98 @@ impl From<Id> for widget::Id {
 /// Produces a [`Task`] that queries the visible screen bounds of the
 /// [`Container`] with the given [`Id`].
 pub fn visible_bounds(id: Id) -> Task<Option<Rectangle>> {
-    struct VisibleBounds {
-        target: widget::Id,
-        depth: usize,
-        scrollables: Vec<(Vector, Rectangle, usize)>,
-        bounds: Option<Rectangle>,
-    }
-
-    impl Operation<Option<Rectangle>> for VisibleBounds {
-        fn scrollable(
-            &mut self,
-            _state: &mut dyn widget::operation::Scrollable,
-            _id: Option<&widget::Id>,
-            bounds: Rectangle,
-            translation: Vector,
-        ) {
-            match self.scrollables.last() {
-                Some((last_translation, last_viewport, _depth)) => {
-                    let viewport = last_viewport
-                        .intersection(&(bounds - *last_translation))
-                        .unwrap_or(Rectangle::new(Point::ORIGIN, Size::ZERO));
-
-                    self.scrollables.push((
-                        translation + *last_translation,
-                        viewport,
-                        self.depth,
-                    ));
-                }
-                None => {
-                    self.scrollables.push((translation, bounds, self.depth));
-                }
-            }
-        }
+    task::widget(VisibleBounds::new(id.into()))
+}
 
-        fn container(
-            &mut self,
-            id: Option<&widget::Id>,
-            bounds: Rectangle,
-            operate_on_children: &mut dyn FnMut(
-                &mut dyn Operation<Option<Rectangle>>,
-            ),
-        ) {
-            if self.bounds.is_some() {
-                return;
-            }
+/// An [`Operation`] that finds the visible bounds of the widget with the
+/// given [`widget::Id`], taking into account the scrollables it is in.
+pub(crate) struct VisibleBounds {
+    target: widget::Id,
+    depth: usize,
+    scrollables: Vec<(Vector, Rectangle, usize)>,
+    bounds: Option<Rectangle>,
+}
 
-            if id == Some(&self.target) {
-                match self.scrollables.last() {
-                    Some((translation, viewport, _)) => {
-                        self.bounds =
-                            viewport.intersection(&(bounds - *translation));
-                    }
-                    None => {
-                        self.bounds = Some(bounds);
-                    }
-                }
+impl VisibleBounds {
+    pub(crate) fn new(target: widget::Id) -> Self {
+        Self {
+            target,
+            depth: 0,
+            scrollables: Vec::new(),
+            bounds: None,
+        }
+    }
+}
 
-                return;
+impl Operation<Option<Rectangle>> for VisibleBounds {
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn widget::operation::Scrollable,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        match self.scrollables.last() {
+            Some((last_translation, last_viewport, _depth)) => {
+                let viewport = last_viewport
+                    .intersection(&(bounds - *last_translation))
+                    .unwrap_or(Rectangle::new(Point::ORIGIN, Size::ZERO));
+
+                self.scrollables.push((
+                    translation + *last_translation,
+                    viewport,
+                    self.depth,
+                ));
             }
+            None => {
+                self.scrollables.push((translation, bounds, self.depth));
+            }
+        }
+    }
 
-            self.depth += 1;
-
-            operate_on_children(self);
-
-            self.depth -= 1;
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(
+            &mut dyn Operation<Option<Rectangle>>,
+        ),
+    ) {
+        if self.bounds.is_some() {
+            return;
+        }
 
+        if id == Some(&self.target) {
             match self.scrollables.last() {
-                Some((_, _, depth)) if self.depth == *depth => {
-                    let _ = self.scrollables.pop();
+                Some((translation, viewport, _)) => {
+                    self.bounds =
+                        viewport.intersection(&(bounds - *translation));
+                }
+                None => {
+                    self.bounds = Some(bounds);
                 }
-                _ => {}
             }
+
+            return;
         }
 
-        fn finish(&self) -> widget::operation::Outcome<Option<Rectangle>> {
-            widget::operation::Outcome::Some(self.bounds)
+        self.depth += 1;
+
+        operate_on_children(self);
+
+        self.depth -= 1;
+
+        match self.scrollables.last() {
+            Some((_, _, depth)) if self.depth == *depth => {
+                let _ = self.scrollables.pop();
+            }
+            _ => {}
         }
     }
 
-    task::widget(VisibleBounds {
-        target: id.into(),
-        depth: 0,
-        scrollables: Vec::new(),
-        bounds: None,
-    })
+    fn finish(&self) -> widget::operation::Outcome<Option<Rectangle>> {
+        widget::operation::Outcome::Some(self.bounds)
+    }
 }
 
 /// The appearance of a container.
@@ -546,6 +561,17 @@ pub struct Style {
     pub shadow: Shadow,
 }
 
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            text_color: layer.text_color.or(self.text_color),
+            background: layer.background.or(self.background),
+            border: layer.border(self.border),
+            shadow: layer.shadow.unwrap_or(self.shadow),
+        }
+    }
+}
+
 impl Style {
     /// Updates the text color of the [`Style`].
     pub fn color(self, color: impl Into<Color>) -> Self {
@@ -0,0 +1,257 @@
+//! A container for intercepting events before or after its contents.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// Observe or intercept the events of some content.
+///
+/// Events travel through an [`EventFilter`] in two phases, like in the DOM:
+///
+/// - The capture phase runs before the content handles an event. Intercepting
+///   an event here hides it from the content entirely.
+/// - The bubble phase runs after the content, and only for events that the
+///   content did not capture.
+///
+/// Both filters receive the position of the cursor relative to the bounds of
+/// the [`EventFilter`], if the cursor is over it.
+#[allow(missing_debug_implementations)]
+pub struct EventFilter<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_capture:
+        Option<Box<dyn Fn(&Event, Option<Point>) -> Filter<Message> + 'a>>,
+    on_bubble:
+        Option<Box<dyn Fn(&Event, Option<Point>) -> Filter<Message> + 'a>>,
+}
+
+/// The decision of a filter of an [`EventFilter`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter<Message> {
+    /// Let the event keep propagating.
+    Pass,
+    /// Produce a message and let the event keep propagating.
+    Observe(Message),
+    /// Stop the propagation of the event, optionally producing a message.
+    Intercept(Option<Message>),
+}
+
+impl<'a, Message, Theme, Renderer> EventFilter<'a, Message, Theme, Renderer> {
+    /// Creates an [`EventFilter`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        EventFilter {
+            content: content.into(),
+            on_capture: None,
+            on_bubble: None,
+        }
+    }
+
+    /// Sets the filter of the capture phase of the [`EventFilter`].
+    ///
+    /// It runs before the content gets to handle an event.
+    #[must_use]
+    pub fn on_capture(
+        mut self,
+        filter: impl Fn(&Event, Option<Point>) -> Filter<Message> + 'a,
+    ) -> Self {
+        self.on_capture = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the filter of the bubble phase of the [`EventFilter`].
+    ///
+    /// It runs after the content, only for events it did not capture.
+    #[must_use]
+    pub fn on_bubble(
+        mut self,
+        filter: impl Fn(&Event, Option<Point>) -> Filter<Message> + 'a,
+    ) -> Self {
+        self.on_bubble = Some(Box::new(filter));
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for EventFilter<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let position = cursor.position_in(layout.bounds());
+
+        if let Some(on_capture) = &self.on_capture {
+            if let event::Status::Captured =
+                apply(on_capture(&event, position), shell)
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        match &self.on_bubble {
+            Some(on_bubble) => apply(on_bubble(&event, position), shell),
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<EventFilter<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        filter: EventFilter<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(filter)
+    }
+}
+
+fn apply<Message>(
+    filter: Filter<Message>,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status {
+    match filter {
+        Filter::Pass => event::Status::Ignored,
+        Filter::Observe(message) => {
+            shell.publish(message);
+
+            event::Status::Ignored
+        }
+        Filter::Intercept(message) => {
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+
+            event::Status::Captured
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Feed random widget trees and limits through layout and check its
+//! invariants.
+use crate::core::alignment;
+use crate::core::layout::{self, invariant};
+use crate::core::widget::Tree;
+use crate::core::{Length, Size};
+use crate::{Column, Container, Row, Space, Theme};
+
+type Element = crate::core::Element<'static, (), Theme, ()>;
+
+const SEEDS: u64 = 1_000;
+const MAX_DEPTH: usize = 4;
+const MAX_CHILDREN: usize = 4;
+
+/// A tiny xorshift generator, so failures are reproducible from a seed.
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn float(&mut self, max: f32) -> f32 {
+        (self.below(10_000) as f32 / 10_000.0) * max
+    }
+}
+
+/// Generates random trees.
+///
+/// When `fluid`, the trees only use fluid lengths and no padding nor
+/// spacing; so their contents always fit and no overflow is expected.
+struct Generator {
+    random: Random,
+    fluid: bool,
+}
+
+impl Generator {
+    fn length(&mut self) -> Length {
+        match self.random.below(if self.fluid { 3 } else { 4 }) {
+            0 => Length::Fill,
+            1 => Length::FillPortion(1 + self.random.below(4) as u16),
+            2 => Length::Shrink,
+            _ => Length::Fixed(self.random.float(300.0)),
+        }
+    }
+
+    fn gap(&mut self) -> f32 {
+        if self.fluid {
+            0.0
+        } else {
+            self.random.float(10.0)
+        }
+    }
+
+    fn element(&mut self, depth: usize) -> Element {
+        let kind = if depth >= MAX_DEPTH {
+            0
+        } else {
+            self.random.below(4)
+        };
+
+        match kind {
+            0 => Space::new(self.length(), self.length()).into(),
+            1 => Column::with_children(self.children(depth))
+                .width(self.length())
+                .height(self.length())
+                .padding(self.gap())
+                .spacing(self.gap())
+                .into(),
+            2 => Row::with_children(self.children(depth))
+                .width(self.length())
+                .height(self.length())
+                .padding(self.gap())
+                .spacing(self.gap())
+                .into(),
+            _ => Container::new(self.element(depth + 1))
+                .width(self.length())
+                .height(self.length())
+                .padding(self.gap())
+                .align_x(alignment::Horizontal::Center)
+                .align_y(alignment::Vertical::Bottom)
+                .into(),
+        }
+    }
+
+    fn children(&mut self, depth: usize) -> Vec<Element> {
+        (0..self.random.below(MAX_CHILDREN as u64 + 1))
+            .map(|_| self.element(depth + 1))
+            .collect()
+    }
+
+    fn limits(&mut self) -> layout::Limits {
+        layout::Limits::new(
+            Size::ZERO,
+            Size::new(self.random.float(1_000.0), self.random.float(1_000.0)),
+        )
+    }
+}
+
+fn run(fluid: bool) {
+    for seed in 0..SEEDS {
+        let mut generator = Generator {
+            random: Random::new(seed),
+            fluid,
+        };
+
+        let element = generator.element(0);
+        let limits = generator.limits();
+
+        let mut tree = Tree::new(element.as_widget());
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+
+        for violation in invariant::check(&node, &limits) {
+            assert!(
+                !fluid && !violation.is_fatal(),
+                "seed {seed} (fluid: {fluid}): {violation}"
+            );
+        }
+    }
+}
+
+#[test]
+fn constrained_layouts_are_valid() {
+    run(false);
+}
+
+#[test]
+fn fluid_layouts_never_overflow() {
+    run(true);
+}
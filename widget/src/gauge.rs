@@ -0,0 +1,194 @@
+//! Display values on dashboards with dials, bargraphs, and readouts.
+//!
+//! Gauges are read-only widgets drawn with the geometry backend of the
+//! [`Canvas`]. They share the same [`Style`], which makes a set of them
+//! look consistent on a dashboard.
+//!
+//! [`Canvas`]: crate::Canvas
+mod bargraph;
+mod dial;
+mod readout;
+
+pub use bargraph::Bargraph;
+pub use dial::Dial;
+pub use readout::Readout;
+
+use crate::canvas::Frame;
+use crate::core::{Color, Point, Rectangle, Theme, Vector};
+use crate::graphics::geometry;
+
+use std::ops::RangeInclusive;
+
+/// The severity of a [`Zone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// The value needs attention.
+    Warning,
+    /// The value is dangerous.
+    Critical,
+}
+
+/// A range of values of a gauge that is highlighted with a [`Level`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Zone {
+    /// The values covered by the [`Zone`].
+    pub range: RangeInclusive<f32>,
+    /// The [`Level`] of the [`Zone`].
+    pub level: Level,
+}
+
+/// Returns the most severe [`Level`] of the zones containing the value.
+fn level_at(zones: &[Zone], value: f32) -> Option<Level> {
+    zones
+        .iter()
+        .filter(|zone| zone.range.contains(&value))
+        .map(|zone| zone.level)
+        .max()
+}
+
+/// Returns the position of the value in the range, from `0.0` to `1.0`.
+fn ratio(range: &RangeInclusive<f32>, value: f32) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    if start >= end {
+        0.0
+    } else {
+        ((value - start) / (end - start)).clamp(0.0, 1.0)
+    }
+}
+
+/// Draws a gauge on a new [`Frame`] covering the given bounds.
+fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    f: impl FnOnce(&mut Frame<Renderer>),
+) where
+    Renderer: geometry::Renderer,
+{
+    let mut frame = Frame::new(renderer, bounds.size());
+
+    f(&mut frame);
+
+    let geometry = frame.into_geometry();
+
+    renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+        renderer.draw_geometry(geometry);
+    });
+}
+
+/// The appearance of a gauge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background [`Color`] of the gauge.
+    pub background: Color,
+    /// The [`Color`] of the empty part of the track.
+    pub track: Color,
+    /// The [`Color`] of the filled part of the track.
+    pub value: Color,
+    /// The [`Color`] of the needle of a [`Dial`].
+    pub needle: Color,
+    /// The [`Color`] of the ticks of a [`Dial`].
+    pub tick: Color,
+    /// The [`Color`] of the text of a [`Dial`].
+    pub text: Color,
+    /// The [`Color`] of a [`Level::Warning`] zone.
+    pub warning: Color,
+    /// The [`Color`] of a [`Level::Critical`] zone.
+    pub critical: Color,
+    /// The [`Color`] of the lit segments of a [`Readout`].
+    pub segment_on: Color,
+    /// The [`Color`] of the unlit segments of a [`Readout`].
+    pub segment_off: Color,
+}
+
+impl Style {
+    /// Returns the [`Color`] of the given [`Level`].
+    pub fn level(&self, level: Level) -> Color {
+        match level {
+            Level::Warning => self.warning,
+            Level::Critical => self.critical,
+        }
+    }
+
+    /// Returns the [`Color`] of a value of a gauge, given its [`Level`].
+    fn value(&self, level: Option<Level>) -> Color {
+        level.map_or(self.value, |level| self.level(level))
+    }
+}
+
+/// The theme catalog of a gauge.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a gauge.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a gauge.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color,
+        track: palette.background.strong.color,
+        value: palette.primary.base.color,
+        needle: palette.background.base.text,
+        tick: palette.background.strong.text,
+        text: palette.background.weak.text,
+        warning: if palette.is_dark {
+            Color::from_rgb8(0xF5, 0xB7, 0x2F)
+        } else {
+            Color::from_rgb8(0xE0, 0x9A, 0x00)
+        },
+        critical: palette.danger.base.color,
+        segment_on: palette.primary.strong.color,
+        segment_off: palette.background.strong.color.scale_alpha(0.3),
+    }
+}
+
+/// A point at the given angle and distance from a center.
+fn polar(center: Point, angle: f32, distance: f32) -> Point {
+    center + Vector::new(angle.cos(), angle.sin()) * distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_severe_level() {
+        let zones = [
+            Zone {
+                range: 50.0..=100.0,
+                level: Level::Warning,
+            },
+            Zone {
+                range: 80.0..=100.0,
+                level: Level::Critical,
+            },
+        ];
+
+        assert_eq!(level_at(&zones, 10.0), None);
+        assert_eq!(level_at(&zones, 60.0), Some(Level::Warning));
+        assert_eq!(level_at(&zones, 90.0), Some(Level::Critical));
+    }
+}
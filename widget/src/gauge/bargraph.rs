@@ -0,0 +1,269 @@
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    Element, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+};
+use crate::gauge::{self, ratio, Catalog, Level, Style, StyleFn, Zone};
+use crate::graphics::geometry;
+
+use std::ops::RangeInclusive;
+
+/// A linear bargraph filled up to a value, with highlighted zones.
+///
+/// The filled part of a [`Bargraph`] takes the color of the most severe
+/// [`Zone`] containing the value, and the zones themselves are marked along
+/// its edge.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::gauge::{self, Level};
+///
+/// fn view<'a, Message: 'a>(temperature: f32) -> Element<'a, Message> {
+///     gauge::Bargraph::new(0.0..=120.0, temperature)
+///         .zone(90.0..=105.0, Level::Warning)
+///         .zone(105.0..=120.0, Level::Critical)
+///         .segments(24)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Bargraph<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    zones: Vec<Zone>,
+    segments: u16,
+    is_vertical: bool,
+    length: Length,
+    thickness: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Bargraph<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default thickness of a [`Bargraph`].
+    pub const DEFAULT_THICKNESS: f32 = 16.0;
+
+    /// The space between the segments of a [`Bargraph`].
+    const SEGMENT_GAP: f32 = 2.0;
+
+    /// The thickness of the zone markers of a [`Bargraph`], relative to the
+    /// thickness of the bar.
+    const ZONE_THICKNESS: f32 = 0.2;
+
+    /// Creates a new [`Bargraph`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values, and
+    ///   * the current value of the [`Bargraph`].
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            range,
+            value,
+            zones: Vec::new(),
+            segments: 0,
+            is_vertical: false,
+            length: Length::Fill,
+            thickness: Self::DEFAULT_THICKNESS,
+            class: Theme::default(),
+        }
+    }
+
+    /// Highlights the given range of values of the [`Bargraph`] with a
+    /// [`Level`].
+    pub fn zone(mut self, range: RangeInclusive<f32>, level: Level) -> Self {
+        self.zones.push(Zone { range, level });
+        self
+    }
+
+    /// Divides the [`Bargraph`] into the given amount of segments, like an
+    /// LED meter.
+    ///
+    /// Each segment takes the color of the zone of its own values. By
+    /// default, the [`Bargraph`] is continuous.
+    pub fn segments(mut self, segments: u16) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Sets whether the [`Bargraph`] is vertical; filling from the bottom
+    /// to the top.
+    pub fn vertical(mut self, is_vertical: bool) -> Self {
+        self.is_vertical = is_vertical;
+        self
+    }
+
+    /// Sets the length of the [`Bargraph`], along its direction.
+    pub fn length(mut self, length: impl Into<Length>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    /// Sets the thickness of the [`Bargraph`], across its direction.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Bargraph`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Bargraph`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn dimensions(&self) -> Size<Length> {
+        let thickness = Length::Fixed(self.thickness);
+
+        if self.is_vertical {
+            Size::new(thickness, self.length)
+        } else {
+            Size::new(self.length, thickness)
+        }
+    }
+
+    fn value_at(&self, ratio: f32) -> f32 {
+        self.range.start() + (self.range.end() - self.range.start()) * ratio
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Bargraph<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.dimensions()
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.dimensions();
+
+        layout::atomic(limits, size.width, size.height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let (length, thickness) = if self.is_vertical {
+            (bounds.height, bounds.width)
+        } else {
+            (bounds.width, bounds.height)
+        };
+
+        // Maps a span along the bar and across it to the bounds of the frame
+        let span = |from: f32, to: f32, across: f32, height: f32| {
+            if self.is_vertical {
+                (
+                    Point::new(across, length - to),
+                    Size::new(height, to - from),
+                )
+            } else {
+                (Point::new(from, across), Size::new(to - from, height))
+            }
+        };
+
+        gauge::draw(renderer, bounds, |frame| {
+            let zone_thickness = thickness * Self::ZONE_THICKNESS;
+            let bar_thickness = thickness - zone_thickness;
+
+            let filled = ratio(&self.range, self.value);
+
+            if self.segments == 0 {
+                let (position, size) = span(0.0, length, 0.0, bar_thickness);
+                frame.fill_rectangle(position, size, style.track);
+
+                let (position, size) =
+                    span(0.0, length * filled, 0.0, bar_thickness);
+
+                frame.fill_rectangle(
+                    position,
+                    size,
+                    style.value(gauge::level_at(&self.zones, self.value)),
+                );
+            } else {
+                let segments = f32::from(self.segments);
+                let segment = length / segments;
+
+                for i in 0..self.segments {
+                    let i = f32::from(i);
+
+                    let color = if filled * segments > i {
+                        style.value(gauge::level_at(
+                            &self.zones,
+                            self.value_at((i + 0.5) / segments),
+                        ))
+                    } else {
+                        style.track
+                    };
+
+                    let (position, size) = span(
+                        segment * i,
+                        segment * (i + 1.0) - Self::SEGMENT_GAP,
+                        0.0,
+                        bar_thickness,
+                    );
+
+                    frame.fill_rectangle(position, size, color);
+                }
+            }
+
+            for zone in &self.zones {
+                let (position, size) = span(
+                    length * ratio(&self.range, *zone.range.start()),
+                    length * ratio(&self.range, *zone.range.end()),
+                    bar_thickness,
+                    zone_thickness,
+                );
+
+                frame.fill_rectangle(position, size, style.level(zone.level));
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Bargraph<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(bargraph: Bargraph<'a, Theme>) -> Self {
+        Element::new(bargraph)
+    }
+}
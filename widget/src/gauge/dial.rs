@@ -0,0 +1,265 @@
+use crate::canvas::{path, LineCap, Path, Stroke, Text};
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    Element, Font, Layout, Length, Pixels, Point, Radians, Rectangle, Size,
+    Widget,
+};
+use crate::gauge::{self, polar, ratio, Catalog, Level, Style, StyleFn, Zone};
+use crate::graphics::geometry;
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+/// A circular dial with a needle pointing at a value.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::gauge::{self, Level};
+///
+/// fn view<'a, Message: 'a>(rpm: f32) -> Element<'a, Message> {
+///     gauge::Dial::new(0.0..=8000.0, rpm)
+///         .zone(6000.0..=7000.0, Level::Warning)
+///         .zone(7000.0..=8000.0, Level::Critical)
+///         .ticks(8)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Dial<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    zones: Vec<Zone>,
+    ticks: u16,
+    precision: usize,
+    size: f32,
+    font: Font,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Dial<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Dial`].
+    pub const DEFAULT_SIZE: f32 = 120.0;
+
+    /// The angle where the scale of a [`Dial`] starts, clockwise from the
+    /// positive x-axis.
+    const START: f32 = PI * 0.75;
+
+    /// The angle covered by the scale of a [`Dial`].
+    const SWEEP: f32 = PI * 1.5;
+
+    /// Creates a new [`Dial`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values, and
+    ///   * the current value of the [`Dial`].
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            range,
+            value,
+            zones: Vec::new(),
+            ticks: 10,
+            precision: 0,
+            size: Self::DEFAULT_SIZE,
+            font: Font::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Highlights the given range of values of the [`Dial`] with a
+    /// [`Level`].
+    pub fn zone(mut self, range: RangeInclusive<f32>, level: Level) -> Self {
+        self.zones.push(Zone { range, level });
+        self
+    }
+
+    /// Sets the amount of divisions of the scale of the [`Dial`].
+    pub fn ticks(mut self, ticks: u16) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the amount of decimals of the value displayed by the [`Dial`].
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the size of the [`Dial`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the [`Font`] of the value displayed by the [`Dial`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the style of the [`Dial`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Dial`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn angle(&self, value: f32) -> f32 {
+        Self::START + Self::SWEEP * ratio(&self.range, value)
+    }
+
+    fn arc(&self, center: Point, radius: f32, from: f32, to: f32) -> Path {
+        Path::new(|builder| {
+            builder.arc(path::Arc {
+                center,
+                radius,
+                start_angle: Radians(self.angle(from)),
+                end_angle: Radians(self.angle(to)),
+            });
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dial<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        gauge::draw(renderer, bounds, |frame| {
+            let size = bounds.width.min(bounds.height);
+            let center = frame.center();
+            let width = size * 0.06;
+            let radius = size / 2.0 - width;
+
+            let (start, end) = (*self.range.start(), *self.range.end());
+
+            frame.fill(&Path::circle(center, size / 2.0), style.background);
+
+            frame.stroke(
+                &self.arc(center, radius, start, end),
+                Stroke::default().with_width(width).with_color(style.track),
+            );
+
+            frame.stroke(
+                &self.arc(center, radius, start, self.value),
+                Stroke::default().with_width(width).with_color(
+                    style.value(gauge::level_at(&self.zones, self.value)),
+                ),
+            );
+
+            for zone in &self.zones {
+                frame.stroke(
+                    &self.arc(
+                        center,
+                        radius - width * 1.2,
+                        *zone.range.start(),
+                        *zone.range.end(),
+                    ),
+                    Stroke::default()
+                        .with_width(width * 0.4)
+                        .with_color(style.level(zone.level)),
+                );
+            }
+
+            for tick in 0..=self.ticks {
+                let angle = Self::START
+                    + Self::SWEEP * f32::from(tick)
+                        / f32::from(self.ticks.max(1));
+
+                frame.stroke(
+                    &Path::line(
+                        polar(center, angle, radius - width * 2.6),
+                        polar(center, angle, radius - width * 1.8),
+                    ),
+                    Stroke::default().with_width(1.5).with_color(style.tick),
+                );
+            }
+
+            let angle = self.angle(self.value);
+
+            frame.stroke(
+                &Path::line(center, polar(center, angle, radius - width * 1.8)),
+                Stroke::default()
+                    .with_width(size * 0.02)
+                    .with_color(style.needle)
+                    .with_line_cap(LineCap::Round),
+            );
+
+            frame.fill(&Path::circle(center, size * 0.05), style.needle);
+
+            frame.fill_text(Text {
+                content: format!("{:.*}", self.precision, self.value),
+                position: Point::new(center.x, center.y + radius * 0.55),
+                color: style.text,
+                size: Pixels(size * 0.14),
+                font: self.font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Dial<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(dial: Dial<'a, Theme>) -> Self {
+        Element::new(dial)
+    }
+}
@@ -0,0 +1,356 @@
+use crate::canvas::{Frame, Path};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+};
+use crate::gauge::{self, Catalog, Style, StyleFn};
+use crate::graphics::geometry;
+
+/// A numeric readout drawn like a seven-segment display.
+///
+/// A [`Readout`] displays digits, the hexadecimal letters, a few other
+/// letters that have a seven-segment form—like `H`, `L`, `P`, `r`, or
+/// `o`—and the `-`, `_`, `.`, `:`, and space characters. Any other
+/// character is left blank.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::gauge;
+///
+/// fn view<'a, Message: 'a>(voltage: f32) -> Element<'a, Message> {
+///     gauge::Readout::new(format!("{voltage:5.1}")).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Readout<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    content: String,
+    height: f32,
+    is_ghosted: bool,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Readout<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of the digits of a [`Readout`].
+    pub const DEFAULT_HEIGHT: f32 = 32.0;
+
+    /// Creates a new [`Readout`] displaying the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            height: Self::DEFAULT_HEIGHT,
+            is_ghosted: true,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the height of the digits of the [`Readout`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets whether the unlit segments of the [`Readout`] are drawn.
+    ///
+    /// It is enabled by default.
+    pub fn ghosted(mut self, is_ghosted: bool) -> Self {
+        self.is_ghosted = is_ghosted;
+        self
+    }
+
+    /// Sets the style of the [`Readout`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Readout`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn digit_width(&self) -> f32 {
+        self.height * 0.55
+    }
+
+    fn thickness(&self) -> f32 {
+        self.digit_width() * 0.18
+    }
+
+    fn padding(&self) -> f32 {
+        self.height * 0.15
+    }
+
+    fn spacing(&self) -> f32 {
+        self.height * 0.12
+    }
+
+    fn cell_width(&self, character: char) -> f32 {
+        match character {
+            '.' | ':' => self.thickness() * 1.5,
+            _ => self.digit_width(),
+        }
+    }
+
+    fn intrinsic_size(&self) -> Size {
+        let cells: f32 = self.content.chars().map(|c| self.cell_width(c)).sum();
+        let gaps = self.content.chars().count().saturating_sub(1) as f32;
+
+        Size::new(
+            cells + gaps * self.spacing() + self.padding() * 2.0,
+            self.height + self.padding() * 2.0,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Readout<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        let size = self.intrinsic_size();
+
+        Size {
+            width: Length::Fixed(size.width),
+            height: Length::Fixed(size.height),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.intrinsic_size();
+
+        layout::atomic(limits, size.width, size.height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        gauge::draw(renderer, bounds, |frame| {
+            frame.fill_rectangle(Point::ORIGIN, frame.size(), style.background);
+
+            let thickness = self.thickness();
+            let mut x = self.padding();
+            let y = self.padding();
+
+            for character in self.content.chars() {
+                let origin = Point::new(x, y);
+
+                match character {
+                    '.' => {
+                        dot(
+                            frame,
+                            origin,
+                            self.height,
+                            thickness,
+                            style.segment_on,
+                        );
+                    }
+                    ':' => {
+                        for height in [self.height * 0.7, self.height * 0.3] {
+                            dot(
+                                frame,
+                                origin,
+                                height,
+                                thickness,
+                                style.segment_on,
+                            );
+                        }
+                    }
+                    _ => {
+                        let lit = segments(character);
+
+                        for (i, segment) in
+                            self.segment_paths(origin).into_iter().enumerate()
+                        {
+                            let color = if lit & (1 << i) != 0 {
+                                style.segment_on
+                            } else if self.is_ghosted {
+                                style.segment_off
+                            } else {
+                                continue;
+                            };
+
+                            frame.fill(&segment, color);
+                        }
+                    }
+                }
+
+                x += self.cell_width(character) + self.spacing();
+            }
+        });
+    }
+}
+
+impl<'a, Theme> Readout<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Returns the seven segments of a digit, from `a` to `g`.
+    fn segment_paths(&self, origin: Point) -> [Path; 7] {
+        let width = self.digit_width();
+        let height = self.height;
+        let thickness = self.thickness();
+        let half = thickness / 2.0;
+        let gap = thickness * 0.15;
+
+        let horizontal = |y: f32| {
+            hexagon(
+                Point::new(origin.x + half + gap, origin.y + y),
+                Point::new(origin.x + width - half - gap, origin.y + y),
+                half,
+            )
+        };
+
+        let vertical = |x: f32, from: f32, to: f32| {
+            hexagon(
+                Point::new(origin.x + x, origin.y + from + gap),
+                Point::new(origin.x + x, origin.y + to - gap),
+                half,
+            )
+        };
+
+        [
+            horizontal(half),
+            vertical(width - half, half, height / 2.0),
+            vertical(width - half, height / 2.0, height - half),
+            horizontal(height - half),
+            vertical(half, height / 2.0, height - half),
+            vertical(half, half, height / 2.0),
+            horizontal(height / 2.0),
+        ]
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Readout<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(readout: Readout<'a, Theme>) -> Self {
+        Element::new(readout)
+    }
+}
+
+/// Returns the lit segments of a character, with segment `a` as the lowest
+/// bit.
+fn segments(character: char) -> u8 {
+    match character {
+        '0' | 'O' => 0x3F,
+        '1' => 0x06,
+        '2' => 0x5B,
+        '3' => 0x4F,
+        '4' => 0x66,
+        '5' | 'S' | 's' => 0x6D,
+        '6' => 0x7D,
+        '7' => 0x07,
+        '8' => 0x7F,
+        '9' => 0x6F,
+        'A' | 'a' => 0x77,
+        'B' | 'b' => 0x7C,
+        'C' => 0x39,
+        'c' => 0x58,
+        'D' | 'd' => 0x5E,
+        'E' | 'e' => 0x79,
+        'F' | 'f' => 0x71,
+        'H' => 0x76,
+        'h' => 0x74,
+        'L' | 'l' => 0x38,
+        'n' => 0x54,
+        'o' => 0x5C,
+        'P' | 'p' => 0x73,
+        'r' => 0x50,
+        't' => 0x78,
+        'U' => 0x3E,
+        'u' => 0x1C,
+        'y' => 0x6E,
+        '-' => 0x40,
+        '_' => 0x08,
+        _ => 0x00,
+    }
+}
+
+/// A segment between two points, with pointed ends.
+fn hexagon(from: Point, to: Point, half: f32) -> Path {
+    Path::new(|builder| {
+        if from.y == to.y {
+            builder.move_to(from);
+            builder.line_to(Point::new(from.x + half, from.y - half));
+            builder.line_to(Point::new(to.x - half, to.y - half));
+            builder.line_to(to);
+            builder.line_to(Point::new(to.x - half, to.y + half));
+            builder.line_to(Point::new(from.x + half, from.y + half));
+        } else {
+            builder.move_to(from);
+            builder.line_to(Point::new(from.x + half, from.y + half));
+            builder.line_to(Point::new(to.x + half, to.y - half));
+            builder.line_to(to);
+            builder.line_to(Point::new(to.x - half, to.y - half));
+            builder.line_to(Point::new(from.x - half, from.y + half));
+        }
+
+        builder.close();
+    })
+}
+
+fn dot<Renderer>(
+    frame: &mut Frame<Renderer>,
+    origin: Point,
+    height: f32,
+    thickness: f32,
+    color: Color,
+) where
+    Renderer: geometry::Renderer,
+{
+    frame.fill_rectangle(
+        Point::new(origin.x + thickness * 0.25, origin.y + height - thickness),
+        Size::new(thickness, thickness),
+        color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eight_lights_every_segment() {
+        assert_eq!(segments('8'), 0b111_1111);
+        assert_eq!(segments('-'), 1 << 6);
+        assert_eq!(segments('?'), 0);
+    }
+}
@@ -0,0 +1,953 @@
+//! Edit the color stops of a linear gradient.
+//!
+//! A [`GradientEditor`] displays a [`Linear`] gradient as a bar with a
+//! handle for each of its color stops. Clicking the bar adds a stop, dragging
+//! a handle moves it—or removes it, when dragged away from the bar—and
+//! selecting a handle opens a color picker below it.
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::gradient::{ColorStop, Linear};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Radians, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// An editor of the color stops of a [`Linear`] gradient.
+///
+/// A [`Linear`] gradient holds up to 8 color stops; the [`GradientEditor`]
+/// does not add stops past that limit, and it always keeps at least one.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::core::gradient::Linear;
+/// use iced_widget::core::{Color, Degrees};
+/// use iced_widget::gradient_editor;
+///
+/// struct State {
+///     gradient: Linear,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     GradientChanged(Linear),
+/// }
+///
+/// impl Default for State {
+///     fn default() -> Self {
+///         Self {
+///             gradient: Linear::new(Degrees(90.0))
+///                 .add_stop(0.0, Color::BLACK)
+///                 .add_stop(1.0, Color::WHITE),
+///         }
+///     }
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     gradient_editor(state.gradient, Message::GradientChanged).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GradientEditor<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    gradient: Linear,
+    on_change: Box<dyn Fn(Linear) -> Message + 'a>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> GradientEditor<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of the bar of a [`GradientEditor`].
+    pub const DEFAULT_HEIGHT: f32 = 24.0;
+
+    /// The size of the handles of the color stops.
+    pub const HANDLE_SIZE: f32 = 12.0;
+
+    /// The distance a handle needs to be dragged away from the bar to be
+    /// removed.
+    pub const REMOVE_DISTANCE: f32 = 32.0;
+
+    /// Creates a new [`GradientEditor`].
+    ///
+    /// It expects:
+    ///   * the [`Linear`] gradient to edit, and
+    ///   * a function that produces a message with the edited gradient.
+    ///
+    /// The angle of the gradient is preserved, but the editor always displays
+    /// it from left to right.
+    pub fn new(
+        gradient: Linear,
+        on_change: impl Fn(Linear) -> Message + 'a,
+    ) -> Self {
+        Self {
+            gradient,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`GradientEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the bar of the [`GradientEditor`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the style of the [`GradientEditor`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`GradientEditor`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn stops(&self) -> Vec<ColorStop> {
+        self.gradient.stops.iter().flatten().copied().collect()
+    }
+
+    /// Publishes the given stops and returns the new index of the given
+    /// stop among them.
+    fn publish(
+        &self,
+        stops: Vec<ColorStop>,
+        stop: Option<ColorStop>,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<usize> {
+        let gradient = Linear::new(self.gradient.angle).add_stops(stops);
+
+        shell.publish((self.on_change)(gradient));
+
+        let stop = stop?;
+
+        gradient
+            .stops
+            .iter()
+            .flatten()
+            .position(|other| *other == stop)
+    }
+
+    fn bar(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + Self::HANDLE_SIZE / 2.0,
+            y: bounds.y,
+            width: (bounds.width - Self::HANDLE_SIZE).max(0.0),
+            height: self.height,
+        }
+    }
+
+    fn handle(&self, bounds: Rectangle, stop: &ColorStop) -> Rectangle {
+        let bar = self.bar(bounds);
+
+        Rectangle {
+            x: bar.x + bar.width * stop.offset - Self::HANDLE_SIZE / 2.0,
+            y: bar.y + bar.height + HANDLE_GAP,
+            width: Self::HANDLE_SIZE,
+            height: Self::HANDLE_SIZE,
+        }
+    }
+
+    fn offset(&self, bounds: Rectangle, x: f32) -> f32 {
+        let bar = self.bar(bounds);
+
+        if bar.width == 0.0 {
+            0.0
+        } else {
+            ((x - bar.x) / bar.width).clamp(0.0, 1.0)
+        }
+    }
+
+    fn stop_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let stops = self.stops();
+
+        stops
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, stop)| {
+                self.handle(bounds, stop).expand(2.0).contains(position)
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+const HANDLE_GAP: f32 = 2.0;
+const MAX_STOPS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    Stop(usize),
+    Saturation,
+    Hue,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    selected: Option<usize>,
+    drag: Option<Drag>,
+    hsv: [f32; 3],
+}
+
+impl State {
+    fn select(&mut self, index: usize, stop: &ColorStop) {
+        self.selected = Some(index);
+        self.hsv = stop.color.into_hsv();
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for GradientEditor<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(
+            limits,
+            self.width,
+            self.height + HANDLE_GAP + Self::HANDLE_SIZE,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let mut stops = self.stops();
+
+        if state.selected.is_some_and(|index| index >= stops.len()) {
+            *state = State::default();
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.selected = None;
+
+                    return event::Status::Ignored;
+                };
+
+                if let Some(index) = self.stop_at(bounds, position) {
+                    state.select(index, &stops[index]);
+                    state.drag = Some(Drag::Stop(index));
+
+                    return event::Status::Captured;
+                }
+
+                if !self.bar(bounds).contains(position)
+                    || stops.len() >= MAX_STOPS
+                {
+                    state.selected = None;
+
+                    return event::Status::Captured;
+                }
+
+                let offset = self.offset(bounds, position.x);
+                let stop = ColorStop {
+                    offset,
+                    color: color_at(&stops, offset),
+                };
+
+                stops.push(stop);
+
+                if let Some(index) = self.publish(stops, Some(stop), shell) {
+                    state.select(index, &stop);
+                    state.drag = Some(Drag::Stop(index));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(Drag::Stop(index)), Some(position)) =
+                    (state.drag, cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let offset = self.offset(bounds, position.x);
+
+                if index < stops.len() && stops[index].offset != offset {
+                    stops[index].offset = offset;
+
+                    let stop = stops[index];
+
+                    if let Some(index) = self.publish(stops, Some(stop), shell)
+                    {
+                        state.selected = Some(index);
+                        state.drag = Some(Drag::Stop(index));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(Drag::Stop(index)) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                let is_away = cursor.position().is_some_and(|position| {
+                    position.y < bounds.y - Self::REMOVE_DISTANCE
+                        || position.y
+                            > bounds.y + bounds.height + Self::REMOVE_DISTANCE
+                });
+
+                if is_away && index < stops.len() && stops.len() > 1 {
+                    let _ = stops.remove(index);
+                    let _ = self.publish(stops, None, shell);
+
+                    state.selected = None;
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Named(key::Named::Delete | key::Named::Backspace),
+                ..
+            }) => {
+                let Some(index) = state.selected else {
+                    return event::Status::Ignored;
+                };
+
+                if stops.len() > 1 {
+                    let _ = stops.remove(index);
+                    let _ = self.publish(stops, None, shell);
+                }
+
+                state.selected = None;
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if state.selected.is_some() => {
+                state.selected = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(Drag::Stop(_)) = state.drag {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if self.stop_at(bounds, position).is_some() {
+            mouse::Interaction::Grab
+        } else if self.bar(bounds).contains(position)
+            && self.stops().len() < MAX_STOPS
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let bar = self.bar(bounds);
+
+        let status = if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                border: style.bar_border,
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(
+                Linear {
+                    angle: Radians(FRAC_PI_2),
+                    ..self.gradient
+                }
+                .into(),
+            ),
+        );
+
+        for (index, stop) in self.stops().iter().enumerate() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.handle(bounds, stop),
+                    border: if state.selected == Some(index) {
+                        style.selected_handle_border
+                    } else {
+                        style.handle_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                stop.color,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let stop = *self.stops().get(state.selected?)?;
+
+        if let Some(Drag::Stop(_)) = state.drag {
+            return None;
+        }
+
+        let anchor = self.handle(layout.bounds(), &stop) + translation;
+
+        Some(overlay::Element::new(Box::new(Picker {
+            editor: self,
+            state,
+            anchor,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<GradientEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(gradient_editor: GradientEditor<'a, Message, Theme>) -> Self {
+        Element::new(gradient_editor)
+    }
+}
+
+/// Returns the color of the gradient formed by the given stops at the given
+/// offset.
+fn color_at(stops: &[ColorStop], offset: f32) -> Color {
+    let after = stops.iter().position(|stop| stop.offset >= offset);
+
+    match after {
+        None => stops.last().map_or(Color::BLACK, |stop| stop.color),
+        Some(0) => stops[0].color,
+        Some(index) => {
+            let before = stops[index - 1];
+            let after = stops[index];
+
+            let span = after.offset - before.offset;
+
+            if span == 0.0 {
+                after.color
+            } else {
+                before
+                    .color
+                    .mix(after.color, (offset - before.offset) / span)
+            }
+        }
+    }
+}
+
+/// The color picker of the selected stop of a [`GradientEditor`].
+struct Picker<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    editor: &'b GradientEditor<'a, Message, Theme>,
+    state: &'b mut State,
+    anchor: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme> Picker<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    const PADDING: Padding = Padding::new(8.0);
+    const SIZE: f32 = 160.0;
+    const HUE_HEIGHT: f32 = 12.0;
+    const GAP: f32 = 4.0;
+
+    fn saturation_value(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + Self::PADDING.left,
+            y: bounds.y + Self::PADDING.top,
+            width: Self::SIZE,
+            height: Self::SIZE,
+        }
+    }
+
+    fn hue(bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + Self::PADDING.left,
+            y: bounds.y + Self::PADDING.top + Self::SIZE + Self::PADDING.top,
+            width: Self::SIZE,
+            height: Self::HUE_HEIGHT,
+        }
+    }
+
+    fn pick(
+        &mut self,
+        drag: Drag,
+        bounds: Rectangle,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(index) = self.state.selected else {
+            return;
+        };
+
+        let mut stops = self.editor.stops();
+
+        let Some(stop) = stops.get_mut(index) else {
+            return;
+        };
+
+        let [hue, saturation, value] = &mut self.state.hsv;
+
+        match drag {
+            Drag::Saturation => {
+                let area = Self::saturation_value(bounds);
+
+                *saturation =
+                    ((position.x - area.x) / area.width).clamp(0.0, 1.0);
+                *value =
+                    1.0 - ((position.y - area.y) / area.height).clamp(0.0, 1.0);
+            }
+            Drag::Hue => {
+                let area = Self::hue(bounds);
+
+                *hue = ((position.x - area.x) / area.width).clamp(0.0, 1.0)
+                    * 359.9;
+            }
+            Drag::Stop(_) => return,
+        }
+
+        stop.color = Color {
+            a: stop.color.a,
+            ..Color::from_hsv(*hue, *saturation, *value)
+        };
+
+        let stop = *stop;
+
+        if let Some(index) = self.editor.publish(stops, Some(stop), shell) {
+            self.state.selected = Some(index);
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Picker<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = Size::new(
+            Self::SIZE,
+            Self::SIZE + Self::PADDING.top + Self::HUE_HEIGHT,
+        )
+        .expand(Self::PADDING);
+
+        let bounds = overlay::Placement::new(overlay::placement::Side::Bottom)
+            .gap(Self::GAP)
+            .resolve(self.anchor, size, Rectangle::with_size(bounds));
+
+        layout::Node::new(size).move_to(bounds.position())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let drag = if Self::saturation_value(bounds).contains(position)
+                {
+                    Some(Drag::Saturation)
+                } else if Self::hue(bounds).contains(position) {
+                    Some(Drag::Hue)
+                } else {
+                    None
+                };
+
+                if let Some(drag) = drag {
+                    self.state.drag = Some(drag);
+                    self.pick(drag, bounds, position, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(drag), Some(position)) =
+                    (self.state.drag, cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                self.pick(drag, bounds, position, shell);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if self.state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if cursor.is_over(Self::saturation_value(bounds)) {
+            mouse::Interaction::Crosshair
+        } else if cursor.is_over(Self::hue(bounds)) {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Idle
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.editor.class, Status::Active);
+        let [hue, saturation, value] = self.state.hsv;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.picker_border,
+                ..renderer::Quad::default()
+            },
+            style.picker_background,
+        );
+
+        let area = Self::saturation_value(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: area,
+                ..renderer::Quad::default()
+            },
+            Color::from_hsv(hue, 1.0, 1.0),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: area,
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(
+                Linear::new(FRAC_PI_2)
+                    .add_stop(0.0, Color::WHITE)
+                    .add_stop(1.0, Color::WHITE.scale_alpha(0.0))
+                    .into(),
+            ),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: area,
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(
+                Linear::new(PI)
+                    .add_stop(0.0, Color::BLACK.scale_alpha(0.0))
+                    .add_stop(1.0, Color::BLACK)
+                    .into(),
+            ),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(
+                        area.x + area.width * saturation - MARKER / 2.0,
+                        area.y + area.height * (1.0 - value) - MARKER / 2.0,
+                    ),
+                    Size::new(MARKER, MARKER),
+                ),
+                border: style.marker_border,
+                ..renderer::Quad::default()
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+
+        let hues = Self::hue(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: hues,
+                border: border::rounded(2),
+                ..renderer::Quad::default()
+            },
+            Background::Gradient(
+                Linear::new(FRAC_PI_2)
+                    .add_stops((0..=6).map(|i| ColorStop {
+                        offset: i as f32 / 6.0,
+                        color: Color::from_hsv(i as f32 * 60.0, 1.0, 1.0),
+                    }))
+                    .into(),
+            ),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: hues.x + hues.width * hue / 360.0 - MARKER / 4.0,
+                    width: MARKER / 2.0,
+                    ..hues
+                },
+                border: style.marker_border,
+                ..renderer::Quad::default()
+            },
+            Background::Color(Color::TRANSPARENT),
+        );
+    }
+}
+
+const MARKER: f32 = 10.0;
+
+/// The possible status of a [`GradientEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`GradientEditor`] can be interacted with.
+    Active,
+    /// The [`GradientEditor`] is being hovered.
+    Hovered,
+    /// A stop of the [`GradientEditor`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`GradientEditor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Border`] of the gradient bar.
+    pub bar_border: Border,
+    /// The [`Border`] of the handle of a stop.
+    pub handle_border: Border,
+    /// The [`Border`] of the handle of the selected stop.
+    pub selected_handle_border: Border,
+    /// The [`Background`] of the color picker.
+    pub picker_background: Background,
+    /// The [`Border`] of the color picker.
+    pub picker_border: Border,
+    /// The [`Border`] of the markers of the color picker.
+    pub marker_border: Border,
+}
+
+/// The theme catalog of a [`GradientEditor`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`GradientEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`GradientEditor`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let handle_color = match status {
+        Status::Active => palette.background.strong.color,
+        Status::Hovered | Status::Dragged => palette.background.base.text,
+    };
+
+    Style {
+        bar_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        handle_border: Border {
+            color: handle_color,
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+        selected_handle_border: Border {
+            color: palette.primary.strong.color,
+            width: 2.0,
+            radius: 2.0.into(),
+        },
+        picker_background: palette.background.base.color.into(),
+        picker_border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        marker_border: Border {
+            color: Color::WHITE,
+            width: 2.0,
+            radius: MARKER.into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_between_stops() {
+        let stops = [
+            ColorStop {
+                offset: 0.25,
+                color: Color::BLACK,
+            },
+            ColorStop {
+                offset: 0.75,
+                color: Color::WHITE,
+            },
+        ];
+
+        assert_eq!(color_at(&stops, 0.0), Color::BLACK);
+        assert_eq!(color_at(&stops, 1.0), Color::WHITE);
+        assert_eq!(color_at(&stops, 0.5), Color::BLACK.mix(Color::WHITE, 0.5));
+    }
+}
@@ -2,40 +2,73 @@
 use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
+use crate::command_palette::{self, CommandPalette};
 use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::window;
 use crate::core::{Element, Length, Pixels, Widget};
+use crate::event_filter::EventFilter;
+use crate::gradient_editor::{self, GradientEditor};
 use crate::keyed;
+use crate::log_view::{self, LogView};
+use crate::minimap::{self, Minimap};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
+use crate::portal::Portal;
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::range_slider::RangeSlider;
 use crate::rule::{self, Rule};
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::spectrum::{self, Spectrum};
+use crate::spotlight::{self, Spotlight};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::timeline::{self, Timeline};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, MouseArea, Row, Space, Stack, Themer};
+use crate::virtual_keyboard::{self, VirtualKeyboard};
+use crate::waveform::{self, Waveform};
+use crate::{Column, KeyListener, MouseArea, Row, Space, Stack, Themer};
 
 use std::borrow::{Borrow, Cow};
 use std::ops::RangeInclusive;
 
 /// Creates a [`Column`] with the given children.
 ///
+/// The children can be preceded by a list of method calls to configure the
+/// [`Column`], followed by a semicolon; like `column![spacing(10); a, b]`.
+///
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::Alignment::Center; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, row, text};
+/// use iced::Center;
+///
+/// fn view<'a, Message: 'a>() -> Element<'a, Message> {
+///     column![spacing(10), padding(20), align_x(Center);
+///         text("Hello"),
+///         row![spacing(5); text("from"), text("iced")],
+///     ]
+///     .into()
+/// }
+/// ```
+///
 /// [`Column`]: crate::Column
 #[macro_export]
 macro_rules! column {
     () => (
         $crate::Column::new()
     );
+    ($($method:ident($($argument:expr),* $(,)?)),+ $(,)?; $($x:expr),* $(,)?) => (
+        $crate::column![$($x),*]$(.$method($($argument),*))+
+    );
     ($($x:expr),+ $(,)?) => (
         $crate::Column::with_children([$($crate::core::Element::from($x)),+])
     );
@@ -43,12 +76,18 @@ macro_rules! column {
 
 /// Creates a [`Row`] with the given children.
 ///
+/// The children can be preceded by a list of method calls to configure the
+/// [`Row`], followed by a semicolon; like `row![spacing(10); a, b]`.
+///
 /// [`Row`]: crate::Row
 #[macro_export]
 macro_rules! row {
     () => (
         $crate::Row::new()
     );
+    ($($method:ident($($argument:expr),* $(,)?)),+ $(,)?; $($x:expr),* $(,)?) => (
+        $crate::row![$($x),*]$(.$method($($argument),*))+
+    );
     ($($x:expr),+ $(,)?) => (
         $crate::Row::with_children([$($crate::core::Element::from($x)),+])
     );
@@ -56,12 +95,18 @@ macro_rules! row {
 
 /// Creates a [`Stack`] with the given children.
 ///
+/// The children can be preceded by a list of method calls to configure the
+/// [`Stack`], followed by a semicolon; like `stack![width(Fill); a, b]`.
+///
 /// [`Stack`]: crate::Stack
 #[macro_export]
 macro_rules! stack {
     () => (
         $crate::Stack::new()
     );
+    ($($method:ident($($argument:expr),* $(,)?)),+ $(,)?; $($x:expr),* $(,)?) => (
+        $crate::stack![$($x),*]$(.$method($($argument),*))+
+    );
     ($($x:expr),+ $(,)?) => (
         $crate::Stack::with_children([$($crate::core::Element::from($x)),+])
     );
@@ -182,6 +227,28 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`keyed::Row`] with the given children.
+pub fn keyed_row<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> keyed::Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: core::Renderer,
+{
+    keyed::Row::with_children(children)
+}
+
+/// Creates a new [`keyed::Switch`] showing the branch with the given key.
+pub fn keyed_switch<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> keyed::Switch<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + std::hash::Hash,
+{
+    keyed::Switch::new(key, content)
+}
+
 /// Creates a new [`Row`] with the given children.
 ///
 /// [`Row`]: crate::Row
@@ -681,6 +748,35 @@ where
     Tooltip::new(content, tooltip, position)
 }
 
+/// Creates a new [`Spotlight`] of the widget with the given
+/// [`container::Id`] inside of the content, with the given coach mark.
+///
+/// [`Spotlight`]: crate::Spotlight
+pub fn spotlight<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    target: container::Id,
+    coach_mark: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Spotlight<'a, Message, Theme, Renderer>
+where
+    Theme: spotlight::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Spotlight::new(content, target, coach_mark)
+}
+
+/// Creates a new [`Portal`] that renders the given content on top of
+/// everything else.
+///
+/// [`Portal`]: crate::Portal
+pub fn portal<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Portal<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Portal::new(content)
+}
+
 /// Creates a new [`Text`] widget with the provided content.
 pub fn text<'a, Theme, Renderer>(
     text: impl text::IntoFragment<'a>,
@@ -806,6 +902,85 @@ where
     TextEditor::new(content)
 }
 
+/// Creates a new [`LogView`] displaying the given [`log_view::Content`].
+pub fn log_view<'a, Theme, Renderer>(
+    content: &'a log_view::Content,
+) -> LogView<'a, Theme, Renderer>
+where
+    Theme: log_view::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    LogView::new(content)
+}
+
+/// Creates a new [`GradientEditor`] for the given [`Linear`] gradient.
+///
+/// [`Linear`]: core::gradient::Linear
+pub fn gradient_editor<'a, Message, Theme>(
+    gradient: core::gradient::Linear,
+    on_change: impl Fn(core::gradient::Linear) -> Message + 'a,
+) -> GradientEditor<'a, Message, Theme>
+where
+    Theme: gradient_editor::Catalog + 'a,
+{
+    GradientEditor::new(gradient, on_change)
+}
+
+/// Creates a new [`Minimap`] of the given content.
+///
+/// It expects:
+///   * the content to draw scaled down,
+///   * the full [`Size`] of the content, and
+///   * the region of the content that is currently visible.
+///
+/// [`Size`]: core::Size
+pub fn minimap<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content_size: core::Size,
+    visible: core::Rectangle,
+) -> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: minimap::Catalog + 'a,
+    Renderer: core::Renderer,
+{
+    Minimap::new(content, content_size, visible)
+}
+
+/// Creates a new [`Timeline`] with the given [`Track`]s.
+///
+/// [`Track`]: timeline::Track
+pub fn timeline<'a, Message, Theme, Renderer>(
+    tracks: &'a [timeline::Track],
+) -> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: timeline::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    Timeline::new(tracks)
+}
+
+/// Creates a new [`Waveform`] displaying the given [`Samples`].
+///
+/// [`Samples`]: waveform::Samples
+pub fn waveform<'a, Message, Theme>(
+    samples: impl Into<waveform::Samples<'a>>,
+) -> Waveform<'a, Message, Theme>
+where
+    Theme: waveform::Catalog + 'a,
+{
+    Waveform::new(samples)
+}
+
+/// Creates a new [`Spectrum`] displaying the given magnitudes.
+pub fn spectrum<'a, Theme>(
+    magnitudes: impl Into<waveform::Samples<'a>>,
+) -> Spectrum<'a, Theme>
+where
+    Theme: spectrum::Catalog + 'a,
+{
+    Spectrum::new(magnitudes)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: crate::Slider
@@ -822,6 +997,22 @@ where
     Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`RangeSlider`].
+///
+/// [`RangeSlider`]: crate::RangeSlider
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    value: std::ops::RangeInclusive<T>,
+    on_change: impl Fn(std::ops::RangeInclusive<T>) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: slider::Catalog + 'a,
+{
+    RangeSlider::new(range, value, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// [`VerticalSlider`]: crate::VerticalSlider
@@ -838,6 +1029,18 @@ where
     VerticalSlider::new(range, value, on_change)
 }
 
+/// Creates a new [`VirtualKeyboard`] displayed below the given content
+/// whenever one of its widgets is focused.
+pub fn virtual_keyboard<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> VirtualKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: virtual_keyboard::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    VirtualKeyboard::new(content)
+}
+
 /// Creates a new [`PickList`].
 ///
 /// [`PickList`]: crate::PickList
@@ -874,6 +1077,22 @@ where
     ComboBox::new(state, placeholder, selection, on_selected)
 }
 
+/// Creates a new [`CommandPalette`] displayed on top of the given content.
+///
+/// [`CommandPalette`]: crate::CommandPalette
+pub fn command_palette<'a, T, Message, Theme, Renderer>(
+    state: &'a command_palette::State<T>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: std::fmt::Display + Clone,
+    Theme: command_palette::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    CommandPalette::new(state, content, on_selected)
+}
+
 /// Creates a new [`Space`] widget that fills the available
 /// horizontal space.
 ///
@@ -1012,6 +1231,22 @@ where
     crate::QRCode::new(data)
 }
 
+/// Creates a new [`Map`] drawing the given [`Tiles`] in a [`Viewport`].
+///
+/// [`Map`]: crate::Map
+/// [`Tiles`]: crate::map::Tiles
+/// [`Viewport`]: crate::map::Viewport
+#[cfg(feature = "map")]
+pub fn map<'a, Message, Theme>(
+    tiles: &'a crate::map::Tiles,
+    viewport: crate::map::Viewport,
+) -> crate::Map<'a, Message, Theme>
+where
+    Theme: crate::map::Catalog + 'a,
+{
+    crate::Map::new(tiles, viewport)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -1043,6 +1278,26 @@ where
     MouseArea::new(widget)
 }
 
+/// A container observing or intercepting the events of its contents.
+pub fn event_filter<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> EventFilter<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    EventFilter::new(widget)
+}
+
+/// A container listening to keyboard events while its contents are focused.
+pub fn key_listener<'a, Message, Theme, Renderer>(
+    widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> KeyListener<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    KeyListener::new(widget)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
@@ -187,6 +187,7 @@ pub fn draw<Renderer, Handle>(
                 rotation: rotation.radians(),
                 opacity,
                 snap: true,
+                tint: None,
             },
             drawing_bounds,
         );
@@ -355,6 +355,7 @@ where
                         rotation: Radians(0.0),
                         opacity: 1.0,
                         snap: true,
+                        tint: None,
                     },
                     drawing_bounds,
                 );
@@ -0,0 +1,244 @@
+//! A container for listening to keyboard events.
+use crate::core::event::{self, Event};
+use crate::core::keyboard::{self, Key, Modifiers};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::{self, focusable, Operation};
+use crate::core::widget::Tree;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// Emit messages on keyboard events while some widget inside is focused.
+///
+/// Key events captured by the content—like the text typed into a focused
+/// text input—are not reported.
+#[allow(missing_debug_implementations)]
+pub struct KeyListener<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_key_press: Option<Box<dyn Fn(Key, Modifiers) -> Option<Message> + 'a>>,
+    on_key_release: Option<Box<dyn Fn(Key, Modifiers) -> Option<Message> + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> KeyListener<'a, Message, Theme, Renderer> {
+    /// Creates a [`KeyListener`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        KeyListener {
+            content: content.into(),
+            on_key_press: None,
+            on_key_release: None,
+        }
+    }
+
+    /// Sets the function that will be called when a key is pressed while
+    /// the content of the [`KeyListener`] is focused.
+    ///
+    /// If it returns `None`, the key press will keep propagating.
+    #[must_use]
+    pub fn on_key_press(
+        mut self,
+        build_message: impl Fn(Key, Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_key_press = Some(Box::new(build_message));
+        self
+    }
+
+    /// Sets the function that will be called when a key is released while
+    /// the content of the [`KeyListener`] is focused.
+    ///
+    /// If it returns `None`, the key release will keep propagating.
+    #[must_use]
+    pub fn on_key_release(
+        mut self,
+        build_message: impl Fn(Key, Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_key_release = Some(Box::new(build_message));
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for KeyListener<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let (build_message, key, modifiers) = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => (self.on_key_press.as_ref(), key, modifiers),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                modifiers,
+                ..
+            }) => (self.on_key_release.as_ref(), key, modifiers),
+            _ => return event::Status::Ignored,
+        };
+
+        let Some(build_message) = build_message else {
+            return event::Status::Ignored;
+        };
+
+        let mut is_any_focused = focusable::is_any_focused();
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut operation::black_box(&mut is_any_focused),
+        );
+
+        if !matches!(is_any_focused.finish(), operation::Outcome::Some(true)) {
+            return event::Status::Ignored;
+        }
+
+        match build_message(key, modifiers) {
+            Some(message) => {
+                shell.publish(message);
+
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<KeyListener<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        listener: KeyListener<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(listener)
+    }
+}
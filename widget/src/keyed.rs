@@ -37,9 +37,16 @@
 //! The widgets in this module will all ask for a "hint" of some sort. In order
 //! to help them keep continuity, you need to make sure the hint stays the same
 //! for the same items in your user interface between `view` calls.
+//!
+//! A [`Switch`] takes this further for whole branches of a view; like tabs,
+//! keeping the state of every branch under its key while it is hidden.
 pub mod column;
+pub mod row;
+pub mod switch;
 
 pub use column::Column;
+pub use row::Row;
+pub use switch::Switch;
 
 /// Creates a [`Column`] with the given children.
 #[macro_export]
@@ -0,0 +1,232 @@
+//! Switch between branches of a view while preserving their state.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A widget that shows one of many branches of a view, identified by a key.
+///
+/// Normally, switching between branches with different widgets (like tabs or
+/// the pages of a wizard) resets the state of the widgets of every branch
+/// shown; losing scroll positions, text selections, and so on.
+///
+/// A [`Switch`] stashes the state of the branch that is being hidden under
+/// its key, and restores it when a branch with the same key is shown again.
+/// The [`Switch`] itself must stay in the same place of the widget tree
+/// between `view` calls.
+///
+/// The state of every branch shown is kept for as long as the [`Switch`]
+/// lives; so the keys should be drawn from a bounded set.
+#[allow(missing_debug_implementations)]
+pub struct Switch<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Key: Clone + Eq + Hash,
+{
+    key: Key,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    Switch<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash,
+{
+    /// Creates a new [`Switch`] showing the branch with the given key.
+    pub fn new(
+        key: Key,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            key,
+            content: content.into(),
+        }
+    }
+}
+
+struct State<Key> {
+    current: Key,
+    stash: HashMap<Key, Tree>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Switch<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            current: self.key.clone(),
+            stash: HashMap::new(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+
+        if state.current != self.key {
+            let restored = state
+                .stash
+                .remove(&self.key)
+                .unwrap_or_else(|| Tree::new(&self.content));
+
+            let hidden = std::mem::replace(&mut children[0], restored);
+            let previous =
+                std::mem::replace(&mut state.current, self.key.clone());
+
+            let _ = state.stash.insert(previous, hidden);
+        }
+
+        children[0].diff(&self.content);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<Switch<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(switch: Switch<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(switch)
+    }
+}
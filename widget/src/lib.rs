@@ -9,6 +9,7 @@ pub use iced_runtime as runtime;
 pub use iced_runtime::core;
 
 mod column;
+mod key_listener;
 mod mouse_area;
 mod row;
 mod space;
@@ -18,27 +19,42 @@ mod themer;
 pub mod button;
 pub mod checkbox;
 pub mod combo_box;
+pub mod command_palette;
 pub mod container;
+pub mod event_filter;
+pub mod gradient_editor;
 pub mod keyed;
+pub mod log_view;
+pub mod minimap;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
+pub mod portal;
 pub mod progress_bar;
 pub mod radio;
+pub mod range_slider;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spectrum;
+pub mod spotlight;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
+pub mod timeline;
 pub mod toggler;
 pub mod tooltip;
 pub mod vertical_slider;
+pub mod virtual_keyboard;
+pub mod waveform;
 
 mod helpers;
 
 pub use helpers::*;
 
+#[cfg(test)]
+mod fuzz;
+
 #[cfg(feature = "lazy")]
 mod lazy;
 
@@ -57,18 +73,34 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use combo_box::ComboBox;
 #[doc(no_inline)]
+pub use command_palette::CommandPalette;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use event_filter::EventFilter;
+#[doc(no_inline)]
+pub use gradient_editor::GradientEditor;
+#[doc(no_inline)]
+pub use key_listener::KeyListener;
+#[doc(no_inline)]
+pub use log_view::LogView;
+#[doc(no_inline)]
+pub use minimap::Minimap;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
+pub use portal::Portal;
+#[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
@@ -79,6 +111,10 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spectrum::Spectrum;
+#[doc(no_inline)]
+pub use spotlight::Spotlight;
+#[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
 pub use text::Text;
@@ -89,11 +125,17 @@ pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use themer::Themer;
 #[doc(no_inline)]
+pub use timeline::Timeline;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use virtual_keyboard::VirtualKeyboard;
+#[doc(no_inline)]
+pub use waveform::Waveform;
 
 #[cfg(feature = "wgpu")]
 pub mod shader;
@@ -123,6 +165,9 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+pub mod gauge;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
@@ -133,5 +178,12 @@ pub use qr_code::QRCode;
 #[cfg(feature = "markdown")]
 pub mod markdown;
 
+#[cfg(feature = "map")]
+pub mod map;
+
+#[cfg(feature = "map")]
+#[doc(no_inline)]
+pub use map::Map;
+
 pub use crate::core::theme::{self, Theme};
 pub use renderer::Renderer;
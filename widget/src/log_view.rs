@@ -0,0 +1,528 @@
+//! Display an append-only log of colored lines.
+//!
+//! A [`LogView`] only renders the lines that are currently visible and keeps
+//! following the tail of its [`Content`] until the user scrolls up.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Text};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Widget,
+};
+
+use std::collections::VecDeque;
+
+/// A widget that displays an append-only log, such as the output of a
+/// process.
+///
+/// # Example
+/// ```no_run
+/// # type LogView<'a> = iced_widget::LogView<'a>;
+/// use iced_widget::log_view::{Content, Level};
+///
+/// let mut logs = Content::with_capacity(1_000);
+///
+/// logs.push(Level::Info, "Server started");
+/// logs.push(Level::Error, "Connection refused");
+///
+/// let log_view = LogView::new(&logs).level(Level::Warn);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LogView<'a, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: &'a Content,
+    level: Level,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`LogView`] displaying the given [`Content`].
+    pub fn new(content: &'a Content) -> Self {
+        Self {
+            content,
+            level: Level::Trace,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(5.0),
+            text_size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the minimum [`Level`] of the lines displayed by the [`LogView`].
+    ///
+    /// Lines with a lower [`Level`] are hidden.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the width of the [`LogView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LogView`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`LogView`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`LogView`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`LogView`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`LogView`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`LogView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LogView`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn visible_lines(&self) -> impl Iterator<Item = (usize, &Line)> + '_ {
+        let level = self.level;
+        let dropped = self.content.dropped;
+
+        self.content
+            .lines
+            .iter()
+            .enumerate()
+            .filter(move |(_, line)| line.level >= level)
+            .map(move |(i, line)| (dropped + i, line))
+    }
+
+    /// Returns the index of the first visible line at or after the given
+    /// anchor.
+    fn position(&self, anchor: usize) -> usize {
+        self.visible_lines()
+            .take_while(|(id, _)| *id < anchor)
+            .count()
+    }
+
+    fn page_size(&self, renderer: &Renderer, bounds: Rectangle) -> usize {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = f32::from(self.line_height.to_absolute(size));
+
+        // Only rows that fit completely count; so the newest line is never
+        // clipped when following the tail
+        (bounds.shrink(self.padding).height / line_height)
+            .floor()
+            .max(1.0) as usize
+    }
+}
+
+/// The lines of a [`LogView`].
+///
+/// The [`Content`] is a ring buffer: once its capacity is reached, pushing
+/// a new line drops the oldest one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Content {
+    lines: VecDeque<Line>,
+    capacity: usize,
+    dropped: usize,
+}
+
+impl Content {
+    /// The default capacity of a [`Content`].
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Creates an empty [`Content`] with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates an empty [`Content`] holding at most the given amount of
+    /// lines.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Appends a new line with the given [`Level`] to the [`Content`].
+    ///
+    /// Multi-line text is split into multiple lines.
+    pub fn push(&mut self, level: Level, text: impl AsRef<str>) {
+        for text in text.as_ref().lines() {
+            if self.lines.len() == self.capacity {
+                let _ = self.lines.pop_front();
+                self.dropped += 1;
+            }
+
+            self.lines.push_back(Line {
+                level,
+                text: text.to_owned(),
+            });
+        }
+    }
+
+    /// Removes all the lines of the [`Content`].
+    pub fn clear(&mut self) {
+        self.dropped += self.lines.len();
+        self.lines.clear();
+    }
+
+    /// Returns the amount of lines in the [`Content`].
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns true if the [`Content`] has no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns an iterator over the lines of the [`Content`].
+    pub fn lines(&self) -> impl Iterator<Item = &Line> {
+        self.lines.iter()
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A line of a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The [`Level`] of the line.
+    pub level: Level,
+    /// The text of the line.
+    pub text: String,
+}
+
+/// The severity of a [`Line`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Level {
+    /// Very detailed tracing information.
+    Trace,
+    /// Debugging information.
+    Debug,
+    /// Regular information.
+    #[default]
+    Info,
+    /// A potential problem.
+    Warn,
+    /// An error.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The identifier of the first visible line, or `None` if
+    /// following the tail.
+    anchor: Option<usize>,
+    partial_scroll: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let lines = match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if cursor.is_over(bounds) =>
+            {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        -y / f32::from(
+                            self.line_height
+                                .to_absolute(self.text_size.unwrap_or_else(
+                                    || renderer.default_size(),
+                                )),
+                        )
+                    }
+                } + state.partial_scroll;
+
+                state.partial_scroll = lines.fract();
+
+                lines as isize
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::End),
+                ..
+            }) if cursor.is_over(bounds) => {
+                if state.anchor.take().is_some() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        if lines == 0 {
+            return event::Status::Captured;
+        }
+
+        let page = self.page_size(renderer, bounds);
+        let last = self.visible_lines().count().saturating_sub(page);
+
+        let first = state
+            .anchor
+            .map_or(last, |anchor| self.position(anchor))
+            .saturating_add_signed(lines)
+            .min(last);
+
+        state.anchor = if first >= last {
+            None
+        } else {
+            self.visible_lines().nth(first).map(|(id, _)| id)
+        };
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+        event::Status::Captured
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_bounds = bounds.shrink(self.padding);
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = f32::from(self.line_height.to_absolute(size));
+
+        let page = self.page_size(renderer, bounds);
+        let last = self.visible_lines().count().saturating_sub(page);
+
+        let first = state
+            .anchor
+            .map_or(last, |anchor| self.position(anchor).min(last));
+
+        for (i, (_, line)) in
+            self.visible_lines().skip(first).take(page).enumerate()
+        {
+            renderer.fill_text(
+                Text {
+                    content: line.text.clone(),
+                    bounds: Size::new(f32::INFINITY, line_height),
+                    size,
+                    line_height: self.line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    justification: text::Justification::None,
+                },
+                Point::new(
+                    text_bounds.x,
+                    text_bounds.y + line_height * i as f32,
+                ),
+                style.color(line.level),
+                text_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LogView<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(log_view: LogView<'a, Theme, Renderer>) -> Self {
+        Element::new(log_view)
+    }
+}
+
+/// The appearance of a [`LogView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the log view.
+    pub background: Background,
+    /// The [`Border`] of the log view.
+    pub border: Border,
+    /// The text [`Color`] of [`Level::Trace`] lines.
+    pub trace: Color,
+    /// The text [`Color`] of [`Level::Debug`] lines.
+    pub debug: Color,
+    /// The text [`Color`] of [`Level::Info`] lines.
+    pub info: Color,
+    /// The text [`Color`] of [`Level::Warn`] lines.
+    pub warn: Color,
+    /// The text [`Color`] of [`Level::Error`] lines.
+    pub error: Color,
+}
+
+impl Style {
+    /// Returns the text [`Color`] of the given [`Level`].
+    pub fn color(&self, level: Level) -> Color {
+        match level {
+            Level::Trace => self.trace,
+            Level::Debug => self.debug,
+            Level::Info => self.info,
+            Level::Warn => self.warn,
+            Level::Error => self.error,
+        }
+    }
+}
+
+/// The theme catalog of a [`LogView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LogView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LogView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: border::rounded(2)
+            .width(1)
+            .color(palette.background.strong.color),
+        trace: palette.background.strong.color,
+        debug: palette.background.strong.color,
+        info: palette.background.base.text,
+        warn: palette.primary.strong.color,
+        error: palette.danger.base.color,
+    }
+}
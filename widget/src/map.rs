@@ -0,0 +1,908 @@
+//! Display slippy maps with markers and polylines.
+//!
+//! A [`Map`] draws the square [`Tile`]s of a web map—like the ones of
+//! OpenStreetMap—in the usual Web Mercator projection, together with
+//! layers of [`Marker`]s and [`Polyline`]s.
+//!
+//! The [`Map`] does not perform any requests by itself. It draws the tiles
+//! it finds in a [`Tiles`] cache, and asks for the missing ones; which you
+//! can fetch with [`Tiles::fetch`] and any asynchronous HTTP client.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::runtime::Task; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! # async fn download(url: String) -> Result<Vec<u8>, String> { unimplemented!() }
+//! use iced::widget::map;
+//! use iced::widget::map::{Fetched, Marker, Tile, Tiles, Viewport};
+//! use iced::Task;
+//!
+//! struct State {
+//!     tiles: Tiles,
+//!     viewport: Viewport,
+//!     markers: Vec<Marker>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ViewportChanged(Viewport),
+//!     TilesRequested(Vec<Tile>),
+//!     TileFetched(Fetched),
+//!     MarkerClicked(usize),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     map(&state.tiles, state.viewport)
+//!         .markers(&state.markers)
+//!         .on_change(Message::ViewportChanged)
+//!         .on_request(Message::TilesRequested)
+//!         .on_marker_click(Message::MarkerClicked)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::ViewportChanged(viewport) => {
+//!             state.viewport = viewport;
+//!         }
+//!         Message::TilesRequested(tiles) => {
+//!             return state
+//!                 .tiles
+//!                 .fetch(tiles, |tile| {
+//!                     download(tile.url("https://tile.openstreetmap.org/{z}/{x}/{y}.png"))
+//!                 })
+//!                 .map(Message::TileFetched);
+//!         }
+//!         Message::TileFetched(fetched) => {
+//!             state.tiles.insert(fetched);
+//!         }
+//!         Message::MarkerClicked(_marker) => {}
+//!     }
+//!
+//!     Task::none()
+//! }
+//! ```
+//!
+//! Keep in mind that most tile providers require an attribution to be
+//! displayed alongside their maps.
+mod tiles;
+
+pub use tiles::{Error, Fetched, Tile, Tiles};
+
+use crate::canvas::{Frame, LineCap, LineJoin, Path, Stroke};
+use crate::core::border;
+use crate::core::event::{self, Event};
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Theme, Vector, Widget,
+};
+use crate::graphics::geometry;
+
+use rustc_hash::FxHashSet;
+use std::f64::consts::PI;
+use std::ops::RangeInclusive;
+
+/// A geographic position, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coordinate {
+    /// The latitude of the [`Coordinate`], from south to north.
+    pub latitude: f64,
+    /// The longitude of the [`Coordinate`], from west to east.
+    pub longitude: f64,
+}
+
+impl Coordinate {
+    /// Creates a new [`Coordinate`].
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+/// The region displayed by a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Viewport {
+    /// The [`Coordinate`] at the center of the [`Map`].
+    pub center: Coordinate,
+    /// The zoom level of the [`Map`].
+    ///
+    /// At zoom `0.0`, the whole world fits in a single [`Tile`]; every
+    /// level doubles its size.
+    pub zoom: f64,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`].
+    pub const fn new(center: Coordinate, zoom: f64) -> Self {
+        Self { center, zoom }
+    }
+}
+
+/// A point of interest on a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker {
+    /// The position of the [`Marker`].
+    pub position: Coordinate,
+}
+
+impl Marker {
+    /// Creates a new [`Marker`] at the given position.
+    pub const fn new(position: Coordinate) -> Self {
+        Self { position }
+    }
+}
+
+/// A path drawn on a [`Map`], like a route or a track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// The points of the [`Polyline`].
+    pub points: Vec<Coordinate>,
+    /// The width of the [`Polyline`], in logical pixels.
+    pub width: f32,
+}
+
+impl Polyline {
+    /// Creates a new [`Polyline`] going through the given points.
+    pub fn new(points: impl IntoIterator<Item = Coordinate>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            width: 3.0,
+        }
+    }
+
+    /// Sets the width of the [`Polyline`].
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// A widget that displays a slippy map.
+#[allow(missing_debug_implementations)]
+pub struct Map<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    tiles: &'a Tiles,
+    viewport: Viewport,
+    zoom_range: RangeInclusive<f64>,
+    markers: &'a [Marker],
+    polylines: &'a [Polyline],
+    on_change: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    on_request: Option<Box<dyn Fn(Vec<Tile>) -> Message + 'a>>,
+    on_click: Option<Box<dyn Fn(Coordinate) -> Message + 'a>>,
+    on_marker_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_polyline_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Map<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The radius of a [`Marker`].
+    const MARKER_RADIUS: f32 = 7.0;
+
+    /// The distance from a [`Polyline`] within which a click hits it.
+    const POLYLINE_TOLERANCE: f32 = 4.0;
+
+    /// The distance the cursor needs to travel for a press to become a drag.
+    const DRAG_THRESHOLD: f32 = 3.0;
+
+    /// The zoom levels covered by a line of the mouse wheel.
+    const ZOOM_STEP: f64 = 0.5;
+
+    /// Creates a new [`Map`] drawing the given [`Tiles`] in a [`Viewport`].
+    pub fn new(tiles: &'a Tiles, viewport: Viewport) -> Self {
+        Self {
+            tiles,
+            viewport,
+            zoom_range: 0.0..=19.0,
+            markers: &[],
+            polylines: &[],
+            on_change: None,
+            on_request: None,
+            on_click: None,
+            on_marker_click: None,
+            on_polyline_click: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the range of zoom levels of the [`Map`].
+    ///
+    /// By default, it goes from `0.0` to `19.0`; the deepest level offered
+    /// by most tile providers.
+    pub fn zoom_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.zoom_range = range;
+        self
+    }
+
+    /// Sets the [`Marker`]s of the [`Map`].
+    pub fn markers(mut self, markers: &'a [Marker]) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Sets the [`Polyline`]s of the [`Map`].
+    pub fn polylines(mut self, polylines: &'a [Polyline]) -> Self {
+        self.polylines = polylines;
+        self
+    }
+
+    /// Sets the message that should be produced when the user pans or
+    /// zooms the [`Map`].
+    ///
+    /// If this method is not called, the [`Map`] stays still.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(Viewport) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Map`] needs
+    /// tiles that are not in its [`Tiles`] cache.
+    ///
+    /// Each missing [`Tile`] is only requested once, until it is dropped
+    /// from the cache.
+    pub fn on_request(
+        mut self,
+        on_request: impl Fn(Vec<Tile>) -> Message + 'a,
+    ) -> Self {
+        self.on_request = Some(Box::new(on_request));
+        self
+    }
+
+    /// Sets the message that should be produced when the user clicks on
+    /// the [`Map`] outside of any [`Marker`] or [`Polyline`].
+    pub fn on_click(
+        mut self,
+        on_click: impl Fn(Coordinate) -> Message + 'a,
+    ) -> Self {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Sets the message that should be produced when the user clicks on a
+    /// [`Marker`], given its index.
+    pub fn on_marker_click(
+        mut self,
+        on_marker_click: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_marker_click = Some(Box::new(on_marker_click));
+        self
+    }
+
+    /// Sets the message that should be produced when the user clicks on a
+    /// [`Polyline`], given its index.
+    pub fn on_polyline_click(
+        mut self,
+        on_polyline_click: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_polyline_click = Some(Box::new(on_polyline_click));
+        self
+    }
+
+    /// Sets the width of the [`Map`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Map`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Map`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Map`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn zoom(&self) -> f64 {
+        self.clamp_zoom(self.viewport.zoom)
+    }
+
+    fn clamp_zoom(&self, zoom: f64) -> f64 {
+        zoom.clamp(*self.zoom_range.start(), *self.zoom_range.end())
+    }
+
+    /// Returns the position of a [`Coordinate`] relative to the center of
+    /// the [`Map`].
+    fn offset(&self, coordinate: Coordinate) -> Vector {
+        let zoom = self.zoom();
+        let (x, y) = project(coordinate, zoom);
+        let (center_x, center_y) = project(self.viewport.center, zoom);
+
+        Vector::new((x - center_x) as f32, (y - center_y) as f32)
+    }
+
+    /// Returns the position of a [`Marker`] relative to the center of the
+    /// [`Map`], on the closest copy of the world.
+    fn marker_offset(&self, marker: &Marker) -> Vector {
+        let offset = self.offset(marker.position);
+        let world = (TILE_SIZE * 2.0_f64.powf(self.zoom())) as f32;
+
+        Vector::new(
+            (offset.x + world / 2.0).rem_euclid(world) - world / 2.0,
+            offset.y,
+        )
+    }
+
+    fn coordinate_at(&self, bounds: Rectangle, position: Point) -> Coordinate {
+        let zoom = self.zoom();
+        let (center_x, center_y) = project(self.viewport.center, zoom);
+        let offset = position - bounds.center();
+
+        unproject(
+            (
+                center_x + f64::from(offset.x),
+                center_y + f64::from(offset.y),
+            ),
+            zoom,
+        )
+    }
+
+    /// Returns the tiles covering the bounds of the [`Map`], together with
+    /// their bounds.
+    fn visible_tiles(&self, bounds: Rectangle) -> Vec<(Tile, Rectangle)> {
+        let zoom = self.zoom();
+        let tile_zoom = zoom.floor().max(0.0) as u8;
+        let size = TILE_SIZE * 2.0_f64.powf(zoom - f64::from(tile_zoom));
+        let count = 1_i64 << tile_zoom;
+
+        let (center_x, center_y) = project(self.viewport.center, zoom);
+        let left = center_x - f64::from(bounds.width) / 2.0;
+        let top = center_y - f64::from(bounds.height) / 2.0;
+
+        let columns = (left / size).floor() as i64
+            ..=((left + f64::from(bounds.width)) / size).floor() as i64;
+
+        let rows = ((top / size).floor() as i64).max(0)
+            ..=(((top + f64::from(bounds.height)) / size).floor() as i64)
+                .min(count - 1);
+
+        let mut tiles = Vec::new();
+
+        for y in rows {
+            for x in columns.clone() {
+                let tile = Tile {
+                    x: x.rem_euclid(count) as u32,
+                    y: y as u32,
+                    zoom: tile_zoom,
+                };
+
+                let bounds = Rectangle {
+                    x: bounds.x + (x as f64 * size - left) as f32,
+                    y: bounds.y + (y as f64 * size - top) as f32,
+                    width: size as f32,
+                    height: size as f32,
+                };
+
+                tiles.push((tile, bounds));
+            }
+        }
+
+        tiles
+    }
+
+    fn click(
+        &self,
+        bounds: Rectangle,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let center = bounds.center();
+
+        if let Some(on_marker_click) = &self.on_marker_click {
+            if let Some(index) = self.marker_at(bounds, position) {
+                shell.publish(on_marker_click(index));
+                return;
+            }
+        }
+
+        if let Some(on_polyline_click) = &self.on_polyline_click {
+            let hit = self.polylines.iter().rposition(|polyline| {
+                polyline.points.windows(2).any(|segment| {
+                    let from = center + self.offset(segment[0]);
+                    let to = center + self.offset(segment[1]);
+
+                    distance_to_segment(position, from, to)
+                        <= polyline.width / 2.0 + Self::POLYLINE_TOLERANCE
+                })
+            });
+
+            if let Some(index) = hit {
+                shell.publish(on_polyline_click(index));
+                return;
+            }
+        }
+
+        if let Some(on_click) = &self.on_click {
+            shell.publish(on_click(self.coordinate_at(bounds, position)));
+        }
+    }
+
+    fn marker_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let center = bounds.center();
+
+        self.markers.iter().rposition(|marker| {
+            (center + self.marker_offset(marker)).distance(position)
+                <= Self::MARKER_RADIUS
+        })
+    }
+}
+
+/// The size of a [`Tile`], in pixels.
+const TILE_SIZE: f64 = 256.0;
+
+/// The latitude beyond which the Web Mercator projection is cut, in
+/// degrees.
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Projects a [`Coordinate`] to the pixels of the world at some zoom level.
+fn project(coordinate: Coordinate, zoom: f64) -> (f64, f64) {
+    let size = TILE_SIZE * 2.0_f64.powf(zoom);
+    let latitude = coordinate
+        .latitude
+        .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+        .to_radians();
+
+    (
+        (coordinate.longitude + 180.0) / 360.0 * size,
+        (1.0 - (latitude.tan() + 1.0 / latitude.cos()).ln() / PI) / 2.0 * size,
+    )
+}
+
+/// Finds the [`Coordinate`] of some pixel of the world at some zoom level.
+fn unproject((x, y): (f64, f64), zoom: f64) -> Coordinate {
+    let size = TILE_SIZE * 2.0_f64.powf(zoom);
+    let latitude = (PI * (1.0 - 2.0 * y / size)).sinh().atan();
+
+    Coordinate {
+        latitude: latitude.to_degrees().clamp(-MAX_LATITUDE, MAX_LATITUDE),
+        longitude: (x / size * 360.0 + 180.0).rem_euclid(360.0) - 180.0,
+    }
+}
+
+fn distance_to_segment(point: Point, from: Point, to: Point) -> f32 {
+    let segment = to - from;
+    let length = segment.x * segment.x + segment.y * segment.y;
+
+    if length == 0.0 {
+        return point.distance(from);
+    }
+
+    let t = (((point - from).x * segment.x + (point - from).y * segment.y)
+        / length)
+        .clamp(0.0, 1.0);
+
+    point.distance(from + segment * t)
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    requested: FxHashSet<Tile>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    center: (f64, f64),
+    is_moving: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Map<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = image::Handle> + geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(_)) => {
+                let Some(on_request) = &self.on_request else {
+                    return event::Status::Ignored;
+                };
+
+                state.requested.retain(|tile| !self.tiles.contains(tile));
+
+                let missing: Vec<_> = self
+                    .visible_tiles(bounds)
+                    .into_iter()
+                    .map(|(tile, _)| tile)
+                    .filter(|tile| {
+                        !self.tiles.contains(tile)
+                            && !state.requested.contains(tile)
+                    })
+                    .collect();
+
+                if !missing.is_empty() {
+                    state.requested.extend(missing.iter().copied());
+                    shell.publish(on_request(missing));
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.drag = Some(Drag {
+                    origin: position,
+                    center: project(self.viewport.center, self.zoom()),
+                    is_moving: false,
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(drag), Some(position)) =
+                    (&mut state.drag, cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let delta = position - drag.origin;
+
+                if !drag.is_moving
+                    && drag.origin.distance(position) < Self::DRAG_THRESHOLD
+                {
+                    return event::Status::Captured;
+                }
+
+                drag.is_moving = true;
+
+                if let Some(on_change) = &self.on_change {
+                    let center = unproject(
+                        (
+                            drag.center.0 - f64::from(delta.x),
+                            drag.center.1 - f64::from(delta.y),
+                        ),
+                        self.zoom(),
+                    );
+
+                    shell.publish(on_change(Viewport {
+                        center,
+                        zoom: self.zoom(),
+                    }));
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if !drag.is_moving {
+                    self.click(bounds, drag.origin, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let (Some(on_change), Some(position)) =
+                    (&self.on_change, cursor.position_over(bounds))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => f64::from(y),
+                    mouse::ScrollDelta::Pixels { y, .. } => f64::from(y) / 60.0,
+                };
+
+                let zoom =
+                    self.clamp_zoom(self.zoom() + lines * Self::ZOOM_STEP);
+
+                if zoom != self.zoom() {
+                    // Keep the coordinate under the cursor in place
+                    let anchor =
+                        project(self.coordinate_at(bounds, position), zoom);
+                    let offset = position - bounds.center();
+
+                    let center = unproject(
+                        (
+                            anchor.0 - f64::from(offset.x),
+                            anchor.1 - f64::from(offset.y),
+                        ),
+                        zoom,
+                    );
+
+                    shell.publish(on_change(Viewport { center, zoom }));
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            for (tile, tile_bounds) in self.visible_tiles(bounds) {
+                if let Some(handle) = self.tiles.get(&tile) {
+                    renderer.draw_image(handle.into(), tile_bounds);
+                }
+            }
+
+            if !self.polylines.is_empty() {
+                let mut frame = Frame::new(renderer, bounds.size());
+                let center = frame.center();
+
+                for polyline in self.polylines {
+                    let path = Path::new(|builder| {
+                        let mut points = polyline
+                            .points
+                            .iter()
+                            .map(|point| center + self.offset(*point));
+
+                        if let Some(first) = points.next() {
+                            builder.move_to(first);
+                        }
+
+                        for point in points {
+                            builder.line_to(point);
+                        }
+                    });
+
+                    frame.stroke(
+                        &path,
+                        Stroke::default()
+                            .with_width(polyline.width)
+                            .with_color(style.polyline)
+                            .with_line_cap(LineCap::Round)
+                            .with_line_join(LineJoin::Round),
+                    );
+                }
+
+                let geometry = frame.into_geometry();
+
+                renderer.with_translation(
+                    Vector::new(bounds.x, bounds.y),
+                    |renderer| {
+                        renderer.draw_geometry(geometry);
+                    },
+                );
+            }
+
+            let center = bounds.center();
+
+            for marker in self.markers {
+                let position = center + self.marker_offset(marker);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: position.x - Self::MARKER_RADIUS,
+                            y: position.y - Self::MARKER_RADIUS,
+                            width: Self::MARKER_RADIUS * 2.0,
+                            height: Self::MARKER_RADIUS * 2.0,
+                        },
+                        border: border::rounded(Self::MARKER_RADIUS)
+                            .width(2)
+                            .color(style.marker_border),
+                        ..renderer::Quad::default()
+                    },
+                    style.marker,
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag.is_some_and(|drag| drag.is_moving)
+            && self.on_change.is_some()
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if self.on_marker_click.is_some()
+            && self.marker_at(bounds, position).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else if self.on_change.is_some() {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Map<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer<Handle = image::Handle> + geometry::Renderer + 'a,
+{
+    fn from(map: Map<'a, Message, Theme>) -> Self {
+        Element::new(map)
+    }
+}
+
+/// The appearance of a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] behind the tiles, visible while they load.
+    pub background: Color,
+    /// The [`Color`] of the [`Marker`]s.
+    pub marker: Color,
+    /// The [`Color`] of the border of the [`Marker`]s.
+    pub marker_border: Color,
+    /// The [`Color`] of the [`Polyline`]s.
+    pub polyline: Color,
+}
+
+/// The theme catalog of a [`Map`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Map`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Map`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color,
+        marker: palette.primary.base.color,
+        marker_border: palette.primary.base.text,
+        polyline: palette.primary.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn projection_round_trip() {
+        let coordinate = Coordinate::new(60.39, 5.32);
+
+        let (x, y) = project(coordinate, 12.0);
+        let result = unproject((x, y), 12.0);
+
+        assert!((result.latitude - coordinate.latitude).abs() < 1e-9);
+        assert!((result.longitude - coordinate.longitude).abs() < 1e-9);
+    }
+
+    #[test]
+    fn world_fits_first_tile() {
+        let (x, y) = project(Coordinate::new(0.0, 0.0), 0.0);
+
+        assert_eq!((x, y), (128.0, 128.0));
+        assert_eq!(
+            unproject((0.0, 0.0), 0.0).latitude.round(),
+            MAX_LATITUDE.round()
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::core::image;
+use crate::runtime::futures::MaybeSend;
+use crate::runtime::Task;
+
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// A square of the map at some zoom level, in the usual "slippy map"
+/// numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The column of the [`Tile`], from west to east.
+    pub x: u32,
+    /// The row of the [`Tile`], from north to south.
+    pub y: u32,
+    /// The zoom level of the [`Tile`].
+    pub zoom: u8,
+}
+
+impl Tile {
+    /// Builds the URL of the [`Tile`] from a template, replacing `{x}`,
+    /// `{y}`, and `{z}` with its coordinates.
+    ///
+    /// ```
+    /// # use iced_widget::map::Tile;
+    /// let tile = Tile { x: 3, y: 5, zoom: 4 };
+    ///
+    /// assert_eq!(
+    ///     tile.url("https://tile.example.com/{z}/{x}/{y}.png"),
+    ///     "https://tile.example.com/4/3/5.png"
+    /// );
+    /// ```
+    pub fn url(&self, template: &str) -> String {
+        template
+            .replace("{x}", &self.x.to_string())
+            .replace("{y}", &self.y.to_string())
+            .replace("{z}", &self.zoom.to_string())
+    }
+}
+
+/// A cache of the [`Tile`]s of a [`Map`].
+///
+/// A [`Map`] only draws the tiles it finds here, and asks for the missing
+/// ones through [`Map::on_request`]. Fetching them is up to you: [`fetch`]
+/// turns any asynchronous function producing the bytes of a [`Tile`] into
+/// a [`Task`], and [`insert`] stores its result.
+///
+/// Once the cache holds more tiles than its capacity, the oldest ones are
+/// dropped and fetched again when needed.
+///
+/// [`Map`]: super::Map
+/// [`Map::on_request`]: super::Map::on_request
+/// [`fetch`]: Self::fetch
+/// [`insert`]: Self::insert
+#[derive(Debug, Clone)]
+pub struct Tiles {
+    entries: FxHashMap<Tile, Entry>,
+    loaded: VecDeque<Tile>,
+    capacity: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    Loading,
+    Loaded(image::Handle),
+    Failed(Error),
+}
+
+impl Tiles {
+    /// The default capacity of a [`Tiles`] cache.
+    pub const DEFAULT_CAPACITY: usize = 512;
+
+    /// Creates a new empty [`Tiles`] cache holding up to the given amount
+    /// of tiles.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            loaded: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the image of a [`Tile`], if it has been loaded.
+    pub fn get(&self, tile: &Tile) -> Option<&image::Handle> {
+        match self.entries.get(tile) {
+            Some(Entry::Loaded(handle)) => Some(handle),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Error`] that happened while fetching a [`Tile`], if
+    /// any.
+    pub fn error(&self, tile: &Tile) -> Option<&Error> {
+        match self.entries.get(tile) {
+            Some(Entry::Failed(error)) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the [`Tile`] is loaded, being fetched, or failed to
+    /// be fetched.
+    pub fn contains(&self, tile: &Tile) -> bool {
+        self.entries.contains_key(tile)
+    }
+
+    /// Fetches the given tiles that are not in the cache yet with an
+    /// asynchronous function.
+    ///
+    /// The tiles are marked as loading right away, and the returned
+    /// [`Task`] produces a [`Fetched`] tile for each of them, which should
+    /// be given back to [`insert`].
+    ///
+    /// [`insert`]: Self::insert
+    pub fn fetch<F, B, E>(
+        &mut self,
+        tiles: impl IntoIterator<Item = Tile>,
+        fetch: impl Fn(Tile) -> F,
+    ) -> Task<Fetched>
+    where
+        F: Future<Output = Result<B, E>> + MaybeSend + 'static,
+        B: Into<image::Bytes>,
+        E: fmt::Display,
+    {
+        let mut tasks = Vec::new();
+
+        for tile in tiles {
+            if self.contains(&tile) {
+                continue;
+            }
+
+            let _ = self.entries.insert(tile, Entry::Loading);
+
+            let future = fetch(tile);
+
+            tasks.push(Task::future(async move {
+                let result = future
+                    .await
+                    .map(image::Handle::from_bytes)
+                    .map_err(|error| Error(Arc::from(error.to_string())));
+
+                Fetched { tile, result }
+            }));
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Stores a [`Fetched`] tile in the cache.
+    pub fn insert(&mut self, fetched: Fetched) {
+        let Fetched { tile, result } = fetched;
+
+        match result {
+            Ok(handle) => {
+                if let Some(Entry::Loaded(_)) =
+                    self.entries.insert(tile, Entry::Loaded(handle))
+                {
+                    self.loaded.retain(|loaded| loaded != &tile);
+                }
+
+                self.loaded.push_back(tile);
+
+                while self.loaded.len() > self.capacity {
+                    if let Some(oldest) = self.loaded.pop_front() {
+                        let _ = self.entries.remove(&oldest);
+                    }
+                }
+            }
+            Err(error) => {
+                let _ = self.entries.insert(tile, Entry::Failed(error));
+            }
+        }
+    }
+
+    /// Forgets the tiles that failed to be fetched, so they are requested
+    /// again.
+    pub fn retry(&mut self) {
+        self.entries
+            .retain(|_, entry| !matches!(entry, Entry::Failed(_)));
+    }
+
+    /// Removes all the tiles of the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.loaded.clear();
+    }
+}
+
+impl Default for Tiles {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// A [`Tile`] produced by [`Tiles::fetch`].
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    tile: Tile,
+    result: Result<image::Handle, Error>,
+}
+
+impl Fetched {
+    /// Returns the [`Tile`] that was fetched.
+    pub fn tile(&self) -> Tile {
+        self.tile
+    }
+}
+
+/// An error that happened while fetching a [`Tile`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("failed to fetch tile: {0}")]
+pub struct Error(Arc<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loaded(x: u32) -> Fetched {
+        Fetched {
+            tile: Tile { x, y: 0, zoom: 1 },
+            result: Ok(image::Handle::from_bytes(vec![0])),
+        }
+    }
+
+    #[test]
+    fn oldest_tiles_are_dropped() {
+        let mut tiles = Tiles::new(2);
+
+        tiles.insert(loaded(0));
+        tiles.insert(loaded(1));
+        tiles.insert(loaded(2));
+
+        assert!(!tiles.contains(&Tile {
+            x: 0,
+            y: 0,
+            zoom: 1
+        }));
+        assert!(tiles
+            .get(&Tile {
+                x: 1,
+                y: 0,
+                zoom: 1
+            })
+            .is_some());
+        assert!(tiles
+            .get(&Tile {
+                x: 2,
+                y: 0,
+                zoom: 1
+            })
+            .is_some());
+    }
+}
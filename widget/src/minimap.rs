@@ -0,0 +1,525 @@
+//! Navigate large content through a scaled-down overview.
+//!
+//! A [`Minimap`] draws some content scaled to fit its bounds, together with
+//! a rectangle marking the region currently visible elsewhere—like in a
+//! [`Scrollable`] or a zoomable [`Canvas`]. Dragging the rectangle produces
+//! the new position of the visible region, and updating the region of the
+//! [`Minimap`] moves the rectangle; keeping both sides in sync is just a
+//! matter of messages.
+//!
+//! [`Scrollable`]: crate::Scrollable
+//! [`Canvas`]: crate::Canvas
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Size, Theme, Transformation, Vector, Widget,
+};
+
+/// A scaled-down overview of some content, with a draggable rectangle
+/// marking its visible region.
+///
+/// The content of a [`Minimap`] is only drawn; it does not receive any
+/// events.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::core::{Point, Rectangle, Size};
+/// use iced_widget::{minimap, row, scrollable, text};
+///
+/// const CONTENT: Size = Size::new(300.0, 3000.0);
+///
+/// struct State {
+///     visible: Rectangle,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Scrolled(scrollable::Viewport),
+///     Moved(Point),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     row![
+///         scrollable(text("A very long document...").height(CONTENT.height))
+///             .id(scrollable::Id::new("document"))
+///             .on_scroll(Message::Scrolled),
+///         minimap(text("A very long document..."), CONTENT, state.visible)
+///             .on_move(Message::Moved)
+///             .width(60),
+///     ]
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Minimap<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    content_size: Size,
+    visible: Rectangle,
+    on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`Minimap`] of the given content.
+    ///
+    /// It expects:
+    ///   * the content to draw scaled down,
+    ///   * the full [`Size`] of the content, and
+    ///   * the region of the content that is currently visible.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content_size: Size,
+        visible: Rectangle,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            content_size,
+            visible,
+            on_move: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when the visible region is
+    /// dragged or the [`Minimap`] is clicked.
+    ///
+    /// The message receives the new top-left corner of the visible region,
+    /// kept within the content. If this method is not called, the
+    /// [`Minimap`] will not be interactive.
+    pub fn on_move(mut self, on_move: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the width of the [`Minimap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`].
+    ///
+    /// By default, it follows the aspect ratio of the content.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Minimap`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Minimap`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn content_size(&self) -> Size {
+        Size::new(
+            self.content_size.width.max(1.0),
+            self.content_size.height.max(1.0),
+        )
+    }
+
+    /// Returns the scale and the offset of the content inside the bounds.
+    fn fit(&self, bounds: Rectangle) -> (f32, Vector) {
+        let content = self.content_size();
+
+        let scale =
+            (bounds.width / content.width).min(bounds.height / content.height);
+
+        let offset = Vector::new(
+            (bounds.width - content.width * scale) / 2.0,
+            (bounds.height - content.height * scale) / 2.0,
+        );
+
+        (scale, offset)
+    }
+
+    fn to_content(&self, bounds: Rectangle, point: Point) -> Point {
+        let (scale, offset) = self.fit(bounds);
+
+        Point::new(
+            (point.x - bounds.x - offset.x) / scale,
+            (point.y - bounds.y - offset.y) / scale,
+        )
+    }
+
+    fn to_minimap(&self, bounds: Rectangle, region: Rectangle) -> Rectangle {
+        let (scale, offset) = self.fit(bounds);
+
+        Rectangle {
+            x: bounds.x + offset.x + region.x * scale,
+            y: bounds.y + offset.y + region.y * scale,
+            width: region.width * scale,
+            height: region.height * scale,
+        }
+    }
+
+    fn clamp(&self, position: Point) -> Point {
+        let content = self.content_size();
+
+        Point::new(
+            position.x.min(content.width - self.visible.width).max(0.0),
+            position
+                .y
+                .min(content.height - self.visible.height)
+                .max(0.0),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    grab: Option<Vector>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content_size = self.content_size();
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, content_size),
+        );
+
+        let limits = limits.width(self.width).height(self.height);
+        let max = limits.max();
+
+        let scale = (max.width / content_size.width)
+            .min(max.height / content_size.height);
+
+        let intrinsic = if scale.is_finite() {
+            content_size * scale
+        } else {
+            content_size
+        };
+
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_move) = &self.on_move else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let position = self.to_content(bounds, position);
+
+                let grab = if self.visible.contains(position) {
+                    position - self.visible.position()
+                } else {
+                    let grab = Vector::new(
+                        self.visible.width / 2.0,
+                        self.visible.height / 2.0,
+                    );
+
+                    shell.publish(on_move(self.clamp(position - grab)));
+
+                    grab
+                };
+
+                state.grab = Some(grab);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(grab), Some(position)) =
+                    (state.grab, cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let position = self.to_content(bounds, position);
+
+                shell.publish(on_move(self.clamp(position - grab)));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if self.on_move.is_none() {
+            return mouse::Interaction::default();
+        }
+
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(self.to_minimap(bounds, self.visible)) {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let visible = self.to_minimap(bounds, self.visible);
+
+        let Some(clipped) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let status = if self.on_move.is_none() {
+            Status::Active
+        } else if state.grab.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(visible) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let content_layout = layout.children().next().unwrap();
+        let origin = content_layout.position();
+        let (scale, offset) = self.fit(bounds);
+
+        renderer.with_layer(clipped, |renderer| {
+            renderer.with_transformation(
+                Transformation::translate(
+                    origin.x + offset.x,
+                    origin.y + offset.y,
+                ) * Transformation::scale(scale)
+                    * Transformation::translate(-origin.x, -origin.y),
+                |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        renderer_style,
+                        content_layout,
+                        mouse::Cursor::Unavailable,
+                        &Rectangle::new(origin, self.content_size()),
+                    );
+                },
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: visible,
+                    border: style.visible_border,
+                    ..renderer::Quad::default()
+                },
+                style.visible_background,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(minimap: Minimap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(minimap)
+    }
+}
+
+/// The possible status of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Minimap`] can be interacted with.
+    Active,
+    /// The visible region of the [`Minimap`] is being hovered.
+    Hovered,
+    /// The visible region of the [`Minimap`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Minimap`].
+    pub background: Background,
+    /// The [`Border`] of the [`Minimap`].
+    pub border: Border,
+    /// The [`Background`] of the visible region.
+    pub visible_background: Background,
+    /// The [`Border`] of the visible region.
+    pub visible_border: Border,
+}
+
+/// The theme catalog of a [`Minimap`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Minimap`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Minimap`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let visible = match status {
+        Status::Active => palette.primary.weak.color,
+        Status::Hovered | Status::Dragged => palette.primary.base.color,
+    };
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: border::rounded(2),
+        visible_background: Color { a: 0.2, ..visible }.into(),
+        visible_border: Border {
+            color: visible,
+            width: 1.0,
+            radius: 2.0.into(),
+        },
+    }
+}
@@ -3,12 +3,15 @@
 use iced_renderer::core::Point;
 
 use crate::core::event::{self, Event};
+use crate::core::gesture;
 use crate::core::layout;
-use crate::core::mouse;
+use crate::core::mouse::{self, click};
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
 };
@@ -31,6 +34,12 @@ pub struct MouseArea<
     on_enter: Option<Message>,
     on_move: Option<Box<dyn Fn(Point) -> Message>>,
     on_exit: Option<Message>,
+    on_double_click: Option<Message>,
+    on_long_press: Option<Message>,
+    on_button_press: Option<Box<dyn Fn(mouse::Button) -> Message + 'a>>,
+    on_button_release: Option<Box<dyn Fn(mouse::Button) -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_scroll: Option<Box<dyn Fn(mouse::ScrollDelta) -> Message + 'a>>,
     interaction: Option<mouse::Interaction>,
 }
 
@@ -101,6 +110,72 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit on a left button double click.
+    #[must_use]
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// The message to emit when the left button is held down in the area,
+    /// without moving, for the [`gesture::Thresholds::long_press`] duration.
+    ///
+    /// On touch screens, the runtime recognizes long presses by itself and
+    /// delivers them as a secondary click; see [`touch::LongPress`].
+    #[must_use]
+    pub fn on_long_press(mut self, message: Message) -> Self {
+        self.on_long_press = Some(message);
+        self
+    }
+
+    /// The message to emit when any mouse button is pressed in the area.
+    ///
+    /// It receives the [`mouse::Button`] that was pressed.
+    #[must_use]
+    pub fn on_button_press(
+        mut self,
+        build_message: impl Fn(mouse::Button) -> Message + 'a,
+    ) -> Self {
+        self.on_button_press = Some(Box::new(build_message));
+        self
+    }
+
+    /// The message to emit when any mouse button is released in the area.
+    ///
+    /// It receives the [`mouse::Button`] that was released.
+    #[must_use]
+    pub fn on_button_release(
+        mut self,
+        build_message: impl Fn(mouse::Button) -> Message + 'a,
+    ) -> Self {
+        self.on_button_release = Some(Box::new(build_message));
+        self
+    }
+
+    /// The message to emit when the mouse is dragged after a left button
+    /// press in the area.
+    ///
+    /// It receives the distance the cursor moved since the last message;
+    /// drags keep going outside the area until the button is released.
+    #[must_use]
+    pub fn on_drag(
+        mut self,
+        build_message: impl Fn(Vector) -> Message + 'a,
+    ) -> Self {
+        self.on_drag = Some(Box::new(build_message));
+        self
+    }
+
+    /// The message to emit when the mouse wheel is scrolled in the area.
+    #[must_use]
+    pub fn on_scroll(
+        mut self,
+        build_message: impl Fn(mouse::ScrollDelta) -> Message + 'a,
+    ) -> Self {
+        self.on_scroll = Some(Box::new(build_message));
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
@@ -113,6 +188,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
 #[derive(Default)]
 struct State {
     is_hovered: bool,
+    last_click: Option<mouse::Click>,
+    drag_position: Option<Point>,
+    long_press: Option<(Point, Instant)>,
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -131,6 +209,12 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
             on_enter: None,
             on_move: None,
             on_exit: None,
+            on_double_click: None,
+            on_long_press: None,
+            on_button_press: None,
+            on_button_release: None,
+            on_drag: None,
+            on_scroll: None,
             interaction: None,
         }
     }
@@ -302,6 +386,41 @@ fn update<Message: Clone, Theme, Renderer>(
     cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
 ) -> event::Status {
+    if let Some(message) = widget.on_long_press.as_ref() {
+        let state: &mut State = tree.state.downcast_mut();
+
+        if let Some((origin, pressed_at)) = state.long_press {
+            let thresholds = gesture::current();
+
+            match event {
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    if now >= pressed_at + thresholds.long_press {
+                        shell.publish(message.clone());
+                        state.long_press = None;
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(
+                            pressed_at + thresholds.long_press,
+                        ));
+                    }
+                }
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) if position.distance(origin) > thresholds.drag => {
+                    state.long_press = None;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.long_press = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
     if let Event::Mouse(mouse::Event::CursorMoved { .. })
     | Event::Touch(touch::Event::FingerMoved { .. }) = event
     {
@@ -328,18 +447,103 @@ fn update<Message: Clone, Theme, Renderer>(
             }
             _ => {}
         }
+
+        if let (Some(on_drag), Some(last_position), Some(position)) = (
+            widget.on_drag.as_ref(),
+            state.drag_position,
+            cursor.position(),
+        ) {
+            if position != last_position {
+                shell.publish(on_drag(position - last_position));
+                state.drag_position = Some(position);
+            }
+
+            return event::Status::Captured;
+        }
+    }
+
+    if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    | Event::Touch(touch::Event::FingerLifted { .. })
+    | Event::Touch(touch::Event::FingerLost { .. }) = event
+    {
+        let state: &mut State = tree.state.downcast_mut();
+
+        state.drag_position = None;
     }
 
     if !cursor.is_over(layout.bounds()) {
         return event::Status::Ignored;
     }
 
-    if let Some(message) = widget.on_press.as_ref() {
-        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) = event
-        {
+    if let Some(on_button_press) = widget.on_button_press.as_ref() {
+        if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+            shell.publish(on_button_press(button));
+        }
+    }
+
+    if let Some(on_button_release) = widget.on_button_release.as_ref() {
+        if let Event::Mouse(mouse::Event::ButtonReleased(button)) = event {
+            shell.publish(on_button_release(button));
+        }
+    }
+
+    if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        let state: &mut State = tree.state.downcast_mut();
+        let mut captured = widget.on_button_press.is_some();
+
+        if let Some(message) = widget.on_press.as_ref() {
             shell.publish(message.clone());
 
+            captured = true;
+        }
+
+        if let Some(position) = cursor.position() {
+            if widget.on_drag.is_some() {
+                state.drag_position = Some(position);
+
+                captured = true;
+            }
+
+            if widget.on_long_press.is_some()
+                && matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(_))
+                )
+            {
+                let pressed_at = Instant::now();
+
+                state.long_press = Some((position, pressed_at));
+                shell.request_redraw(window::RedrawRequest::At(
+                    pressed_at + gesture::current().long_press,
+                ));
+
+                captured = true;
+            }
+
+            if let Some(message) = widget.on_double_click.as_ref() {
+                let click = mouse::Click::new(position, state.last_click);
+
+                if matches!(click.kind(), click::Kind::Double) {
+                    shell.publish(message.clone());
+                }
+
+                state.last_click = Some(click);
+
+                captured = true;
+            }
+        }
+
+        if captured {
+            return event::Status::Captured;
+        }
+    }
+
+    if let Some(on_scroll) = widget.on_scroll.as_ref() {
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+            shell.publish(on_scroll(delta));
+
             return event::Status::Captured;
         }
     }
@@ -397,5 +601,17 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
+    if widget.on_button_press.is_some() {
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+            return event::Status::Captured;
+        }
+    }
+
+    if widget.on_button_release.is_some() {
+        if let Event::Mouse(mouse::Event::ButtonReleased(_)) = event {
+            return event::Status::Captured;
+        }
+    }
+
     event::Status::Ignored
 }
@@ -1,2 +1,53 @@
 //! Display interactive elements on top of other widgets.
 pub mod menu;
+pub mod spell;
+
+use crate::core::widget::operation::Operation;
+use crate::core::widget::Id;
+use crate::core::Rectangle;
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
+
+use std::any::Any;
+
+/// The open state of a widget that displays an overlay on demand, like a
+/// [`PickList`] or a [`ComboBox`].
+///
+/// These widgets expose it to any [`Operation`] through
+/// [`Operation::custom`].
+///
+/// [`PickList`]: crate::PickList
+/// [`ComboBox`]: crate::ComboBox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Toggle {
+    /// Whether the overlay of the widget is open.
+    pub is_open: bool,
+}
+
+/// Produces a [`Task`] that closes the overlays of every dropdown-like
+/// widget.
+///
+/// Unlike an interaction of the user, closing an overlay this way does not
+/// produce any `on_close` message.
+pub fn close_all<T>() -> Task<T> {
+    struct CloseAll;
+
+    impl Operation for CloseAll {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(toggle) = state.downcast_mut::<Toggle>() {
+                toggle.is_open = false;
+            }
+        }
+    }
+
+    task::effect(Action::widget(CloseAll))
+}
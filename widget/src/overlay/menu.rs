@@ -10,8 +10,8 @@ use crate::core::text::{self, Text};
 use crate::core::touch;
 use crate::core::widget::Tree;
 use crate::core::{
-    Background, Clipboard, Color, Length, Padding, Pixels, Point, Rectangle,
-    Size, Theme, Vector,
+    Alignment, Background, Clipboard, Color, Length, Padding, Pixels, Point,
+    Rectangle, Size, Theme,
 };
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
@@ -35,6 +35,7 @@ pub struct Menu<
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_close: Option<Message>,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
@@ -69,16 +70,24 @@ where
             hovered_option,
             on_selected: Box::new(on_selected),
             on_option_hovered,
+            on_close: None,
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
             text_line_height: text::LineHeight::default(),
-            text_shaping: text::Shaping::Basic,
+            text_shaping: text::Shaping::default(),
             font: None,
             class,
         }
     }
 
+    /// Sets the message that will be produced right after the message of
+    /// a selected option, since the [`Menu`] closes itself.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -123,7 +132,7 @@ where
     ///
     /// The `target_height` will be used to display the menu either on top
     /// of the target or under it, depending on the screen position and the
-    /// dimensions of the [`Menu`].
+    /// dimensions of the [`Menu`]. See [`overlay::Placement`].
     pub fn overlay(
         self,
         position: Point,
@@ -163,11 +172,10 @@ where
     Theme: Catalog,
     Renderer: crate::core::Renderer,
 {
-    position: Point,
+    target: Rectangle,
     state: &'a mut Tree,
     list: Scrollable<'a, Message, Theme, Renderer>,
     width: f32,
-    target_height: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -192,6 +200,7 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            on_close,
             width,
             padding,
             font,
@@ -206,6 +215,7 @@ where
             hovered_option,
             on_selected,
             on_option_hovered,
+            on_close,
             font,
             text_size,
             text_line_height,
@@ -217,11 +227,10 @@ where
         state.tree.diff(&list as &dyn Widget<_, _, _>);
 
         Self {
-            position,
+            target: Rectangle::new(position, Size::new(width, target_height)),
             state: &mut state.tree,
             list,
             width,
-            target_height,
             class,
         }
     }
@@ -235,31 +244,21 @@ where
     Renderer: text::Renderer,
 {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
-        let space_below =
-            bounds.height - (self.position.y + self.target_height);
-        let space_above = self.position.y;
+        let viewport = Rectangle::with_size(bounds);
+        let placement =
+            overlay::Placement::new(overlay::placement::Side::Bottom)
+                .align(Alignment::Start);
 
         let limits = layout::Limits::new(
             Size::ZERO,
-            Size::new(
-                bounds.width - self.position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
+            placement.limits(self.target, viewport),
         )
         .width(self.width);
 
         let node = self.list.layout(self.state, renderer, &limits);
-        let size = node.size();
+        let bounds = placement.resolve(self.target, node.size(), viewport);
 
-        node.move_to(if space_below > space_above {
-            self.position + Vector::new(0.0, self.target_height)
-        } else {
-            self.position - Vector::new(0.0, size.height)
-        })
+        node.move_to(bounds.position())
     }
 
     fn on_event(
@@ -326,6 +325,7 @@ where
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    on_close: Option<Message>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
@@ -391,6 +391,11 @@ where
                     if let Some(index) = *self.hovered_option {
                         if let Some(option) = self.options.get(index) {
                             shell.publish((self.on_selected)(option.clone()));
+
+                            if let Some(on_close) = self.on_close.take() {
+                                shell.publish(on_close);
+                            }
+
                             return event::Status::Captured;
                         }
                     }
@@ -443,6 +448,11 @@ where
                     if let Some(index) = *self.hovered_option {
                         if let Some(option) = self.options.get(index) {
                             shell.publish((self.on_selected)(option.clone()));
+
+                            if let Some(on_close) = self.on_close.take() {
+                                shell.publish(on_close);
+                            }
+
                             return event::Status::Captured;
                         }
                     }
@@ -532,6 +542,7 @@ where
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    justification: text::Justification::None,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
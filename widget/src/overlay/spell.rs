@@ -0,0 +1,396 @@
+//! Display spelling suggestions for a misspelled word.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Background, Border, Clipboard, Color, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector,
+};
+
+/// A list of spelling suggestions shown on top of a text editing widget.
+#[allow(missing_debug_implementations)]
+pub struct Suggestions<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    state: &'a mut State,
+    on_selected: Box<dyn FnMut(String) -> Message + 'a>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    style: Box<dyn Fn(&Theme) -> Style + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Suggestions<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    /// Creates new [`Suggestions`] with the given [`State`], the message
+    /// to produce when a suggestion is selected, and its [`Style`].
+    pub fn new(
+        state: &'a mut State,
+        on_selected: impl FnMut(String) -> Message + 'a,
+        style: impl Fn(&Theme) -> Style + 'a,
+    ) -> Self {
+        Self {
+            state,
+            on_selected: Box::new(on_selected),
+            padding: Padding::from([2, 8]),
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            style: Box::new(style),
+        }
+    }
+
+    /// Sets the [`Padding`] of each suggestion.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Suggestions`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Suggestions`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`Suggestions`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Turns the [`Suggestions`] into an overlay [`Element`] placed at the
+    /// position of its [`State`], translated by the given [`Vector`].
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        self,
+        translation: Vector,
+    ) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(Overlay {
+            position: self.state.position + translation,
+            suggestions: self,
+        }))
+    }
+}
+
+/// The local state of [`Suggestions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    suggestions: Vec<String>,
+    position: Point,
+    target_height: f32,
+    hovered: Option<usize>,
+    is_open: bool,
+}
+
+impl State {
+    /// Creates a new open [`State`] with the given suggestions.
+    ///
+    /// The suggestions will be displayed under the given position, or above
+    /// it minus the `target_height` if there is not enough space.
+    pub fn new(
+        suggestions: Vec<String>,
+        position: Point,
+        target_height: f32,
+    ) -> Self {
+        Self {
+            suggestions,
+            position,
+            target_height,
+            hovered: None,
+            is_open: true,
+        }
+    }
+
+    /// Returns whether the [`Suggestions`] are currently open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Closes the [`Suggestions`].
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+}
+
+struct Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    position: Point,
+    suggestions: Suggestions<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self
+            .suggestions
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.suggestions.text_line_height.to_absolute(text_size))
+            + self.suggestions.padding.vertical()
+    }
+
+    fn row_at(
+        &self,
+        bounds: Rectangle,
+        row_height: f32,
+        position: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let row = ((position.y - bounds.y) / row_height) as usize;
+
+        (row < self.suggestions.state.suggestions.len()).then_some(row)
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text_size = self
+            .suggestions
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self
+            .suggestions
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+
+        let text_width = self
+            .suggestions
+            .state
+            .suggestions
+            .iter()
+            .map(|suggestion| {
+                Renderer::Paragraph::with_text(Text {
+                    content: suggestion.as_str(),
+                    bounds: Size::INFINITY,
+                    size: text_size,
+                    line_height: self.suggestions.text_line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    justification: text::Justification::None,
+                })
+                .min_width()
+            })
+            .fold(0.0, f32::max);
+
+        let size = Size::new(
+            (text_width + self.suggestions.padding.horizontal())
+                .min(bounds.width),
+            self.row_height(renderer)
+                * self.suggestions.state.suggestions.len() as f32,
+        );
+
+        let target_height = self.suggestions.state.target_height;
+
+        let x = self.position.x.min(bounds.width - size.width).max(0.0);
+        let y = if self.position.y + size.height > bounds.height {
+            (self.position.y - target_height - size.height).max(0.0)
+        } else {
+            self.position.y
+        };
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let row_height = self.row_height(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                self.suggestions.state.hovered =
+                    self.row_at(bounds, row_height, position);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(row) = self.row_at(bounds, row_height, position)
+                else {
+                    self.suggestions.state.close();
+
+                    return event::Status::Ignored;
+                };
+
+                let suggestion =
+                    self.suggestions.state.suggestions[row].clone();
+
+                self.suggestions.state.close();
+                shell.publish((self.suggestions.on_selected)(suggestion));
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.suggestions.state.close();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = (self.suggestions.style)(theme);
+        let padding = self.suggestions.padding;
+        let row_height = self.row_height(renderer);
+
+        let text_size = self
+            .suggestions
+            .text_size
+            .unwrap_or_else(|| renderer.default_size());
+        let font = self
+            .suggestions
+            .font
+            .unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        for (i, suggestion) in
+            self.suggestions.state.suggestions.iter().enumerate()
+        {
+            let row_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + row_height * i as f32,
+                width: bounds.width,
+                height: row_height,
+            };
+
+            let is_hovered = self.suggestions.state.hovered == Some(i);
+
+            if is_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: row_bounds.x + style.border.width,
+                            width: row_bounds.width - style.border.width * 2.0,
+                            ..row_bounds
+                        },
+                        border: Border {
+                            radius: style.border.radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: suggestion.clone(),
+                    bounds: Size::new(f32::INFINITY, row_bounds.height),
+                    size: text_size,
+                    line_height: self.suggestions.text_line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    justification: text::Justification::None,
+                },
+                Point::new(row_bounds.x + padding.left, row_bounds.center_y()),
+                if is_hovered {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                bounds,
+            );
+        }
+    }
+}
+
+/// The appearance of [`Suggestions`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the suggestions.
+    pub background: Background,
+    /// The [`Border`] of the suggestions.
+    pub border: Border,
+    /// The text [`Color`] of a suggestion.
+    pub text_color: Color,
+    /// The text [`Color`] of the hovered suggestion.
+    pub selected_text_color: Color,
+    /// The [`Background`] of the hovered suggestion.
+    pub selected_background: Background,
+}
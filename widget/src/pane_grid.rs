@@ -32,6 +32,7 @@ pub use title_bar::TitleBar;
 
 use crate::container;
 use crate::core::event::{self, Event};
+use crate::core::gesture;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay::{self, Group};
@@ -44,7 +45,6 @@ use crate::core::{
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 
-const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
 
 /// A collection of panes distributed using either vertical or horizontal splits
@@ -316,7 +316,8 @@ where
             })
             .collect();
 
-        layout::Node::with_children(size, children)
+        // The contents of every pane are clipped to its region
+        layout::Node::with_children(size, children).overflowing()
     }
 
     fn operate(
@@ -423,7 +424,7 @@ where
                     if let Some(on_drag) = on_drag {
                         if let Some(cursor_position) = cursor.position() {
                             if cursor_position.distance(origin)
-                                > DRAG_DEADBAND_DISTANCE
+                                > gesture::current().drag
                             {
                                 let event = if let Some(edge) =
                                     in_edge(layout, cursor_position)
@@ -632,7 +633,7 @@ where
                 .position()
                 .map(|position| position.distance(*origin))
                 .unwrap_or_default()
-                > DRAG_DEADBAND_DISTANCE
+                > gesture::current().drag
         });
 
         let picked_split = action
@@ -1,20 +1,24 @@
 //! Display a dropdown list of selectable values.
 use crate::core::alignment;
+use crate::core::direction;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::overlay::menu::{self, Menu};
+use crate::overlay::Toggle;
 
 use std::borrow::Borrow;
 use std::f32;
@@ -43,12 +47,13 @@ pub struct PickList<
     placeholder: Option<String>,
     selected: Option<V>,
     width: Length,
-    padding: Padding,
+    padding: Option<Padding>,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
+    mirrored: Option<bool>,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
 }
@@ -78,12 +83,13 @@ where
             placeholder: None,
             selected,
             width: Length::Shrink,
-            padding: crate::button::DEFAULT_PADDING,
+            padding: None,
             text_size: None,
             text_line_height: text::LineHeight::default(),
-            text_shaping: text::Shaping::Basic,
+            text_shaping: text::Shaping::default(),
             font: None,
             handle: Handle::default(),
+            mirrored: None,
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
         }
@@ -103,7 +109,7 @@ where
 
     /// Sets the [`Padding`] of the [`PickList`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
-        self.padding = padding.into();
+        self.padding = Some(padding.into());
         self
     }
 
@@ -140,13 +146,29 @@ where
         self
     }
 
+    /// Sets whether the [`PickList`] is mirrored, placing its [`Handle`] on
+    /// the left side.
+    ///
+    /// By default, it is mirrored when the current [`direction`] is
+    /// right-to-left.
+    ///
+    /// [`direction`]: direction::current
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = Some(mirrored);
+        self
+    }
+
     /// Sets the message that will be produced when the [`PickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
         self
     }
 
-    /// Sets the message that will be produced when the [`PickList`] is closed.
+    /// Sets the message that will be produced when the [`PickList`] is closed,
+    /// either by selecting an option or by pressing outside of its menu.
+    ///
+    /// When an option is selected, it is produced right after the message
+    /// of the selection.
     pub fn on_close(mut self, on_close: Message) -> Self {
         self.on_close = Some(on_close);
         self
@@ -234,6 +256,10 @@ where
         let font = self.font.unwrap_or_else(|| renderer.default_font());
         let text_size =
             self.text_size.unwrap_or_else(|| renderer.default_size());
+        let density = renderer.density();
+        let padding = self
+            .padding
+            .unwrap_or_else(|| density.padding(crate::button::DEFAULT_PADDING));
         let options = self.options.borrow();
 
         state.options.resize_with(options.len(), Default::default);
@@ -250,6 +276,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: self.text_shaping,
+            justification: text::Justification::None,
         };
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
@@ -288,18 +315,35 @@ where
 
         let size = {
             let intrinsic = Size::new(
-                max_width + text_size.0 + self.padding.left,
+                max_width + text_size.0 + padding.left,
                 f32::from(self.text_line_height.to_absolute(text_size)),
             );
 
             limits
                 .width(self.width)
-                .shrink(self.padding)
+                .shrink(padding)
                 .resolve(self.width, Length::Shrink, intrinsic)
-                .expand(self.padding)
+                .expand(padding)
         };
 
-        layout::Node::new(size)
+        density.expand(limits, layout::Node::new(size))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let mut toggle = Toggle {
+            is_open: state.is_open,
+        };
+
+        operation.custom(&mut toggle, None);
+
+        state.is_open = toggle.is_open;
     }
 
     fn on_event(
@@ -436,6 +480,9 @@ where
         viewport: &Rectangle,
     ) {
         let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let padding = self.padding.unwrap_or_else(|| {
+            renderer.density().padding(crate::button::DEFAULT_PADDING)
+        });
         let selected = self.selected.as_ref().map(Borrow::borrow);
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
@@ -452,6 +499,25 @@ where
         };
 
         let style = Catalog::style(theme, &self.class, status);
+        let is_mirrored = self
+            .mirrored
+            .unwrap_or_else(|| direction::current().is_rtl());
+
+        let (start, start_alignment, end, end_alignment) = if is_mirrored {
+            (
+                bounds.x + bounds.width - padding.right,
+                alignment::Horizontal::Right,
+                bounds.x + padding.left,
+                alignment::Horizontal::Left,
+            )
+        } else {
+            (
+                bounds.x + padding.left,
+                alignment::Horizontal::Left,
+                bounds.x + bounds.width - padding.right,
+                alignment::Horizontal::Right,
+            )
+        };
 
         renderer.fill_quad(
             renderer::Quad {
@@ -512,14 +578,12 @@ where
                         bounds.width,
                         f32::from(line_height.to_absolute(size)),
                     ),
-                    horizontal_alignment: alignment::Horizontal::Right,
+                    horizontal_alignment: end_alignment,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping,
+                    justification: text::Justification::None,
                 },
-                Point::new(
-                    bounds.x + bounds.width - self.padding.right,
-                    bounds.center_y(),
-                ),
+                Point::new(end, bounds.center_y()),
                 style.handle_color,
                 *viewport,
             );
@@ -538,14 +602,15 @@ where
                     line_height: self.text_line_height,
                     font,
                     bounds: Size::new(
-                        bounds.width - self.padding.horizontal(),
+                        bounds.width - padding.horizontal(),
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
-                    horizontal_alignment: alignment::Horizontal::Left,
+                    horizontal_alignment: start_alignment,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    justification: text::Justification::None,
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                Point::new(start, bounds.center_y()),
                 if is_selected {
                     style.text_color
                 } else {
@@ -565,6 +630,9 @@ where
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let padding = self.padding.unwrap_or_else(|| {
+            renderer.density().padding(crate::button::DEFAULT_PADDING)
+        });
 
         if state.is_open {
             let bounds = layout.bounds();
@@ -584,7 +652,7 @@ where
                 &self.menu_class,
             )
             .width(bounds.width)
-            .padding(self.padding)
+            .padding(padding)
             .font(font)
             .text_shaping(self.text_shaping);
 
@@ -592,6 +660,10 @@ where
                 menu = menu.text_size(text_size);
             }
 
+            if let Some(on_close) = &self.on_close {
+                menu = menu.on_close(on_close.clone());
+            }
+
             Some(menu.overlay(layout.position() + translation, bounds.height))
         } else {
             None
@@ -717,6 +789,17 @@ pub struct Style {
     pub border: Border,
 }
 
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            text_color: layer.text_color.unwrap_or(self.text_color),
+            background: layer.background.unwrap_or(self.background),
+            border: layer.border(self.border),
+            ..self
+        }
+    }
+}
+
 /// The theme catalog of a [`PickList`].
 pub trait Catalog: menu::Catalog {
     /// The item class of the [`Catalog`].
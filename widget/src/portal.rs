@@ -0,0 +1,358 @@
+//! Declare content in one place of a view, but render it on top of
+//! everything else.
+//!
+//! A [`Portal`] escapes the clipping and layering of its ancestors by
+//! rendering its content into the overlay layer; while its messages are
+//! still mapped like any other content declared in the same place.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::core::alignment::Vertical;
+//! use iced::widget::portal::Destination;
+//! use iced::widget::{column, portal, scrollable, text};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view<'a>() -> Element<'a, Message> {
+//!     scrollable(column![
+//!         text("Lots of content..."),
+//!         portal(text("Saved!")).destination(
+//!             Destination::viewport().align_y(Vertical::Bottom).padding(10),
+//!         ),
+//!     ])
+//!     .into()
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Length, Padding, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+/// A widget that renders its content into the overlay layer.
+///
+/// By default, the content is rendered right where the [`Portal`] is
+/// declared; taking the same space, but drawn on top of everything else
+/// and unclipped by the scrollables and containers around it.
+#[allow(missing_debug_implementations)]
+pub struct Portal<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    destination: Destination,
+}
+
+impl<'a, Message, Theme, Renderer> Portal<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Portal`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            destination: Destination::Inline,
+        }
+    }
+
+    /// Sets the [`Destination`] of the [`Portal`].
+    pub fn destination(mut self, destination: impl Into<Destination>) -> Self {
+        self.destination = destination.into();
+        self
+    }
+}
+
+/// Where a [`Portal`] renders its content.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Destination {
+    /// Right where the [`Portal`] is declared.
+    #[default]
+    Inline,
+
+    /// Aligned inside of the viewport; like a toast or a floating panel.
+    ///
+    /// The [`Portal`] does not take any space where it is declared.
+    Viewport(Viewport),
+}
+
+impl Destination {
+    /// Creates a new [`Viewport`] destination, centered in the viewport.
+    pub fn viewport() -> Viewport {
+        Viewport::default()
+    }
+}
+
+/// A [`Destination`] aligned inside of the viewport.
+#[derive(Debug, Clone, Copy)]
+pub struct Viewport {
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    padding: Padding,
+}
+
+impl Viewport {
+    /// Sets the horizontal alignment of the content in the viewport.
+    pub fn align_x(self, align: impl Into<alignment::Horizontal>) -> Self {
+        Self {
+            align_x: align.into(),
+            ..self
+        }
+    }
+
+    /// Sets the vertical alignment of the content in the viewport.
+    pub fn align_y(self, align: impl Into<alignment::Vertical>) -> Self {
+        Self {
+            align_y: align.into(),
+            ..self
+        }
+    }
+
+    /// Sets the [`Padding`] between the content and the edges of the
+    /// viewport.
+    pub fn padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: padding.into(),
+            ..self
+        }
+    }
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            align_x: alignment::Horizontal::Center,
+            align_y: alignment::Vertical::Center,
+            padding: Padding::ZERO,
+        }
+    }
+}
+
+impl From<Viewport> for Destination {
+    fn from(viewport: Viewport) -> Self {
+        Self::Viewport(viewport)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Portal<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        match self.destination {
+            Destination::Inline => self.content.as_widget().size(),
+            Destination::Viewport(_) => Size {
+                width: Length::Shrink,
+                height: Length::Shrink,
+            },
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match self.destination {
+            Destination::Inline => self.content.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                limits,
+            ),
+            Destination::Viewport(_) => layout::Node::new(Size::ZERO),
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        // The content is drawn by the overlay
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        Some(overlay::Element::new(Box::new(Overlay {
+            content: &mut self.content,
+            tree: &mut tree.children[0],
+            destination: self.destination,
+            bounds: layout.bounds() + translation,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Portal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(portal: Portal<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(portal)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer> {
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    destination: Destination,
+    bounds: Rectangle,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        match self.destination {
+            Destination::Inline => {
+                let size = self.bounds.size();
+
+                self.content
+                    .as_widget()
+                    .layout(
+                        self.tree,
+                        renderer,
+                        &layout::Limits::new(size, size),
+                    )
+                    .move_to(self.bounds.position())
+            }
+            Destination::Viewport(viewport) => {
+                let padding = viewport.padding;
+                let space = Size::new(
+                    (bounds.width - padding.horizontal()).max(0.0),
+                    (bounds.height - padding.vertical()).max(0.0),
+                );
+
+                self.content
+                    .as_widget()
+                    .layout(
+                        self.tree,
+                        renderer,
+                        &layout::Limits::new(Size::ZERO, space),
+                    )
+                    .align(
+                        Alignment::from(viewport.align_x),
+                        Alignment::from(viewport.align_y),
+                        space,
+                    )
+                    .translate(Vector::new(padding.left, padding.top))
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            self.tree,
+            layout,
+            renderer,
+            Vector::ZERO,
+        )
+    }
+}
@@ -1,11 +1,13 @@
 //! Provide progress feedback to your users.
 use crate::core::border::{self, Border};
+use crate::core::direction;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::Tree;
 use crate::core::{
-    self, Background, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+    self, Background, Color, Element, Layout, Length, Rectangle, Size, Theme,
+    Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -21,6 +23,13 @@ use std::ops::RangeInclusive;
 /// ProgressBar::new(0.0..=100.0, value);
 /// ```
 ///
+/// A [`ProgressBar`] can also display a secondary [`buffered`] value, be
+/// divided into [`segments`], and be laid out [`vertical`]ly.
+///
+/// [`buffered`]: Self::buffered
+/// [`segments`]: Self::segments
+/// [`vertical`]: Self::vertical
+///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<'a, Theme = crate::Theme>
@@ -29,7 +38,11 @@ where
 {
     range: RangeInclusive<f32>,
     value: f32,
-    width: Length,
+    buffered: Option<f32>,
+    segments: Vec<f32>,
+    is_vertical: bool,
+    mirrored: Option<bool>,
+    width: Option<Length>,
     height: Option<Length>,
     class: Theme::Class<'a>,
 }
@@ -39,8 +52,15 @@ where
     Theme: Catalog,
 {
     /// The default height of a [`ProgressBar`].
+    ///
+    /// It is used as the default width of a [`vertical`] [`ProgressBar`].
+    ///
+    /// [`vertical`]: Self::vertical
     pub const DEFAULT_HEIGHT: f32 = 30.0;
 
+    /// The thickness of the segment markers of a [`ProgressBar`].
+    pub const SEGMENT_WIDTH: f32 = 2.0;
+
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
@@ -50,7 +70,11 @@ where
         ProgressBar {
             value: value.clamp(*range.start(), *range.end()),
             range,
-            width: Length::Fill,
+            buffered: None,
+            segments: Vec::new(),
+            is_vertical: false,
+            mirrored: None,
+            width: None,
             height: None,
             class: Theme::default(),
         }
@@ -58,7 +82,7 @@ where
 
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.width = Some(width.into());
         self
     }
 
@@ -68,6 +92,102 @@ where
         self
     }
 
+    /// Sets the buffered value of the [`ProgressBar`].
+    ///
+    /// The buffered value is displayed behind the current value; like the
+    /// amount of a video that has been downloaded ahead of playback.
+    pub fn buffered(mut self, buffered: f32) -> Self {
+        self.buffered =
+            Some(buffered.clamp(*self.range.start(), *self.range.end()));
+        self
+    }
+
+    /// Divides the [`ProgressBar`] into segments by placing markers at the
+    /// given values.
+    pub fn segments(mut self, segments: impl IntoIterator<Item = f32>) -> Self {
+        self.segments = segments
+            .into_iter()
+            .filter(|segment| self.range.contains(segment))
+            .collect();
+        self
+    }
+
+    /// Sets whether the [`ProgressBar`] is vertical; filling from the
+    /// bottom to the top.
+    pub fn vertical(mut self, is_vertical: bool) -> Self {
+        self.is_vertical = is_vertical;
+        self
+    }
+
+    /// Sets whether a horizontal [`ProgressBar`] is mirrored; filling from
+    /// the right to the left.
+    ///
+    /// By default, it is mirrored when the current [`direction`] is
+    /// right-to-left. Progress that runs along a timeline, like the playback
+    /// of a video, should usually disable it.
+    ///
+    /// [`direction`]: direction::current
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = Some(mirrored);
+        self
+    }
+
+    fn is_mirrored(&self) -> bool {
+        !self.is_vertical
+            && self
+                .mirrored
+                .unwrap_or_else(|| direction::current().is_rtl())
+    }
+
+    fn length(&self) -> Size<Length> {
+        let thickness = Length::Fixed(Self::DEFAULT_HEIGHT);
+
+        if self.is_vertical {
+            Size {
+                width: self.width.unwrap_or(thickness),
+                height: self.height.unwrap_or(Length::Fill),
+            }
+        } else {
+            Size {
+                width: self.width.unwrap_or(Length::Fill),
+                height: self.height.unwrap_or(thickness),
+            }
+        }
+    }
+
+    /// Returns the portion of the given bounds filled up to `value`.
+    fn filled(&self, bounds: Rectangle, value: f32) -> Rectangle {
+        let (range_start, range_end) = self.range.clone().into_inner();
+
+        let ratio = if range_start >= range_end {
+            0.0
+        } else {
+            (value - range_start) / (range_end - range_start)
+        };
+
+        if self.is_vertical {
+            let height = bounds.height * ratio;
+
+            Rectangle {
+                y: bounds.y + bounds.height - height,
+                height,
+                ..bounds
+            }
+        } else {
+            let width = bounds.width * ratio;
+
+            Rectangle {
+                x: if self.is_mirrored() {
+                    bounds.x + bounds.width - width
+                } else {
+                    bounds.x
+                },
+                width,
+                ..bounds
+            }
+        }
+    }
+
     /// Sets the style of the [`ProgressBar`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -94,10 +214,7 @@ where
     Renderer: core::Renderer,
 {
     fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
-        }
+        self.length()
     }
 
     fn layout(
@@ -106,11 +223,9 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(
-            limits,
-            self.width,
-            self.height.unwrap_or(Length::Fixed(Self::DEFAULT_HEIGHT)),
-        )
+        let size = self.length();
+
+        layout::atomic(limits, size.width, size.height)
     }
 
     fn draw(
@@ -124,15 +239,6 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
-
-        let active_progress_width = if range_start >= range_end {
-            0.0
-        } else {
-            bounds.width * (self.value - range_start)
-                / (range_end - range_start)
-        };
-
         let style = theme.style(&self.class);
 
         renderer.fill_quad(
@@ -144,19 +250,67 @@ where
             style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if let Some(buffered) = self.buffered {
+            let buffered = self.filled(bounds, buffered);
+
+            if buffered.width > 0.0 && buffered.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: buffered,
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.buffer,
+                );
+            }
+        }
+
+        let active = self.filled(bounds, self.value);
+
+        if active.width > 0.0 && active.height > 0.0 {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
+                    bounds: active,
                     border: border::rounded(style.border.radius),
                     ..renderer::Quad::default()
                 },
                 style.bar,
             );
         }
+
+        for &segment in &self.segments {
+            let filled = self.filled(bounds, segment);
+
+            let marker = if self.is_vertical {
+                Rectangle {
+                    y: filled.y - Self::SEGMENT_WIDTH / 2.0,
+                    height: Self::SEGMENT_WIDTH,
+                    ..bounds
+                }
+            } else if self.is_mirrored() {
+                Rectangle {
+                    x: filled.x - Self::SEGMENT_WIDTH / 2.0,
+                    width: Self::SEGMENT_WIDTH,
+                    ..bounds
+                }
+            } else {
+                Rectangle {
+                    x: filled.x + filled.width - Self::SEGMENT_WIDTH / 2.0,
+                    width: Self::SEGMENT_WIDTH,
+                    ..bounds
+                }
+            };
+
+            if let Some(marker) = marker.intersection(&bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: marker,
+                        ..renderer::Quad::default()
+                    },
+                    style.segment,
+                );
+            }
+        }
     }
 }
 
@@ -181,6 +335,10 @@ pub struct Style {
     pub background: Background,
     /// The [`Background`] of the bar of the progress bar.
     pub bar: Background,
+    /// The [`Background`] of the buffered part of the progress bar.
+    pub buffer: Background,
+    /// The [`Color`] of the segment markers of the progress bar.
+    pub segment: Color,
     /// The [`Border`] of the progress bar.
     pub border: Border,
 }
@@ -221,6 +379,8 @@ pub fn primary(theme: &Theme) -> Style {
     styled(
         palette.background.strong.color,
         palette.primary.strong.color,
+        palette.primary.weak.color,
+        palette.background.base.color,
     )
 }
 
@@ -231,6 +391,8 @@ pub fn secondary(theme: &Theme) -> Style {
     styled(
         palette.background.strong.color,
         palette.secondary.base.color,
+        palette.secondary.weak.color,
+        palette.background.base.color,
     )
 }
 
@@ -238,23 +400,37 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    styled(palette.background.strong.color, palette.success.base.color)
+    styled(
+        palette.background.strong.color,
+        palette.success.base.color,
+        palette.success.weak.color,
+        palette.background.base.color,
+    )
 }
 
 /// The danger style of a [`ProgressBar`].
 pub fn danger(theme: &Theme) -> Style {
     let palette = theme.extended_palette();
 
-    styled(palette.background.strong.color, palette.danger.base.color)
+    styled(
+        palette.background.strong.color,
+        palette.danger.base.color,
+        palette.danger.weak.color,
+        palette.background.base.color,
+    )
 }
 
 fn styled(
     background: impl Into<Background>,
     bar: impl Into<Background>,
+    buffer: impl Into<Background>,
+    segment: Color,
 ) -> Style {
     Style {
         background: background.into(),
         bar: bar.into(),
+        buffer: buffer.into(),
+        segment,
         border: border::rounded(2),
     }
 }
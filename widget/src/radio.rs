@@ -77,8 +77,8 @@ where
     on_click: Message,
     label: String,
     width: Length,
-    size: f32,
-    spacing: f32,
+    size: Option<f32>,
+    spacing: Option<f32>,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
@@ -121,11 +121,11 @@ where
             on_click: f(value),
             label: label.into(),
             width: Length::Shrink,
-            size: Self::DEFAULT_SIZE,
-            spacing: Self::DEFAULT_SPACING, //15
+            size: None,
+            spacing: None, //15
             text_size: None,
             text_line_height: text::LineHeight::default(),
-            text_shaping: text::Shaping::Basic,
+            text_shaping: text::Shaping::default(),
             font: None,
             class: Theme::default(),
         }
@@ -133,7 +133,7 @@ where
 
     /// Sets the size of the [`Radio`] button.
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
-        self.size = size.into().0;
+        self.size = Some(size.into().0);
         self
     }
 
@@ -145,7 +145,7 @@ where
 
     /// Sets the spacing between the [`Radio`] button and the text.
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
-        self.spacing = spacing.into().0;
+        self.spacing = Some(spacing.into().0);
         self
     }
 
@@ -223,10 +223,18 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::next_to_each_other(
+        let density = renderer.density();
+        let size = self
+            .size
+            .unwrap_or_else(|| Self::DEFAULT_SIZE * density.scale());
+        let spacing = self
+            .spacing
+            .unwrap_or_else(|| Self::DEFAULT_SPACING * density.scale());
+
+        let node = layout::next_to_each_other(
             &limits.width(self.width),
-            self.spacing,
-            |_| layout::Node::new(Size::new(self.size, self.size)),
+            spacing,
+            |_| layout::Node::new(Size::new(size, size)),
             |limits| {
                 let state = tree
                     .state
@@ -245,9 +253,12 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Justification::None,
                 )
             },
-        )
+        );
+
+        density.expand(limits, node)
     }
 
     fn on_event(
@@ -363,6 +374,7 @@ where
                 state.0.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    ..crate::text::Style::default()
                 },
                 viewport,
             );
@@ -0,0 +1,497 @@
+//! Display an interactive selector of a sub-range from a range of values.
+use std::ops::RangeInclusive;
+
+pub use crate::slider::{
+    default, Catalog, Handle, HandleShape, Status, Style, StyleFn,
+};
+
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+/// An horizontal bar and two handles that select a sub-range from a range
+/// of values.
+///
+/// A [`RangeSlider`] will try to fill the horizontal space of its container.
+///
+/// Like a [`Slider`], its range of numeric values is generic and its step
+/// size defaults to 1 unit. The handles cannot cross each other.
+///
+/// # Example
+/// ```no_run
+/// # type RangeSlider<'a, T, Message> = iced_widget::RangeSlider<'a, T, Message>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceChanged(std::ops::RangeInclusive<f32>),
+/// }
+///
+/// let price = 20.0..=80.0;
+///
+/// RangeSlider::new(0.0..=100.0, price, Message::PriceChanged);
+/// ```
+///
+/// [`Slider`]: crate::Slider
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    shift_step: Option<T>,
+    start: T,
+    end: T,
+    on_change: Box<dyn Fn(RangeInclusive<T>) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: Option<f32>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the currently selected sub-range of the [`RangeSlider`]
+    ///   * a function that will be called when any of the handles of the
+    ///     [`RangeSlider`] is dragged. It receives the new sub-range of the
+    ///     [`RangeSlider`] and must produce a `Message`.
+    pub fn new<F>(
+        range: RangeInclusive<T>,
+        value: RangeInclusive<T>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'a + Fn(RangeInclusive<T>) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (start, end) = value.into_inner();
+        let (start, end) = (clamp(start), clamp(end));
+
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            start,
+            end,
+            range,
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`].
+    /// This is called when the mouse is released from the slider.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = Some(height.into().0);
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step for the [`RangeSlider`].
+    ///
+    /// If set, this value is used as the step while the shift key is pressed.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RangeSlider`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let density = renderer.density();
+        let height = self
+            .height
+            .unwrap_or_else(|| Self::DEFAULT_HEIGHT * density.scale());
+
+        density.expand(limits, layout::atomic(limits, self.width, height))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let (current_start, current_end) = (self.start, self.end);
+
+        let start: f64 = (*self.range.start()).into();
+        let end: f64 = (*self.range.end()).into();
+
+        let ratio = |value: T| -> f64 {
+            if start >= end {
+                0.0
+            } else {
+                (value.into() - start) / (end - start)
+            }
+        };
+
+        let locate = |cursor_position: Point| -> f64 {
+            if cursor_position.x <= bounds.x {
+                start
+            } else if cursor_position.x >= bounds.x + bounds.width {
+                end
+            } else {
+                let step = if state.keyboard_modifiers.shift() {
+                    self.shift_step.unwrap_or(self.step)
+                } else {
+                    self.step
+                }
+                .into();
+
+                let percent = f64::from(cursor_position.x - bounds.x)
+                    / f64::from(bounds.width);
+
+                let steps = (percent * (end - start) / step).round();
+
+                (steps * step + start).min(end)
+            }
+        };
+
+        let nearest = |cursor_position: Point| -> Thumb {
+            let x = |value: T| bounds.x + bounds.width * ratio(value) as f32;
+
+            let to_start = (cursor_position.x - x(current_start)).abs();
+            let to_end = (cursor_position.x - x(current_end)).abs();
+
+            // Overlapping handles are split by the side of the cursor
+            if to_start < to_end
+                || (to_start == to_end && cursor_position.x < x(current_start))
+            {
+                Thumb::Start
+            } else {
+                Thumb::End
+            }
+        };
+
+        let mut change = |thumb: Thumb, value: f64| {
+            let (new_start, new_end) = match thumb {
+                Thumb::Start => (value.min(self.end.into()), self.end.into()),
+                Thumb::End => (self.start.into(), value.max(self.start.into())),
+            };
+
+            let (Some(new_start), Some(new_end)) =
+                (T::from_f64(new_start), T::from_f64(new_end))
+            else {
+                return;
+            };
+
+            if (self.start.into() - new_start.into()).abs() > f64::EPSILON
+                || (self.end.into() - new_end.into()).abs() > f64::EPSILON
+            {
+                shell.publish((self.on_change)(new_start..=new_end));
+
+                self.start = new_start;
+                self.end = new_end;
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
+                    let thumb = nearest(cursor_position);
+
+                    change(thumb, locate(cursor_position));
+                    state.dragging = Some(thumb);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    state.dragging = None;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = state.dragging {
+                    if let Some(cursor_position) = cursor.position() {
+                        change(thumb, locate(cursor_position));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        let style = theme.style(
+            &self.class,
+            if state.dragging.is_some() {
+                Status::Dragged
+            } else if is_mouse_over {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (handle_width, handle_height, handle_border_radius) =
+            match style.handle.shape {
+                HandleShape::Circle { radius } => {
+                    (radius * 2.0, radius * 2.0, radius.into())
+                }
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                } => (f32::from(width), bounds.height, border_radius),
+            };
+
+        let (range_start, range_end) = {
+            let (start, end) = self.range.clone().into_inner();
+
+            (start.into() as f32, end.into() as f32)
+        };
+
+        let offset = |value: T| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (bounds.width - handle_width)
+                    * (value.into() as f32 - range_start)
+                    / (range_end - range_start)
+            }
+        };
+
+        let start_offset = offset(self.start);
+        let end_offset = offset(self.end);
+
+        let rail_y = bounds.y + bounds.height / 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: rail_y - style.rail.width / 2.0,
+                    width: bounds.width,
+                    height: style.rail.width,
+                },
+                border: border::rounded(style.rail.border_radius),
+                ..renderer::Quad::default()
+            },
+            style.rail.inactive,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + start_offset + handle_width / 2.0,
+                    y: rail_y - style.rail.width / 2.0,
+                    width: end_offset - start_offset,
+                    height: style.rail.width,
+                },
+                border: border::rounded(style.rail.border_radius),
+                ..renderer::Quad::default()
+            },
+            style.rail.active,
+        );
+
+        for offset in [start_offset, end_offset] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset,
+                        y: rail_y - handle_height / 2.0,
+                        width: handle_width,
+                        height: handle_height,
+                    },
+                    border: Border {
+                        radius: handle_border_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.color,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(
+        slider: RangeSlider<'a, T, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(slider)
+    }
+}
+
+/// One of the two handles of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct State {
+    dragging: Option<Thumb>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
@@ -199,7 +199,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::flex::resolve(
+        let node = layout::flex::resolve(
             layout::flex::Axis::Horizontal,
             renderer,
             limits,
@@ -210,7 +210,13 @@ where
             self.align,
             &self.children,
             &mut tree.children,
-        )
+        );
+
+        if self.clip {
+            node.overflowing()
+        } else {
+            node
+        }
     }
 
     fn operate(
@@ -1,12 +1,14 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::container;
 use crate::core::border::{self, Border};
+use crate::core::direction;
 use crate::core::event::{self, Event};
-use crate::core::keyboard;
+use crate::core::keyboard::{self, key};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
@@ -38,6 +40,11 @@ pub struct Scrollable<
     direction: Direction,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    markers: Vec<(f32, Color)>,
+    line_step: f32,
+    page_step: Option<f32>,
+    chaining: Chaining,
+    mirrored: Option<bool>,
     class: Theme::Class<'a>,
 }
 
@@ -46,6 +53,9 @@ where
     Theme: Catalog,
     Renderer: core::Renderer,
 {
+    /// The default distance scrolled by the arrow keys.
+    pub const DEFAULT_LINE_STEP: f32 = 40.0;
+
     /// Creates a new vertical [`Scrollable`].
     pub fn new(
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
@@ -65,6 +75,11 @@ where
             direction: direction.into(),
             content: content.into(),
             on_scroll: None,
+            markers: Vec::new(),
+            line_step: Self::DEFAULT_LINE_STEP,
+            page_step: None,
+            chaining: Chaining::default(),
+            mirrored: None,
             class: Theme::default(),
         }
         .validate()
@@ -126,6 +141,49 @@ where
         self
     }
 
+    /// Sets the markers displayed along the vertical [`Scrollbar`] of the
+    /// [`Scrollable`]; like search hits, errors, or bookmarks.
+    ///
+    /// Each marker is a position relative to the content, between `0.0`
+    /// (top) and `1.0` (bottom), paired with the [`Color`] used to draw it.
+    /// Clicking a marker scrolls its position to the center of the
+    /// [`Scrollable`].
+    pub fn markers(
+        mut self,
+        markers: impl IntoIterator<Item = (f32, Color)>,
+    ) -> Self {
+        self.markers = markers
+            .into_iter()
+            .map(|(position, color)| (position.clamp(0.0, 1.0), color))
+            .collect();
+        self
+    }
+
+    /// Sets the distance scrolled by the arrow keys.
+    ///
+    /// The [`Scrollable`] reacts to the keyboard while hovered or after
+    /// being clicked, as long as its contents do not capture the keys.
+    pub fn line_step(mut self, line_step: impl Into<Pixels>) -> Self {
+        self.line_step = line_step.into().0;
+        self
+    }
+
+    /// Sets the distance scrolled by the page up, page down, and space keys.
+    ///
+    /// By default, it is the height of the [`Scrollable`] minus one
+    /// line step; so some context remains visible.
+    pub fn page_step(mut self, page_step: impl Into<Pixels>) -> Self {
+        self.page_step = Some(page_step.into().0);
+        self
+    }
+
+    /// Sets the [`Chaining`] strategy of the [`Scrollable`]; that is, when
+    /// the wheel events it cannot consume are passed to its parents.
+    pub fn chaining(mut self, chaining: Chaining) -> Self {
+        self.chaining = chaining;
+        self
+    }
+
     /// Anchors the vertical [`Scrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -189,6 +247,23 @@ where
         self
     }
 
+    /// Sets whether the [`Scrollable`] is mirrored, placing its vertical
+    /// [`Scrollbar`] on the left side.
+    ///
+    /// By default, it is mirrored when the current [`direction`] is
+    /// right-to-left.
+    ///
+    /// [`direction`]: direction::current
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = Some(mirrored);
+        self
+    }
+
+    fn is_mirrored(&self) -> bool {
+        self.mirrored
+            .unwrap_or_else(|| direction::current().is_rtl())
+    }
+
     /// Sets the style of this [`Scrollable`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -324,6 +399,32 @@ pub enum Anchor {
     End,
 }
 
+/// The strategy a [`Scrollable`] follows to pass wheel events to its parents
+/// once it reaches the end of its contents.
+///
+/// The innermost hovered [`Scrollable`] always gets to consume a wheel event
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Chaining {
+    /// Wheel events are passed to the parents only once a new scroll gesture
+    /// starts; so the momentum of a gesture never leaks out of the
+    /// [`Scrollable`].
+    ///
+    /// While a gesture is scrolling a [`Scrollable`], it also stays latched
+    /// to it even if the cursor moves over a nested one.
+    #[default]
+    Latched,
+    /// Wheel events are passed to the parents as soon as the [`Scrollable`]
+    /// cannot scroll any further.
+    Immediate,
+    /// Wheel events are never passed to the parents, as long as the contents
+    /// of the [`Scrollable`] overflow.
+    Never,
+}
+
+/// The time without wheel events after which a new scroll gesture starts.
+const GESTURE_TIMEOUT: Duration = Duration::from_millis(200);
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Scrollable<'a, Message, Theme, Renderer>
 where
@@ -359,7 +460,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (right_padding, bottom_padding) = match self.direction {
+        let (side_padding, bottom_padding) = match self.direction {
             Direction::Vertical(Scrollbar {
                 width,
                 margin,
@@ -379,10 +480,18 @@ where
             limits,
             self.width,
             self.height,
-            Padding {
-                right: right_padding,
-                bottom: bottom_padding,
-                ..Padding::ZERO
+            if self.is_mirrored() {
+                Padding {
+                    left: side_padding,
+                    bottom: bottom_padding,
+                    ..Padding::ZERO
+                }
+            } else {
+                Padding {
+                    right: side_padding,
+                    bottom: bottom_padding,
+                    ..Padding::ZERO
+                }
             },
             |limits| {
                 let child_limits = layout::Limits::new(
@@ -408,6 +517,7 @@ where
                 )
             },
         )
+        .overflowing()
     }
 
     fn operate(
@@ -464,8 +574,19 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = cursor_over_scrollable.is_some();
+        }
+
+        let scrollbars = Scrollbars::new(
+            state,
+            self.direction,
+            bounds,
+            content_bounds,
+            self.is_mirrored(),
+        );
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
@@ -511,6 +632,51 @@ where
                         return event::Status::Ignored;
                     };
 
+                    if let Some((scrollbar, marker)) =
+                        scrollbars.y.and_then(|scrollbar| {
+                            let is_over_scroller =
+                                scrollbar.scroller.is_some_and(|scroller| {
+                                    scroller.bounds.contains(cursor_position)
+                                });
+
+                            if is_over_scroller {
+                                return None;
+                            }
+
+                            Some((
+                                scrollbar,
+                                marker_at(
+                                    &self.markers,
+                                    &scrollbar,
+                                    cursor_position,
+                                )?,
+                            ))
+                        })
+                    {
+                        let percentage = (marker * content_bounds.height
+                            - bounds.height / 2.0)
+                            / (content_bounds.height - bounds.height);
+
+                        state.scroll_y_to(
+                            match scrollbar.alignment {
+                                Anchor::Start => percentage,
+                                Anchor::End => 1.0 - percentage,
+                            },
+                            bounds,
+                            content_bounds,
+                        );
+
+                        let _ = notify_on_scroll(
+                            state,
+                            &self.on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
+
+                        return event::Status::Captured;
+                    }
+
                     if let (Some(scroller_grabbed_at), Some(scrollbar)) = (
                         scrollbars.grab_y_scroller(cursor_position),
                         scrollbars.y,
@@ -612,7 +778,24 @@ where
             }
         }
 
-        let mut event_status = {
+        let now = Instant::now();
+
+        let is_latched = self.chaining == Chaining::Latched
+            && cursor_over_scrollable.is_some()
+            && matches!(
+                event,
+                Event::Mouse(mouse::Event::WheelScrolled { .. })
+            )
+            && state.last_scrolled_at.is_some_and(|last_scrolled_at| {
+                now.saturating_duration_since(last_scrolled_at)
+                    < GESTURE_TIMEOUT
+            });
+
+        let mut event_status = if is_latched {
+            // Keep the gesture scrolling this scrollable; even if the
+            // cursor moved over a nested one
+            event::Status::Ignored
+        } else {
             let cursor = match cursor_over_scrollable {
                 Some(cursor_position)
                     if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) =>
@@ -697,15 +880,48 @@ where
                     mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
                 };
 
+                let is_new_gesture =
+                    state.last_scrolled_at.map_or(true, |last_scrolled_at| {
+                        now.saturating_duration_since(last_scrolled_at)
+                            >= GESTURE_TIMEOUT
+                    });
+
+                let previous =
+                    state.translation(self.direction, bounds, content_bounds);
+
                 state.scroll(delta, self.direction, bounds, content_bounds);
 
-                event_status = if notify_on_scroll(
+                let _ = notify_on_scroll(
                     state,
                     &self.on_scroll,
                     bounds,
                     content_bounds,
                     shell,
-                ) {
+                );
+
+                let has_scrolled = previous
+                    != state.translation(
+                        self.direction,
+                        bounds,
+                        content_bounds,
+                    );
+
+                let is_overflowing = content_bounds.width > bounds.width
+                    || content_bounds.height > bounds.height;
+
+                let is_captured = has_scrolled
+                    || is_overflowing
+                        && match self.chaining {
+                            Chaining::Latched => !is_new_gesture,
+                            Chaining::Immediate => false,
+                            Chaining::Never => true,
+                        };
+
+                if is_captured {
+                    state.last_scrolled_at = Some(now);
+                }
+
+                event_status = if is_captured {
                     event::Status::Captured
                 } else {
                     event::Status::Ignored
@@ -762,6 +978,47 @@ where
 
                 event_status = event::Status::Captured;
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) if state.is_focused || cursor_over_scrollable.is_some() => {
+                let page = self
+                    .page_step
+                    .unwrap_or(bounds.height - self.line_step)
+                    .max(self.line_step);
+
+                let delta = match named {
+                    key::Named::ArrowUp => Vector::new(0.0, self.line_step),
+                    key::Named::ArrowDown => Vector::new(0.0, -self.line_step),
+                    key::Named::ArrowLeft => Vector::new(self.line_step, 0.0),
+                    key::Named::ArrowRight => Vector::new(-self.line_step, 0.0),
+                    key::Named::PageUp => Vector::new(0.0, page),
+                    key::Named::PageDown => Vector::new(0.0, -page),
+                    key::Named::Space if modifiers.shift() => {
+                        Vector::new(0.0, page)
+                    }
+                    key::Named::Space => Vector::new(0.0, -page),
+                    key::Named::Home => Vector::new(0.0, content_bounds.height),
+                    key::Named::End => Vector::new(0.0, -content_bounds.height),
+                    _ => return event_status,
+                };
+
+                state.scroll(delta, self.direction, bounds, content_bounds);
+
+                // Let parents scroll if this one is already at the edge
+                event_status = if notify_on_scroll(
+                    state,
+                    &self.on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                ) {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                };
+            }
             _ => {}
         }
 
@@ -788,8 +1045,13 @@ where
             return;
         };
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars = Scrollbars::new(
+            state,
+            self.direction,
+            bounds,
+            content_bounds,
+            self.is_mirrored(),
+        );
 
         let cursor_over_scrollable = cursor.position_over(bounds);
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
@@ -909,6 +1171,21 @@ where
                             style.vertical_rail,
                             &scrollbar,
                         );
+
+                        for (position, color) in &self.markers {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        y: marker_y(&scrollbar, *position)
+                                            - MARKER_HEIGHT / 2.0,
+                                        height: MARKER_HEIGHT,
+                                        ..scrollbar.bounds
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                *color,
+                            );
+                        }
                     }
 
                     if let Some(scrollbar) = scrollbars.x {
@@ -972,8 +1249,13 @@ where
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let scrollbars =
-            Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars = Scrollbars::new(
+            state,
+            self.direction,
+            bounds,
+            content_bounds,
+            self.is_mirrored(),
+        );
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
             scrollbars.is_mouse_over(cursor);
@@ -1088,6 +1370,35 @@ pub fn scroll_to<T>(id: Id, offset: AbsoluteOffset) -> Task<T> {
     )))
 }
 
+const MARKER_HEIGHT: f32 = 2.0;
+
+fn marker_y(scrollbar: &internals::Scrollbar, position: f32) -> f32 {
+    let bounds = scrollbar.bounds;
+
+    (bounds.y + position * bounds.height).clamp(
+        bounds.y + MARKER_HEIGHT / 2.0,
+        bounds.y + bounds.height - MARKER_HEIGHT / 2.0,
+    )
+}
+
+fn marker_at(
+    markers: &[(f32, Color)],
+    scrollbar: &internals::Scrollbar,
+    cursor_position: Point,
+) -> Option<f32> {
+    markers
+        .iter()
+        .map(|(position, _)| {
+            (
+                *position,
+                (marker_y(scrollbar, *position) - cursor_position.y).abs(),
+            )
+        })
+        .filter(|(_, distance)| *distance <= MARKER_HEIGHT * 2.0)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(position, _)| position)
+}
+
 /// Returns [`true`] if the viewport actually changed.
 fn notify_on_scroll<Message>(
     state: &mut State,
@@ -1147,6 +1458,8 @@ struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    last_scrolled_at: Option<Instant>,
+    is_focused: bool,
 }
 
 impl Default for State {
@@ -1159,6 +1472,8 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            last_scrolled_at: None,
+            is_focused: false,
         }
     }
 }
@@ -1417,9 +1732,23 @@ impl Scrollbars {
         direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
+        mirrored: bool,
     ) -> Self {
         let translation = state.translation(direction, bounds, content_bounds);
 
+        // Moves the vertical scrollbar to the left side, if mirrored
+        let mirror = |rectangle: Rectangle| {
+            if mirrored {
+                Rectangle {
+                    x: bounds.x + bounds.width
+                        - (rectangle.x + rectangle.width - bounds.x),
+                    ..rectangle
+                }
+            } else {
+                rectangle
+            }
+        };
+
         let show_scrollbar_x = direction.horizontal().filter(|scrollbar| {
             scrollbar.spacing.is_some() || content_bounds.width > bounds.width
         });
@@ -1445,22 +1774,22 @@ impl Scrollbars {
                 width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width - total_scrollbar_width,
                 y: bounds.y,
                 width: total_scrollbar_width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width
                     - total_scrollbar_width / 2.0
                     - width / 2.0,
                 y: bounds.y,
                 width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             let ratio = bounds.height / content_bounds.height;
 
@@ -1474,14 +1803,14 @@ impl Scrollbars {
                     translation.y * ratio * scrollbar_bounds.height
                         / bounds.height;
 
-                let scroller_bounds = Rectangle {
+                let scroller_bounds = mirror(Rectangle {
                     x: bounds.x + bounds.width
                         - total_scrollbar_width / 2.0
                         - scroller_width / 2.0,
                     y: (scrollbar_bounds.y + scroller_offset).max(0.0),
                     width: scroller_width,
                     height: scroller_height,
-                };
+                });
 
                 Some(internals::Scroller {
                     bounds: scroller_bounds,
@@ -1515,22 +1844,22 @@ impl Scrollbars {
                 width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height
                     - total_scrollbar_height / 2.0
                     - width / 2.0,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: width,
-            };
+            });
 
             let ratio = bounds.width / content_bounds.width;
 
@@ -2,7 +2,10 @@
 mod event;
 mod program;
 
+pub mod offscreen;
+
 pub use event::Event;
+pub use offscreen::Offscreen;
 pub use program::Program;
 
 use crate::core;
@@ -107,6 +110,7 @@ where
                 Some(Event::Keyboard(keyboard_event))
             }
             core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Pen(pen_event) => Some(Event::Pen(pen_event)),
             core::Event::Window(window::Event::RedrawRequested(instant)) => {
                 Some(Event::RedrawRequested(instant))
             }
@@ -0,0 +1,452 @@
+//! Embed scenes rendered to an offscreen texture.
+//!
+//! Rendering a 3D scene straight into the window—like a plain [`Primitive`]
+//! does—means sharing its depth buffer and viewport, and getting distorted
+//! as soon as the widget is partially clipped. An [`Offscreen`] primitive
+//! takes care of all that: it allocates a color and a depth texture of the
+//! exact physical size of its bounds, lets a [`Scene`] render into them
+//! however it likes, and then composites the result into the window,
+//! clipped to the visible region.
+//!
+//! The textures live in a [`Cache`], which is usually kept as the
+//! [`Program::State`] of a [`Shader`]; so they are only reallocated when the
+//! widget is resized.
+//!
+//! ```no_run
+//! use iced_widget::core::mouse;
+//! use iced_widget::core::Rectangle;
+//! use iced_widget::shader::{self, offscreen, wgpu, Offscreen};
+//!
+//! #[derive(Debug)]
+//! struct Cubes {
+//!     rotation: f32,
+//! }
+//!
+//! impl offscreen::Scene for Cubes {
+//!     fn render(
+//!         &self,
+//!         encoder: &mut wgpu::CommandEncoder,
+//!         _storage: &shader::Storage,
+//!         frame: &offscreen::Frame<'_>,
+//!     ) {
+//!         // Build a projection with `frame.aspect_ratio()`, clear
+//!         // `frame.color` and `frame.depth`, and draw!
+//!     }
+//! }
+//!
+//! struct Editor {
+//!     rotation: f32,
+//! }
+//!
+//! impl<Message> shader::Program<Message> for Editor {
+//!     type State = offscreen::Cache;
+//!     type Primitive = Offscreen<Cubes>;
+//!
+//!     fn draw(
+//!         &self,
+//!         cache: &offscreen::Cache,
+//!         _cursor: mouse::Cursor,
+//!         _bounds: Rectangle,
+//!     ) -> Self::Primitive {
+//!         Offscreen::new(cache, Cubes { rotation: self.rotation })
+//!     }
+//! }
+//! ```
+//!
+//! [`Program::State`]: super::Program::State
+//! [`Shader`]: crate::Shader
+use crate::core::{Rectangle, Size};
+use crate::graphics::Viewport;
+use crate::renderer::wgpu::primitive::{Primitive, Storage};
+use crate::renderer::wgpu::wgpu;
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// The format of the depth texture of a [`Frame`].
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+/// A scene that renders into the textures of an [`Offscreen`] primitive.
+pub trait Scene: fmt::Debug + Send + Sync + 'static {
+    /// Prepares the [`Scene`] for rendering a frame of the given physical
+    /// size; creating pipelines and uploading buffers.
+    ///
+    /// The color texture of the frame has the given `format`, and its depth
+    /// texture has the [`DEPTH_FORMAT`].
+    ///
+    /// By default, this method does nothing.
+    fn prepare(
+        &self,
+        _device: &wgpu::Device,
+        _queue: &wgpu::Queue,
+        _format: wgpu::TextureFormat,
+        _storage: &mut Storage,
+        _size: Size<u32>,
+    ) {
+    }
+
+    /// Renders the [`Scene`] into the given [`Frame`].
+    ///
+    /// The textures of the [`Frame`] keep their contents between frames;
+    /// they should be cleared when rendering starts.
+    fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        storage: &Storage,
+        frame: &Frame<'_>,
+    );
+}
+
+/// The textures a [`Scene`] renders into.
+#[derive(Debug)]
+pub struct Frame<'a> {
+    /// The color texture of the [`Frame`].
+    ///
+    /// Its colors are composited into the window with premultiplied alpha.
+    pub color: &'a wgpu::TextureView,
+    /// The depth texture of the [`Frame`].
+    pub depth: &'a wgpu::TextureView,
+    /// The physical size of the textures.
+    pub size: Size<u32>,
+    /// The scale factor of the window.
+    pub scale_factor: f32,
+}
+
+impl Frame<'_> {
+    /// Returns the ratio between the width and the height of the [`Frame`];
+    /// usually needed to build the projection of a camera.
+    pub fn aspect_ratio(&self) -> f32 {
+        self.size.width as f32 / self.size.height.max(1) as f32
+    }
+}
+
+/// The textures of an [`Offscreen`] primitive, kept between frames.
+///
+/// Cloning a [`Cache`] produces a new handle to the same textures.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    target: Arc<Mutex<Option<Target>>>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A [`Primitive`] that renders a [`Scene`] to an offscreen texture and
+/// composites it into the window.
+#[derive(Debug)]
+pub struct Offscreen<S> {
+    scene: S,
+    cache: Cache,
+}
+
+impl<S> Offscreen<S>
+where
+    S: Scene,
+{
+    /// Creates a new [`Offscreen`] primitive rendering the [`Scene`] into
+    /// the textures of the given [`Cache`].
+    pub fn new(cache: &Cache, scene: S) -> Self {
+        Self {
+            scene,
+            cache: cache.clone(),
+        }
+    }
+}
+
+impl<S> Primitive for Offscreen<S>
+where
+    S: Scene,
+{
+    fn prepare(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        storage: &mut Storage,
+        bounds: &Rectangle,
+        viewport: &Viewport,
+    ) {
+        let scale_factor = viewport.scale_factor() as f32;
+        let bounds = *bounds * scale_factor;
+
+        let size = Size::new(
+            (bounds.width.ceil() as u32).max(1),
+            (bounds.height.ceil() as u32).max(1),
+        );
+
+        if storage
+            .get::<Pipeline>()
+            .map_or(true, |pipeline| pipeline.format != format)
+        {
+            storage.store(Pipeline::new(device, format));
+        }
+
+        self.scene.prepare(device, queue, format, storage, size);
+
+        let Some(pipeline) = storage.get::<Pipeline>() else {
+            return;
+        };
+
+        let mut cache = self.cache.target.lock().expect("Lock offscreen cache");
+
+        let is_outdated = cache.as_ref().map_or(true, |target| {
+            target.size != size || target.format != format
+        });
+
+        if is_outdated {
+            *cache = Some(Target::new(device, pipeline, format, size));
+        }
+
+        if let Some(target) = cache.as_mut() {
+            target.bounds = bounds;
+            target.scale_factor = scale_factor;
+        }
+    }
+
+    fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        storage: &Storage,
+        target: &wgpu::TextureView,
+        clip_bounds: &Rectangle<u32>,
+    ) {
+        let Some(pipeline) = storage.get::<Pipeline>() else {
+            return;
+        };
+
+        let offscreen = self.cache.target.lock().expect("Lock offscreen cache");
+
+        let Some(offscreen) = offscreen.as_ref() else {
+            return;
+        };
+
+        self.scene.render(
+            encoder,
+            storage,
+            &Frame {
+                color: &offscreen.color,
+                depth: &offscreen.depth,
+                size: offscreen.size,
+                scale_factor: offscreen.scale_factor,
+            },
+        );
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_widget::shader::offscreen composite pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        // The viewport covers the whole texture, even if only a part of it
+        // is visible; so clipping never distorts the scene.
+        pass.set_viewport(
+            offscreen.bounds.x,
+            offscreen.bounds.y,
+            offscreen.size.width as f32,
+            offscreen.size.height as f32,
+            0.0,
+            1.0,
+        );
+
+        pass.set_scissor_rect(
+            clip_bounds.x,
+            clip_bounds.y,
+            clip_bounds.width,
+            clip_bounds.height,
+        );
+
+        pass.set_pipeline(&pipeline.pipeline);
+        pass.set_bind_group(0, &offscreen.bind_group, &[]);
+        pass.draw(0..6, 0..1);
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    size: Size<u32>,
+    format: wgpu::TextureFormat,
+    color: wgpu::TextureView,
+    depth: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    bounds: Rectangle,
+    scale_factor: f32,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        pipeline: &Pipeline,
+        format: wgpu::TextureFormat,
+        size: Size<u32>,
+    ) -> Self {
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+
+        let color = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_widget::shader::offscreen color texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        let depth = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_widget::shader::offscreen depth texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        let color = color.create_view(&wgpu::TextureViewDescriptor::default());
+        let depth = depth.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_widget::shader::offscreen bind group"),
+            layout: &pipeline.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&color),
+                },
+            ],
+        });
+
+        Self {
+            size,
+            format,
+            color,
+            depth,
+            bind_group,
+            bounds: Rectangle::with_size(Size::new(
+                size.width as f32,
+                size.height as f32,
+            )),
+            scale_factor: 1.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl Pipeline {
+    fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler =
+            device.create_sampler(&wgpu::SamplerDescriptor::default());
+
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_widget::shader::offscreen layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::NonFiltering,
+                        ),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: false,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_widget::shader::offscreen pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_widget::shader::offscreen shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("offscreen.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_widget::shader::offscreen pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            });
+
+        Self {
+            format,
+            pipeline,
+            layout,
+            sampler,
+        }
+    }
+}
@@ -1,18 +1,27 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::core::alignment;
 use crate::core::border::{self, Border};
+use crate::core::direction;
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
 use crate::core::keyboard::key::{self, Key};
 use crate::core::layout;
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
-    Shell, Size, Theme, Widget,
+    self, Background, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
 
+use std::cell::Cell;
 use std::ops::RangeInclusive;
 
 /// An horizontal bar and a handle that selects a single value from a range of
@@ -23,6 +32,14 @@ use std::ops::RangeInclusive;
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
 ///
+/// # Keyboard
+/// A [`Slider`] is focused when clicked, and unfocused when something else
+/// is clicked. While focused or hovered, the arrow keys move it by its step,
+/// the page keys move it by its page step, and the Home and End keys move it
+/// to the bounds of its range.
+///
+/// A [`Slider`] with an [`Id`] can also be focused with [`focus`].
+///
 /// # Example
 /// ```no_run
 /// # type Slider<'a, T, Message> = iced_widget::Slider<'a, Message, T>;
@@ -43,15 +60,26 @@ pub struct Slider<'a, T, Message, Theme = crate::Theme>
 where
     Theme: Catalog,
 {
+    id: Option<Id>,
     range: RangeInclusive<T>,
     step: T,
     shift_step: Option<T>,
+    page_step: Option<T>,
     value: T,
     default: Option<T>,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
+    tooltip: Option<Box<dyn Fn(T) -> String + 'a>>,
+    detents: Vec<T>,
+    snap_radius: f32,
+    on_detent: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    ticks: Vec<T>,
+    tick_label: Option<Box<dyn Fn(T) -> String + 'a>>,
+    snap_to_ticks: bool,
+    fine_ratio: f32,
     width: Length,
-    height: f32,
+    height: Option<f32>,
+    mirrored: Option<bool>,
     class: Theme::Class<'a>,
 }
 
@@ -61,8 +89,11 @@ where
     Message: Clone,
     Theme: Catalog,
 {
-    /// The default height of a [`Slider`].
-    pub const DEFAULT_HEIGHT: f32 = 16.0;
+    /// The default snap radius of the detents of a [`Slider`].
+    pub const DEFAULT_SNAP_RADIUS: f32 = 6.0;
+
+    /// The default ratio of the fine adjustment of a [`Slider`].
+    pub const DEFAULT_FINE_RATIO: f32 = 10.0;
 
     /// Creates a new [`Slider`].
     ///
@@ -89,15 +120,26 @@ where
         };
 
         Slider {
+            id: None,
             value,
             default: None,
             range,
             step: T::from(1),
             shift_step: None,
+            page_step: None,
             on_change: Box::new(on_change),
             on_release: None,
+            tooltip: None,
+            detents: Vec::new(),
+            snap_radius: Self::DEFAULT_SNAP_RADIUS,
+            on_detent: None,
+            ticks: Vec::new(),
+            tick_label: None,
+            snap_to_ticks: false,
+            fine_ratio: Self::DEFAULT_FINE_RATIO,
             width: Length::Fill,
-            height: Self::DEFAULT_HEIGHT,
+            height: None,
+            mirrored: None,
             class: Theme::default(),
         }
     }
@@ -121,6 +163,80 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`Slider`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Shows a bubble with the value of the [`Slider`] above its handle
+    /// while it is being dragged, formatted with the given closure.
+    ///
+    /// The bubble is drawn as an overlay; so it can escape the bounds of the
+    /// [`Slider`].
+    pub fn tooltip(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.tooltip = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the detents of the [`Slider`].
+    ///
+    /// While dragging, the handle will snap to any detent closer to the
+    /// cursor than the snap radius of the [`Slider`].
+    pub fn detents(mut self, detents: &[T]) -> Self {
+        self.detents = detents.to_vec();
+        self
+    }
+
+    /// Sets the snap radius of the detents of the [`Slider`], in logical
+    /// pixels.
+    pub fn snap_radius(mut self, snap_radius: impl Into<Pixels>) -> Self {
+        self.snap_radius = snap_radius.into().0;
+        self
+    }
+
+    /// Sets the message that should be produced when the handle of the
+    /// [`Slider`] snaps to one of its detents.
+    ///
+    /// It receives the value of the detent that was hit.
+    pub fn on_detent(mut self, on_detent: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_detent = Some(Box::new(on_detent));
+        self
+    }
+
+    /// Sets the values of the tick marks drawn along the rail of the
+    /// [`Slider`].
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = T>) -> Self {
+        self.ticks = ticks.into_iter().collect();
+        self
+    }
+
+    /// Shows a label below every tick mark of the [`Slider`], formatted
+    /// with the given closure.
+    ///
+    /// The [`Slider`] grows to fit the labels below its rail.
+    pub fn tick_labels(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.tick_label = Some(Box::new(format));
+        self
+    }
+
+    /// Sets whether the [`Slider`] snaps to its nearest tick mark while it
+    /// is dragged, instead of moving by its step.
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Sets the ratio of the fine adjustment of the [`Slider`].
+    ///
+    /// While the shift key is held, dragging moves the handle relative to
+    /// its current value, that many times slower than the cursor; instead
+    /// of placing it under the cursor.
+    pub fn fine_adjustment(mut self, ratio: f32) -> Self {
+        self.fine_ratio = ratio.max(1.0);
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -129,7 +245,7 @@ where
 
     /// Sets the height of the [`Slider`].
     pub fn height(mut self, height: impl Into<Pixels>) -> Self {
-        self.height = height.into().0;
+        self.height = Some(height.into().0);
         self
     }
 
@@ -147,6 +263,27 @@ where
         self
     }
 
+    /// Sets the page step of the [`Slider`].
+    ///
+    /// This value is used as the step when the PageUp or PageDown keys are
+    /// pressed. By default, it is ten times the step of the [`Slider`].
+    pub fn page_step(mut self, page_step: impl Into<T>) -> Self {
+        self.page_step = Some(page_step.into());
+        self
+    }
+
+    /// Sets whether the [`Slider`] is mirrored, growing from the right side
+    /// instead of the left one.
+    ///
+    /// By default, it is mirrored when the current [`direction`] is
+    /// right-to-left.
+    ///
+    /// [`direction`]: direction::current
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = Some(mirrored);
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -172,7 +309,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
     Theme: Catalog,
-    Renderer: core::Renderer,
+    Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -192,10 +329,31 @@ where
     fn layout(
         &self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        renderer.density().expand(
+            limits,
+            layout::atomic(
+                limits,
+                self.width,
+                self.rail_height(renderer) + self.label_space(renderer),
+            ),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        state.value = self.value.into();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.adjustable(state, self.id.as_ref().map(|id| &id.0));
     }
 
     fn on_event(
@@ -211,11 +369,54 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<State>();
 
+        if let Some(value) = state.requested.take() {
+            let start = (*self.range.start()).into();
+            let end = (*self.range.end()).into();
+            let step = self.step.into();
+
+            let value = if step > 0.0 {
+                ((value - start) / step).round() * step + start
+            } else {
+                value
+            };
+
+            if let Some(new_value) = T::from_f64(value.max(start).min(end)) {
+                if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
+                    shell.publish((self.on_change)(new_value));
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    self.value = new_value;
+                }
+            }
+        }
+
         let is_dragging = state.is_dragging;
         let current_value = self.value;
+        let is_mirrored = self
+            .mirrored
+            .unwrap_or_else(|| direction::current().is_rtl());
+
+        // The horizontal position of the cursor, from the start of the rail
+        let position = |cursor_position: Point| -> Point {
+            if is_mirrored {
+                let bounds = layout.bounds();
+
+                Point::new(
+                    bounds.x + bounds.width - (cursor_position.x - bounds.x),
+                    cursor_position.y,
+                )
+            } else {
+                cursor_position
+            }
+        };
 
         let locate = |cursor_position: Point| -> Option<T> {
             let bounds = layout.bounds();
+            let cursor_position = position(cursor_position);
+
             let new_value = if cursor_position.x <= bounds.x {
                 Some(*self.range.start())
             } else if cursor_position.x >= bounds.x + bounds.width {
@@ -240,17 +441,96 @@ where
                 T::from_f64(value.min(end))
             };
 
+            if self.snap_to_ticks {
+                let value: f64 = new_value?.into();
+
+                let distance = |tick: &T| {
+                    let tick: f64 = (*tick).into();
+
+                    (tick - value).abs()
+                };
+
+                let nearest = self
+                    .ticks
+                    .iter()
+                    .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+
+                if let Some(tick) = nearest {
+                    return Some(*tick);
+                }
+            }
+
             new_value
         };
 
-        let increment = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
+        // Moves the value from an anchor, slower than the cursor
+        let adjust = |anchor: (f32, f64), cursor_position: Point| {
+            let (anchor_x, anchor_value) = anchor;
+            let bounds = layout.bounds();
+            let cursor_position = position(cursor_position);
+
+            let start: f64 = (*self.range.start()).into();
+            let end: f64 = (*self.range.end()).into();
+            let step: f64 = self.shift_step.unwrap_or(self.step).into();
+
+            let delta = f64::from(cursor_position.x - anchor_x)
+                / f64::from(bounds.width)
+                * (end - start)
+                / f64::from(self.fine_ratio);
+
+            let value = anchor_value + delta;
+
+            let value = if step > 0.0 {
+                ((value - start) / step).round() * step + start
             } else {
-                self.step
+                value
+            };
+
+            T::from_f64(value.max(start).min(end))
+        };
+
+        let snap = |cursor_position: Point| -> Option<usize> {
+            let bounds = layout.bounds();
+            let cursor_position = position(cursor_position);
+
+            let start = (*self.range.start()).into();
+            let end = (*self.range.end()).into();
+
+            if start >= end {
+                return None;
+            }
+
+            self.detents
+                .iter()
+                .enumerate()
+                .filter_map(|(index, detent)| {
+                    let percent = ((*detent).into() - start) / (end - start);
+                    let x = bounds.x + bounds.width * percent as f32;
+                    let distance = (cursor_position.x - x).abs();
+
+                    (distance <= self.snap_radius).then_some((index, distance))
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(index, _)| index)
+        };
+
+        let key_step = if state.keyboard_modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into();
+
+        let page_step = match self.page_step {
+            Some(page_step) => page_step.into(),
+            None => {
+                let step: f64 = self.step.into();
+
+                step * 10.0
             }
-            .into();
+        };
 
+        let increment = |value: T, step: f64| -> Option<T> {
             let steps = (value.into() / step).round();
             let new_value = step * (steps + 1.0);
 
@@ -261,14 +541,7 @@ where
             T::from_f64(new_value)
         };
 
-        let decrement = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
-                self.shift_step.unwrap_or(self.step)
-            } else {
-                self.step
-            }
-            .into();
-
+        let decrement = |value: T, step: f64| -> Option<T> {
             let steps = (value.into() / step).round();
             let new_value = step * (steps - 1.0);
 
@@ -290,14 +563,35 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if let Some(cursor_position) =
-                    cursor.position_over(layout.bounds())
-                {
+                let cursor_position = cursor.position_over(layout.bounds());
+
+                state.is_focused = cursor_position.is_some();
+
+                if let Some(cursor_position) = cursor_position {
                     if state.keyboard_modifiers.command() {
                         let _ = self.default.map(change);
                         state.is_dragging = false;
+                    } else if state.keyboard_modifiers.shift() {
+                        state.anchor = Some((
+                            position(cursor_position).x,
+                            current_value.into(),
+                        ));
+                        state.is_dragging = true;
                     } else {
-                        let _ = locate(cursor_position).map(change);
+                        let detent = snap(cursor_position);
+
+                        let _ = detent
+                            .map(|index| self.detents[index])
+                            .or_else(|| locate(cursor_position))
+                            .map(change);
+
+                        if let (Some(index), Some(on_detent)) =
+                            (detent, &self.on_detent)
+                        {
+                            shell.publish(on_detent(self.detents[index]));
+                        }
+
+                        state.detent = detent;
                         state.is_dragging = true;
                     }
 
@@ -312,6 +606,8 @@ where
                         shell.publish(on_release);
                     }
                     state.is_dragging = false;
+                    state.detent = None;
+                    state.anchor = None;
 
                     return event::Status::Captured;
                 }
@@ -319,22 +615,78 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if is_dragging {
-                    let _ = cursor.position().and_then(locate).map(change);
+                    if let Some(cursor_position) = cursor.position() {
+                        if state.keyboard_modifiers.shift() {
+                            let anchor = *state.anchor.get_or_insert((
+                                position(cursor_position).x,
+                                current_value.into(),
+                            ));
+
+                            let _ = adjust(anchor, cursor_position).map(change);
+
+                            return event::Status::Captured;
+                        }
+
+                        state.anchor = None;
+
+                        let detent = snap(cursor_position);
+
+                        let _ = detent
+                            .map(|index| self.detents[index])
+                            .or_else(|| locate(cursor_position))
+                            .map(change);
+
+                        if detent != state.detent {
+                            if let (Some(index), Some(on_detent)) =
+                                (detent, &self.on_detent)
+                            {
+                                shell.publish(on_detent(self.detents[index]));
+                            }
+
+                            state.detent = detent;
+                        }
+                    }
 
                     return event::Status::Captured;
                 }
             }
             Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
-                if cursor.position_over(layout.bounds()).is_some() {
-                    match key {
+                if state.is_focused
+                    || cursor.position_over(layout.bounds()).is_some()
+                {
+                    let (forward, backward) = if is_mirrored {
+                        (key::Named::ArrowLeft, key::Named::ArrowRight)
+                    } else {
+                        (key::Named::ArrowRight, key::Named::ArrowLeft)
+                    };
+
+                    let new_value = match key {
                         Key::Named(key::Named::ArrowUp) => {
-                            let _ = increment(current_value).map(change);
+                            increment(current_value, key_step)
                         }
                         Key::Named(key::Named::ArrowDown) => {
-                            let _ = decrement(current_value).map(change);
+                            decrement(current_value, key_step)
                         }
-                        _ => (),
-                    }
+                        Key::Named(named) if named == forward => {
+                            increment(current_value, key_step)
+                        }
+                        Key::Named(named) if named == backward => {
+                            decrement(current_value, key_step)
+                        }
+                        Key::Named(key::Named::PageUp) => {
+                            increment(current_value, page_step)
+                        }
+                        Key::Named(key::Named::PageDown) => {
+                            decrement(current_value, page_step)
+                        }
+                        Key::Named(key::Named::Home) => {
+                            Some(*self.range.start())
+                        }
+                        Key::Named(key::Named::End) => Some(*self.range.end()),
+                        _ => return event::Status::Ignored,
+                    };
+
+                    let _ = new_value.map(change);
 
                     return event::Status::Captured;
                 }
@@ -356,11 +708,12 @@ where
         _style: &renderer::Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
+        let rail_height = bounds.height - self.label_space(renderer);
 
         let style = theme.style(
             &self.class,
@@ -381,7 +734,7 @@ where
                 HandleShape::Rectangle {
                     width,
                     border_radius,
-                } => (f32::from(width), bounds.height, border_radius),
+                } => (f32::from(width), rail_height, border_radius),
             };
 
         let value = self.value.into() as f32;
@@ -391,43 +744,118 @@ where
             (start.into() as f32, end.into() as f32)
         };
 
-        let offset = if range_start >= range_end {
-            0.0
+        let ratio = |value: f32| {
+            if range_start >= range_end {
+                0.0
+            } else {
+                (value - range_start) / (range_end - range_start)
+            }
+        };
+
+        let offset = (bounds.width - handle_width) * ratio(value);
+
+        let is_mirrored = self
+            .mirrored
+            .unwrap_or_else(|| direction::current().is_rtl());
+
+        let (offset, active) = if is_mirrored {
+            let offset = bounds.width - handle_width - offset;
+
+            (
+                offset,
+                Rectangle {
+                    x: bounds.x + offset + handle_width / 2.0,
+                    width: bounds.width - offset - handle_width / 2.0,
+                    ..bounds
+                },
+            )
         } else {
-            (bounds.width - handle_width) * (value - range_start)
-                / (range_end - range_start)
+            (
+                offset,
+                Rectangle {
+                    width: offset + handle_width / 2.0,
+                    ..bounds
+                },
+            )
         };
 
-        let rail_y = bounds.y + bounds.height / 2.0;
+        let rail_y = bounds.y + rail_height / 2.0;
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
                     x: bounds.x,
                     y: rail_y - style.rail.width / 2.0,
-                    width: offset + handle_width / 2.0,
+                    width: bounds.width,
                     height: style.rail.width,
                 },
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.0,
+            style.rail.inactive,
         );
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
-                    x: bounds.x + offset + handle_width / 2.0,
                     y: rail_y - style.rail.width / 2.0,
-                    width: bounds.width - offset - handle_width / 2.0,
                     height: style.rail.width,
+                    ..active
                 },
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.1,
+            style.rail.active,
         );
 
+        for tick in &self.ticks {
+            let offset =
+                (bounds.width - handle_width) * ratio((*tick).into() as f32);
+
+            let x = if is_mirrored {
+                bounds.x + bounds.width - handle_width / 2.0 - offset
+            } else {
+                bounds.x + handle_width / 2.0 + offset
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - style.ticks.width / 2.0,
+                        y: rail_y - style.ticks.length / 2.0,
+                        width: style.ticks.width,
+                        height: style.ticks.length,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.ticks.color,
+            );
+
+            if let Some(tick_label) = &self.tick_label {
+                renderer.fill_text(
+                    Text {
+                        content: tick_label(*tick),
+                        bounds: Size::INFINITY,
+                        size: renderer.default_size(),
+                        line_height: text::LineHeight::default(),
+                        font: renderer.default_font(),
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        justification: text::Justification::None,
+                    },
+                    Point::new(
+                        x,
+                        rail_y
+                            + self.rail_height(renderer) / 2.0
+                            + Self::LABEL_GAP,
+                    ),
+                    style.ticks.label_color,
+                    *viewport,
+                );
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -467,6 +895,92 @@ where
             mouse::Interaction::default()
         }
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.is_dragging {
+            return None;
+        }
+
+        let tooltip = self.tooltip.as_ref()?;
+
+        let value = self.value.into() as f32;
+        let (range_start, range_end) = {
+            let (start, end) = self.range.clone().into_inner();
+
+            (start.into() as f32, end.into() as f32)
+        };
+
+        let ratio = if range_start >= range_end {
+            0.0
+        } else {
+            (value - range_start) / (range_end - range_start)
+        };
+
+        let ratio = if self
+            .mirrored
+            .unwrap_or_else(|| direction::current().is_rtl())
+        {
+            1.0 - ratio
+        } else {
+            ratio
+        };
+
+        Some(overlay::Element::new(Box::new(Tooltip {
+            content: tooltip(self.value),
+            slider: layout.bounds() + translation,
+            ratio,
+            viewport: &state.viewport,
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, T, Message, Theme> Slider<'a, T, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`Slider`].
+    pub const DEFAULT_HEIGHT: f32 = 16.0;
+
+    /// The gap between the rail and the tick labels of a [`Slider`].
+    pub const LABEL_GAP: f32 = 4.0;
+
+    /// Returns the height of the rail, scaled by the [`Density`] of the
+    /// [`Renderer`] unless it was set explicitly.
+    ///
+    /// [`Density`]: crate::core::Density
+    /// [`Renderer`]: crate::core::Renderer
+    fn rail_height<Renderer: crate::core::Renderer>(
+        &self,
+        renderer: &Renderer,
+    ) -> f32 {
+        self.height.unwrap_or_else(|| {
+            Self::DEFAULT_HEIGHT * renderer.density().scale()
+        })
+    }
+
+    /// Returns the space taken by the tick labels below the rail.
+    fn label_space<Renderer: text::Renderer>(
+        &self,
+        renderer: &Renderer,
+    ) -> f32 {
+        if self.tick_label.is_none() || self.ticks.is_empty() {
+            return 0.0;
+        }
+
+        Self::LABEL_GAP
+            + text::LineHeight::default()
+                .to_absolute(renderer.default_size())
+                .0
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<Slider<'a, T, Message, Theme>>
@@ -475,7 +989,7 @@ where
     T: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
     Message: Clone + 'a,
     Theme: Catalog + 'a,
-    Renderer: core::Renderer + 'a,
+    Renderer: text::Renderer + 'a,
 {
     fn from(
         slider: Slider<'a, T, Message, Theme>,
@@ -484,10 +998,214 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The identifier of a [`Slider`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Task`] that focuses the [`Slider`] with the given [`Id`];
+/// so it can be moved with the keyboard.
+pub fn focus<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::focusable::focus(id.0)))
+}
+
+/// Produces a [`Task`] that moves the [`Slider`] with the given [`Id`] to
+/// the given value, as if it was dragged.
+///
+/// The value is stepped and clamped to the range of the [`Slider`], which
+/// produces both its change and release messages if it moves.
+pub fn set_value<T>(id: Id, value: impl Into<f64>) -> Task<T> {
+    task::effect(Action::widget(operation::adjustable::set_value(
+        id.0,
+        value.into(),
+    )))
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
 struct State {
     is_dragging: bool,
+    is_focused: bool,
+    detent: Option<usize>,
+    anchor: Option<(f32, f64)>,
     keyboard_modifiers: keyboard::Modifiers,
+    value: f64,
+    requested: Option<f64>,
+    viewport: Cell<Size>,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl operation::Adjustable for State {
+    fn value(&self) -> f64 {
+        self.requested.unwrap_or(self.value)
+    }
+
+    fn set_value(&mut self, value: f64) {
+        self.requested = Some(value);
+    }
+}
+
+/// The value bubble of a [`Slider`] being dragged.
+struct Tooltip<'a, 'b, Theme>
+where
+    Theme: Catalog,
+{
+    content: String,
+    slider: Rectangle,
+    ratio: f32,
+    viewport: &'b Cell<Size>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Theme> Tooltip<'a, 'b, Theme>
+where
+    Theme: Catalog,
+{
+    const PADDING: Padding = Padding {
+        top: 4.0,
+        right: 8.0,
+        bottom: 4.0,
+        left: 8.0,
+    };
+
+    const GAP: f32 = 6.0;
+
+    fn text<Renderer: text::Renderer>(
+        &self,
+        renderer: &Renderer,
+    ) -> Text<String, Renderer::Font> {
+        Text {
+            content: self.content.clone(),
+            bounds: Size::INFINITY,
+            size: renderer.default_size(),
+            line_height: text::LineHeight::default(),
+            font: renderer.default_font(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            justification: text::Justification::None,
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> core::Overlay<Message, Theme, Renderer>
+    for Tooltip<'a, 'b, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let text = self.text(renderer);
+
+        let paragraph = Renderer::Paragraph::with_text(Text {
+            content: text.content.as_str(),
+            bounds: text.bounds,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            justification: text.justification,
+        });
+
+        let size = paragraph.min_bounds().expand(Self::PADDING);
+
+        let anchor = Rectangle {
+            x: self.slider.x + self.slider.width * self.ratio,
+            width: 0.0,
+            ..self.slider
+        };
+
+        // The bubble is moved when drawn; so it needs the viewport to stay
+        // inside of it
+        self.viewport.set(bounds);
+
+        let bounds = overlay::Placement::new(overlay::placement::Side::Top)
+            .gap(Self::GAP)
+            .resolve(anchor, size, Rectangle::with_size(bounds));
+
+        layout::Node::new(size).move_to(bounds.position())
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class, Status::Dragged);
+
+        // The layout cannot know the shape of the handle; so the bubble
+        // is centered on top of it here
+        let handle_width = match style.handle.shape {
+            HandleShape::Circle { radius } => radius * 2.0,
+            HandleShape::Rectangle { width, .. } => f32::from(width),
+        };
+
+        let center = self.slider.x
+            + handle_width / 2.0
+            + (self.slider.width - handle_width) * self.ratio;
+
+        let bounds = layout.bounds();
+        let viewport = self.viewport.get();
+
+        let bounds = Rectangle {
+            x: (center - bounds.width / 2.0)
+                .min(viewport.width - bounds.width)
+                .max(0.0),
+            ..bounds
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.bubble.border,
+                ..renderer::Quad::default()
+            },
+            style.bubble.background,
+        );
+
+        renderer.fill_text(
+            self.text(renderer),
+            bounds.center(),
+            style.bubble.text_color,
+            bounds,
+        );
+    }
 }
 
 /// The possible status of a [`Slider`].
@@ -508,6 +1226,10 @@ pub struct Style {
     pub rail: Rail,
     /// The appearance of the [`Handle`] of the slider.
     pub handle: Handle,
+    /// The appearance of the value [`Bubble`] shown while dragging.
+    pub bubble: Bubble,
+    /// The appearance of the [`Ticks`] along the rail.
+    pub ticks: Ticks,
 }
 
 impl Style {
@@ -524,8 +1246,11 @@ impl Style {
 /// The appearance of a slider rail
 #[derive(Debug, Clone, Copy)]
 pub struct Rail {
-    /// The colors of the rail of the slider.
-    pub colors: (Color, Color),
+    /// The [`Background`] of the active track of the rail; that is, the
+    /// filled portion between the start of the range and the handle.
+    pub active: Background,
+    /// The [`Background`] of the whole rail, behind the active track.
+    pub inactive: Background,
     /// The width of the stroke of a slider rail.
     pub width: f32,
     /// The border radius of the corners of the rail.
@@ -545,6 +1270,30 @@ pub struct Handle {
     pub border_color: Color,
 }
 
+/// The appearance of the value bubble of a slider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bubble {
+    /// The [`Background`] of the bubble.
+    pub background: Background,
+    /// The text [`Color`] of the bubble.
+    pub text_color: Color,
+    /// The [`Border`] of the bubble.
+    pub border: Border,
+}
+
+/// The appearance of the tick marks of a slider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ticks {
+    /// The [`Color`] of the tick marks.
+    pub color: Color,
+    /// The width of the tick marks.
+    pub width: f32,
+    /// The length of the tick marks, across the rail.
+    pub length: f32,
+    /// The [`Color`] of the labels of the tick marks.
+    pub label_color: Color,
+}
+
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
 pub enum HandleShape {
@@ -601,7 +1350,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
 
     Style {
         rail: Rail {
-            colors: (color, palette.secondary.base.color),
+            active: color.into(),
+            inactive: palette.secondary.base.color.into(),
             width: 4.0,
             border_radius: 2.0.into(),
         },
@@ -611,5 +1361,16 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             border_color: Color::TRANSPARENT,
             border_width: 0.0,
         },
+        bubble: Bubble {
+            background: palette.primary.strong.color.into(),
+            text_color: palette.primary.strong.text,
+            border: border::rounded(4),
+        },
+        ticks: Ticks {
+            color: palette.background.strong.color,
+            width: 1.0,
+            length: 10.0,
+            label_color: palette.background.base.text,
+        },
     }
 }
@@ -0,0 +1,347 @@
+//! Display the frequency spectrum of some audio.
+//!
+//! A [`Spectrum`] draws the magnitudes of the bins of a frequency analysis,
+//! like the output of a Fourier transform, on a decibel scale. The bins can
+//! be borrowed from a slice or read from a [`Ring`], and they are decimated
+//! to their peak for each bar or pixel column.
+//!
+//! [`Ring`]: crate::waveform::Ring
+use crate::core::border::{self, Border};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::{
+    Background, Element, Layout, Length, Rectangle, Size, Theme, Widget,
+};
+use crate::waveform::{self, Samples};
+
+use std::ops::{Range, RangeInclusive};
+
+/// A widget that displays the magnitudes of a frequency spectrum.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::spectrum;
+///
+/// struct State {
+///    magnitudes: Vec<f32>,
+/// }
+///
+/// fn view<'a, Message: 'a>(state: &'a State) -> Element<'a, Message> {
+///     spectrum(&state.magnitudes)
+///         .logarithmic(true)
+///         .bars(32)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spectrum<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    magnitudes: Samples<'a>,
+    range: RangeInclusive<f32>,
+    bars: u16,
+    is_logarithmic: bool,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Spectrum<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`Spectrum`].
+    pub const DEFAULT_HEIGHT: f32 = 80.0;
+
+    /// The space between the bars of a [`Spectrum`].
+    const BAR_GAP: f32 = 1.0;
+
+    /// Creates a new [`Spectrum`] displaying the given magnitudes.
+    ///
+    /// The magnitudes are linear amplitudes, ordered from the lowest to the
+    /// highest frequency.
+    pub fn new(magnitudes: impl Into<Samples<'a>>) -> Self {
+        Self {
+            magnitudes: magnitudes.into(),
+            range: -90.0..=0.0,
+            bars: 0,
+            is_logarithmic: false,
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the range of decibels displayed by the [`Spectrum`].
+    ///
+    /// By default, it goes from `-90.0` to `0.0`.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Groups the bins of the [`Spectrum`] into the given amount of bars.
+    ///
+    /// By default, the [`Spectrum`] is drawn continuously, one pixel column
+    /// at a time.
+    pub fn bars(mut self, bars: u16) -> Self {
+        self.bars = bars;
+        self
+    }
+
+    /// Sets whether the frequencies of the [`Spectrum`] are laid out on a
+    /// logarithmic scale; giving more room to the lower frequencies.
+    pub fn logarithmic(mut self, is_logarithmic: bool) -> Self {
+        self.is_logarithmic = is_logarithmic;
+        self
+    }
+
+    /// Sets the width of the [`Spectrum`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Spectrum`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Spectrum`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spectrum`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the height of a magnitude, from `0.0` to `1.0`.
+    fn level(&self, magnitude: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+
+        if start >= end {
+            return 0.0;
+        }
+
+        let decibels = 20.0 * magnitude.max(f32::MIN_POSITIVE).log10();
+
+        ((decibels - start) / (end - start)).clamp(0.0, 1.0)
+    }
+}
+
+/// Splits the given amount of bins into bands, one for each column, on a
+/// logarithmic scale.
+fn logarithmic(
+    len: usize,
+    columns: usize,
+) -> impl Iterator<Item = Range<usize>> {
+    let edge = move |column: usize| {
+        let ratio = column as f32 / columns as f32;
+
+        ((len as f32 + 1.0).powf(ratio) - 1.0).round() as usize
+    };
+
+    (0..columns).filter(move |_| len > 0).map(move |column| {
+        let start = edge(column).min(len - 1);
+        let end = edge(column + 1).clamp(start + 1, len);
+
+        start..end
+    })
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Spectrum<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let (columns, gap) = if self.bars == 0 {
+            (bounds.width.max(1.0) as usize, 0.0)
+        } else {
+            let width = bounds.width / f32::from(self.bars);
+
+            (
+                usize::from(self.bars),
+                if width > Self::BAR_GAP * 3.0 {
+                    Self::BAR_GAP
+                } else {
+                    0.0
+                },
+            )
+        };
+
+        let column_width = bounds.width / columns as f32;
+        self.magnitudes.with(|first, second| {
+            let len = first.len() + second.len();
+
+            let bands: Box<dyn Iterator<Item = Range<usize>>> =
+                if self.is_logarithmic {
+                    Box::new(logarithmic(len, columns))
+                } else {
+                    Box::new(waveform::columns(len, columns))
+                };
+
+            for (column, band) in bands.enumerate() {
+                let (_, peak) = waveform::peak(first, second, band);
+                let height = bounds.height * self.level(peak);
+
+                if height <= 0.0 {
+                    continue;
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column_width * column as f32,
+                            y: bounds.y + bounds.height - height,
+                            width: column_width - gap,
+                            height,
+                        },
+                        border: if gap > 0.0 {
+                            border::rounded(style.bar_radius)
+                        } else {
+                            Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.bar,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spectrum<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(spectrum: Spectrum<'a, Theme>) -> Self {
+        Element::new(spectrum)
+    }
+}
+
+/// The appearance of a [`Spectrum`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Spectrum`].
+    pub background: Background,
+    /// The [`Border`] of the [`Spectrum`].
+    pub border: Border,
+    /// The [`Background`] of the bars.
+    pub bar: Background,
+    /// The border radius of the bars, when they are separated.
+    pub bar_radius: border::Radius,
+}
+
+/// The theme catalog of a [`Spectrum`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Spectrum`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Spectrum`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: border::rounded(2),
+        bar: palette.primary.base.color.into(),
+        bar_radius: 1.0.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logarithmic_bands_cover_every_bin() {
+        let bands: Vec<_> = logarithmic(100, 8).collect();
+
+        assert_eq!(bands.first().map(|band| band.start), Some(0));
+        assert_eq!(bands.last().map(|band| band.end), Some(100));
+        assert!(bands
+            .windows(2)
+            .all(|pair| pair[0].end <= pair[1].start + 1));
+        assert!(bands[0].len() < bands[7].len());
+    }
+}
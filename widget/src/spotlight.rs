@@ -0,0 +1,793 @@
+//! Spotlight a widget with a coach mark, dimming everything else.
+//!
+//! A [`Tour`] steps through a script of widgets to spotlight; which is a
+//! common way to onboard users of an application.
+use crate::button::{self, Button};
+use crate::container;
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay::{self, placement::Side, Placement};
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation, Outcome};
+use crate::core::widget::{self, Tree, Widget};
+use crate::core::{
+    Background, Clipboard, Color, Element, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+use crate::text::{self, Text};
+use crate::{Column, Row, Space};
+
+/// A widget that spotlights a widget of its content with a coach mark
+/// next to it, dimming everything else.
+///
+/// The target is found by the [`container::Id`] of a widget inside the
+/// content. The dimmed content cannot be interacted with while the
+/// [`Spotlight`] is shown; but the target can.
+#[allow(missing_debug_implementations)]
+pub struct Spotlight<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    coach_mark: Element<'a, Message, Theme, Renderer>,
+    target: widget::Id,
+    side: Side,
+    gap: f32,
+    margin: f32,
+    padding: f32,
+    max_width: f32,
+    on_dismiss: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Spotlight<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// The default gap between the target and the coach mark.
+    const DEFAULT_GAP: f32 = 12.0;
+
+    /// The default margin around the target.
+    const DEFAULT_MARGIN: f32 = 4.0;
+
+    /// The default padding of the coach mark.
+    const DEFAULT_PADDING: f32 = 12.0;
+
+    /// The default maximum width of the coach mark.
+    const DEFAULT_MAX_WIDTH: f32 = 300.0;
+
+    /// Creates a new [`Spotlight`] of the widget with the given
+    /// [`container::Id`] inside of the content, with the given coach mark.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        target: container::Id,
+        coach_mark: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Spotlight {
+            content: content.into(),
+            coach_mark: coach_mark.into(),
+            target: target.into(),
+            side: Side::Bottom,
+            gap: Self::DEFAULT_GAP,
+            margin: Self::DEFAULT_MARGIN,
+            padding: Self::DEFAULT_PADDING,
+            max_width: Self::DEFAULT_MAX_WIDTH,
+            on_dismiss: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the preferred [`Side`] of the target where the coach mark is
+    /// placed.
+    ///
+    /// The coach mark flips to the opposite [`Side`] when there is not
+    /// enough room.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the gap between the target and the coach mark.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the margin around the target that is kept clear.
+    pub fn margin(mut self, margin: impl Into<Pixels>) -> Self {
+        self.margin = margin.into().0;
+        self
+    }
+
+    /// Sets the padding of the coach mark.
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Sets the maximum width of the coach mark.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the message that will be produced when the dimmed content is
+    /// clicked.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Spotlight`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spotlight`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Spotlight<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.coach_mark)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            self.content.as_widget(),
+            self.coach_mark.as_widget(),
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut visible_bounds =
+            container::VisibleBounds::new(self.target.clone());
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut operation::black_box(&mut visible_bounds),
+        );
+
+        let target = match visible_bounds.finish() {
+            Outcome::Some(bounds) => bounds,
+            Outcome::None | Outcome::Chain(_) => None,
+        };
+
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            translation,
+        );
+
+        let spotlight = target.map(|target| {
+            overlay::Element::new(Box::new(Overlay {
+                coach_mark: &mut self.coach_mark,
+                tree: children.next().unwrap(),
+                hole: (target + translation).expand(self.margin),
+                side: self.side,
+                gap: self.gap,
+                padding: self.padding,
+                max_width: self.max_width,
+                on_dismiss: self.on_dismiss.as_ref(),
+                class: &self.class,
+            }))
+        });
+
+        if content.is_some() || spotlight.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(spotlight).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spotlight<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        spotlight: Spotlight<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(spotlight)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    coach_mark: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    hole: Rectangle,
+    side: Side,
+    gap: f32,
+    padding: f32,
+    max_width: f32,
+    on_dismiss: Option<&'b Message>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+        let padding = Padding::new(self.padding);
+
+        let coach_mark = self.coach_mark.as_widget().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(self.max_width.min(bounds.width), bounds.height),
+            )
+            .shrink(padding),
+        );
+
+        let size = coach_mark.size().expand(padding);
+
+        let bubble = Placement::new(self.side)
+            .gap(self.gap)
+            .resolve(self.hole, size, viewport);
+
+        layout::Node::with_children(
+            bounds,
+            vec![layout::Node::with_children(
+                bubble.size(),
+                vec![coach_mark
+                    .translate(Vector::new(self.padding, self.padding))],
+            )
+            .translate(Vector::new(bubble.x, bubble.y))],
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+        let hole = self.hole;
+
+        let backdrop = [
+            Rectangle {
+                x: bounds.x,
+                y: bounds.y,
+                width: bounds.width,
+                height: hole.y - bounds.y,
+            },
+            Rectangle {
+                x: bounds.x,
+                y: hole.y + hole.height,
+                width: bounds.width,
+                height: bounds.y + bounds.height - hole.y - hole.height,
+            },
+            Rectangle {
+                x: bounds.x,
+                y: hole.y,
+                width: hole.x - bounds.x,
+                height: hole.height,
+            },
+            Rectangle {
+                x: hole.x + hole.width,
+                y: hole.y,
+                width: bounds.x + bounds.width - hole.x - hole.width,
+                height: hole.height,
+            },
+        ];
+
+        for bounds in backdrop {
+            if bounds.width > 0.0 && bounds.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    style.backdrop,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: hole,
+                border: style.highlight,
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        let bubble = layout.children().next().unwrap();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bubble.bounds(),
+                border: style.border,
+                shadow: style.shadow,
+            },
+            style.background,
+        );
+
+        self.coach_mark.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style
+                    .text_color
+                    .unwrap_or(inherited_style.text_color),
+            },
+            bubble.children().next().unwrap(),
+            cursor,
+            &bounds,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let bubble = layout.children().next().unwrap();
+
+        self.coach_mark.as_widget().operate(
+            self.tree,
+            bubble.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bubble = layout.children().next().unwrap();
+
+        let status = self.coach_mark.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            bubble.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.hole.contains(position)
+                    || bubble.bounds().contains(position)
+                {
+                    return event::Status::Ignored;
+                }
+
+                if let Some(on_dismiss) = self.on_dismiss {
+                    shell.publish(on_dismiss.clone());
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bubble = layout.children().next().unwrap();
+
+        self.coach_mark.as_widget().mouse_interaction(
+            self.tree,
+            bubble.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        // The target can still be interacted with
+        !self.hole.contains(cursor_position)
+    }
+}
+
+/// A step of a [`Tour`].
+#[derive(Debug, Clone)]
+pub struct Step {
+    /// The [`container::Id`] of the widget spotlighted by the [`Step`].
+    pub target: container::Id,
+    /// The title of the coach mark of the [`Step`].
+    pub title: String,
+    /// The description of the coach mark of the [`Step`].
+    pub description: String,
+}
+
+impl Step {
+    /// Creates a new [`Step`] that spotlights the widget with the given
+    /// [`container::Id`].
+    pub fn new(
+        target: container::Id,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            target,
+            title: title.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// An action performed on a [`Tour`]; usually, by pressing the buttons of
+/// its coach marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Moves to the next [`Step`], finishing the [`Tour`] after the last.
+    Next,
+    /// Moves back to the previous [`Step`].
+    Back,
+    /// Finishes the [`Tour`] early.
+    Skip,
+}
+
+/// A scripted sequence of [`Step`]s that spotlights a widget at a time.
+///
+/// A [`Tour`] is kept in the state of an application, which performs the
+/// [`Action`]s produced by its coach marks.
+#[derive(Debug, Clone, Default)]
+pub struct Tour {
+    steps: Vec<Step>,
+    current: Option<usize>,
+}
+
+impl Tour {
+    /// Creates a new [`Tour`] with the given [`Step`]s.
+    ///
+    /// The [`Tour`] does not show anything until it is started.
+    pub fn new(steps: impl IntoIterator<Item = Step>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+            current: None,
+        }
+    }
+
+    /// Starts the [`Tour`] from its first [`Step`].
+    pub fn start(&mut self) {
+        self.current = (!self.steps.is_empty()).then_some(0);
+    }
+
+    /// Returns whether the [`Tour`] is showing a [`Step`].
+    pub fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Returns the index of the current [`Step`] of the [`Tour`], if active.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns the [`Step`]s of the [`Tour`].
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Performs an [`Action`] on the [`Tour`].
+    pub fn perform(&mut self, action: Action) {
+        self.current = match (action, self.current) {
+            (Action::Next, Some(current)) => {
+                Some(current + 1).filter(|next| *next < self.steps.len())
+            }
+            (Action::Back, Some(current)) => Some(current.saturating_sub(1)),
+            (Action::Skip, _) | (_, None) => None,
+        };
+    }
+
+    /// Spotlights the current [`Step`] of the [`Tour`] on top of the given
+    /// content, with a coach mark to move through the [`Tour`].
+    ///
+    /// The content is returned as is when the [`Tour`] is not active.
+    pub fn view<'a, Message, Theme, Renderer>(
+        &'a self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_action: impl Fn(Action) -> Message + 'a,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: Clone + 'a,
+        Theme: Catalog + button::Catalog + text::Catalog + 'a,
+        Renderer: crate::core::text::Renderer + 'a,
+    {
+        let content = content.into();
+
+        let Some((index, step)) = self
+            .current
+            .and_then(|index| Some((index, self.steps.get(index)?)))
+        else {
+            return content;
+        };
+
+        let is_last = index + 1 == self.steps.len();
+
+        let mut controls = Row::new()
+            .spacing(8)
+            .align_y(alignment::Vertical::Center)
+            .push(
+                Text::new(format!("{} / {}", index + 1, self.steps.len()))
+                    .size(12),
+            )
+            .push(Space::with_width(Length::Fill));
+
+        if !is_last {
+            controls = controls.push(
+                Button::new(Text::new("Skip"))
+                    .on_press(on_action(Action::Skip)),
+            );
+        }
+
+        if index > 0 {
+            controls = controls.push(
+                Button::new(Text::new("Back"))
+                    .on_press(on_action(Action::Back)),
+            );
+        }
+
+        controls = controls.push(
+            Button::new(Text::new(if is_last { "Done" } else { "Next" }))
+                .on_press(on_action(Action::Next)),
+        );
+
+        let coach_mark = Column::new()
+            .spacing(8)
+            .push(Text::new(&step.title).size(18))
+            .push(Text::new(&step.description))
+            .push(controls);
+
+        Spotlight::new(content, step.target.clone(), coach_mark)
+            .on_dismiss(on_action(Action::Skip))
+            .into()
+    }
+}
+
+/// The appearance of a [`Spotlight`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Background`] of the dimmed content.
+    pub backdrop: Background,
+    /// The [`Border`] drawn around the target.
+    pub highlight: Border,
+    /// The [`Background`] of the coach mark.
+    pub background: Background,
+    /// The [`Border`] of the coach mark.
+    pub border: Border,
+    /// The [`Shadow`] of the coach mark.
+    pub shadow: Shadow,
+    /// The text [`Color`] of the coach mark, if any.
+    pub text_color: Option<Color>,
+}
+
+/// The theme catalog of a [`Spotlight`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Spotlight`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Spotlight`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.6).into(),
+        highlight: Border {
+            width: 2.0,
+            radius: border::Radius::default(),
+            color: palette.primary.strong.color,
+        },
+        background: palette.background.base.color.into(),
+        border: Border {
+            width: 1.0,
+            radius: 8.0.into(),
+            color: palette.background.strong.color,
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 16.0,
+        },
+        text_color: Some(palette.background.base.text),
+    }
+}
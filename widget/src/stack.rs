@@ -181,7 +181,8 @@ where
             ))
             .collect();
 
-        layout::Node::with_children(size, nodes)
+        // Layers may float outside of the base layer
+        layout::Node::with_children(size, nodes).overflowing()
     }
 
     fn operate(
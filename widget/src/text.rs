@@ -1,10 +1,16 @@
 //! Draw and interact with text.
 mod rich;
 
-pub use crate::core::text::{Fragment, Highlighter, IntoFragment, Span};
+#[cfg(feature = "canvas")]
+mod rotated;
+
+pub use crate::core::text::{spell, Fragment, Highlighter, IntoFragment, Span};
 pub use crate::core::widget::text::*;
 pub use rich::Rich;
 
+#[cfg(feature = "canvas")]
+pub use rotated::Rotated;
+
 /// A paragraph.
 pub type Text<'a, Theme = crate::Theme, Renderer = crate::Renderer> =
     crate::core::widget::Text<'a, Theme, Renderer>;
@@ -5,15 +5,16 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::{Paragraph, Span};
 use crate::core::widget::text::{
-    self, Catalog, LineHeight, Shaping, Style, StyleFn,
+    self, Catalog, Justification, LineHeight, Shaping, Style, StyleFn,
 };
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Clipboard, Color, Element, Event, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Size, Vector, Widget,
+    self, Background, Clipboard, Color, Element, Event, Layout, Length, Pixels,
+    Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
 use std::borrow::Cow;
+use std::ops::Range;
 
 /// A bunch of [`Rich`] text.
 #[allow(missing_debug_implementations)]
@@ -31,6 +32,8 @@ where
     font: Option<Renderer::Font>,
     align_x: alignment::Horizontal,
     align_y: alignment::Vertical,
+    justification: Justification,
+    highlights: Vec<(Range<usize>, Background)>,
     class: Theme::Class<'a>,
 }
 
@@ -51,6 +54,8 @@ where
             font: None,
             align_x: alignment::Horizontal::Left,
             align_y: alignment::Vertical::Top,
+            justification: Justification::default(),
+            highlights: Vec::new(),
             class: Theme::default(),
         }
     }
@@ -119,6 +124,25 @@ where
         self
     }
 
+    /// Sets the [`Justification`] of the [`Rich`] text.
+    pub fn justification(mut self, justification: Justification) -> Self {
+        self.justification = justification;
+        self
+    }
+
+    /// Highlights the given byte range of the contents of the [`Rich`] text
+    /// with a [`Background`]; for instance, to show search matches.
+    ///
+    /// The range spans the concatenated text of all the [`Span`]s.
+    pub fn highlight(
+        mut self,
+        range: Range<usize>,
+        background: impl Into<Background>,
+    ) -> Self {
+        self.highlights.push((range, background.into()));
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -144,7 +168,10 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
     }
 
     /// Sets the default style class of the [`Rich`] text.
@@ -227,6 +254,7 @@ where
             self.font,
             self.align_x,
             self.align_y,
+            self.justification,
         )
     }
 
@@ -246,6 +274,13 @@ where
 
         let style = theme.style(&self.class);
 
+        text::draw_highlights(
+            renderer,
+            layout,
+            &state.paragraph,
+            &self.highlights,
+        );
+
         let hovered_span = cursor
             .position_in(layout.bounds())
             .and_then(|position| state.paragraph.hit_span(position));
@@ -451,6 +486,7 @@ fn layout<Link, Renderer>(
     font: Option<Renderer::Font>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    justification: Justification,
 ) -> layout::Node
 where
     Link: Clone,
@@ -471,6 +507,7 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping: Shaping::Advanced,
+            justification,
         };
 
         if state.spans != spans {
@@ -487,6 +524,7 @@ where
                 horizontal_alignment,
                 vertical_alignment,
                 shaping: Shaping::Advanced,
+                justification,
             }) {
                 core::text::Difference::None => {}
                 core::text::Difference::Bounds => {
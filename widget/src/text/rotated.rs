@@ -0,0 +1,232 @@
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::widget::Tree;
+use crate::core::{
+    Color, Element, Font, Layout, Length, Pixels, Radians, Rectangle, Size,
+    Vector, Widget,
+};
+use crate::graphics::geometry::{self, Frame};
+use crate::text::{Catalog, LineHeight, Shaping, Style, StyleFn};
+
+/// A single line of text rotated by some angle; like the label of an axis
+/// or a vertical tab.
+///
+/// The glyphs are drawn as paths; so they are rotated instead of stacked.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::core::Degrees;
+/// use iced_widget::text;
+///
+/// fn view<'a, Message: 'a>() -> Element<'a, Message> {
+///     text::Rotated::new("Revenue", Degrees(-90.0)).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Rotated<'a, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    fragment: text::Fragment<'a>,
+    rotation: Radians,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Font>,
+    shaping: Shaping,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> Rotated<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Rotated`] text with the given contents, rotated
+    /// clockwise by the given angle.
+    ///
+    /// A rotation of 90 degrees reads from top to bottom, while a rotation
+    /// of -90 degrees reads from bottom to top.
+    pub fn new(
+        fragment: impl text::IntoFragment<'a>,
+        rotation: impl Into<Radians>,
+    ) -> Self {
+        Self {
+            fragment: fragment.into_fragment(),
+            rotation: rotation.into(),
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            shaping: Shaping::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the size of the [`Rotated`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rotated`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Rotated`] text.
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Rotated`] text.
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the style of the [`Rotated`] text.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Rotated`] text.
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = Some(color.into());
+
+        self.style(move |_theme| Style {
+            color,
+            ..Style::default()
+        })
+    }
+
+    /// Sets the style class of the [`Rotated`] text.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the size and font of the text, using the defaults of the
+    /// given renderer when unset.
+    fn resolve<Renderer>(&self, renderer: &Renderer) -> (Pixels, Font)
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        (
+            self.size.unwrap_or_else(|| renderer.default_size()),
+            self.font.unwrap_or_else(|| renderer.default_font()),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Rotated<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font> + geometry::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (size, font) = self.resolve(renderer);
+
+        let paragraph = Renderer::Paragraph::with_text(text::Text {
+            content: self.fragment.as_ref(),
+            bounds: Size::INFINITY,
+            size,
+            line_height: self.line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.shaping,
+            justification: text::Justification::None,
+        });
+
+        let bounds = paragraph.min_bounds();
+
+        // The bounding box of the text once rotated
+        let (sin, cos) = self.rotation.0.sin_cos();
+
+        let width = bounds.width * cos.abs() + bounds.height * sin.abs();
+        let height = bounds.width * sin.abs() + bounds.height * cos.abs();
+
+        layout::atomic(limits, width, height)
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let (size, font) = self.resolve(renderer);
+
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        frame.fill_text_rotated(
+            geometry::Text {
+                content: self.fragment.to_string(),
+                position: frame.center(),
+                color: style.color.unwrap_or(defaults.text_color),
+                size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: self.shaping,
+                outline: style.outline,
+                shadow: style.shadow,
+            },
+            self.rotation,
+        );
+
+        let geometry = frame.into_geometry();
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw_geometry(geometry);
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Rotated<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + geometry::Renderer + 'a,
+{
+    fn from(rotated: Rotated<'a, Theme>) -> Self {
+        Element::new(rotated)
+    }
+}
@@ -6,10 +6,12 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight, Text};
+use crate::core::text::{self, LineHeight, Paragraph as _, Text, Wrapping};
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
 use crate::core::widget::{self, Widget};
@@ -18,6 +20,7 @@ use crate::core::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, SmolStr, Theme, Vector,
 };
+use crate::overlay::spell::{self, Suggestions};
 
 use std::cell::RefCell;
 use std::fmt;
@@ -47,6 +50,11 @@ pub struct TextEditor<
     width: Length,
     height: Length,
     padding: Padding,
+    wrapping: Wrapping,
+    line_numbers: bool,
+    is_read_only: bool,
+    highlights: Vec<((usize, usize), (usize, usize), Background)>,
+    spell_checker: Option<&'a dyn text::spell::Checker>,
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
@@ -74,6 +82,11 @@ where
             width: Length::Fill,
             height: Length::Shrink,
             padding: Padding::new(5.0),
+            wrapping: Wrapping::default(),
+            line_numbers: false,
+            is_read_only: false,
+            highlights: Vec::new(),
+            spell_checker: None,
             class: Theme::default(),
             key_binding: None,
             on_edit: None,
@@ -148,6 +161,36 @@ where
         self
     }
 
+    /// Sets the [`Wrapping`] strategy of the [`TextEditor`].
+    ///
+    /// Soft wrapping can be disabled with [`Wrapping::None`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] should display a gutter with line
+    /// numbers.
+    ///
+    /// Clicking a line number selects the whole line.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets whether the [`TextEditor`] is read-only.
+    ///
+    /// A read-only [`TextEditor`] never produces editing actions, but its
+    /// contents can still be scrolled, selected, and copied. If no
+    /// [`on_action`] handler is set, these actions will be performed on the
+    /// [`Content`] directly; which makes it usable as a selectable log viewer.
+    ///
+    /// [`on_action`]: Self::on_action
+    pub fn read_only(mut self, is_read_only: bool) -> Self {
+        self.is_read_only = is_read_only;
+        self
+    }
+
     /// Highlights the [`TextEditor`] using the given syntax and theme.
     #[cfg(feature = "highlighter")]
     pub fn highlight(
@@ -186,6 +229,11 @@ where
             width: self.width,
             height: self.height,
             padding: self.padding,
+            wrapping: self.wrapping,
+            line_numbers: self.line_numbers,
+            is_read_only: self.is_read_only,
+            highlights: self.highlights,
+            spell_checker: self.spell_checker,
             class: self.class,
             key_binding: self.key_binding,
             on_edit: self.on_edit,
@@ -194,6 +242,40 @@ where
         }
     }
 
+    /// Highlights the text between the given cursor positions of the
+    /// [`TextEditor`] with a [`Background`]; for instance, to show
+    /// search matches.
+    ///
+    /// Positions are given as line and column, respectively. This method
+    /// can be called multiple times to highlight many ranges.
+    ///
+    /// A highlighted range can be scrolled into view by performing an
+    /// [`Action::SelectRange`] on the [`Content`].
+    pub fn highlight_range(
+        mut self,
+        start: (usize, usize),
+        end: (usize, usize),
+        background: impl Into<Background>,
+    ) -> Self {
+        self.highlights.push((start, end, background.into()));
+        self
+    }
+
+    /// Checks the spelling of the contents of the [`TextEditor`] with the
+    /// given [`Checker`].
+    ///
+    /// Misspelled words will be underlined. Right clicking one of them will
+    /// select it and display the suggestions of the [`Checker`] to replace it.
+    ///
+    /// [`Checker`]: text::spell::Checker
+    pub fn spell_check(
+        mut self,
+        checker: &'a dyn text::spell::Checker,
+    ) -> Self {
+        self.spell_checker = Some(checker);
+        self
+    }
+
     /// Sets the closure to produce key bindings on key presses.
     ///
     /// See [`Binding`] for the list of available bindings.
@@ -222,6 +304,13 @@ where
         self.class = class.into();
         self
     }
+
+    fn text_padding(&self, gutter_width: f32) -> Padding {
+        Padding {
+            left: self.padding.left + gutter_width,
+            ..self.padding
+        }
+    }
 }
 
 /// The content of a [`TextEditor`].
@@ -375,6 +464,8 @@ pub struct State<Highlighter: text::Highlighter> {
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
+    gutter_width: f32,
+    suggestions: Option<spell::State>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
@@ -449,6 +540,8 @@ where
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
+            gutter_width: 0.0,
+            suggestions: None,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
@@ -491,11 +584,36 @@ where
 
         let limits = limits.height(self.height);
 
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        state.gutter_width = if self.line_numbers {
+            let digits = internal.editor.line_count().max(1).ilog10() + 1;
+
+            let numbers = Renderer::Paragraph::with_text(Text {
+                content: &"0".repeat(digits as usize),
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+                justification: text::Justification::None,
+            });
+
+            numbers.min_width() + text_size.0
+        } else {
+            0.0
+        };
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
-            self.font.unwrap_or_else(|| renderer.default_font()),
-            self.text_size.unwrap_or_else(|| renderer.default_size()),
+            limits.shrink(self.text_padding(state.gutter_width)).max(),
+            font,
+            text_size,
             self.line_height,
+            self.wrapping,
             state.highlighter.borrow_mut().deref_mut(),
         );
 
@@ -527,9 +645,11 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        let Some(on_edit) = self.on_edit.as_ref() else {
+        if self.on_edit.is_none() && !self.is_read_only {
             return event::Status::Ignored;
-        };
+        }
+
+        let on_edit = self.on_edit.as_deref();
 
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
@@ -568,11 +688,81 @@ where
             _ => {}
         }
 
+        let padding = self.text_padding(state.gutter_width);
+        let text_bounds = layout.bounds().shrink(padding);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+            let selection = match button {
+                mouse::Button::Left if self.line_numbers => {
+                    let gutter = Rectangle {
+                        x: text_bounds.x - state.gutter_width,
+                        width: state.gutter_width,
+                        ..text_bounds
+                    };
+
+                    cursor.position_over(gutter).and_then(|position| {
+                        select_line(
+                            &self.content.0.borrow().editor,
+                            position.y - text_bounds.y,
+                        )
+                    })
+                }
+                mouse::Button::Right if !self.is_read_only => self
+                    .spell_checker
+                    .zip(cursor.position_in(text_bounds))
+                    .and_then(|(checker, position)| {
+                        let internal = self.content.0.borrow();
+
+                        let (start, end, suggestions) = select_misspelling(
+                            &internal.editor,
+                            checker,
+                            position,
+                        )?;
+
+                        state.suggestions = internal
+                            .editor
+                            .range_bounds(start, end)
+                            .first()
+                            .filter(|_| !suggestions.is_empty())
+                            .map(|bounds| {
+                                spell::State::new(
+                                    suggestions,
+                                    Point::new(
+                                        text_bounds.x + bounds.x,
+                                        text_bounds.y
+                                            + bounds.y
+                                            + bounds.height,
+                                    ),
+                                    bounds.height,
+                                )
+                            });
+
+                        Some(Action::SelectRange { start, end })
+                    }),
+                _ => None,
+            };
+
+            if let Some(selection) = selection {
+                state.focus = Some(Focus::now());
+                state.drag_click = None;
+
+                perform(
+                    selection,
+                    self.content,
+                    on_edit,
+                    self.is_read_only,
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+        }
+
         let Some(update) = Update::from_event(
             event,
             state,
             layout.bounds(),
-            self.padding,
+            padding,
             cursor,
             self.key_binding.as_deref(),
         ) else {
@@ -593,13 +783,53 @@ where
                 state.last_click = Some(click);
                 state.drag_click = Some(click.kind());
 
-                shell.publish(on_edit(action));
+                perform(
+                    action,
+                    self.content,
+                    on_edit,
+                    self.is_read_only,
+                    shell,
+                );
             }
             Update::Drag(position) => {
-                shell.publish(on_edit(Action::Drag(position)));
+                perform(
+                    Action::Drag(position),
+                    self.content,
+                    on_edit,
+                    self.is_read_only,
+                    shell,
+                );
             }
             Update::Release => {
                 state.drag_click = None;
+
+                if state.focus.is_some() && clipboard::has_primary() {
+                    if let Some(selection) = self.content.selection() {
+                        clipboard.write(clipboard::Kind::Primary, selection);
+                    }
+                }
+            }
+            Update::PastePrimary(position) => {
+                state.focus = Some(Focus::now());
+
+                perform(
+                    Action::Click(position),
+                    self.content,
+                    on_edit,
+                    self.is_read_only,
+                    shell,
+                );
+
+                if let Some(contents) = clipboard.read(clipboard::Kind::Primary)
+                {
+                    perform(
+                        Action::Edit(Edit::Paste(Arc::new(contents))),
+                        self.content,
+                        on_edit,
+                        self.is_read_only,
+                        shell,
+                    );
+                }
             }
             Update::Scroll(lines) => {
                 let bounds = self.content.0.borrow().editor.bounds();
@@ -611,9 +841,15 @@ where
                 let lines = lines + state.partial_scroll;
                 state.partial_scroll = lines.fract();
 
-                shell.publish(on_edit(Action::Scroll {
-                    lines: lines as i32,
-                }));
+                perform(
+                    Action::Scroll {
+                        lines: lines as i32,
+                    },
+                    self.content,
+                    on_edit,
+                    self.is_read_only,
+                    shell,
+                );
             }
             Update::Binding(binding) => {
                 fn apply_binding<
@@ -624,11 +860,14 @@ where
                     binding: Binding<Message>,
                     content: &Content<R>,
                     state: &mut State<H>,
-                    on_edit: &dyn Fn(Action) -> Message,
+                    on_edit: Option<&dyn Fn(Action) -> Message>,
+                    is_read_only: bool,
                     clipboard: &mut dyn Clipboard,
                     shell: &mut Shell<'_, Message>,
                 ) {
-                    let mut publish = |action| shell.publish(on_edit(action));
+                    let mut publish = |action| {
+                        perform(action, content, on_edit, is_read_only, shell);
+                    };
 
                     match binding {
                         Binding::Unfocus => {
@@ -692,8 +931,13 @@ where
                         Binding::Sequence(sequence) => {
                             for binding in sequence {
                                 apply_binding(
-                                    binding, content, state, on_edit,
-                                    clipboard, shell,
+                                    binding,
+                                    content,
+                                    state,
+                                    on_edit,
+                                    is_read_only,
+                                    clipboard,
+                                    shell,
                                 );
                             }
                         }
@@ -708,6 +952,7 @@ where
                     self.content,
                     state,
                     on_edit,
+                    self.is_read_only,
                     clipboard,
                     shell,
                 );
@@ -744,7 +989,7 @@ where
             |highlight| (self.highlighter_format)(highlight, theme),
         );
 
-        let is_disabled = self.on_edit.is_none();
+        let is_disabled = self.on_edit.is_none() && !self.is_read_only;
         let is_mouse_over = cursor.is_over(bounds);
 
         let status = if is_disabled {
@@ -768,7 +1013,60 @@ where
             style.background,
         );
 
-        let text_bounds = bounds.shrink(self.padding);
+        let text_bounds = bounds.shrink(self.text_padding(state.gutter_width));
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        if self.line_numbers {
+            let size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            let gutter = Rectangle {
+                x: text_bounds.x - state.gutter_width,
+                width: state.gutter_width,
+                ..text_bounds
+            };
+
+            for (line, top) in internal.editor.visible_lines() {
+                renderer.fill_text(
+                    Text {
+                        content: (line + 1).to_string(),
+                        bounds: Size::new(f32::INFINITY, text_bounds.height),
+                        size,
+                        line_height: self.line_height,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        justification: text::Justification::None,
+                    },
+                    Point::new(
+                        text_bounds.x - size.0 / 2.0,
+                        text_bounds.y + top,
+                    ),
+                    style.placeholder,
+                    gutter,
+                );
+            }
+        }
+
+        for (start, end, background) in &self.highlights {
+            for range in internal
+                .editor
+                .range_bounds(*start, *end)
+                .into_iter()
+                .filter_map(|range| {
+                    text_bounds.intersection(&(range + translation))
+                })
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: range,
+                        ..renderer::Quad::default()
+                    },
+                    *background,
+                );
+            }
+        }
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -784,6 +1082,7 @@ where
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Top,
                         shaping: text::Shaping::Advanced,
+                        justification: text::Justification::None,
                     },
                     text_bounds.position(),
                     style.placeholder,
@@ -799,7 +1098,48 @@ where
             );
         }
 
-        let translation = text_bounds.position() - Point::ORIGIN;
+        if let Some(checker) = self.spell_checker {
+            let size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+            let line_height = self.line_height.to_absolute(size);
+
+            let underline_offset =
+                size.0 + (line_height.0 - size.0) / 2.0 - size.0 * 0.08;
+
+            // Only the visible lines are checked; so the cost of spell
+            // checking does not grow with the size of the document
+            for (line, _) in internal.editor.visible_lines() {
+                let Some(text) = internal.editor.line(line) else {
+                    continue;
+                };
+
+                for word in text::spell::misspellings(checker, text) {
+                    for bounds in internal
+                        .editor
+                        .range_bounds((line, word.start), (line, word.end))
+                    {
+                        let underline = Rectangle::new(
+                            bounds.position()
+                                + translation
+                                + Vector::new(0.0, underline_offset),
+                            Size::new(bounds.width, 1.0),
+                        );
+
+                        if let Some(underline) =
+                            text_bounds.intersection(&underline)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: underline,
+                                    ..renderer::Quad::default()
+                                },
+                                style.misspelling,
+                            );
+                        }
+                    }
+                }
+            }
+        }
 
         if let Some(focus) = state.focus.as_ref() {
             match internal.editor.cursor() {
@@ -855,7 +1195,7 @@ where
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let is_disabled = self.on_edit.is_none();
+        let is_disabled = self.on_edit.is_none() && !self.is_read_only;
 
         if cursor.is_over(layout.bounds()) {
             if is_disabled {
@@ -879,6 +1219,54 @@ where
 
         operation.focusable(state, None);
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        _layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        let suggestions = state
+            .suggestions
+            .as_mut()
+            .filter(|suggestions| suggestions.is_open())?;
+
+        if self.is_read_only {
+            return None;
+        }
+
+        let on_edit = self.on_edit.as_ref()?;
+        let class = &self.class;
+
+        Some(
+            Suggestions::new(
+                suggestions,
+                move |suggestion| {
+                    on_edit(Action::Edit(Edit::Paste(Arc::new(suggestion))))
+                },
+                move |theme: &Theme| {
+                    let style = theme.style(class, Status::Focused);
+
+                    spell::Style {
+                        background: style.background,
+                        border: style.border,
+                        text_color: style.value,
+                        selected_text_color: style.value,
+                        selected_background: style.selection.into(),
+                    }
+                },
+            )
+            .font(self.font.unwrap_or_else(|| renderer.default_font()))
+            .text_size(
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+            )
+            .text_line_height(self.line_height)
+            .overlay(translation),
+        )
+    }
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -1017,10 +1405,76 @@ impl<Message> Binding<Message> {
     }
 }
 
+fn perform<R, Message>(
+    action: Action,
+    content: &Content<R>,
+    on_edit: Option<&dyn Fn(Action) -> Message>,
+    is_read_only: bool,
+    shell: &mut Shell<'_, Message>,
+) where
+    R: text::Renderer,
+{
+    if is_read_only && action.is_edit() {
+        return;
+    }
+
+    if let Some(on_edit) = on_edit {
+        shell.publish(on_edit(action));
+    } else {
+        let mut internal = content.0.borrow_mut();
+
+        internal.editor.perform(action);
+        internal.is_dirty = true;
+
+        shell.invalidate_layout();
+    }
+}
+
+fn select_line(editor: &impl text::Editor, y: f32) -> Option<Action> {
+    let (line, _) = editor
+        .visible_lines()
+        .into_iter()
+        .take_while(|(_, top)| *top <= y)
+        .last()?;
+
+    let end = if line + 1 < editor.line_count() {
+        (line + 1, 0)
+    } else {
+        (line, editor.line(line).map_or(0, str::len))
+    };
+
+    Some(Action::SelectRange {
+        start: (line, 0),
+        end,
+    })
+}
+
+#[allow(clippy::type_complexity)]
+fn select_misspelling(
+    editor: &impl text::Editor,
+    checker: &dyn text::spell::Checker,
+    position: Point,
+) -> Option<((usize, usize), (usize, usize), Vec<String>)> {
+    let (line, index) = editor.hit_test(position)?;
+    let text = editor.line(line)?;
+    let word = text::spell::word_at(text, index)?;
+
+    if checker.check(&text[word.clone()]) {
+        return None;
+    }
+
+    Some((
+        (line, word.start),
+        (line, word.end),
+        checker.suggest(&text[word.clone()]),
+    ))
+}
+
 enum Update<Message> {
     Click(mouse::Click),
     Drag(Point),
     Release,
+    PastePrimary(Point),
     Scroll(f32),
     Binding(Binding<Message>),
 }
@@ -1041,7 +1495,7 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
                         let cursor_position = cursor_position
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -1058,10 +1512,18 @@ impl<Message> Update<Message> {
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     Some(Update::Release)
                 }
+                mouse::Event::ButtonPressed(mouse::Button::Middle)
+                    if clipboard::has_primary() =>
+                {
+                    let cursor_position = cursor.position_in(bounds)?
+                        - Vector::new(padding.left, padding.top);
+
+                    Some(Update::PastePrimary(cursor_position))
+                }
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
                         let cursor_position = cursor.position_in(bounds)?
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         Some(Update::Drag(cursor_position))
                     }
@@ -1156,6 +1618,19 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the underline of misspelled words.
+    pub misspelling: Color,
+}
+
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            background: layer.background.unwrap_or(self.background),
+            border: layer.border(self.border),
+            value: layer.text_color.unwrap_or(self.value),
+            ..self
+        }
+    }
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -1200,6 +1675,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        misspelling: palette.danger.base.color,
     };
 
     match status {
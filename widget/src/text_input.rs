@@ -18,7 +18,9 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
 use crate::core::mouse::{self, click};
+use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::style;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::time::{Duration, Instant};
@@ -31,9 +33,12 @@ use crate::core::{
     Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
     Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::overlay::spell::{self, Suggestions};
 use crate::runtime::task::{self, Task};
 use crate::runtime::Action;
 
+use std::ops::Range;
+
 /// A field that can be filled with text.
 ///
 /// # Example
@@ -71,13 +76,14 @@ pub struct TextInput<
     is_secure: bool,
     font: Option<Renderer::Font>,
     width: Length,
-    padding: Padding,
+    padding: Option<Padding>,
     size: Option<Pixels>,
     line_height: text::LineHeight,
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     icon: Option<Icon<Renderer::Font>>,
+    spell_checker: Option<&'a dyn text::spell::Checker>,
     class: Theme::Class<'a>,
 }
 
@@ -100,13 +106,14 @@ where
             is_secure: false,
             font: None,
             width: Length::Fill,
-            padding: DEFAULT_PADDING,
+            padding: None,
             size: None,
             line_height: text::LineHeight::default(),
             on_input: None,
             on_paste: None,
             on_submit: None,
             icon: None,
+            spell_checker: None,
             class: Theme::default(),
         }
     }
@@ -174,7 +181,7 @@ where
 
     /// Sets the [`Padding`] of the [`TextInput`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
-        self.padding = padding.into();
+        self.padding = Some(padding.into());
         self
     }
 
@@ -193,6 +200,23 @@ where
         self
     }
 
+    /// Checks the spelling of the value of the [`TextInput`] with the
+    /// given [`Checker`].
+    ///
+    /// Misspelled words will be underlined. Right clicking one of them will
+    /// select it and display the suggestions of the [`Checker`] to replace it.
+    ///
+    /// Secure inputs are never checked.
+    ///
+    /// [`Checker`]: text::spell::Checker
+    pub fn spell_check(
+        mut self,
+        checker: &'a dyn text::spell::Checker,
+    ) -> Self {
+        self.spell_checker = Some(checker);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -225,7 +249,10 @@ where
 
         let font = self.font.unwrap_or_else(|| renderer.default_font());
         let text_size = self.size.unwrap_or_else(|| renderer.default_size());
-        let padding = self.padding.fit(Size::ZERO, limits.max());
+        let padding = self
+            .padding
+            .unwrap_or_else(|| renderer.density().padding(DEFAULT_PADDING))
+            .fit(Size::ZERO, limits.max());
         let height = self.line_height.to_absolute(text_size);
 
         let limits = limits.width(self.width).shrink(padding);
@@ -240,6 +267,7 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
+            justification: text::Justification::None,
         };
 
         state.placeholder.update(placeholder_text);
@@ -264,6 +292,7 @@ where
                 horizontal_alignment: alignment::Horizontal::Center,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
+                justification: text::Justification::None,
             };
 
             state.icon.update(icon_text);
@@ -370,6 +399,30 @@ where
 
         let text = value.to_string();
 
+        let misspellings: Vec<Rectangle> = match self.spell_checker {
+            Some(checker) if !self.is_secure => {
+                let size = self.size.unwrap_or_else(|| renderer.default_size());
+                let line_height = self.line_height.to_absolute(size);
+
+                let top = text_bounds.center_y()
+                    - state.value.min_bounds().height / 2.0
+                    + size.0
+                    + (line_height.0 - size.0) / 2.0
+                    - size.0 * 0.08;
+
+                text::spell::misspellings(checker, &text)
+                    .flat_map(|word| state.value.raw().range_bounds(word))
+                    .map(|bounds| Rectangle {
+                        x: text_bounds.x + bounds.x,
+                        y: top + bounds.y,
+                        width: bounds.width,
+                        height: 1.0,
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+
         let (cursor, offset, is_selecting) = if let Some(focus) = state
             .is_focused
             .as_ref()
@@ -390,12 +443,13 @@ where
                         % 2
                         == 0;
 
+                    // The renderer snaps thin quads to physical pixels;
+                    // rounding here would misplace the caret at high DPI
                     let cursor = if is_cursor_visible {
                         Some((
                             renderer::Quad {
                                 bounds: Rectangle {
-                                    x: (text_bounds.x + text_value_width)
-                                        .floor(),
+                                    x: text_bounds.x + text_value_width,
                                     y: text_bounds.y,
                                     width: 1.0,
                                     height: text_bounds.height,
@@ -428,7 +482,22 @@ where
                             right,
                         );
 
-                    let width = right_position - left_position;
+                    // Use the actual bounds of the selected glyphs, if
+                    // possible; since the positions of the graphemes
+                    // can be off for ligatures and right-to-left text
+                    let (left_position, width) = state
+                        .value
+                        .raw()
+                        .range_bounds(
+                            value.byte_offset(left)..value.byte_offset(right),
+                        )
+                        .into_iter()
+                        .reduce(|a, b| a.union(&b))
+                        .map(|bounds| (bounds.x, bounds.width))
+                        .unwrap_or((
+                            left_position,
+                            right_position - left_position,
+                        ));
 
                     (
                         Some((
@@ -457,6 +526,20 @@ where
         };
 
         let draw = |renderer: &mut Renderer, viewport| {
+            for underline in &misspellings {
+                if let Some(underline) = text_bounds
+                    .intersection(&(*underline - Vector::new(offset, 0.0)))
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: underline,
+                            ..renderer::Quad::default()
+                        },
+                        style.misspelling,
+                    );
+                }
+            }
+
             if let Some((cursor, color)) = cursor {
                 renderer.with_translation(
                     Vector::new(-offset, 0.0),
@@ -534,7 +617,9 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.layout(tree, renderer, limits, None)
+        renderer
+            .density()
+            .expand(limits, self.layout(tree, renderer, limits, None))
     }
 
     fn operate(
@@ -574,6 +659,84 @@ where
         };
 
         match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                let state = state::<Renderer>(tree);
+
+                let checker =
+                    self.spell_checker.filter(|_| self.on_input.is_some());
+
+                if let (Some(checker), Some(cursor_position), false) = (
+                    checker,
+                    cursor.position_over(layout.bounds()),
+                    self.is_secure,
+                ) {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let offset = offset(text_bounds, &self.value, state);
+                    let value = self.value.to_string();
+
+                    let misspelling = state
+                        .value
+                        .raw()
+                        .hit_test(Point::new(
+                            cursor_position.x - text_bounds.x + offset,
+                            text_bounds.height / 2.0,
+                        ))
+                        .map(text::Hit::cursor)
+                        .and_then(|index| text::spell::word_at(&value, index))
+                        .filter(|word| !checker.check(&value[word.clone()]));
+
+                    if let Some(word) = misspelling {
+                        let suggestions = checker.suggest(&value[word.clone()]);
+
+                        let graphemes = |index: usize| {
+                            unicode_segmentation::UnicodeSegmentation::graphemes(
+                                &value[..index],
+                                true,
+                            )
+                            .count()
+                        };
+
+                        state.suggestions = state
+                            .value
+                            .raw()
+                            .range_bounds(word.clone())
+                            .first()
+                            .filter(|_| !suggestions.is_empty())
+                            .map(|bounds| {
+                                (
+                                    word.clone(),
+                                    spell::State::new(
+                                        suggestions,
+                                        Point::new(
+                                            text_bounds.x + bounds.x - offset,
+                                            text_bounds.y + text_bounds.height,
+                                        ),
+                                        text_bounds.height,
+                                    ),
+                                )
+                            });
+
+                        state.cursor.select_range(
+                            graphemes(word.start),
+                            graphemes(word.end),
+                        );
+
+                        state.is_dragging = false;
+                        state.is_focused = state.is_focused.or_else(|| {
+                            let now = Instant::now();
+
+                            Some(Focus {
+                                updated_at: now,
+                                now,
+                                is_window_focused: true,
+                            })
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let state = state::<Renderer>(tree);
@@ -666,10 +829,92 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) if clipboard::has_primary() => {
+                let state = state::<Renderer>(tree);
+
+                let Some(on_input) = &self.on_input else {
+                    return event::Status::Ignored;
+                };
+
+                let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let text_layout = layout.children().next().unwrap();
+                let target = cursor_position.x - text_layout.bounds().x;
+
+                let position = if target > 0.0 {
+                    let value = if self.is_secure {
+                        self.value.secure()
+                    } else {
+                        self.value.clone()
+                    };
+
+                    find_cursor_position(
+                        text_layout.bounds(),
+                        &value,
+                        state,
+                        target,
+                    )
+                } else {
+                    None
+                }
+                .unwrap_or(0);
+
+                let now = Instant::now();
+
+                state.is_focused = Some(Focus {
+                    updated_at: now,
+                    now,
+                    is_window_focused: true,
+                });
+
+                state.cursor.move_to(position);
+
+                let content: String = clipboard
+                    .read(clipboard::Kind::Primary)
+                    .unwrap_or_default()
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                if !content.is_empty() {
+                    let mut editor =
+                        Editor::new(&mut self.value, &mut state.cursor);
+
+                    editor.paste(Value::new(&content));
+
+                    let message = if let Some(paste) = &self.on_paste {
+                        (paste)(editor.contents())
+                    } else {
+                        (on_input)(editor.contents())
+                    };
+                    shell.publish(message);
+
+                    update_cache(state, &self.value);
+                }
+
+                return event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
-                state::<Renderer>(tree).is_dragging = false;
+                let state = state::<Renderer>(tree);
+
+                if state.is_focused.is_some() {
+                    copy_to_primary(
+                        clipboard,
+                        &self.value,
+                        &state.cursor,
+                        self.is_secure,
+                    );
+                }
+
+                state.is_dragging = false;
             }
             Event::Mouse(mouse::Event::CursorMoved { position })
             | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
@@ -794,6 +1039,13 @@ where
                         {
                             state.cursor.select_all(&self.value);
 
+                            copy_to_primary(
+                                clipboard,
+                                &self.value,
+                                &state.cursor,
+                                self.is_secure,
+                            );
+
                             return event::Status::Captured;
                         }
                         _ => {}
@@ -828,19 +1080,11 @@ where
                             }
                         }
                         keyboard::Key::Named(key::Named::Backspace) => {
-                            if modifiers.jump()
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
-                                    let cursor_pos =
-                                        state.cursor.end(&self.value);
-                                    state.cursor.select_range(0, cursor_pos);
-                                } else {
-                                    state
-                                        .cursor
-                                        .select_left_by_words(&self.value);
-                                }
-                            }
+                            select_before(
+                                &mut state.cursor,
+                                &self.value,
+                                Extent::new(modifiers, self.is_secure),
+                            );
 
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
@@ -852,22 +1096,11 @@ where
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
-                            if modifiers.jump()
-                                && state.cursor.selection(&self.value).is_none()
-                            {
-                                if self.is_secure {
-                                    let cursor_pos =
-                                        state.cursor.end(&self.value);
-                                    state.cursor.select_range(
-                                        cursor_pos,
-                                        self.value.len(),
-                                    );
-                                } else {
-                                    state
-                                        .cursor
-                                        .select_right_by_words(&self.value);
-                                }
-                            }
+                            select_after(
+                                &mut state.cursor,
+                                &self.value,
+                                Extent::new(modifiers, self.is_secure),
+                            );
 
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
@@ -974,6 +1207,15 @@ where
                         _ => {}
                     }
 
+                    if modifiers.shift() {
+                        copy_to_primary(
+                            clipboard,
+                            &self.value,
+                            &state.cursor,
+                            self.is_secure,
+                        );
+                    }
+
                     return event::Status::Captured;
                 }
             }
@@ -1078,6 +1320,56 @@ where
             mouse::Interaction::default()
         }
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = state::<Renderer>(tree);
+
+        let (word, suggestions) = state
+            .suggestions
+            .as_mut()
+            .filter(|suggestions| suggestions.1.is_open())?;
+
+        let on_input = self.on_input.as_ref()?;
+        let word = word.clone();
+        let value = self.value.to_string();
+        let class = &self.class;
+
+        Some(
+            Suggestions::new(
+                suggestions,
+                move |suggestion| {
+                    let mut value = value.clone();
+
+                    if value.get(word.clone()).is_some() {
+                        value.replace_range(word.clone(), &suggestion);
+                    }
+
+                    on_input(value)
+                },
+                move |theme: &Theme| {
+                    let style = theme.style(class, Status::Focused);
+
+                    spell::Style {
+                        background: style.background,
+                        border: style.border,
+                        text_color: style.value,
+                        selected_text_color: style.value,
+                        selected_background: style.selection.into(),
+                    }
+                },
+            )
+            .font(self.font.unwrap_or_else(|| renderer.default_font()))
+            .text_size(self.size.unwrap_or_else(|| renderer.default_size()))
+            .text_line_height(self.line_height)
+            .overlay(translation),
+        )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
@@ -1188,6 +1480,7 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    suggestions: Option<(Range<usize>, spell::State)>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1356,6 +1649,26 @@ fn find_cursor_position<P: text::Paragraph>(
     )
 }
 
+/// Copies the current selection of a [`TextInput`] to the primary clipboard,
+/// if the platform has one.
+fn copy_to_primary(
+    clipboard: &mut dyn Clipboard,
+    value: &Value,
+    cursor: &Cursor,
+    is_secure: bool,
+) {
+    if is_secure || !clipboard::has_primary() {
+        return;
+    }
+
+    if let Some((start, end)) = cursor.selection(value) {
+        clipboard.write(
+            clipboard::Kind::Primary,
+            value.select(start, end).to_string(),
+        );
+    }
+}
+
 fn replace_paragraph<Renderer>(
     renderer: &Renderer,
     state: &mut State<Renderer::Paragraph>,
@@ -1382,6 +1695,7 @@ fn replace_paragraph<Renderer>(
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
+        justification: text::Justification::None,
     });
 }
 
@@ -1415,6 +1729,19 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the underline of misspelled words.
+    pub misspelling: Color,
+}
+
+impl style::Layered for Style {
+    fn layer(self, layer: &style::Layer) -> Self {
+        Self {
+            background: layer.background.unwrap_or(self.background),
+            border: layer.border(self.border),
+            value: layer.text_color.unwrap_or(self.value),
+            ..self
+        }
+    }
 }
 
 /// The theme catalog of a [`TextInput`].
@@ -1461,6 +1788,7 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        misspelling: palette.danger.base.color,
     };
 
     match status {
@@ -1486,3 +1814,123 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+/// The amount of text removed by a deletion when nothing is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Extent {
+    Character,
+    Word,
+    Line,
+}
+
+impl Extent {
+    fn new(modifiers: keyboard::Modifiers, is_secure: bool) -> Self {
+        if modifiers.macos_command() {
+            Self::Line
+        } else if modifiers.jump() {
+            // The words of a secure input must not be revealed
+            if is_secure {
+                Self::Line
+            } else {
+                Self::Word
+            }
+        } else {
+            Self::Character
+        }
+    }
+}
+
+/// Selects the text before the cursor that a backspace with the given
+/// [`Extent`] removes, unless some text is selected already.
+fn select_before(cursor: &mut Cursor, value: &Value, extent: Extent) {
+    if cursor.selection(value).is_some() {
+        return;
+    }
+
+    match extent {
+        Extent::Character => {}
+        Extent::Word => cursor.select_left_by_words(value),
+        Extent::Line => {
+            let position = cursor.end(value);
+            cursor.select_range(0, position);
+        }
+    }
+}
+
+/// Selects the text after the cursor that a delete with the given
+/// [`Extent`] removes, unless some text is selected already.
+fn select_after(cursor: &mut Cursor, value: &Value, extent: Extent) {
+    if cursor.selection(value).is_some() {
+        return;
+    }
+
+    match extent {
+        Extent::Character => {}
+        Extent::Word => cursor.select_right_by_words(value),
+        Extent::Line => {
+            let position = cursor.end(value);
+            cursor.select_range(position, value.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backspace(content: &str, position: usize, extent: Extent) -> String {
+        let mut value = Value::new(content);
+        let mut cursor = Cursor::default();
+        cursor.move_to(position);
+
+        select_before(&mut cursor, &value, extent);
+        Editor::new(&mut value, &mut cursor).backspace();
+
+        value.to_string()
+    }
+
+    fn delete(content: &str, position: usize, extent: Extent) -> String {
+        let mut value = Value::new(content);
+        let mut cursor = Cursor::default();
+        cursor.move_to(position);
+
+        select_after(&mut cursor, &value, extent);
+        Editor::new(&mut value, &mut cursor).delete();
+
+        value.to_string()
+    }
+
+    #[test]
+    fn line_deletion_removes_everything_up_to_the_cursor() {
+        assert_eq!(backspace("héllo wörld", 7, Extent::Line), "wörld");
+        assert_eq!(delete("héllo wörld", 5, Extent::Line), "héllo");
+
+        assert_eq!(backspace("héllo wörld", 0, Extent::Line), "héllo wörld");
+        assert_eq!(delete("héllo wörld", 11, Extent::Line), "héllo wörld");
+    }
+
+    #[test]
+    fn word_and_character_deletion_are_unchanged() {
+        assert_eq!(backspace("héllo wörld", 11, Extent::Word), "héllo ");
+        assert_eq!(delete("héllo wörld", 0, Extent::Word), " wörld");
+
+        assert_eq!(backspace("héllo", 5, Extent::Character), "héll");
+        assert_eq!(delete("héllo", 0, Extent::Character), "éllo");
+    }
+
+    #[test]
+    fn jumping_deletes_the_whole_line_of_secure_inputs() {
+        let modifiers = if cfg!(target_os = "macos") {
+            keyboard::Modifiers::ALT
+        } else {
+            keyboard::Modifiers::CTRL
+        };
+
+        assert_eq!(Extent::new(modifiers, true), Extent::Line);
+        assert_eq!(Extent::new(modifiers, false), Extent::Word);
+        assert_eq!(
+            Extent::new(keyboard::Modifiers::empty(), false),
+            Extent::Character
+        );
+    }
+}
@@ -81,6 +81,15 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns the byte offset of the grapheme at the given `index` in the
+    /// string representation of the [`Value`].
+    pub fn byte_offset(&self, index: usize) -> usize {
+        self.graphemes[..index.min(self.len())]
+            .iter()
+            .map(String::len)
+            .sum()
+    }
+
     /// Returns a new [`Value`] containing the graphemes until the given
     /// `index`.
     pub fn until(&self, index: usize) -> Self {
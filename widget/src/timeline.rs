@@ -0,0 +1,1146 @@
+//! Arrange clips on tracks along a scrollable and zoomable time axis.
+//!
+//! A [`Timeline`] displays a ruler on top of some [`Track`]s, each holding
+//! [`Clip`]s. Clips can be selected, dragged along the time axis or to
+//! another track, and resized from either edge; which makes it suitable
+//! for video editors, audio sequencers, and schedulers.
+//!
+//! Like most widgets, a [`Timeline`] does not own its data. It produces
+//! messages describing the desired changes, and your application decides
+//! whether to apply them.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+/// A set of [`Track`]s of [`Clip`]s along a time axis.
+///
+/// The time axis can be scrolled horizontally with the mouse wheel—holding
+/// shift for vertical wheels—or by dragging it with the middle button, and
+/// zoomed around the cursor while holding the command modifier.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::timeline::{Change, ClipId, Track};
+/// use iced_widget::timeline;
+///
+/// struct State {
+///     tracks: Vec<Track>,
+///     selected: Option<ClipId>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(Option<ClipId>),
+///     Changed(ClipId, Change),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     timeline(&state.tracks)
+///         .selected(state.selected)
+///         .snap(0.5)
+///         .on_select(Message::Selected)
+///         .on_change(Message::Changed)
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Timeline<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tracks: &'a [Track],
+    selected: Option<ClipId>,
+    playhead: Option<f32>,
+    duration: Option<f32>,
+    zoom: f32,
+    zoom_range: RangeInclusive<f32>,
+    snap: Option<f32>,
+    snap_to_edges: bool,
+    on_select: Option<Box<dyn Fn(Option<ClipId>) -> Message + 'a>>,
+    on_change: Option<Box<dyn Fn(ClipId, Change) -> Message + 'a>>,
+    on_seek: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    width: Length,
+    track_height: f32,
+    ruler_height: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+/// A track of a [`Timeline`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Track {
+    /// The [`Clip`]s of the [`Track`].
+    pub clips: Vec<Clip>,
+}
+
+impl Track {
+    /// Creates a new [`Track`] with the given [`Clip`]s.
+    pub fn new(clips: impl IntoIterator<Item = Clip>) -> Self {
+        Self {
+            clips: clips.into_iter().collect(),
+        }
+    }
+}
+
+/// A clip of a [`Track`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clip {
+    /// The time where the [`Clip`] starts.
+    pub start: f32,
+    /// The duration of the [`Clip`].
+    pub duration: f32,
+    /// The label of the [`Clip`].
+    pub label: String,
+}
+
+impl Clip {
+    /// Creates a new [`Clip`] with the given start and duration.
+    pub fn new(start: f32, duration: f32) -> Self {
+        Self {
+            start,
+            duration,
+            label: String::new(),
+        }
+    }
+
+    /// Sets the label of the [`Clip`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Returns the time where the [`Clip`] ends.
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+}
+
+/// The identifier of a [`Clip`] in a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClipId {
+    /// The index of the [`Track`] of the [`Clip`].
+    pub track: usize,
+    /// The index of the [`Clip`] in its [`Track`].
+    pub index: usize,
+}
+
+/// A change to a [`Clip`] requested by the user of a [`Timeline`].
+///
+/// It describes the new placement of the [`Clip`], both when it is moved
+/// and when it is resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    /// The index of the new [`Track`] of the [`Clip`].
+    pub track: usize,
+    /// The new start of the [`Clip`].
+    pub start: f32,
+    /// The new duration of the [`Clip`].
+    pub duration: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of a [`Track`] of a [`Timeline`].
+    pub const DEFAULT_TRACK_HEIGHT: f32 = 40.0;
+
+    /// The default height of the ruler of a [`Timeline`].
+    pub const DEFAULT_RULER_HEIGHT: f32 = 24.0;
+
+    /// The default zoom of a [`Timeline`], in pixels per unit of time.
+    pub const DEFAULT_ZOOM: f32 = 100.0;
+
+    /// The distance, in pixels, from which clips snap to nearby edges.
+    pub const SNAP_DISTANCE: f32 = 8.0;
+
+    /// The width, in pixels, of the grabbable edges of a [`Clip`].
+    pub const EDGE_WIDTH: f32 = 6.0;
+
+    /// Creates a new [`Timeline`] with the given [`Track`]s.
+    pub fn new(tracks: &'a [Track]) -> Self {
+        Self {
+            tracks,
+            selected: None,
+            playhead: None,
+            duration: None,
+            zoom: Self::DEFAULT_ZOOM,
+            zoom_range: 1.0..=10_000.0,
+            snap: None,
+            snap_to_edges: true,
+            on_select: None,
+            on_change: None,
+            on_seek: None,
+            width: Length::Fill,
+            track_height: Self::DEFAULT_TRACK_HEIGHT,
+            ruler_height: Self::DEFAULT_RULER_HEIGHT,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the selected [`Clip`] of the [`Timeline`].
+    pub fn selected(mut self, selected: Option<ClipId>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the time of the playhead of the [`Timeline`].
+    pub fn playhead(mut self, time: f32) -> Self {
+        self.playhead = Some(time);
+        self
+    }
+
+    /// Sets the total duration of the [`Timeline`].
+    ///
+    /// By default, it is the end of the last [`Clip`].
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the initial zoom of the [`Timeline`], in pixels per unit of
+    /// time.
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the range of zoom of the [`Timeline`], in pixels per unit of
+    /// time.
+    pub fn zoom_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.zoom_range = range;
+        self
+    }
+
+    /// Snaps the clips of the [`Timeline`] to multiples of the given step
+    /// while they are moved or resized.
+    pub fn snap(mut self, step: f32) -> Self {
+        self.snap = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets whether the clips of the [`Timeline`] snap to the edges of other
+    /// clips and to the playhead.
+    ///
+    /// It is enabled by default.
+    pub fn snap_to_edges(mut self, snap_to_edges: bool) -> Self {
+        self.snap_to_edges = snap_to_edges;
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Clip`] is
+    /// selected, or when the selection is cleared.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Option<ClipId>) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Clip`] is moved or
+    /// resized.
+    ///
+    /// If this method is not called, clips cannot be moved or resized.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(ClipId, Change) -> Message + 'a,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the message that should be produced when the ruler of the
+    /// [`Timeline`] is clicked or dragged.
+    ///
+    /// The message receives the time under the cursor.
+    pub fn on_seek(mut self, on_seek: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_seek = Some(Box::new(on_seek));
+        self
+    }
+
+    /// Sets the width of the [`Timeline`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of each [`Track`] of the [`Timeline`].
+    pub fn track_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.track_height = height.into().0;
+        self
+    }
+
+    /// Sets the height of the ruler of the [`Timeline`].
+    pub fn ruler_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.ruler_height = height.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`Timeline`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`Timeline`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Timeline`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Timeline`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn total_duration(&self) -> f32 {
+        self.duration.unwrap_or_else(|| {
+            self.tracks
+                .iter()
+                .flat_map(|track| &track.clips)
+                .map(Clip::end)
+                .fold(0.0, f32::max)
+        })
+    }
+
+    fn clip(&self, id: ClipId) -> Option<&Clip> {
+        self.tracks.get(id.track)?.clips.get(id.index)
+    }
+
+    fn track_at(&self, bounds: Rectangle, y: f32) -> Option<usize> {
+        let y = y - bounds.y - self.ruler_height;
+
+        (y >= 0.0)
+            .then(|| (y / self.track_height) as usize)
+            .filter(|track| *track < self.tracks.len())
+    }
+
+    fn clip_bounds(
+        &self,
+        view: &View,
+        bounds: Rectangle,
+        id: ClipId,
+        clip: &Clip,
+    ) -> Rectangle {
+        Rectangle {
+            x: view.x(bounds, clip.start),
+            y: bounds.y
+                + self.ruler_height
+                + self.track_height * id.track as f32
+                + CLIP_PADDING,
+            width: clip.duration * view.zoom,
+            height: (self.track_height - CLIP_PADDING * 2.0).max(0.0),
+        }
+    }
+
+    /// Returns the topmost clip under the given point, and the edge of it
+    /// under the point, if any.
+    fn clip_at(
+        &self,
+        view: &View,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<(ClipId, Option<Edge>)> {
+        let track = self.track_at(bounds, point.y)?;
+
+        self.tracks[track].clips.iter().enumerate().rev().find_map(
+            |(index, clip)| {
+                let id = ClipId { track, index };
+                let clip_bounds = self.clip_bounds(view, bounds, id, clip);
+
+                if !clip_bounds.contains(point) {
+                    return None;
+                }
+
+                let edge_width = Self::EDGE_WIDTH.min(clip_bounds.width / 3.0);
+
+                let edge = if point.x <= clip_bounds.x + edge_width {
+                    Some(Edge::Start)
+                } else if point.x
+                    >= clip_bounds.x + clip_bounds.width - edge_width
+                {
+                    Some(Edge::End)
+                } else {
+                    None
+                };
+
+                Some((id, edge))
+            },
+        )
+    }
+
+    /// Snaps the given time to the grid or to nearby edges, excluding the
+    /// edges of the given clip.
+    fn snap_time(&self, view: &View, time: f32, exclude: ClipId) -> f32 {
+        let threshold = Self::SNAP_DISTANCE / view.zoom;
+
+        if self.snap_to_edges {
+            let edges = self
+                .tracks
+                .iter()
+                .enumerate()
+                .flat_map(|(track, Track { clips })| {
+                    clips.iter().enumerate().filter_map(move |(index, clip)| {
+                        (ClipId { track, index } != exclude)
+                            .then_some([clip.start, clip.end()])
+                    })
+                })
+                .flatten()
+                .chain(self.playhead);
+
+            let nearest = edges
+                .map(|edge| (edge, (edge - time).abs()))
+                .filter(|(_, distance)| *distance <= threshold)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((edge, _)) = nearest {
+                return edge;
+            }
+        }
+
+        match self.snap {
+            Some(step) => (time / step).round() * step,
+            None => time,
+        }
+    }
+
+    fn min_duration(&self, view: &View) -> f32 {
+        self.snap.unwrap_or(Self::EDGE_WIDTH * 2.0 / view.zoom)
+    }
+}
+
+const CLIP_PADDING: f32 = 2.0;
+const LABEL_PADDING: f32 = 4.0;
+const MIN_TICK_SPACING: f32 = 80.0;
+const LINE_HEIGHT: f32 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    Move { clip: ClipId, grab: f32 },
+    Resize { clip: ClipId, edge: Edge },
+    Seek,
+    Pan { origin: f32, offset: f32 },
+}
+
+/// The visible span of the time axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    offset: f32,
+    zoom: f32,
+}
+
+impl View {
+    fn x(&self, bounds: Rectangle, time: f32) -> f32 {
+        bounds.x + (time - self.offset) * self.zoom
+    }
+
+    fn time(&self, bounds: Rectangle, x: f32) -> f32 {
+        self.offset + (x - bounds.x) / self.zoom
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    zoom: Option<f32>,
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    fn view<Message, Theme, Renderer>(
+        &self,
+        timeline: &Timeline<'_, Message, Theme, Renderer>,
+        bounds: Rectangle,
+    ) -> View
+    where
+        Theme: Catalog,
+        Renderer: text::Renderer,
+    {
+        let zoom = self
+            .zoom
+            .unwrap_or(timeline.zoom)
+            .clamp(*timeline.zoom_range.start(), *timeline.zoom_range.end());
+
+        let max_offset =
+            (timeline.total_duration() - bounds.width / zoom).max(0.0);
+
+        View {
+            offset: self.offset.clamp(0.0, max_offset),
+            zoom,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Timeline<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height =
+            self.ruler_height + self.track_height * self.tracks.len() as f32;
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let view = state.view(self, bounds);
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if position.y < bounds.y + self.ruler_height {
+                    let Some(on_seek) = &self.on_seek else {
+                        return event::Status::Ignored;
+                    };
+
+                    shell.publish(on_seek(view.time(bounds, position.x)));
+                    state.drag = Some(Drag::Seek);
+
+                    return event::Status::Captured;
+                }
+
+                let hit = self.clip_at(&view, bounds, position);
+
+                if let Some(on_select) = &self.on_select {
+                    let selected = hit.map(|(clip, _)| clip);
+
+                    if selected != self.selected {
+                        shell.publish(on_select(selected));
+                    }
+                }
+
+                if let (Some((clip, edge)), Some(_)) = (hit, &self.on_change) {
+                    state.drag = Some(match edge {
+                        Some(edge) => Drag::Resize { clip, edge },
+                        None => Drag::Move {
+                            clip,
+                            grab: view.time(bounds, position.x)
+                                - self.tracks[clip.track].clips[clip.index]
+                                    .start,
+                        },
+                    });
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.offset = view.offset;
+                state.drag = Some(Drag::Pan {
+                    origin: position.x,
+                    offset: view.offset,
+                });
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(drag), Some(position)) =
+                    (state.drag, cursor.position())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let time = view.time(bounds, position.x);
+
+                match drag {
+                    Drag::Seek => {
+                        if let Some(on_seek) = &self.on_seek {
+                            shell.publish(on_seek(time.max(0.0)));
+                        }
+                    }
+                    Drag::Pan { origin, offset } => {
+                        state.offset =
+                            offset + (origin - position.x) / view.zoom;
+                    }
+                    Drag::Move { clip: id, grab } => {
+                        let (Some(clip), Some(on_change)) =
+                            (self.clip(id), &self.on_change)
+                        else {
+                            return event::Status::Ignored;
+                        };
+
+                        let start = time - grab;
+                        let snapped_start = self.snap_time(&view, start, id);
+                        let snapped_end =
+                            self.snap_time(&view, start + clip.duration, id)
+                                - clip.duration;
+
+                        let start = if (snapped_start - start).abs()
+                            <= (snapped_end - start).abs()
+                        {
+                            snapped_start
+                        } else {
+                            snapped_end
+                        };
+
+                        let track =
+                            self.track_at(bounds, position.y).unwrap_or(
+                                if position.y < bounds.y + self.ruler_height {
+                                    0
+                                } else {
+                                    self.tracks.len().saturating_sub(1)
+                                },
+                            );
+
+                        let change = Change {
+                            track,
+                            start: start.max(0.0),
+                            duration: clip.duration,
+                        };
+
+                        if change.start != clip.start || track != id.track {
+                            shell.publish(on_change(id, change));
+                        }
+                    }
+                    Drag::Resize { clip: id, edge } => {
+                        let (Some(clip), Some(on_change)) =
+                            (self.clip(id), &self.on_change)
+                        else {
+                            return event::Status::Ignored;
+                        };
+
+                        let time = self.snap_time(&view, time, id);
+                        let min_duration = self.min_duration(&view);
+
+                        let (start, end) = match edge {
+                            Edge::Start => (
+                                time.min(clip.end() - min_duration).max(0.0),
+                                clip.end(),
+                            ),
+                            Edge::End => (
+                                clip.start,
+                                time.max(clip.start + min_duration),
+                            ),
+                        };
+
+                        let change = Change {
+                            track: id.track,
+                            start,
+                            duration: end - start,
+                        };
+
+                        if change.start != clip.start
+                            || change.duration != clip.duration
+                        {
+                            shell.publish(on_change(id, change));
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left | mouse::Button::Middle,
+            ))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * LINE_HEIGHT, y * LINE_HEIGHT)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                if state.modifiers.command() {
+                    let time = view.time(bounds, position.x);
+
+                    let zoom = (view.zoom * 1.1_f32.powf(y / LINE_HEIGHT))
+                        .clamp(
+                            *self.zoom_range.start(),
+                            *self.zoom_range.end(),
+                        );
+
+                    state.zoom = Some(zoom);
+                    state.offset = time - (position.x - bounds.x) / zoom;
+
+                    return event::Status::Captured;
+                }
+
+                let scroll = if state.modifiers.shift() && x == 0.0 {
+                    y
+                } else {
+                    x
+                };
+
+                if scroll == 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                state.offset = view.offset - scroll / view.zoom;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        match state.drag {
+            Some(Drag::Move { .. } | Drag::Pan { .. }) => {
+                return mouse::Interaction::Grabbing;
+            }
+            Some(Drag::Resize { .. }) => {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+            Some(Drag::Seek) => return mouse::Interaction::Pointer,
+            None => {}
+        }
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if position.y < bounds.y + self.ruler_height {
+            return if self.on_seek.is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        if self.on_change.is_none() {
+            return mouse::Interaction::default();
+        }
+
+        match self.clip_at(&state.view(self, bounds), bounds, position) {
+            Some((_, Some(_))) => mouse::Interaction::ResizingHorizontally,
+            Some((_, None)) => mouse::Interaction::Grab,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let view = state.view(self, bounds);
+        let style = theme.style(&self.class);
+
+        let Some(clipped) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.with_layer(clipped, |renderer| {
+            let ruler = Rectangle {
+                height: self.ruler_height,
+                ..bounds
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: ruler,
+                    ..renderer::Quad::default()
+                },
+                style.ruler_background,
+            );
+
+            let step = tick_step(MIN_TICK_SPACING / view.zoom);
+            let end = view.time(bounds, bounds.x + bounds.width);
+            let mut tick = (view.offset / step).floor();
+
+            while tick * step <= end {
+                let time = tick * step;
+                let x = view.x(bounds, time);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: ruler.y + ruler.height / 2.0,
+                            width: 1.0,
+                            height: ruler.height / 2.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.tick_color,
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: label(time, step),
+                        bounds: Size::new(f32::INFINITY, ruler.height),
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        justification: text::Justification::None,
+                    },
+                    Point::new(x + LABEL_PADDING, ruler.center_y()),
+                    style.ruler_text_color,
+                    ruler,
+                );
+
+                tick += 1.0;
+            }
+
+            for (track, Track { clips }) in self.tracks.iter().enumerate() {
+                let track_bounds = Rectangle {
+                    y: bounds.y
+                        + self.ruler_height
+                        + self.track_height * track as f32,
+                    height: self.track_height,
+                    ..bounds
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track_bounds,
+                        ..renderer::Quad::default()
+                    },
+                    if track % 2 == 0 {
+                        style.track_background
+                    } else {
+                        style.track_background_alt
+                    },
+                );
+
+                for (index, clip) in clips.iter().enumerate() {
+                    let id = ClipId { track, index };
+                    let clip_bounds = self.clip_bounds(&view, bounds, id, clip);
+
+                    let Some(visible) = clip_bounds.intersection(&track_bounds)
+                    else {
+                        continue;
+                    };
+
+                    let (background, border) = if self.selected == Some(id) {
+                        (
+                            style.selected_clip_background,
+                            style.selected_clip_border,
+                        )
+                    } else {
+                        (style.clip_background, style.clip_border)
+                    };
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: clip_bounds,
+                            border,
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+
+                    if clip.label.is_empty() {
+                        continue;
+                    }
+
+                    renderer.fill_text(
+                        Text {
+                            content: clip.label.clone(),
+                            bounds: Size::new(
+                                f32::INFINITY,
+                                clip_bounds.height,
+                            ),
+                            size: text_size,
+                            line_height: text::LineHeight::default(),
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                            justification: text::Justification::None,
+                        },
+                        Point::new(
+                            visible.x + LABEL_PADDING,
+                            clip_bounds.center_y(),
+                        ),
+                        style.clip_text_color,
+                        visible.shrink(LABEL_PADDING),
+                    );
+                }
+            }
+
+            if let Some(playhead) = self.playhead {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: view.x(bounds, playhead) - 1.0,
+                            width: 2.0,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.playhead_color,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Timeline<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(timeline: Timeline<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(timeline)
+    }
+}
+
+/// Returns the smallest step of `1`, `2`, or `5` times a power of ten that
+/// is at least the given minimum.
+fn tick_step(minimum: f32) -> f32 {
+    let magnitude = 10.0_f32.powf(minimum.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= minimum)
+        .unwrap_or(magnitude * 10.0)
+}
+
+fn label(time: f32, step: f32) -> String {
+    let decimals = if step >= 1.0 {
+        0
+    } else {
+        (-step.log10()).ceil() as usize
+    };
+
+    format!("{time:.decimals$}")
+}
+
+/// The appearance of a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Timeline`].
+    pub background: Background,
+    /// The [`Border`] of the [`Timeline`].
+    pub border: Border,
+    /// The [`Background`] of the ruler.
+    pub ruler_background: Background,
+    /// The [`Color`] of the labels of the ruler.
+    pub ruler_text_color: Color,
+    /// The [`Color`] of the ticks of the ruler.
+    pub tick_color: Color,
+    /// The [`Background`] of the even tracks.
+    pub track_background: Background,
+    /// The [`Background`] of the odd tracks.
+    pub track_background_alt: Background,
+    /// The [`Background`] of a clip.
+    pub clip_background: Background,
+    /// The [`Border`] of a clip.
+    pub clip_border: Border,
+    /// The [`Color`] of the label of a clip.
+    pub clip_text_color: Color,
+    /// The [`Background`] of the selected clip.
+    pub selected_clip_background: Background,
+    /// The [`Border`] of the selected clip.
+    pub selected_clip_border: Border,
+    /// The [`Color`] of the playhead.
+    pub playhead_color: Color,
+}
+
+/// The theme catalog of a [`Timeline`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Timeline`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Timeline`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default(),
+        ruler_background: palette.background.weak.color.into(),
+        ruler_text_color: palette.background.weak.text,
+        tick_color: palette.background.strong.color,
+        track_background: palette.background.base.color.into(),
+        track_background_alt: palette
+            .background
+            .weak
+            .color
+            .scale_alpha(0.5)
+            .into(),
+        clip_background: palette.primary.weak.color.into(),
+        clip_border: border::rounded(4),
+        clip_text_color: palette.primary.weak.text,
+        selected_clip_background: palette.primary.strong.color.into(),
+        selected_clip_border: Border {
+            color: palette.primary.base.text,
+            width: 2.0,
+            radius: 4.0.into(),
+        },
+        playhead_color: palette.danger.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_steps() {
+        assert_eq!(tick_step(1.5), 2.0);
+        assert_eq!(tick_step(30.0), 50.0);
+        assert_eq!(tick_step(120.0), 200.0);
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(label(12.0, 5.0), "12");
+        assert_eq!(label(0.5, 0.5), "0.5");
+        assert_eq!(label(0.25, 0.05), "0.25");
+    }
+}
@@ -7,11 +7,14 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Border, Clipboard, Color, Element, Event, Layout, Length, Pixels,
     Rectangle, Shell, Size, Theme, Widget,
 };
+use crate::runtime::task::{self, Task};
+use crate::runtime::Action;
 
 /// A toggler widget.
 ///
@@ -38,16 +41,17 @@ pub struct Toggler<
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    id: Option<Id>,
     is_toggled: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: Option<String>,
     width: Length,
-    size: f32,
+    size: Option<f32>,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_alignment: alignment::Horizontal,
     text_shaping: text::Shaping,
-    spacing: f32,
+    spacing: Option<f32>,
     font: Option<Renderer::Font>,
     class: Theme::Class<'a>,
 }
@@ -77,24 +81,31 @@ where
         F: 'a + Fn(bool) -> Message,
     {
         Toggler {
+            id: None,
             is_toggled,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
-            size: Self::DEFAULT_SIZE,
+            size: None,
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_alignment: alignment::Horizontal::Left,
-            text_shaping: text::Shaping::Basic,
-            spacing: Self::DEFAULT_SIZE / 2.0,
+            text_shaping: text::Shaping::default(),
+            spacing: None,
             font: None,
             class: Theme::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Toggler`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the size of the [`Toggler`].
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
-        self.size = size.into().0;
+        self.size = Some(size.into().0);
         self
     }
 
@@ -133,7 +144,7 @@ where
 
     /// Sets the spacing between the [`Toggler`] and the text.
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
-        self.spacing = spacing.into().0;
+        self.spacing = Some(spacing.into().0);
         self
     }
 
@@ -171,11 +182,11 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::new(self.is_toggled))
     }
 
     fn size(&self) -> Size<Length> {
@@ -192,19 +203,25 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits.width(self.width);
-
-        layout::next_to_each_other(
+        let density = renderer.density();
+        let size = self
+            .size
+            .unwrap_or_else(|| Self::DEFAULT_SIZE * density.scale());
+        let spacing = self
+            .spacing
+            .unwrap_or_else(|| Self::DEFAULT_SIZE * density.scale() / 2.0);
+
+        let node = layout::next_to_each_other(
             &limits,
-            self.spacing,
-            |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
+            spacing,
+            |_| layout::Node::new(Size::new(2.0 * size, size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -216,17 +233,33 @@ where
                         self.text_alignment,
                         alignment::Vertical::Top,
                         self.text_shaping,
+                        text::Justification::None,
                     )
                 } else {
                     layout::Node::new(Size::ZERO)
                 }
             },
-        )
+        );
+
+        density.expand(&limits, node)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.is_toggled = self.is_toggled;
+
+        operation.toggleable(state, self.id.as_ref().map(|id| &id.0));
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -235,6 +268,14 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Some(is_toggled) = state.requested.take() {
+            if is_toggled != self.is_toggled {
+                shell.publish((self.on_toggle)(is_toggled));
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -289,14 +330,13 @@ where
 
         if self.label.is_some() {
             let label_layout = children.next().unwrap();
-            let state: &widget::text::State<Renderer::Paragraph> =
-                tree.state.downcast_ref();
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
             crate::text::draw(
                 renderer,
                 style,
                 label_layout,
-                state.0.raw(),
+                state.label.0.raw(),
                 crate::text::Style::default(),
                 viewport,
             );
@@ -381,6 +421,73 @@ where
     }
 }
 
+/// The identifier of a [`Toggler`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Task`] that toggles the [`Toggler`] with the given [`Id`],
+/// as if it was clicked.
+pub fn toggle<T>(id: Id) -> Task<T> {
+    task::effect(Action::widget(operation::toggleable::toggle(id.0)))
+}
+
+/// Produces a [`Task`] that turns the [`Toggler`] with the given [`Id`] on
+/// or off.
+///
+/// The [`Toggler`] only produces a message if it changes.
+pub fn set_toggled<T>(id: Id, is_toggled: bool) -> Task<T> {
+    task::effect(Action::widget(operation::toggleable::set_toggled(
+        id.0, is_toggled,
+    )))
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    is_toggled: bool,
+    requested: Option<bool>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new(is_toggled: bool) -> Self {
+        Self {
+            label: widget::text::State::default(),
+            is_toggled,
+            requested: None,
+        }
+    }
+}
+
+impl<P: text::Paragraph> operation::Toggleable for State<P> {
+    fn is_toggled(&self) -> bool {
+        self.requested.unwrap_or(self.is_toggled)
+    }
+
+    fn set_toggled(&mut self, is_toggled: bool) {
+        self.requested = Some(is_toggled);
+    }
+}
+
 /// The possible status of a [`Toggler`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -3,13 +3,13 @@ use crate::container;
 use crate::core::event::{self, Event};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
-use crate::core::overlay;
+use crate::core::overlay::{self, placement::Side, Placement};
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::widget::{self, Widget};
 use crate::core::{
-    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
-    Vector,
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector,
 };
 
 /// An element to display a widget over another.
@@ -72,6 +72,9 @@ where
     }
 
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
+    ///
+    /// A snapped [`Tooltip`] flips to the opposite side of its content when
+    /// there is not enough room, and shifts along it to stay visible.
     pub fn snap_within_viewport(mut self, snap: bool) -> Self {
         self.snap_within_viewport = snap;
         self
@@ -349,79 +352,44 @@ where
         );
 
         let text_bounds = tooltip_layout.bounds();
-        let x_center = self.position.x
-            + (self.content_bounds.width - text_bounds.width) / 2.0;
-        let y_center = self.position.y
-            + (self.content_bounds.height - text_bounds.height) / 2.0;
-
-        let mut tooltip_bounds = {
-            let offset = match self.positioning {
-                Position::Top => Vector::new(
-                    x_center,
-                    self.position.y
-                        - text_bounds.height
-                        - self.gap
-                        - self.padding,
-                ),
-                Position::Bottom => Vector::new(
-                    x_center,
-                    self.position.y
-                        + self.content_bounds.height
-                        + self.gap
-                        + self.padding,
-                ),
-                Position::Left => Vector::new(
-                    self.position.x
-                        - text_bounds.width
-                        - self.gap
-                        - self.padding,
-                    y_center,
-                ),
-                Position::Right => Vector::new(
-                    self.position.x
-                        + self.content_bounds.width
-                        + self.gap
-                        + self.padding,
-                    y_center,
-                ),
-                Position::FollowCursor => {
-                    let translation =
-                        self.position - self.content_bounds.position();
-
-                    Vector::new(
-                        self.cursor_position.x,
-                        self.cursor_position.y - text_bounds.height,
-                    ) + translation
-                }
-            };
-
-            Rectangle {
-                x: offset.x - self.padding,
-                y: offset.y - self.padding,
-                width: text_bounds.width + self.padding * 2.0,
-                height: text_bounds.height + self.padding * 2.0,
-            }
-        };
+        let size = Size::new(
+            text_bounds.width + self.padding * 2.0,
+            text_bounds.height + self.padding * 2.0,
+        );
 
-        if self.snap_within_viewport {
-            if tooltip_bounds.x < viewport.x {
-                tooltip_bounds.x = viewport.x;
-            } else if viewport.x + viewport.width
-                < tooltip_bounds.x + tooltip_bounds.width
-            {
-                tooltip_bounds.x =
-                    viewport.x + viewport.width - tooltip_bounds.width;
-            }
+        let anchor = Rectangle::new(self.position, self.content_bounds.size());
 
-            if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
-            } else if viewport.y + viewport.height
-                < tooltip_bounds.y + tooltip_bounds.height
-            {
-                tooltip_bounds.y =
-                    viewport.y + viewport.height - tooltip_bounds.height;
+        let (anchor, placement) = match self.positioning {
+            Position::Top => (anchor, Placement::new(Side::Top).gap(self.gap)),
+            Position::Bottom => {
+                (anchor, Placement::new(Side::Bottom).gap(self.gap))
             }
-        }
+            Position::Left => {
+                (anchor, Placement::new(Side::Left).gap(self.gap))
+            }
+            Position::Right => {
+                (anchor, Placement::new(Side::Right).gap(self.gap))
+            }
+            Position::FollowCursor => {
+                let translation =
+                    self.position - self.content_bounds.position();
+
+                let cursor = Point::new(
+                    self.cursor_position.x - self.padding,
+                    self.cursor_position.y + self.padding,
+                ) + translation;
+
+                (
+                    Rectangle::new(cursor, Size::ZERO),
+                    Placement::new(Side::Top).align(Alignment::Start),
+                )
+            }
+        };
+
+        let tooltip_bounds = placement
+            .flip(self.snap_within_viewport)
+            .shift(self.snap_within_viewport)
+            .resolve(anchor, size, viewport);
 
         layout::Node::with_children(
             tooltip_bounds.size(),
@@ -52,20 +52,25 @@ where
     default: Option<T>,
     on_change: Box<dyn Fn(T) -> Message + 'a>,
     on_release: Option<Message>,
-    width: f32,
+    width: Option<f32>,
     height: Length,
     class: Theme::Class<'a>,
 }
 
 impl<'a, T, Message, Theme> VerticalSlider<'a, T, Message, Theme>
 where
-    T: Copy + From<u8> + std::cmp::PartialOrd,
-    Message: Clone,
     Theme: Catalog,
 {
     /// The default width of a [`VerticalSlider`].
     pub const DEFAULT_WIDTH: f32 = 16.0;
+}
 
+impl<'a, T, Message, Theme> VerticalSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Theme: Catalog,
+{
     /// Creates a new [`VerticalSlider`].
     ///
     /// It expects:
@@ -98,7 +103,7 @@ where
             shift_step: None,
             on_change: Box::new(on_change),
             on_release: None,
-            width: Self::DEFAULT_WIDTH,
+            width: None,
             height: Length::Fill,
             class: Theme::default(),
         }
@@ -125,7 +130,7 @@ where
 
     /// Sets the width of the [`VerticalSlider`].
     pub fn width(mut self, width: impl Into<Pixels>) -> Self {
-        self.width = width.into().0;
+        self.width = Some(width.into().0);
         self
     }
 
@@ -194,10 +199,15 @@ where
     fn layout(
         &self,
         _tree: &mut Tree,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::atomic(limits, self.width, self.height)
+        let density = renderer.density();
+        let width = self
+            .width
+            .unwrap_or_else(|| Self::DEFAULT_WIDTH * density.scale());
+
+        density.expand(limits, layout::atomic(limits, width, self.height))
     }
 
     fn on_event(
@@ -411,12 +421,12 @@ where
                     x: rail_x - style.rail.width / 2.0,
                     y: bounds.y,
                     width: style.rail.width,
-                    height: offset + handle_width / 2.0,
+                    height: bounds.height,
                 },
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.1,
+            style.rail.inactive,
         );
 
         renderer.fill_quad(
@@ -430,7 +440,7 @@ where
                 border: border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.0,
+            style.rail.active,
         );
 
         renderer.fill_quad(
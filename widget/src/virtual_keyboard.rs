@@ -0,0 +1,788 @@
+//! Type into focused widgets without a physical keyboard.
+//!
+//! A [`VirtualKeyboard`] wraps some content and displays an on-screen
+//! keyboard below it whenever a widget inside the content is focused.
+//! Pressing its keys produces keyboard events for the focused widget,
+//! which makes it suitable for kiosks and touch-only deployments.
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, SmolStr, Theme, Vector, Widget,
+};
+
+/// An on-screen keyboard displayed below some content.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::{column, text_input, virtual_keyboard};
+///
+/// struct State {
+///    name: String,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NameChanged(String),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     virtual_keyboard(
+///         column![text_input("Your name", &state.name)
+///             .on_input(Message::NameChanged)],
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualKeyboard<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    keymap: Keymap,
+    is_always_visible: bool,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    key_height: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of a key of a [`VirtualKeyboard`].
+    pub const DEFAULT_KEY_HEIGHT: f32 = 48.0;
+
+    /// Creates a new [`VirtualKeyboard`] for the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            keymap: Keymap::default(),
+            is_always_visible: false,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(8.0),
+            spacing: 6.0,
+            key_height: Self::DEFAULT_KEY_HEIGHT,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Keymap`] of the [`VirtualKeyboard`].
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets whether the [`VirtualKeyboard`] should be displayed even when
+    /// no widget of its content is focused.
+    pub fn always_visible(mut self, is_always_visible: bool) -> Self {
+        self.is_always_visible = is_always_visible;
+        self
+    }
+
+    /// Sets the width of the [`VirtualKeyboard`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualKeyboard`], including its content.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] around the keys of the [`VirtualKeyboard`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the keys of the [`VirtualKeyboard`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the height of each key of the [`VirtualKeyboard`].
+    pub fn key_height(mut self, key_height: impl Into<Pixels>) -> Self {
+        self.key_height = key_height.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels of the [`VirtualKeyboard`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the labels of the [`VirtualKeyboard`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`VirtualKeyboard`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`VirtualKeyboard`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn keyboard_height(&self) -> f32 {
+        let rows = ROWS as f32;
+
+        rows * self.key_height
+            + (rows - 1.0) * self.spacing
+            + self.padding.vertical()
+    }
+
+    fn keys(&self, state: &State, bounds: Rectangle) -> Vec<(Key, Rectangle)> {
+        let bounds = bounds.shrink(self.padding);
+        let mut keys = Vec::new();
+
+        for (i, row) in rows(self.keymap, state.page).iter().enumerate() {
+            let units: f32 = row.iter().map(|(_, units)| units).sum();
+            let available =
+                bounds.width - self.spacing * (row.len() as f32 - 1.0);
+
+            let y = bounds.y + (self.key_height + self.spacing) * i as f32;
+            let mut x = bounds.x;
+
+            for (key, key_units) in row {
+                let width = available * key_units / units;
+
+                keys.push((
+                    *key,
+                    Rectangle {
+                        x,
+                        y,
+                        width,
+                        height: self.key_height,
+                    },
+                ));
+
+                x += width + self.spacing;
+            }
+        }
+
+        keys
+    }
+}
+
+/// The arrangement of the keys of a [`VirtualKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Keymap {
+    /// A QWERTY layout, with an additional page for digits and symbols.
+    #[default]
+    Qwerty,
+    /// A numeric keypad.
+    Numeric,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Character(char),
+    Space,
+    Backspace,
+    Enter,
+    Shift,
+    Switch,
+}
+
+impl Key {
+    fn label(self, state: &State) -> String {
+        match self {
+            Key::Character(c) if state.is_shifted => c.to_uppercase().collect(),
+            Key::Character(c) => c.to_string(),
+            Key::Space => String::new(),
+            Key::Backspace => String::from("Del"),
+            Key::Enter => String::from("Enter"),
+            Key::Shift => String::from("Shift"),
+            Key::Switch => String::from(match state.page {
+                Page::Letters => "?123",
+                Page::Symbols => "ABC",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Page {
+    #[default]
+    Letters,
+    Symbols,
+}
+
+const ROWS: usize = 4;
+
+fn rows(keymap: Keymap, page: Page) -> [Vec<(Key, f32)>; ROWS] {
+    fn characters(characters: &str) -> Vec<(Key, f32)> {
+        characters
+            .chars()
+            .map(|c| (Key::Character(c), 1.0))
+            .collect()
+    }
+
+    fn with(
+        start: impl IntoIterator<Item = (Key, f32)>,
+        middle: &str,
+        end: impl IntoIterator<Item = (Key, f32)>,
+    ) -> Vec<(Key, f32)> {
+        start
+            .into_iter()
+            .chain(characters(middle))
+            .chain(end)
+            .collect()
+    }
+
+    let bottom = vec![
+        (Key::Switch, 1.5),
+        (Key::Character(','), 1.0),
+        (Key::Space, 5.0),
+        (Key::Character('.'), 1.0),
+        (Key::Enter, 1.5),
+    ];
+
+    match (keymap, page) {
+        (Keymap::Qwerty, Page::Letters) => [
+            characters("qwertyuiop"),
+            characters("asdfghjkl"),
+            with([(Key::Shift, 1.5)], "zxcvbnm", [(Key::Backspace, 1.5)]),
+            bottom,
+        ],
+        (Keymap::Qwerty, Page::Symbols) => [
+            characters("1234567890"),
+            characters("@#$%&-+()"),
+            with([], "*\"':;!?/", [(Key::Backspace, 1.5)]),
+            bottom,
+        ],
+        (Keymap::Numeric, _) => [
+            characters("123-"),
+            with([], "456", [(Key::Backspace, 1.0)]),
+            with([], "789", [(Key::Enter, 1.0)]),
+            with([], ",0.", [(Key::Space, 1.0)]),
+        ],
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_visible: bool,
+    is_shifted: bool,
+    page: Page,
+    pressed: Option<Key>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualKeyboard<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_visible: self.is_always_visible,
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+
+        let keyboard_height = if state.is_visible {
+            self.keyboard_height()
+        } else {
+            0.0
+        };
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(0.0, keyboard_height)),
+        );
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                content.size().width,
+                content.size().height + keyboard_height,
+            ),
+        );
+
+        let keyboard =
+            layout::Node::new(Size::new(size.width, keyboard_height))
+                .move_to(Point::new(0.0, size.height - keyboard_height));
+
+        layout::Node::with_children(size, vec![content, keyboard])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_layout = children.next().unwrap();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.is_visible
+                    && cursor.is_over(keyboard_layout.bounds()) =>
+            {
+                let key = cursor.position().and_then(|position| {
+                    self.keys(state, keyboard_layout.bounds())
+                        .into_iter()
+                        .find(|(_, bounds)| bounds.contains(position))
+                        .map(|(key, _)| key)
+                });
+
+                state.pressed = key;
+
+                if let Some(key) = key {
+                    let key_event = match key {
+                        Key::Character(c) => {
+                            let text: SmolStr = if state.is_shifted {
+                                c.to_uppercase().collect::<String>().into()
+                            } else {
+                                SmolStr::new(c.to_string())
+                            };
+
+                            state.is_shifted = false;
+
+                            Some((
+                                keyboard::Key::Character(text.clone()),
+                                Some(text),
+                            ))
+                        }
+                        Key::Space => Some((
+                            keyboard::Key::Named(keyboard::key::Named::Space),
+                            Some(SmolStr::new(" ")),
+                        )),
+                        Key::Backspace => Some((
+                            keyboard::Key::Named(
+                                keyboard::key::Named::Backspace,
+                            ),
+                            None,
+                        )),
+                        Key::Enter => Some((
+                            keyboard::Key::Named(keyboard::key::Named::Enter),
+                            None,
+                        )),
+                        Key::Shift => {
+                            state.is_shifted = !state.is_shifted;
+
+                            None
+                        }
+                        Key::Switch => {
+                            state.page = match state.page {
+                                Page::Letters => Page::Symbols,
+                                Page::Symbols => Page::Letters,
+                            };
+                            state.is_shifted = false;
+
+                            None
+                        }
+                    };
+
+                    if let Some((key, text)) = key_event {
+                        for event in [
+                            keyboard::Event::KeyPressed {
+                                key: key.clone(),
+                                location: keyboard::Location::Standard,
+                                modifiers: keyboard::Modifiers::default(),
+                                text,
+                            },
+                            keyboard::Event::KeyReleased {
+                                key,
+                                location: keyboard::Location::Standard,
+                                modifiers: keyboard::Modifiers::default(),
+                            },
+                        ] {
+                            let _ = self.content.as_widget_mut().on_event(
+                                &mut tree.children[0],
+                                Event::Keyboard(event),
+                                content_layout,
+                                mouse::Cursor::Unavailable,
+                                renderer,
+                                clipboard,
+                                shell,
+                                viewport,
+                            );
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.pressed.is_some() =>
+            {
+                state.pressed = None;
+
+                event::Status::Captured
+            }
+            _ => self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+        };
+
+        let is_visible = self.is_always_visible || {
+            let mut is_any_focused = operation::focusable::is_any_focused();
+
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                &mut operation::black_box(&mut is_any_focused),
+            );
+
+            matches!(is_any_focused.finish(), operation::Outcome::Some(true))
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_visible != is_visible {
+            state.is_visible = is_visible;
+            state.pressed = None;
+
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_layout = children.next().unwrap();
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_visible && cursor.is_over(keyboard_layout.bounds()) {
+            let is_over_key = cursor.position().is_some_and(|position| {
+                self.keys(state, keyboard_layout.bounds())
+                    .iter()
+                    .any(|(_, bounds)| bounds.contains(position))
+            });
+
+            return if is_over_key {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+        let keyboard_layout = children.next().unwrap();
+
+        let state = tree.state.downcast_ref::<State>();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        if !state.is_visible {
+            return;
+        }
+
+        let bounds = keyboard_layout.bounds();
+        let style = theme.style(&self.class, Status::Active);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (key, key_bounds) in self.keys(state, bounds) {
+            let is_active = match key {
+                Key::Shift => state.is_shifted,
+                _ => false,
+            };
+
+            let status = if state.pressed == Some(key) || is_active {
+                Status::Pressed
+            } else if cursor.is_over(key_bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = theme.style(&self.class, status);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: key_bounds,
+                    border: style.key_border,
+                    ..renderer::Quad::default()
+                },
+                style.key_background,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: key.label(state),
+                    bounds: key_bounds.size(),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    justification: text::Justification::None,
+                },
+                key_bounds.center(),
+                style.key_text_color,
+                key_bounds,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<VirtualKeyboard<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        virtual_keyboard: VirtualKeyboard<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(virtual_keyboard)
+    }
+}
+
+/// The possible status of a key of a [`VirtualKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The key can be pressed.
+    Active,
+    /// The key is being hovered.
+    Hovered,
+    /// The key is being pressed, or is a toggled modifier.
+    Pressed,
+}
+
+/// The appearance of a [`VirtualKeyboard`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the keyboard.
+    pub background: Background,
+    /// The [`Border`] of the keyboard.
+    pub border: Border,
+    /// The [`Background`] of a key.
+    pub key_background: Background,
+    /// The [`Border`] of a key.
+    pub key_border: Border,
+    /// The text [`Color`] of a key.
+    pub key_text_color: Color,
+}
+
+/// The theme catalog of a [`VirtualKeyboard`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`VirtualKeyboard`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`VirtualKeyboard`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let key_background = match status {
+        Status::Active => palette.background.base.color,
+        Status::Hovered => palette.background.strong.color,
+        Status::Pressed => palette.primary.weak.color,
+    };
+
+    let key_text_color = match status {
+        Status::Pressed => palette.primary.weak.text,
+        Status::Active | Status::Hovered => palette.background.base.text,
+    };
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default(),
+        key_background: key_background.into(),
+        key_border: border::rounded(4),
+        key_text_color,
+    }
+}
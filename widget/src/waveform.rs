@@ -0,0 +1,616 @@
+//! Display audio samples as a waveform.
+//!
+//! A [`Waveform`] draws the peaks of a buffer of samples, decimated to a
+//! minimum and maximum per pixel column; so even hours of audio are drawn
+//! with a single quad per column.
+//!
+//! The samples can be borrowed from a slice or read from a [`Ring`]—a
+//! shared buffer that an audio thread can keep pushing samples into while
+//! the user interface draws the latest ones.
+use crate::core::border::{self, Border};
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Rectangle, Shell,
+    Size, Theme, Widget,
+};
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+/// A widget that displays audio samples as a waveform.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::waveform;
+///
+/// struct State {
+///    samples: Vec<f32>,
+///    position: usize,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Seek(usize),
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     waveform(&state.samples)
+///         .playhead(state.position)
+///         .on_seek(Message::Seek)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Seek(position) => {
+///             state.position = position;
+///         }
+///     }
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Waveform<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    samples: Samples<'a>,
+    playhead: Option<usize>,
+    gain: f32,
+    on_seek: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Waveform<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`Waveform`].
+    pub const DEFAULT_HEIGHT: f32 = 80.0;
+
+    /// Creates a new [`Waveform`] displaying the given [`Samples`].
+    pub fn new(samples: impl Into<Samples<'a>>) -> Self {
+        Self {
+            samples: samples.into(),
+            playhead: None,
+            gain: 1.0,
+            on_seek: None,
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the position of the playhead of the [`Waveform`], as the index
+    /// of a sample.
+    pub fn playhead(mut self, position: usize) -> Self {
+        self.playhead = Some(position);
+        self
+    }
+
+    /// Sets the gain applied to the samples of the [`Waveform`].
+    ///
+    /// The samples are expected to range from `-1.0` to `1.0`; anything
+    /// beyond is clipped.
+    pub fn gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Sets the message that should be produced when the user clicks or
+    /// drags on the [`Waveform`], given the index of the sample under the
+    /// cursor.
+    pub fn on_seek(mut self, on_seek: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_seek = Some(Box::new(on_seek));
+        self
+    }
+
+    /// Sets the width of the [`Waveform`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Waveform`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Waveform`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Waveform`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn sample_at(&self, bounds: Rectangle, x: f32) -> usize {
+        let len = self.samples.len();
+        let ratio = ((x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+
+        ((ratio * len as f32) as usize).min(len.saturating_sub(1))
+    }
+}
+
+/// The samples displayed by a [`Waveform`] or a [`Spectrum`].
+///
+/// [`Spectrum`]: crate::Spectrum
+#[derive(Debug, Clone, Copy)]
+pub enum Samples<'a> {
+    /// Samples borrowed from a slice.
+    Slice(&'a [f32]),
+    /// Samples read from a [`Ring`].
+    Ring(&'a Ring),
+}
+
+impl Samples<'_> {
+    /// Returns the amount of samples.
+    pub fn len(&self) -> usize {
+        match self {
+            Samples::Slice(samples) => samples.len(),
+            Samples::Ring(ring) => ring.len(),
+        }
+    }
+
+    /// Returns true if there are no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls the given closure with the samples, in order, split in two
+    /// contiguous slices.
+    pub(crate) fn with<T>(&self, f: impl FnOnce(&[f32], &[f32]) -> T) -> T {
+        match self {
+            Samples::Slice(samples) => f(samples, &[]),
+            Samples::Ring(ring) => {
+                let samples = ring.samples.lock().expect("Lock ring buffer");
+                let (first, second) = samples.as_slices();
+
+                f(first, second)
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a [f32]> for Samples<'a> {
+    fn from(samples: &'a [f32]) -> Self {
+        Samples::Slice(samples)
+    }
+}
+
+impl<'a> From<&'a Vec<f32>> for Samples<'a> {
+    fn from(samples: &'a Vec<f32>) -> Self {
+        Samples::Slice(samples)
+    }
+}
+
+impl<'a> From<&'a Ring> for Samples<'a> {
+    fn from(ring: &'a Ring) -> Self {
+        Samples::Ring(ring)
+    }
+}
+
+/// A shared buffer of samples with a fixed capacity.
+///
+/// Pushing samples into a full [`Ring`] drops the oldest ones. Cloning a
+/// [`Ring`] produces a new handle to the same buffer, which can be sent to
+/// another thread.
+#[derive(Debug, Clone)]
+pub struct Ring {
+    samples: Arc<Mutex<VecDeque<f32>>>,
+    capacity: usize,
+}
+
+impl Ring {
+    /// Creates a new empty [`Ring`] holding up to the given amount of
+    /// samples.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Pushes new samples into the [`Ring`], dropping the oldest ones if it
+    /// is full.
+    pub fn push(&self, new: &[f32]) {
+        let mut samples = self.samples.lock().expect("Lock ring buffer");
+
+        let new = &new[new.len().saturating_sub(self.capacity)..];
+        let overflow =
+            (samples.len() + new.len()).saturating_sub(self.capacity);
+
+        let _ = samples.drain(..overflow);
+        samples.extend(new);
+    }
+
+    /// Removes all the samples of the [`Ring`].
+    pub fn clear(&self) {
+        self.samples.lock().expect("Lock ring buffer").clear();
+    }
+
+    /// Returns the amount of samples in the [`Ring`].
+    pub fn len(&self) -> usize {
+        self.samples.lock().expect("Lock ring buffer").len()
+    }
+
+    /// Returns true if the [`Ring`] has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum amount of samples of the [`Ring`].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Splits the given amount of samples into ranges, one for each column.
+///
+/// If there are less samples than columns, consecutive columns share the
+/// same sample.
+pub(crate) fn columns(
+    len: usize,
+    columns: usize,
+) -> impl Iterator<Item = Range<usize>> {
+    (0..columns).filter(move |_| len > 0).map(move |column| {
+        let start = (column * len / columns).min(len - 1);
+        let end = ((column + 1) * len / columns).clamp(start + 1, len);
+
+        start..end
+    })
+}
+
+/// Returns the minimum and maximum of a range of samples split in two
+/// contiguous slices.
+pub(crate) fn peak(
+    first: &[f32],
+    second: &[f32],
+    range: Range<usize>,
+) -> (f32, f32) {
+    let split = first.len();
+
+    let first = &first[range.start.min(split)..range.end.min(split)];
+    let second = &second[range.start.saturating_sub(split)
+        ..range.end.saturating_sub(split).min(second.len())];
+
+    first
+        .iter()
+        .chain(second)
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &sample| {
+            (min.min(sample), max.max(sample))
+        })
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_seeking: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Waveform<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_seek) = &self.on_seek else {
+            return event::Status::Ignored;
+        };
+
+        if self.samples.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.is_seeking = true;
+                shell.publish(on_seek(self.sample_at(bounds, position.x)));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if !state.is_seeking {
+                    return event::Status::Ignored;
+                }
+
+                shell.publish(on_seek(self.sample_at(bounds, position.x)));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.is_seeking {
+                    state.is_seeking = false;
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let center = bounds.center_y();
+        let amplitude = bounds.height / 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: center - 0.5,
+                    width: bounds.width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            style.center_line,
+        );
+
+        let len = self.samples.with(|first, second| {
+            let len = first.len() + second.len();
+            let columns = columns(len, bounds.width.max(1.0) as usize);
+
+            for (column, range) in columns.enumerate() {
+                let (min, max) = peak(first, second, range);
+
+                let top =
+                    center - (max * self.gain).clamp(-1.0, 1.0) * amplitude;
+                let bottom =
+                    center - (min * self.gain).clamp(-1.0, 1.0) * amplitude;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + column as f32,
+                            y: top,
+                            width: 1.0,
+                            height: (bottom - top).max(1.0),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.wave,
+                );
+            }
+
+            len
+        });
+
+        if let Some(playhead) = self.playhead.filter(|_| len > 0) {
+            let x = bounds.x + bounds.width * playhead as f32 / len as f32;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x.min(bounds.x + bounds.width - 1.0) - 0.5,
+                        y: bounds.y,
+                        width: 2.0,
+                        height: bounds.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.playhead,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.on_seek.is_none() {
+            mouse::Interaction::default()
+        } else if state.is_seeking {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Waveform<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(waveform: Waveform<'a, Message, Theme>) -> Self {
+        Element::new(waveform)
+    }
+}
+
+/// The appearance of a [`Waveform`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Waveform`].
+    pub background: Background,
+    /// The [`Border`] of the [`Waveform`].
+    pub border: Border,
+    /// The [`Color`] of the peaks of the samples.
+    pub wave: Color,
+    /// The [`Color`] of the line marking the silence.
+    pub center_line: Color,
+    /// The [`Color`] of the playhead.
+    pub playhead: Color,
+}
+
+/// The theme catalog of a [`Waveform`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Waveform`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Waveform`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: border::rounded(2),
+        wave: palette.primary.base.color,
+        center_line: palette.background.strong.color,
+        playhead: palette.danger.base.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_cover_every_sample() {
+        let ranges: Vec<_> = columns(10, 4).collect();
+
+        assert_eq!(ranges, [0..2, 2..5, 5..7, 7..10]);
+        assert_eq!(columns(2, 4).collect::<Vec<_>>(), [0..1, 0..1, 1..2, 1..2]);
+        assert_eq!(columns(0, 4).count(), 0);
+    }
+
+    #[test]
+    fn peak_across_slices() {
+        let first = [0.1, -0.5, 0.2];
+        let second = [0.9, -0.1];
+
+        assert_eq!(peak(&first, &second, 0..2), (-0.5, 0.1));
+        assert_eq!(peak(&first, &second, 2..5), (-0.1, 0.9));
+        assert_eq!(peak(&first, &second, 3..4), (0.9, 0.9));
+    }
+
+    #[test]
+    fn ring_drops_oldest_samples() {
+        let ring = Ring::new(4);
+
+        ring.push(&[1.0, 2.0, 3.0]);
+        ring.push(&[4.0, 5.0]);
+
+        let samples =
+            Samples::from(&ring).with(|first, second| [first, second].concat());
+
+        assert_eq!(samples, [2.0, 3.0, 4.0, 5.0]);
+
+        ring.push(&[6.0, 7.0, 8.0, 9.0, 10.0]);
+
+        assert_eq!(ring.len(), 4);
+    }
+}
@@ -4,9 +4,10 @@
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.12/runtime
 use crate::core::keyboard;
 use crate::core::mouse;
+use crate::core::pen;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Point, Radians, Size};
 
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
@@ -32,6 +33,7 @@ pub fn window_attributes(
         })
         .with_decorations(settings.decorations)
         .with_transparent(settings.transparent)
+        .with_blur(settings.blur)
         .with_window_icon(settings.icon.and_then(icon))
         .with_window_level(window_level(settings.level))
         .with_visible(settings.visible);
@@ -79,6 +81,16 @@ pub fn window_attributes(
 
         attributes = attributes
             .with_skip_taskbar(settings.platform_specific.skip_taskbar);
+
+        if let Some(shadow) = settings.shadow {
+            attributes = attributes.with_undecorated_shadow(shadow);
+        }
+
+        attributes = attributes
+            .with_system_backdrop(backdrop(settings.platform_specific.backdrop))
+            .with_corner_preference(corner_preference(
+                settings.platform_specific.corner_preference,
+            ));
     }
 
     #[cfg(target_os = "macos")]
@@ -93,6 +105,10 @@ pub fn window_attributes(
             .with_fullsize_content_view(
                 settings.platform_specific.fullsize_content_view,
             );
+
+        if let Some(shadow) = settings.shadow {
+            attributes = attributes.with_has_shadow(shadow);
+        }
     }
 
     #[cfg(target_os = "linux")]
@@ -120,6 +136,50 @@ pub fn window_attributes(
     attributes
 }
 
+/// Converts some Windows [`Backdrop`] into its `winit` counterpart.
+///
+/// [`Backdrop`]: window::settings::Backdrop
+#[cfg(target_os = "windows")]
+pub fn backdrop(
+    backdrop: window::settings::Backdrop,
+) -> winit::platform::windows::BackdropType {
+    use window::settings::Backdrop;
+    use winit::platform::windows::BackdropType;
+
+    match backdrop {
+        Backdrop::Auto => BackdropType::Auto,
+        Backdrop::None => BackdropType::None,
+        Backdrop::Mica => BackdropType::MainWindow,
+        Backdrop::Acrylic => BackdropType::TransientWindow,
+        Backdrop::Tabbed => BackdropType::TabbedWindow,
+    }
+}
+
+/// Converts some Windows [`CornerPreference`] into its `winit` counterpart.
+///
+/// [`CornerPreference`]: window::settings::CornerPreference
+#[cfg(target_os = "windows")]
+pub fn corner_preference(
+    preference: window::settings::CornerPreference,
+) -> winit::platform::windows::CornerPreference {
+    use window::settings::CornerPreference;
+
+    match preference {
+        CornerPreference::Default => {
+            winit::platform::windows::CornerPreference::Default
+        }
+        CornerPreference::DoNotRound => {
+            winit::platform::windows::CornerPreference::DoNotRound
+        }
+        CornerPreference::Round => {
+            winit::platform::windows::CornerPreference::Round
+        }
+        CornerPreference::RoundSmall => {
+            winit::platform::windows::CornerPreference::RoundSmall
+        }
+    }
+}
+
 /// Converts a winit window event into an iced event.
 pub fn window_event(
     event: winit::event::WindowEvent,
@@ -502,6 +562,49 @@ pub fn touch_event(
     }
 }
 
+/// Converts a `Touch` from [`winit`] to an [`iced`] pen event, if it was
+/// produced by a pen.
+///
+/// [`winit`] does not expose tablets yet; so only pens that report their
+/// altitude through the touch force—like the Apple Pencil—are recognized.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.12
+pub fn pen_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> Option<pen::Event> {
+    let force = touch.force?;
+
+    let winit::event::Force::Calibrated {
+        altitude_angle: Some(altitude),
+        ..
+    } = force
+    else {
+        return None;
+    };
+
+    let id = pen::Pen(touch.id);
+    let sample = pen::Sample {
+        position: {
+            let location = touch.location.to_logical::<f64>(scale_factor);
+
+            Point::new(location.x as f32, location.y as f32)
+        },
+        pressure: force.normalized() as f32,
+        altitude: Some(Radians(altitude as f32)),
+        azimuth: None,
+        is_eraser: false,
+    };
+
+    Some(match touch.phase {
+        winit::event::TouchPhase::Started => pen::Event::Pressed { id, sample },
+        winit::event::TouchPhase::Moved => pen::Event::Moved { id, sample },
+        winit::event::TouchPhase::Ended => pen::Event::Lifted { id, sample },
+        winit::event::TouchPhase::Cancelled => pen::Event::Lost { id, sample },
+    })
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -0,0 +1,232 @@
+//! Pick colors anywhere on the screen with the eyedropper of the platform.
+use crate::core::Color;
+use crate::futures::futures::channel::oneshot;
+
+/// The channel of a picked [`Color`].
+pub type Sender = oneshot::Sender<Option<Color>>;
+
+/// Starts picking a [`Color`] anywhere on the screen, sending it to the given
+/// [`Sender`] once picked.
+///
+/// The [`Sender`] is returned back if the platform has no eyedropper or if it
+/// is unavailable.
+pub fn pick(sender: Sender) -> Result<(), Sender> {
+    platform::pick(sender)
+}
+
+#[cfg(all(feature = "eyedropper", target_os = "linux"))]
+mod platform {
+    use super::Sender;
+    use crate::core::Color;
+
+    use std::collections::HashMap;
+    use std::sync::atomic::{self, AtomicUsize};
+    use std::thread;
+
+    use zbus::blocking::{Connection, Proxy, SignalIterator};
+    use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+
+    const DESTINATION: &str = "org.freedesktop.portal.Desktop";
+    const PATH: &str = "/org/freedesktop/portal/desktop";
+
+    /// The response of a portal request that succeeded.
+    const SUCCESS: u32 = 0;
+
+    pub fn pick(sender: Sender) -> Result<(), Sender> {
+        let mut responses = match request() {
+            Ok(responses) => responses,
+            Err(error) => {
+                log::warn!("The color picker portal is unavailable: {error}");
+
+                return Err(sender);
+            }
+        };
+
+        // The user may take a while to pick a color
+        let _ = thread::Builder::new()
+            .name("iced-eyedropper".to_owned())
+            .spawn(move || {
+                let color = responses.next().and_then(|message| {
+                    let (response, mut results): (
+                        u32,
+                        HashMap<String, OwnedValue>,
+                    ) = message.body().deserialize().ok()?;
+
+                    if response != SUCCESS {
+                        return None;
+                    }
+
+                    let (r, g, b) = <(f64, f64, f64)>::try_from(Value::from(
+                        results.remove("color")?,
+                    ))
+                    .ok()?;
+
+                    Some(Color::from_rgb(r as f32, g as f32, b as f32))
+                });
+
+                let _ = sender.send(color);
+            });
+
+        Ok(())
+    }
+
+    /// Requests a color to the screenshot portal, returning the responses of
+    /// the request.
+    fn request() -> zbus::Result<SignalIterator<'static>> {
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+        let connection = Connection::session()?;
+
+        let name = connection
+            .unique_name()
+            .ok_or_else(|| zbus::Error::Failure("no unique name".to_owned()))?
+            .as_str()
+            .trim_start_matches(':')
+            .replace('.', "_");
+
+        let token = format!(
+            "iced_{}_{}",
+            std::process::id(),
+            REQUESTS.fetch_add(1, atomic::Ordering::Relaxed)
+        );
+
+        // The response is listened for before making the request, since
+        // it could be emitted before the request returns
+        let request = Proxy::new(
+            &connection,
+            DESTINATION,
+            format!("{PATH}/request/{name}/{token}"),
+            "org.freedesktop.portal.Request",
+        )?;
+
+        let responses = request.receive_signal("Response")?;
+
+        let options = HashMap::from([("handle_token", Value::from(token))]);
+
+        let _handle: OwnedObjectPath = connection
+            .call_method(
+                Some(DESTINATION),
+                PATH,
+                Some("org.freedesktop.portal.Screenshot"),
+                "PickColor",
+                &("", options),
+            )?
+            .body()
+            .deserialize()?;
+
+        Ok(responses)
+    }
+}
+
+#[cfg(all(feature = "eyedropper", target_os = "macos"))]
+mod platform {
+    use super::Sender;
+    use crate::core::Color;
+
+    use block2::RcBlock;
+    use objc2_app_kit::{NSColor, NSColorSampler, NSColorSpace};
+
+    use std::cell::Cell;
+
+    #[allow(unsafe_code)]
+    pub fn pick(sender: Sender) -> Result<(), Sender> {
+        // SAFETY: Creating a sampler has no preconditions
+        let sampler = unsafe { NSColorSampler::new() };
+
+        // The sampler is kept alive until a color is picked
+        let state = Cell::new(Some((sender, sampler.clone())));
+
+        let handler = RcBlock::new(move |color: *mut NSColor| {
+            let Some((sender, _sampler)) = state.take() else {
+                return;
+            };
+
+            // SAFETY: The color is either null, if the user cancels, or a
+            // valid `NSColor`
+            let color = unsafe { color.as_ref() }.and_then(|color| unsafe {
+                let color = color
+                    .colorUsingColorSpace(&NSColorSpace::sRGBColorSpace())?;
+
+                Some(Color::new(
+                    color.redComponent() as f32,
+                    color.greenComponent() as f32,
+                    color.blueComponent() as f32,
+                    color.alphaComponent() as f32,
+                ))
+            });
+
+            let _ = sender.send(color);
+        });
+
+        // SAFETY: The handler is called once, in the main thread
+        unsafe {
+            sampler.showSamplerWithSelectionHandler(&handler);
+        }
+
+        Ok(())
+    }
+}
+
+// The color under the cursor is sampled right away, without waiting for the
+// user to click
+#[cfg(all(feature = "eyedropper", target_os = "windows"))]
+mod platform {
+    use super::Sender;
+    use crate::core::Color;
+
+    use std::ptr;
+
+    use winapi::shared::windef::POINT;
+    use winapi::um::wingdi::{
+        GetBValue, GetGValue, GetPixel, GetRValue, CLR_INVALID,
+    };
+    use winapi::um::winuser::{GetCursorPos, GetDC, ReleaseDC};
+
+    #[allow(unsafe_code)]
+    pub fn pick(sender: Sender) -> Result<(), Sender> {
+        let mut cursor = POINT { x: 0, y: 0 };
+
+        // SAFETY: The device context of the screen is released right after
+        // the pixel under the cursor is sampled
+        let pixel = unsafe {
+            if GetCursorPos(&mut cursor) == 0 {
+                return Err(sender);
+            }
+
+            let screen = GetDC(ptr::null_mut());
+
+            if screen.is_null() {
+                return Err(sender);
+            }
+
+            let pixel = GetPixel(screen, cursor.x, cursor.y);
+            let _ = ReleaseDC(ptr::null_mut(), screen);
+
+            pixel
+        };
+
+        if pixel == CLR_INVALID {
+            return Err(sender);
+        }
+
+        let _ = sender.send(Some(Color::from_rgb8(
+            GetRValue(pixel),
+            GetGValue(pixel),
+            GetBValue(pixel),
+        )));
+
+        Ok(())
+    }
+}
+
+#[cfg(not(all(
+    feature = "eyedropper",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+)))]
+mod platform {
+    use super::Sender;
+
+    pub fn pick(sender: Sender) -> Result<(), Sender> {
+        Err(sender)
+    }
+}
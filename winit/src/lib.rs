@@ -34,6 +34,9 @@ pub mod program;
 #[cfg(feature = "system")]
 pub mod system;
 
+#[cfg(feature = "program")]
+mod eyedropper;
+
 mod error;
 mod proxy;
 
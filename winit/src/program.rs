@@ -8,7 +8,8 @@ use crate::conversion;
 use crate::core;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::time::Instant;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
 use crate::core::window;
 use crate::core::{Color, Element, Point, Size, Theme};
@@ -20,6 +21,7 @@ use crate::futures::subscription::{self, Subscription};
 use crate::futures::{Executor, Runtime};
 use crate::graphics;
 use crate::graphics::{compositor, Compositor};
+use crate::runtime::replay::{Recorder, Recording, Replay};
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
 use crate::runtime::{self, Action, Task};
@@ -32,6 +34,9 @@ use std::borrow::Cow;
 use std::mem::ManuallyDrop;
 use std::sync::Arc;
 
+/// The interval between frames of animations while in low-power mode.
+const LOW_POWER_FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
 /// An interactive, native, cross-platform, multi-windowed application.
 ///
 /// This trait is the main entrypoint of multi-window Iced. Once implemented, you can run
@@ -193,6 +198,9 @@ where
 
     let (proxy, worker) = Proxy::new(event_loop.create_proxy());
 
+    #[cfg(feature = "debug")]
+    inspector(proxy.clone());
+
     let mut runtime = {
         let executor =
             P::Executor::new().map_err(Error::ExecutorCreationFailed)?;
@@ -504,7 +512,18 @@ where
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
 
+                                // The colors behind transparent windows are
+                                // unknown; so subpixel coverage cannot be
+                                // blended
+                                let text_antialiasing = if settings.transparent
+                                {
+                                    text::Antialiasing::Grayscale
+                                } else {
+                                    settings.text_antialiasing
+                                };
+
                                 let visible = settings.visible;
+                                let present_mode = settings.present_mode;
 
                                 #[cfg(target_arch = "wasm32")]
                                 let target =
@@ -576,6 +595,8 @@ where
                                         id,
                                         window,
                                         exit_on_close_request,
+                                        text_antialiasing,
+                                        present_mode,
                                         make_visible: visible,
                                         on_open,
                                     },
@@ -626,6 +647,8 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        text_antialiasing: text::Antialiasing,
+        present_mode: Option<window::PresentMode>,
         make_visible: bool,
         on_open: oneshot::Sender<window::Id>,
     },
@@ -669,6 +692,7 @@ async fn run_instance<P, C>(
 
     let mut window_manager = WindowManager::new();
     let mut is_window_opening = !is_daemon;
+    let mut is_low_power = false;
 
     let mut events = Vec::new();
     let mut messages = Vec::new();
@@ -677,6 +701,11 @@ async fn run_instance<P, C>(
     let mut ui_caches = FxHashMap::default();
     let mut user_interfaces = ManuallyDrop::new(FxHashMap::default());
 
+    let started_at = Instant::now();
+    let mut window_ids = Vec::new();
+    let mut recorder = recorder();
+    let mut replay = replay();
+
     debug.startup_finished();
 
     loop {
@@ -696,6 +725,8 @@ async fn run_instance<P, C>(
                 id,
                 window,
                 exit_on_close_request,
+                text_antialiasing,
+                present_mode,
                 make_visible,
                 on_open,
             } => {
@@ -705,6 +736,8 @@ async fn run_instance<P, C>(
                     &program,
                     &mut compositor,
                     exit_on_close_request,
+                    text_antialiasing,
+                    present_mode,
                 );
 
                 let logical_size = window.state.logical_size();
@@ -734,6 +767,8 @@ async fn run_instance<P, C>(
                     }),
                 ));
 
+                window_ids.push(id);
+
                 let _ = on_open.send(id);
                 is_window_opening = false;
             }
@@ -774,6 +809,7 @@ async fn run_instance<P, C>(
                             &mut window_manager,
                             &mut ui_caches,
                             &mut is_window_opening,
+                            &mut is_low_power,
                         );
                         actions += 1;
                     }
@@ -788,6 +824,8 @@ async fn run_instance<P, C>(
                             continue;
                         };
 
+                        let frame_started_at = Instant::now();
+
                         // TODO: Avoid redrawing all the time by forcing widgets to
                         // request redraws on state changes
                         //
@@ -843,6 +881,14 @@ async fn run_instance<P, C>(
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
                                 } => match redraw_request {
+                                    window::RedrawRequest::NextFrame
+                                        if is_low_power =>
+                                    {
+                                        ControlFlow::WaitUntil(
+                                            Instant::now()
+                                                + LOW_POWER_FRAME_INTERVAL,
+                                        )
+                                    }
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
 
@@ -905,8 +951,8 @@ async fn run_instance<P, C>(
                                     new_mouse_interaction;
                             }
 
-                            compositor.configure_surface(
-                                &mut window.surface,
+                            window.configure_surface(
+                                &mut compositor,
                                 physical_size.width,
                                 physical_size.height,
                             );
@@ -925,13 +971,69 @@ async fn run_instance<P, C>(
                         ) {
                             Ok(()) => {
                                 debug.render_finished();
+
+                                window.frame_time = frame_started_at.elapsed();
+
+                                if let Some(received_at) =
+                                    window.input_received_at.take()
+                                {
+                                    window.input_latency =
+                                        Some(received_at.elapsed());
+                                }
                             }
                             Err(error) => match error {
                                 // This is an unrecoverable error.
                                 compositor::SurfaceError::OutOfMemory => {
                                     panic!("{:?}", error);
                                 }
-                                _ => {
+                                compositor::SurfaceError::DeviceLost => {
+                                    debug.render_finished();
+
+                                    log::warn!(
+                                        "Graphics device was recreated; \
+                                        reconfiguring all windows."
+                                    );
+
+                                    for (id, window) in
+                                        window_manager.iter_mut()
+                                    {
+                                        let physical_size =
+                                            window.state.physical_size();
+
+                                        window.configure_surface(
+                                            &mut compositor,
+                                            physical_size.width,
+                                            physical_size.height,
+                                        );
+
+                                        window.renderer = compositor
+                                            .create_renderer_with(
+                                                window.text_antialiasing,
+                                            );
+
+                                        events.push((
+                                            id,
+                                            core::Event::Window(
+                                                window::Event::GraphicsRecovered,
+                                            ),
+                                        ));
+
+                                        window.raw.request_redraw();
+                                    }
+                                }
+                                compositor::SurfaceError::Lost
+                                | compositor::SurfaceError::Outdated => {
+                                    debug.render_finished();
+
+                                    window.configure_surface(
+                                        &mut compositor,
+                                        physical_size.width,
+                                        physical_size.height,
+                                    );
+
+                                    window.raw.request_redraw();
+                                }
+                                compositor::SurfaceError::Timeout => {
                                     debug.render_finished();
 
                                     log::error!(
@@ -988,26 +1090,134 @@ async fn run_instance<P, C>(
                                 core::Event::Window(window::Event::Closed),
                             ));
                         } else {
+                            // Live input is ignored while replaying
+                            if replay.is_some() && is_input(&window_event) {
+                                continue;
+                            }
+
+                            let is_input_event = is_input(&window_event);
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
                                 &mut debug,
                             );
 
+                            if let winit::event::WindowEvent::Touch(touch) =
+                                &window_event
+                            {
+                                if let Some(event) = conversion::pen_event(
+                                    *touch,
+                                    window.state.scale_factor(),
+                                ) {
+                                    events.push((id, core::Event::Pen(event)));
+                                }
+                            }
+
                             if let Some(event) = conversion::window_event(
                                 window_event,
                                 window.state.scale_factor(),
                                 window.state.modifiers(),
                             ) {
+                                if let core::Event::Touch(touch) = &event {
+                                    if !window.long_press.update(touch) {
+                                        continue;
+                                    }
+                                }
+
+                                // The earliest input not presented yet is
+                                // timed as soon as it enters the runtime
+                                if is_input_event
+                                    && window.input_received_at.is_none()
+                                {
+                                    window.input_received_at =
+                                        Some(Instant::now());
+                                }
+
                                 events.push((id, event));
                             }
                         }
                     }
                     event::Event::AboutToWait => {
+                        if let Some(replay) = &mut replay {
+                            let elapsed = started_at.elapsed();
+
+                            if let Some(frame) = replay.next(elapsed) {
+                                for (index, event) in frame.events {
+                                    let Some(&id) = window_ids.get(index)
+                                    else {
+                                        continue;
+                                    };
+
+                                    if let Some(window) =
+                                        window_manager.get_mut(id)
+                                    {
+                                        window.state.replay(&event);
+                                    }
+
+                                    events.push((id, event));
+                                }
+                            }
+
+                            if let Some(deadline) = replay.deadline() {
+                                let _ = control_sender.start_send(
+                                    Control::ChangeFlow(
+                                        ControlFlow::WaitUntil(
+                                            started_at + deadline,
+                                        ),
+                                    ),
+                                );
+                            }
+                        }
+
+                        if replay.as_ref().is_some_and(Replay::is_finished) {
+                            log::info!("Event replay finished");
+                            replay = None;
+                        }
+
+                        // Turn touch long presses into secondary clicks
+                        for (id, window) in window_manager.iter_mut() {
+                            if let Some(long_press) =
+                                window.long_press.recognize(Instant::now())
+                            {
+                                events.extend(
+                                    long_press
+                                        .into_iter()
+                                        .map(|event| (id, event)),
+                                );
+                            }
+
+                            if let Some(deadline) = window.long_press.deadline()
+                            {
+                                let _ = control_sender.start_send(
+                                    Control::ChangeFlow(
+                                        ControlFlow::WaitUntil(deadline),
+                                    ),
+                                );
+                            }
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
 
+                        if let Some(recorder) = &mut recorder {
+                            let frame =
+                                events.iter().filter_map(|(id, event)| {
+                                    let index = window_ids.iter().position(
+                                        |window_id| window_id == id,
+                                    )?;
+
+                                    Some((index, event))
+                                });
+
+                            if let Err(error) =
+                                recorder.record(started_at.elapsed(), frame)
+                            {
+                                log::error!("Error recording events: {error}");
+                            }
+                        }
+
                         debug.event_processing_started();
                         let mut uis_stale = false;
 
@@ -1027,16 +1237,34 @@ async fn run_instance<P, C>(
                                 continue;
                             }
 
-                            let (ui_state, statuses) = user_interfaces
+                            let interface = user_interfaces
                                 .get_mut(&id)
-                                .expect("Get user interface")
-                                .update(
-                                    &window_events,
-                                    window.state.cursor(),
-                                    &mut window.renderer,
-                                    &mut clipboard,
-                                    &mut messages,
-                                );
+                                .expect("Get user interface");
+
+                            let published = messages.len();
+                            interface.set_received_at(window.input_received_at);
+
+                            let (ui_state, statuses) = interface.update(
+                                &window_events,
+                                window.state.cursor(),
+                                &mut window.renderer,
+                                &mut clipboard,
+                                &mut messages,
+                            );
+
+                            // Input that changes nothing is not measured;
+                            // otherwise, its time would be reported by the
+                            // next unrelated frame
+                            if messages.len() == published
+                                && matches!(
+                                    ui_state,
+                                    user_interface::State::Updated {
+                                        redraw_request: None
+                                    }
+                                )
+                            {
+                                window.input_received_at = None;
+                            }
 
                             window.raw.request_redraw();
 
@@ -1122,6 +1350,149 @@ async fn run_instance<P, C>(
     let _ = ManuallyDrop::into_inner(user_interfaces);
 }
 
+/// Creates a [`Recorder`] if the `ICED_RECORD` environment variable
+/// contains the path of a file to record events to.
+fn recorder() -> Option<Recorder> {
+    let path = std::env::var_os("ICED_RECORD")?;
+
+    match Recorder::create(&path) {
+        Ok(recorder) => {
+            log::info!("Recording events to {path:?}");
+
+            Some(recorder)
+        }
+        Err(error) => {
+            log::error!("Error creating event recording {path:?}: {error}");
+
+            None
+        }
+    }
+}
+
+/// Serves snapshots of the widget tree on the address of the `ICED_INSPECT`
+/// environment variable, if any.
+///
+/// Every connection is served in its own thread, which sends a snapshot
+/// operation to the event loop for every `snapshot` line it reads and
+/// answers with its JSON.
+#[cfg(feature = "debug")]
+fn inspector<Message>(proxy: Proxy<Message>)
+where
+    Message: std::fmt::Debug + Send + 'static,
+{
+    use crate::runtime::inspector;
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    let Some(address) = std::env::var_os("ICED_INSPECT") else {
+        return;
+    };
+
+    let listener = match TcpListener::bind(&*address.to_string_lossy()) {
+        Ok(listener) => listener,
+        Err(error) => {
+            log::error!("Error inspecting widgets on {address:?}: {error}");
+
+            return;
+        }
+    };
+
+    log::info!("Inspecting widgets on {address:?}");
+
+    let _ = thread::Builder::new()
+        .name("iced-inspector".to_owned())
+        .spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut proxy = proxy.clone();
+
+                let _ = thread::Builder::new()
+                    .name("iced-inspector-connection".to_owned())
+                    .spawn(move || {
+                        let Ok(reader) = stream.try_clone() else {
+                            return;
+                        };
+
+                        let mut writer = stream;
+
+                        for line in BufReader::new(reader).lines() {
+                            let Ok(line) = line else {
+                                break;
+                            };
+
+                            let response = match line.trim() {
+                                "" => continue,
+                                "snapshot" => {
+                                    let (sender, receiver) = mpsc::channel();
+
+                                    proxy.send_action(Action::widget(
+                                        operation::map(
+                                            inspector::Snapshot::new(),
+                                            move |nodes| {
+                                                let _ = sender.send(nodes);
+                                            },
+                                        ),
+                                    ));
+
+                                    let Ok(nodes) = receiver.recv() else {
+                                        break;
+                                    };
+
+                                    inspector::to_json(&nodes)
+                                }
+                                _ => String::from(
+                                    "{\"error\":\"unknown request\"}",
+                                ),
+                            };
+
+                            if writeln!(writer, "{response}").is_err() {
+                                break;
+                            }
+                        }
+                    });
+            }
+        });
+}
+
+/// Creates a [`Replay`] if the `ICED_REPLAY` environment variable contains
+/// the path of a file with recorded events.
+fn replay() -> Option<Replay> {
+    let path = std::env::var_os("ICED_REPLAY")?;
+
+    match Recording::load(&path) {
+        Ok(recording) => {
+            log::info!("Replaying events from {path:?}");
+
+            Some(Replay::new(recording))
+        }
+        Err(error) => {
+            log::error!("Error loading event recording {path:?}: {error}");
+
+            None
+        }
+    }
+}
+
+/// Returns true if the given [`winit::event::WindowEvent`] is an input
+/// event that would be recorded.
+fn is_input(event: &winit::event::WindowEvent) -> bool {
+    use winit::event::WindowEvent;
+
+    matches!(
+        event,
+        WindowEvent::CursorMoved { .. }
+            | WindowEvent::CursorEntered { .. }
+            | WindowEvent::CursorLeft { .. }
+            | WindowEvent::MouseInput { .. }
+            | WindowEvent::MouseWheel { .. }
+            | WindowEvent::Touch(_)
+            | WindowEvent::KeyboardInput { .. }
+            | WindowEvent::ModifiersChanged(_)
+    )
+}
+
 /// Builds a window's [`UserInterface`] for the [`Program`].
 fn build_user_interface<'a, P: Program>(
     program: &'a P,
@@ -1185,6 +1556,7 @@ fn run_action<P, C>(
     window_manager: &mut WindowManager<P, C>,
     ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
     is_window_opening: &mut bool,
+    is_low_power: &mut bool,
 ) where
     P: Program,
     C: Compositor<Renderer = P::Renderer> + 'static,
@@ -1370,6 +1742,48 @@ fn run_action<P, C>(
                         .set_window_level(conversion::window_level(level));
                 }
             }
+            #[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+            window::Action::SetSkipTaskbar(id, skip) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "windows")]
+                    {
+                        use winit::platform::windows::WindowExtWindows;
+
+                        window.raw.set_skip_taskbar(skip);
+                    }
+                }
+            }
+            window::Action::SetMousePassthrough(id, passthrough) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ = window.raw.set_cursor_hittest(!passthrough);
+                }
+            }
+            window::Action::SetBlur(id, blur) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.raw.set_blur(blur);
+                }
+            }
+            #[cfg_attr(
+                not(any(target_os = "macos", target_os = "windows")),
+                allow(unused_variables)
+            )]
+            window::Action::SetShadow(id, shadow) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    #[cfg(target_os = "macos")]
+                    {
+                        use winit::platform::macos::WindowExtMacOS;
+
+                        window.raw.set_has_shadow(shadow);
+                    }
+
+                    #[cfg(target_os = "windows")]
+                    {
+                        use winit::platform::windows::WindowExtWindows;
+
+                        window.raw.set_undecorated_shadow(shadow);
+                    }
+                }
+            }
             window::Action::ShowSystemMenu(id) => {
                 if let Some(window) = window_manager.get_mut(id) {
                     if let mouse::Cursor::Available(point) =
@@ -1416,6 +1830,75 @@ fn run_action<P, C>(
                     ));
                 }
             }
+            window::Action::GetStatistics(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let statistics = compositor.statistics(&window.renderer);
+
+                    let _ = channel.send(window::Statistics {
+                        frame_time: window.frame_time,
+                        input_latency: window.input_latency,
+                        primitives: statistics.primitives,
+                        draw_calls: statistics.draw_calls,
+                    });
+                }
+            }
+            window::Action::ChangePresentMode(id, present_mode) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.present_mode = Some(present_mode);
+
+                    let physical_size = window.state.physical_size();
+
+                    if physical_size.width > 0 && physical_size.height > 0 {
+                        window.configure_surface(
+                            compositor,
+                            physical_size.width,
+                            physical_size.height,
+                        );
+                    }
+
+                    window.raw.request_redraw();
+                }
+            }
+            window::Action::GetPresentModes(id, channel) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    let _ =
+                        channel.send(compositor.present_modes(&window.surface));
+                }
+            }
+            window::Action::PickScreenColor(id, channel) => {
+                // Without an eyedropper, the last frame of the window is
+                // sampled under the cursor
+                let Err(channel) = crate::eyedropper::pick(channel) else {
+                    return;
+                };
+
+                if let Some(window) = window_manager.get_mut(id) {
+                    let color =
+                        window.state.cursor().position().and_then(|position| {
+                            let scale_factor =
+                                window.state.viewport().scale_factor();
+
+                            let screenshot = window::Screenshot::new(
+                                compositor.screenshot(
+                                    &mut window.renderer,
+                                    &mut window.surface,
+                                    window.state.viewport(),
+                                    window.state.background_color(),
+                                    &debug.overlay(),
+                                ),
+                                window.state.physical_size(),
+                                scale_factor,
+                            );
+
+                            screenshot.pixel(
+                                (f64::from(position.x) * scale_factor) as u32,
+                                (f64::from(position.y) * scale_factor) as u32,
+                            )
+                        });
+
+                    let _ = channel.send(color);
+                }
+            }
         },
         Action::System(action) => match action {
             system::Action::QueryInformation(_channel) => {
@@ -1431,6 +1914,17 @@ fn run_action<P, C>(
                     });
                 }
             }
+            system::Action::SetLowPower(low_power) => {
+                *is_low_power = low_power;
+            }
+            system::Action::QueryGraphics(channel) => {
+                let information = compositor.fetch_information();
+
+                let _ = channel.send(system::Graphics {
+                    backend: information.backend,
+                    adapter: information.adapter,
+                });
+            }
         },
         Action::Widget(operation) => {
             let mut current_operation = Some(operation);
@@ -1439,6 +1933,9 @@ fn run_action<P, C>(
                 for (id, ui) in interfaces.iter_mut() {
                     if let Some(window) = window_manager.get_mut(*id) {
                         ui.operate(&window.renderer, operation.as_mut());
+
+                        // Widgets may react to an operation on their next event
+                        window.raw.request_redraw();
                     }
                 }
 
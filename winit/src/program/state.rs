@@ -1,6 +1,6 @@
 use crate::conversion;
-use crate::core::{mouse, window};
-use crate::core::{Color, Size};
+use crate::core::{mouse, touch, window};
+use crate::core::{Color, Event, Size};
 use crate::graphics::Viewport;
 use crate::program::{self, Program};
 use std::fmt::{Debug, Formatter};
@@ -195,6 +195,35 @@ where
         }
     }
 
+    /// Updates the cursor position of the [`State`] with a replayed
+    /// [`Event`].
+    ///
+    /// Replayed events do not come from the windowing system; so they need
+    /// to keep the cursor in sync themselves.
+    pub fn replay(&mut self, event: &Event) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(
+                touch::Event::FingerPressed { position, .. }
+                | touch::Event::FingerMoved { position, .. }
+                | touch::Event::FingerLifted { position, .. }
+                | touch::Event::FingerLost { position, .. },
+            ) => {
+                self.cursor_position = Some(
+                    winit::dpi::LogicalPosition::new(
+                        f64::from(position.x),
+                        f64::from(position.y),
+                    )
+                    .to_physical(self.viewport.scale_factor()),
+                );
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.cursor_position = None;
+            }
+            _ => {}
+        }
+    }
+
     /// Synchronizes the [`State`] with its [`Program`] and its respective
     /// window.
     ///
@@ -1,5 +1,8 @@
 use crate::core::mouse;
-use crate::core::window::Id;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::window::{Id, PresentMode};
 use crate::core::{Point, Size};
 use crate::graphics::Compositor;
 use crate::program::{DefaultStyle, Program, State};
@@ -39,16 +42,29 @@ where
         application: &P,
         compositor: &mut C,
         exit_on_close_request: bool,
+        text_antialiasing: text::Antialiasing,
+        present_mode: Option<PresentMode>,
     ) -> &mut Window<P, C> {
         let state = State::new(application, id, &window);
         let viewport_version = state.viewport_version();
         let physical_size = state.physical_size();
-        let surface = compositor.create_surface(
+        let mut surface = compositor.create_surface(
             window.clone(),
             physical_size.width,
             physical_size.height,
         );
-        let renderer = compositor.create_renderer();
+
+        if let Some(present_mode) = present_mode {
+            if physical_size.width > 0 && physical_size.height > 0 {
+                compositor.configure_surface_with(
+                    &mut surface,
+                    physical_size.width,
+                    physical_size.height,
+                    present_mode,
+                );
+            }
+        }
+        let renderer = compositor.create_renderer_with(text_antialiasing);
 
         let _ = self.aliases.insert(window.id(), id);
 
@@ -59,9 +75,15 @@ where
                 state,
                 viewport_version,
                 exit_on_close_request,
+                text_antialiasing,
                 surface,
                 renderer,
+                frame_time: Duration::ZERO,
+                input_received_at: None,
+                input_latency: None,
+                present_mode,
                 mouse_interaction: mouse::Interaction::None,
+                long_press: touch::LongPress::default(),
             },
         );
 
@@ -127,9 +149,15 @@ where
     pub state: State<P>,
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
+    pub text_antialiasing: text::Antialiasing,
     pub mouse_interaction: mouse::Interaction,
+    pub long_press: touch::LongPress,
     pub surface: C::Surface,
     pub renderer: P::Renderer,
+    pub frame_time: Duration,
+    pub input_received_at: Option<Instant>,
+    pub input_latency: Option<Duration>,
+    pub present_mode: Option<PresentMode>,
 }
 
 impl<P, C> Window<P, C>
@@ -154,4 +182,23 @@ where
 
         Size::new(size.width, size.height)
     }
+
+    pub fn configure_surface(
+        &mut self,
+        compositor: &mut C,
+        width: u32,
+        height: u32,
+    ) {
+        match self.present_mode {
+            Some(present_mode) => compositor.configure_surface_with(
+                &mut self.surface,
+                width,
+                height,
+                present_mode,
+            ),
+            None => {
+                compositor.configure_surface(&mut self.surface, width, height)
+            }
+        }
+    }
 }
@@ -3,6 +3,8 @@ use crate::graphics::compositor;
 use crate::runtime::system::{Action, Information};
 use crate::runtime::{self, Task};
 
+pub mod power;
+
 /// Query for available system information.
 pub fn fetch_information() -> Task<Information> {
     runtime::task::oneshot(|channel| {
@@ -0,0 +1,302 @@
+//! Manage the power of the native system.
+use crate::runtime::system::Action;
+use crate::runtime::{self, Task};
+
+use std::io;
+use std::sync::Arc;
+
+/// What an [`Inhibitor`] prevents the system from doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inhibit {
+    /// The system will not go to sleep; but the display may still turn off.
+    ///
+    /// Useful for long operations, like downloads or exports.
+    Sleep,
+
+    /// Neither the system will go to sleep, nor the display will turn off or
+    /// start the screensaver.
+    ///
+    /// Useful for media playback and presentations.
+    Display,
+}
+
+/// An active inhibition of the power management of the system.
+///
+/// The inhibition is released once the [`Inhibitor`] and all of its clones
+/// are dropped.
+#[derive(Debug, Clone)]
+pub struct Inhibitor {
+    _guard: Arc<platform::Guard>,
+}
+
+/// An error that occurred while inhibiting the power management of the system.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// Inhibiting power management is not supported on the current platform.
+    #[error("power management cannot be inhibited on this platform")]
+    Unsupported,
+
+    /// The platform failed to acquire the inhibition.
+    #[error("the power management could not be inhibited: {0}")]
+    Io(Arc<io::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+/// Inhibits the power management of the system with the given `reason`,
+/// producing an [`Inhibitor`] that must be kept alive while the inhibition
+/// is needed.
+///
+/// The `reason` may be shown to the user by the platform.
+pub fn inhibit(
+    what: Inhibit,
+    reason: impl Into<String>,
+) -> Task<Result<Inhibitor, Error>> {
+    let reason = reason.into();
+
+    Task::future(async move {
+        let guard = platform::inhibit(what, &reason)?;
+
+        Ok(Inhibitor {
+            _guard: Arc::new(guard),
+        })
+    })
+}
+
+/// Hints whether the application should save power; like when the system
+/// runs on battery.
+///
+/// In low-power mode, animations requesting redraws on every frame are
+/// paced at a lower frame rate.
+pub fn set_low_power<T>(enabled: bool) -> Task<T> {
+    runtime::task::effect(runtime::Action::System(Action::SetLowPower(enabled)))
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{Error, Inhibit};
+
+    use std::io;
+
+    use zbus::blocking::Connection;
+    use zbus::zvariant::OwnedFd;
+
+    #[derive(Debug)]
+    pub struct Guard {
+        // The lock is released once its file descriptor is closed
+        _lock: OwnedFd,
+        screensaver: Option<ScreenSaver>,
+    }
+
+    #[derive(Debug)]
+    struct ScreenSaver {
+        connection: Connection,
+        cookie: u32,
+    }
+
+    pub fn inhibit(what: Inhibit, reason: &str) -> Result<Guard, Error> {
+        let who = std::env::current_exe()
+            .ok()
+            .and_then(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+            .unwrap_or_else(|| String::from("iced"));
+
+        let lock = login(what, &who, reason).map_err(io::Error::other)?;
+
+        // Most screensavers do not honor the idle locks of logind
+        let screensaver = match what {
+            Inhibit::Sleep => None,
+            Inhibit::Display => Some(
+                ScreenSaver::inhibit(&who, reason).map_err(io::Error::other)?,
+            ),
+        };
+
+        Ok(Guard {
+            _lock: lock,
+            screensaver,
+        })
+    }
+
+    fn login(what: Inhibit, who: &str, reason: &str) -> zbus::Result<OwnedFd> {
+        let connection = Connection::system()?;
+
+        let what = match what {
+            Inhibit::Sleep => "sleep",
+            Inhibit::Display => "idle:sleep",
+        };
+
+        connection
+            .call_method(
+                Some("org.freedesktop.login1"),
+                "/org/freedesktop/login1",
+                Some("org.freedesktop.login1.Manager"),
+                "Inhibit",
+                &(what, who, reason, "block"),
+            )?
+            .body()
+            .deserialize()
+    }
+
+    impl ScreenSaver {
+        const DESTINATION: &'static str = "org.freedesktop.ScreenSaver";
+        const PATH: &'static str = "/org/freedesktop/ScreenSaver";
+
+        fn inhibit(who: &str, reason: &str) -> zbus::Result<Self> {
+            // The inhibition is also released if the connection is closed
+            let connection = Connection::session()?;
+
+            let cookie = connection
+                .call_method(
+                    Some(Self::DESTINATION),
+                    Self::PATH,
+                    Some(Self::DESTINATION),
+                    "Inhibit",
+                    &(who, reason),
+                )?
+                .body()
+                .deserialize()?;
+
+            Ok(Self { connection, cookie })
+        }
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            if let Some(screensaver) = &self.screensaver {
+                let _ = screensaver.connection.call_method(
+                    Some(ScreenSaver::DESTINATION),
+                    ScreenSaver::PATH,
+                    Some(ScreenSaver::DESTINATION),
+                    "UnInhibit",
+                    &(screensaver.cookie,),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{Error, Inhibit};
+
+    use std::io;
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use std::time::Duration;
+
+    /// The time given to `caffeinate` to fail before the assertion is
+    /// considered acquired.
+    const STARTUP: Duration = Duration::from_millis(100);
+
+    #[derive(Debug)]
+    pub struct Guard(Child);
+
+    pub fn inhibit(what: Inhibit, _reason: &str) -> Result<Guard, Error> {
+        let mut child = Command::new("caffeinate")
+            .arg(match what {
+                Inhibit::Sleep => "-i",
+                Inhibit::Display => "-id",
+            })
+            // The assertion is held until either the guard is dropped or the
+            // application exits
+            .args(["-w", &std::process::id().to_string()])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        thread::sleep(STARTUP);
+
+        if let Some(status) = child.try_wait()? {
+            return Err(io::Error::other(format!(
+                "caffeinate exited with {status}"
+            ))
+            .into());
+        }
+
+        Ok(Guard(child))
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{Error, Inhibit};
+
+    use std::io;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use winapi::um::winbase::SetThreadExecutionState;
+    use winapi::um::winnt::{
+        ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED,
+    };
+
+    #[derive(Debug)]
+    pub struct Guard {
+        _release: mpsc::Sender<()>,
+    }
+
+    pub fn inhibit(what: Inhibit, _reason: &str) -> Result<Guard, Error> {
+        let flags = match what {
+            Inhibit::Sleep => ES_CONTINUOUS | ES_SYSTEM_REQUIRED,
+            Inhibit::Display => {
+                ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED
+            }
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let (ready, result) = mpsc::channel();
+
+        // The execution state is tied to the thread that sets it
+        let _ = thread::Builder::new()
+            .name("iced-power-inhibitor".to_owned())
+            .spawn(move || {
+                #[allow(unsafe_code)]
+                let previous = unsafe { SetThreadExecutionState(flags) };
+
+                let _ = ready.send(previous != 0);
+
+                if previous == 0 {
+                    return;
+                }
+
+                // Blocks until the guard is dropped
+                let _ = receiver.recv();
+
+                #[allow(unsafe_code)]
+                let _ = unsafe { SetThreadExecutionState(ES_CONTINUOUS) };
+            })?;
+
+        if result.recv().unwrap_or(false) {
+            Ok(Guard { _release: sender })
+        } else {
+            Err(io::Error::other("the execution state could not be set").into())
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+mod platform {
+    use super::{Error, Inhibit};
+
+    #[derive(Debug)]
+    pub struct Guard;
+
+    pub fn inhibit(_what: Inhibit, _reason: &str) -> Result<Guard, Error> {
+        Err(Error::Unsupported)
+    }
+}